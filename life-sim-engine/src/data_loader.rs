@@ -0,0 +1,763 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use crate::migrations;
+use crate::remote_data;
+use crate::validation;
+use crate::models::{EventCard, Action, Decision, Job, Ending, EconomyConfig, EducationProgram, SideGig, InsurancePlan, HousingTier, TransportOption, StageDefinition, LifePath, TutorialStep, Scenario, StatDefinition, SpecialEffect, RarityTier, Milestone, CuratedSeed, Stage};
+use crate::engine::scripting::Scripts;
+
+/// All game data loaded from the data directory. Not Clone: the server shares one load
+/// between games through `Arc`.
+#[derive(Debug)]
+pub struct GameData {
+    /// Stage definitions, in play order.
+    pub stages: Vec<StageDefinition>,
+    pub events: Vec<EventCard>,
+    pub actions: Vec<Action>,
+    pub decisions: Vec<Decision>,
+    pub jobs: Vec<Job>,
+    pub endings: Vec<Ending>,
+    pub economy: EconomyConfig,
+    pub education: Vec<EducationProgram>,
+    pub side_gigs: Vec<SideGig>,
+    pub insurance: Vec<InsurancePlan>,
+    pub housing: Vec<HousingTier>,
+    pub transport: Vec<TransportOption>,
+    pub paths: Vec<LifePath>,
+    /// Scripted opening turns for tutorial games.
+    pub tutorial: Vec<TutorialStep>,
+    /// Preconfigured starts selectable at new_game.
+    pub scenarios: Vec<Scenario>,
+    /// Player stats: the engine's own plus any the content adds.
+    pub stats: Vec<StatDefinition>,
+    /// Scripted hooks actions can run, by `specialEffect`.
+    pub special_effects: Vec<SpecialEffect>,
+    /// Card rarity tiers and their draw weights.
+    pub rarities: Vec<RarityTier>,
+    /// Intermediate goals checked every turn.
+    pub milestones: Vec<Milestone>,
+    /// Playtested seeds teachers can pick from.
+    pub seeds: Vec<CuratedSeed>,
+    /// Which pack supplied each entry: data file name (e.g. "events") → entry ID → pack name ("base" for the main directory).
+    pub sources: BTreeMap<String, BTreeMap<String, String>>,
+    index: DataIndex,
+    scripts: Scripts,
+}
+
+/// Positions of entries in GameData's lists by ID, and of the stage-bound ones by stage,
+/// so lookups on every turn don't scan the lists. Built once the data is loaded; entries
+/// edited in place stay visible through it.
+#[derive(Debug, Default)]
+struct DataIndex {
+    events: HashMap<String, usize>,
+    actions: HashMap<String, usize>,
+    decisions: HashMap<String, usize>,
+    jobs: HashMap<String, usize>,
+    education: HashMap<String, usize>,
+    side_gigs: HashMap<String, usize>,
+    insurance: HashMap<String, usize>,
+    housing: HashMap<String, usize>,
+    transport: HashMap<String, usize>,
+    paths: HashMap<String, usize>,
+    scenarios: HashMap<String, usize>,
+    stats: HashMap<String, usize>,
+    rarities: HashMap<String, usize>,
+    /// Stage → positions, in file order.
+    stage_events: HashMap<Stage, Vec<usize>>,
+    stage_actions: HashMap<Stage, Vec<usize>>,
+    stage_decisions: HashMap<Stage, Vec<usize>>,
+}
+
+impl DataIndex {
+    fn build(data: &GameData) -> Self {
+        Self {
+            events: by_id(&data.events, |e| &e.id),
+            actions: by_id(&data.actions, |a| &a.id),
+            decisions: by_id(&data.decisions, |d| &d.id),
+            jobs: by_id(&data.jobs, |j| &j.id),
+            education: by_id(&data.education, |p| &p.id),
+            side_gigs: by_id(&data.side_gigs, |g| &g.id),
+            insurance: by_id(&data.insurance, |p| &p.id),
+            housing: by_id(&data.housing, |h| &h.id),
+            transport: by_id(&data.transport, |t| &t.id),
+            paths: by_id(&data.paths, |p| &p.id),
+            scenarios: by_id(&data.scenarios, |s| &s.id),
+            stats: by_id(&data.stats, |s| &s.id),
+            rarities: by_id(&data.rarities, |r| &r.id),
+            stage_events: by_stage(&data.events, |e| &e.stages),
+            stage_actions: by_stage(&data.actions, |a| &a.stages),
+            stage_decisions: by_stage(&data.decisions, |d| std::slice::from_ref(&d.stage)),
+        }
+    }
+}
+
+/// ID → position. Like a scan from the front, the first entry wins if an ID repeats.
+fn by_id<T>(items: &[T], id: impl Fn(&T) -> &String) -> HashMap<String, usize> {
+    let mut index = HashMap::with_capacity(items.len());
+    for (i, item) in items.iter().enumerate() {
+        index.entry(id(item).clone()).or_insert(i);
+    }
+    index
+}
+
+fn by_stage<T>(items: &[T], stages: impl Fn(&T) -> &[Stage]) -> HashMap<Stage, Vec<usize>> {
+    let mut index: HashMap<Stage, Vec<usize>> = HashMap::new();
+    for (i, item) in items.iter().enumerate() {
+        for stage in stages(item) {
+            index.entry(stage.clone()).or_default().push(i);
+        }
+    }
+    index
+}
+
+/// Look up one entry by ID through the index.
+fn get<'a, T>(items: &'a [T], index: &HashMap<String, usize>, id: &str) -> Option<&'a T> {
+    index.get(id).and_then(|&i| items.get(i))
+}
+
+fn in_stage<'a, T>(items: &'a [T], index: &'a HashMap<Stage, Vec<usize>>, stage: &Stage) -> impl Iterator<Item = &'a T> {
+    index.get(stage).into_iter().flatten().filter_map(|&i| items.get(i))
+}
+
+impl GameData {
+    /// Load all game data from the data files (JSON, YAML, or TOML) in the given directory.
+    pub fn load_from_dir(data_dir: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        Self::load(&DataSource::Dir(data_dir.to_path_buf()), &[])
+    }
+
+    /// Load the base data set, then layer each content pack directory over it in order.
+    /// A pack only needs the files it changes; an entry whose ID already exists
+    /// replaces the earlier one, and new IDs are appended.
+    /// Every file is read and cross-checked before returning, so a bad edit
+    /// reports all of its problems at once instead of stopping at the first.
+    pub fn load(base: &DataSource, packs: &[PathBuf]) -> Result<Self, Box<dyn std::error::Error>> {
+        if let DataSource::Remote { url, cache } = base {
+            remote_data::sync(url, cache).map_err(|e| DataErrors(vec![e]))?;
+        }
+        let mut loader = PackLoader {
+            base,
+            packs,
+            sources: BTreeMap::new(),
+            errors: Vec::new(),
+        };
+        let stages: Vec<StageDefinition> = loader.load_list("stages");
+        let events: Vec<EventCard> = loader.load_list("events");
+        let actions: Vec<Action> = loader.load_list("actions");
+        let decisions: Vec<Decision> = loader.load_list("decisions");
+        let jobs: Vec<Job> = loader.load_list("jobs");
+        let endings: Vec<Ending> = loader.load_list("endings");
+        let economy: EconomyConfig = loader.load_economy();
+        let education: Vec<EducationProgram> = loader.load_list("education");
+        let side_gigs: Vec<SideGig> = loader.load_list("side_gigs");
+        let insurance: Vec<InsurancePlan> = loader.load_list("insurance");
+        let housing: Vec<HousingTier> = loader.load_list("housing");
+        let transport: Vec<TransportOption> = loader.load_list("transport");
+        let paths: Vec<LifePath> = loader.load_list("paths");
+        let tutorial: Vec<TutorialStep> = loader.load_list("tutorial");
+        let scenarios: Vec<Scenario> = loader.load_list("scenarios");
+        let stats: Vec<StatDefinition> = loader.load_list("stats");
+        let special_effects: Vec<SpecialEffect> = loader.load_list("special_effects");
+        let rarities: Vec<RarityTier> = loader.load_list("rarities");
+        let milestones: Vec<Milestone> = loader.load_list("milestones");
+        let seeds: Vec<CuratedSeed> = loader.load_list("seeds");
+        let PackLoader { sources, mut errors, .. } = loader;
+        let (scripts, script_errors) = Scripts::compile(&special_effects);
+        errors.extend(script_errors);
+
+        let mut data = Self {
+            stages,
+            events,
+            actions,
+            decisions,
+            jobs,
+            endings,
+            economy,
+            education,
+            side_gigs,
+            insurance,
+            housing,
+            transport,
+            paths,
+            tutorial,
+            scenarios,
+            stats,
+            special_effects,
+            rarities,
+            milestones,
+            seeds,
+            sources,
+            index: DataIndex::default(),
+            scripts,
+        };
+        data.index = DataIndex::build(&data);
+        errors.extend(validation::validate(&data));
+        if !errors.is_empty() {
+            return Err(Box::new(DataErrors(errors)));
+        }
+
+        tracing::info!(
+            stages = data.stages.len(),
+            events = data.events.len(),
+            actions = data.actions.len(),
+            decisions = data.decisions.len(),
+            jobs = data.jobs.len(),
+            endings = data.endings.len(),
+            education_programs = data.education.len(),
+            side_gigs = data.side_gigs.len(),
+            insurance_plans = data.insurance.len(),
+            housing_tiers = data.housing.len(),
+            transport_options = data.transport.len(),
+            life_paths = data.paths.len(),
+            tutorial_steps = data.tutorial.len(),
+            scenarios = data.scenarios.len(),
+            milestones = data.milestones.len(),
+            curated_seeds = data.seeds.len(),
+            "Loaded game data from {}", base,
+        );
+        for pack in packs {
+            tracing::info!(pack = %pack_name(pack), "Layered a data pack over the game data");
+        }
+
+        Ok(data)
+    }
+
+    pub fn event(&self, id: &str) -> Option<&EventCard> {
+        get(&self.events, &self.index.events, id)
+    }
+
+    pub fn action(&self, id: &str) -> Option<&Action> {
+        get(&self.actions, &self.index.actions, id)
+    }
+
+    pub fn decision(&self, id: &str) -> Option<&Decision> {
+        get(&self.decisions, &self.index.decisions, id)
+    }
+
+    pub fn job(&self, id: &str) -> Option<&Job> {
+        get(&self.jobs, &self.index.jobs, id)
+    }
+
+    pub fn program(&self, id: &str) -> Option<&EducationProgram> {
+        get(&self.education, &self.index.education, id)
+    }
+
+    pub fn side_gig(&self, id: &str) -> Option<&SideGig> {
+        get(&self.side_gigs, &self.index.side_gigs, id)
+    }
+
+    pub fn insurance_plan(&self, id: &str) -> Option<&InsurancePlan> {
+        get(&self.insurance, &self.index.insurance, id)
+    }
+
+    pub fn housing_tier(&self, id: &str) -> Option<&HousingTier> {
+        get(&self.housing, &self.index.housing, id)
+    }
+
+    pub fn transport_option(&self, id: &str) -> Option<&TransportOption> {
+        get(&self.transport, &self.index.transport, id)
+    }
+
+    pub fn path(&self, id: &str) -> Option<&LifePath> {
+        get(&self.paths, &self.index.paths, id)
+    }
+
+    pub fn scenario(&self, id: &str) -> Option<&Scenario> {
+        get(&self.scenarios, &self.index.scenarios, id)
+    }
+
+    pub fn stat(&self, id: &str) -> Option<&StatDefinition> {
+        get(&self.stats, &self.index.stats, id)
+    }
+
+    pub fn rarity(&self, id: &str) -> Option<&RarityTier> {
+        get(&self.rarities, &self.index.rarities, id)
+    }
+
+    /// The compiled special-effect scripts.
+    pub fn scripts(&self) -> &Scripts {
+        &self.scripts
+    }
+
+    /// Event cards that can be drawn in a stage, in file order.
+    pub fn stage_events(&self, stage: &Stage) -> impl Iterator<Item = &EventCard> {
+        in_stage(&self.events, &self.index.stage_events, stage)
+    }
+
+    /// The stage's deck: its event cards in file order, less its transition cards.
+    pub fn stage_deck(&self, stage: &Stage) -> impl Iterator<Item = &EventCard> {
+        self.stage_events(stage).filter(|e| !e.transition)
+    }
+
+    /// The transition cards that can open a stage, in file order.
+    pub fn stage_transitions(&self, stage: &Stage) -> impl Iterator<Item = &EventCard> {
+        self.stage_events(stage).filter(|e| e.transition)
+    }
+
+    /// Actions offered in a stage, in file order.
+    pub fn stage_actions(&self, stage: &Stage) -> impl Iterator<Item = &Action> {
+        in_stage(&self.actions, &self.index.stage_actions, stage)
+    }
+
+    /// Decisions scheduled in a stage, in file order.
+    pub fn stage_decisions(&self, stage: &Stage) -> impl Iterator<Item = &Decision> {
+        in_stage(&self.decisions, &self.index.stage_decisions, stage)
+    }
+}
+
+/// Where the base data set is read from.
+#[derive(Debug, Clone)]
+pub enum DataSource {
+    /// A directory of data files on disk.
+    Dir(PathBuf),
+    /// The shipped data files compiled into the binary (with the `embedded-data` feature).
+    #[cfg(feature = "embedded-data")]
+    Embedded,
+    /// A content set hosted at an HTTP(S) base URL, synced into a local cache directory.
+    Remote { url: String, cache: PathBuf },
+}
+
+impl std::fmt::Display for DataSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DataSource::Dir(dir) => write!(f, "{}", dir.display()),
+            #[cfg(feature = "embedded-data")]
+            DataSource::Embedded => write!(f, "built-in data"),
+            DataSource::Remote { url, .. } => write!(f, "{}", url),
+        }
+    }
+}
+
+/// The shipped data directory, compiled in so the binary runs without data/ on disk.
+#[cfg(feature = "embedded-data")]
+const EMBEDDED_DATA: [(&str, &str); 20] = [
+    ("stages.json", include_str!("../../data/stages.json")),
+    ("events.json", include_str!("../../data/events.json")),
+    ("actions.json", include_str!("../../data/actions.json")),
+    ("decisions.json", include_str!("../../data/decisions.json")),
+    ("jobs.json", include_str!("../../data/jobs.json")),
+    ("endings.json", include_str!("../../data/endings.json")),
+    ("economy.json", include_str!("../../data/economy.json")),
+    ("education.json", include_str!("../../data/education.json")),
+    ("side_gigs.json", include_str!("../../data/side_gigs.json")),
+    ("insurance.json", include_str!("../../data/insurance.json")),
+    ("housing.json", include_str!("../../data/housing.json")),
+    ("transport.json", include_str!("../../data/transport.json")),
+    ("paths.json", include_str!("../../data/paths.json")),
+    ("tutorial.json", include_str!("../../data/tutorial.json")),
+    ("scenarios.json", include_str!("../../data/scenarios.json")),
+    ("stats.json", include_str!("../../data/stats.json")),
+    ("special_effects.json", include_str!("../../data/special_effects.json")),
+    ("rarities.json", include_str!("../../data/rarities.json")),
+    ("milestones.json", include_str!("../../data/milestones.json")),
+    ("seeds.json", include_str!("../../data/seeds.json")),
+];
+
+/// The live data set and where it came from. Reloading swaps in a new
+/// data set atomically; games already in progress keep the Arc they started with.
+pub struct DataStore {
+    base: DataSource,
+    packs: Vec<PathBuf>,
+    current: RwLock<Arc<GameData>>,
+}
+
+impl DataStore {
+    pub fn new(base: DataSource, packs: Vec<PathBuf>, data: GameData) -> Self {
+        Self { base, packs, current: RwLock::new(Arc::new(data)) }
+    }
+
+    /// The data set new games should start with.
+    pub fn current(&self) -> Arc<GameData> {
+        self.current.read().unwrap().clone()
+    }
+
+    /// Re-read and validate the data set, swapping it in only if it loads cleanly.
+    pub fn reload(&self) -> Result<Arc<GameData>, Box<dyn std::error::Error>> {
+        let data = Arc::new(GameData::load(&self.base, &self.packs)?);
+        *self.current.write().unwrap() = data.clone();
+        Ok(data)
+    }
+}
+
+/// Every problem found while loading the data directory.
+#[derive(Debug)]
+pub struct DataErrors(pub Vec<String>);
+
+impl std::fmt::Display for DataErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} problem(s) in game data:", self.0.len())?;
+        for error in &self.0 {
+            writeln!(f, "  - {}", error)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for DataErrors {}
+
+/// File extensions a data file may use, checked in this order.
+const DATA_EXTENSIONS: [&str; 4] = ["json", "yaml", "yml", "toml"];
+
+/// Find the data file for `name` (e.g. "events") in a directory, whatever its format.
+/// Returns None if there is none, and an error if more than one format is present.
+fn find_data_file(dir: &Path, name: &str) -> Result<Option<PathBuf>, String> {
+    let found: Vec<PathBuf> = DATA_EXTENSIONS.iter()
+        .map(|ext| dir.join(format!("{}.{}", name, ext)))
+        .filter(|path| path.exists())
+        .collect();
+    match found.len() {
+        0 => Ok(None),
+        1 => Ok(found.into_iter().next()),
+        _ => Err(format!(
+            "{} has more than one {} file ({}); keep only one",
+            dir.display(),
+            name,
+            found.iter().map(|p| file_label(p)).collect::<Vec<_>>().join(", ")
+        )),
+    }
+}
+
+/// A data file's contents, ready to parse.
+struct DataFile {
+    /// File name without its directory, for entry error labels.
+    file: String,
+    /// Full location, for read and parse errors.
+    location: String,
+    content: Cow<'static, str>,
+}
+
+/// The embedded copy of a data file, if one was shipped.
+#[cfg(feature = "embedded-data")]
+fn embedded_file(name: &str) -> Option<DataFile> {
+    EMBEDDED_DATA.iter()
+        .find(|(file, _)| file.strip_suffix(".json") == Some(name))
+        .map(|(file, content)| DataFile {
+            file: file.to_string(),
+            location: format!("built-in {}", file),
+            content: Cow::Borrowed(content),
+        })
+}
+
+/// Parse a JSON, YAML, or TOML data file, choosing the parser by extension, and
+/// upgrade it to the current format version. List files come back as their array of entries.
+fn parse_data_file(data: &DataFile, name: &str) -> Result<serde_json::Value, String> {
+    let content = data.content.as_ref();
+    let parsed = match Path::new(&data.file).extension().and_then(|ext| ext.to_str()) {
+        Some("yaml" | "yml") => serde_yaml::from_str(content).map_err(|e| e.to_string()),
+        Some("toml") => toml::from_str::<toml::Value>(content)
+            .map_err(|e| e.to_string())
+            .and_then(|v| serde_json::to_value(v).map_err(|e| e.to_string())),
+        _ => serde_json::from_str(content).map_err(|e| e.to_string()),
+    };
+    let parsed = parsed.map_err(|e| format!("Failed to parse {}: {}", data.location, e))?;
+    let (contents, version) = migrations::upgrade(name, parsed)
+        .map_err(|e| format!("{}: {}", data.location, e))?;
+    if version < migrations::CURRENT_FORMAT_VERSION {
+        tracing::info!(
+            "{} is format version {}; upgraded to {} while loading",
+            data.location, version, migrations::CURRENT_FORMAT_VERSION
+        );
+    }
+    Ok(contents)
+}
+
+/// A data file's name without its directory, for error labels.
+fn file_label(path: &Path) -> String {
+    path.file_name().map_or_else(String::new, |n| n.to_string_lossy().into_owned())
+}
+
+/// Display name for a data directory: its final path component.
+fn pack_name(dir: &Path) -> String {
+    dir.file_name()
+        .map_or_else(|| dir.display().to_string(), |n| n.to_string_lossy().into_owned())
+}
+
+/// Reads each data file from the base data set and every pack, merging entries
+/// by ID and collecting errors along the way.
+struct PackLoader<'a> {
+    base: &'a DataSource,
+    packs: &'a [PathBuf],
+    sources: BTreeMap<String, BTreeMap<String, String>>,
+    errors: Vec<String>,
+}
+
+impl PackLoader<'_> {
+    /// Name recorded for entries from layer `index` (0 is the base data set, then each pack).
+    fn source_name(&self, index: usize) -> String {
+        if index == 0 { "base".to_string() } else { pack_name(&self.packs[index - 1]) }
+    }
+
+    /// Find and read the `name` data file in layer `index`, if that layer has one.
+    fn locate(&self, index: usize, name: &str) -> Result<Option<DataFile>, String> {
+        let dir = match (index, self.base) {
+            #[cfg(feature = "embedded-data")]
+            (0, DataSource::Embedded) => return Ok(embedded_file(name)),
+            (0, DataSource::Dir(dir) | DataSource::Remote { cache: dir, .. }) => dir.as_path(),
+            _ => self.packs[index - 1].as_path(),
+        };
+        let Some(path) = find_data_file(dir, name)? else {
+            return Ok(None);
+        };
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        Ok(Some(DataFile {
+            file: file_label(&path),
+            location: path.display().to_string(),
+            content: Cow::Owned(content),
+        }))
+    }
+
+    /// Read the `name` data file from every layer that has one, in order.
+    /// The file is required in the base data set and optional in packs.
+    fn read_all(&mut self, name: &str) -> Vec<(usize, String, serde_json::Value)> {
+        let mut files = Vec::new();
+        for index in 0..=self.packs.len() {
+            let data = match self.locate(index, name) {
+                Ok(Some(data)) => data,
+                Ok(None) if index > 0 => continue,
+                Ok(None) => {
+                    self.errors.push(format!(
+                        "Missing {} data file in {} (expected {}.json, .yaml, or .toml)",
+                        name, self.base, name
+                    ));
+                    continue;
+                }
+                Err(e) => {
+                    self.errors.push(e);
+                    continue;
+                }
+            };
+            match parse_data_file(&data, name) {
+                Ok(value) => files.push((index, data.file, value)),
+                Err(e) => self.errors.push(e),
+            }
+        }
+        files
+    }
+
+    /// Load a list data file item by item across all directories, recording a
+    /// labeled error for each entry that fails to deserialize and keeping the rest.
+    fn load_list<T: serde::de::DeserializeOwned>(&mut self, name: &str) -> Vec<T> {
+        // (id label, source index, file label, raw item) in final order
+        let mut merged: Vec<(String, usize, String, serde_json::Value)> = Vec::new();
+        for (index, file, value) in self.read_all(name) {
+            let items = match value {
+                serde_json::Value::Array(items) => items,
+                _ => {
+                    self.errors.push(format!("{}: expected a list of entries", file));
+                    continue;
+                }
+            };
+            for (i, item) in items.into_iter().enumerate() {
+                let id = item.get("id").and_then(|v| v.as_str()).map(str::to_string);
+                match id.as_ref().and_then(|id| merged.iter().position(|(label, ..)| label == id)) {
+                    Some(pos) if index > 0 => merged[pos] = (id.unwrap(), index, file.clone(), item),
+                    _ => merged.push((id.unwrap_or_else(|| format!("#{}", i)), index, file.clone(), item)),
+                }
+            }
+        }
+
+        let mut loaded = Vec::new();
+        for (label, index, file, item) in merged {
+            let source = self.source_name(index);
+            match serde_json::from_value(item) {
+                Ok(value) => loaded.push(value),
+                Err(e) if index == 0 => self.errors.push(format!("{} [{}]: {}", file, label, e)),
+                Err(e) => self.errors.push(format!("{} [{}] (pack \"{}\"): {}", file, label, source, e)),
+            }
+            self.sources.entry(name.to_string()).or_default().insert(label, source);
+        }
+        loaded
+    }
+
+    /// Load the economy settings; a pack's copy overrides individual settings from earlier directories.
+    fn load_economy(&mut self) -> EconomyConfig {
+        let mut merged = serde_json::Map::new();
+        for (_, file, value) in self.read_all("economy") {
+            match value {
+                serde_json::Value::Object(settings) => merged.extend(settings),
+                _ => self.errors.push(format!("{}: expected a table of settings", file)),
+            }
+        }
+        serde_json::from_value(serde_json::Value::Object(merged)).unwrap_or_else(|e| {
+            self.errors.push(format!("economy: {}", e));
+            EconomyConfig::default()
+        })
+    }
+}
+
+/// The shipped data set in `data/`, for tests.
+#[cfg(test)]
+pub(crate) fn test_data() -> GameData {
+    let data_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../data");
+    GameData::load_from_dir(&data_dir).expect("Should load test data")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_load_game_data() {
+        let data_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../data");
+        let result = GameData::load_from_dir(&data_dir);
+        assert!(result.is_ok(), "Should load all JSON data files: {:?}", result.err());
+
+        let data = result.unwrap();
+        assert!(!data.events.is_empty(), "Should have at least one event");
+        assert!(!data.actions.is_empty(), "Should have at least one action");
+        assert!(!data.decisions.is_empty(), "Should have at least one decision");
+        assert!(!data.jobs.is_empty(), "Should have at least one job");
+        assert!(!data.endings.is_empty(), "Should have at least one ending");
+        assert!(!data.education.is_empty(), "Should have at least one education program");
+    }
+
+    #[test]
+    fn test_indexes_match_scans() {
+        let data = GameData::load_from_dir(&PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../data")).unwrap();
+        for event in &data.events {
+            assert_eq!(data.event(&event.id).map(|e| &e.title), Some(&event.title));
+        }
+        for job in &data.jobs {
+            assert_eq!(data.job(&job.id).map(|j| &j.title), Some(&job.title));
+        }
+        assert!(data.decision("dec_missing").is_none());
+
+        for stage in &data.stages {
+            let indexed: Vec<&str> = data.stage_events(&stage.id).map(|e| e.id.as_str()).collect();
+            let scanned: Vec<&str> = data.events.iter()
+                .filter(|e| e.stages.contains(&stage.id))
+                .map(|e| e.id.as_str())
+                .collect();
+            assert_eq!(indexed, scanned, "Stage cards keep file order, so draws don't change");
+            assert_eq!(
+                data.stage_decisions(&stage.id).count(),
+                data.decisions.iter().filter(|d| d.stage == stage.id).count()
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "embedded-data")]
+    fn test_embedded_data_matches_data_dir() {
+        let data_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../data");
+        let on_disk = GameData::load_from_dir(&data_dir).unwrap();
+        let embedded = GameData::load(&DataSource::Embedded, &[]).expect("Built-in data should load");
+        assert_eq!(embedded.events.len(), on_disk.events.len());
+        assert_eq!(embedded.decisions.len(), on_disk.decisions.len());
+        assert_eq!(embedded.stages.len(), on_disk.stages.len());
+        assert_eq!(embedded.sources, on_disk.sources);
+    }
+
+    #[test]
+    fn test_failed_reload_keeps_current_data() {
+        let dir = std::env::temp_dir().join(format!("life-sim-reload-{}", std::process::id()));
+        let source = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../data");
+        std::fs::create_dir_all(&dir).unwrap();
+        for entry in std::fs::read_dir(&source).unwrap() {
+            let entry = entry.unwrap();
+            std::fs::copy(entry.path(), dir.join(entry.file_name())).unwrap();
+        }
+
+        let store = DataStore::new(DataSource::Dir(dir.clone()), Vec::new(), GameData::load_from_dir(&dir).unwrap());
+        let before = store.current();
+        std::fs::write(dir.join("jobs.json"), "[{ broken").unwrap();
+        assert!(store.reload().is_err());
+        assert!(Arc::ptr_eq(&before, &store.current()), "Bad data must not be swapped in");
+
+        std::fs::copy(source.join("jobs.json"), dir.join("jobs.json")).unwrap();
+        store.reload().unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(!Arc::ptr_eq(&before, &store.current()));
+    }
+
+    #[test]
+    fn test_packs_override_and_extend_by_id() {
+        let base = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../data");
+        let pack = std::env::temp_dir().join(format!("life-sim-pack-{}", std::process::id()));
+        std::fs::create_dir_all(&pack).unwrap();
+        std::fs::write(pack.join("paths.json"), r#"[
+            { "id": "path_military", "title": "Service", "description": "Renamed by a pack." },
+            { "id": "path_arts", "title": "Arts", "description": "Added by a pack." }
+        ]"#).unwrap();
+        std::fs::write(pack.join("economy.json"), r#"{ "jobChangeStress": 9 }"#).unwrap();
+
+        let plain = GameData::load_from_dir(&base).unwrap();
+        let data = GameData::load(&DataSource::Dir(base.clone()), std::slice::from_ref(&pack));
+        std::fs::remove_dir_all(&pack).unwrap();
+        let data = data.unwrap();
+
+        assert_eq!(data.paths.len(), plain.paths.len() + 1);
+        let military = data.path("path_military").unwrap();
+        assert_eq!(military.title, "Service");
+        assert_eq!(data.path("path_arts").unwrap().title, "Arts", "Packs' new entries are indexed");
+        assert_eq!(data.economy.job_change_stress, 9);
+        assert_eq!(data.economy.debt_interest_rate, plain.economy.debt_interest_rate);
+
+        let pack_name = pack.file_name().unwrap().to_str().unwrap();
+        assert_eq!(data.sources["paths"]["path_military"], pack_name);
+        assert_eq!(data.sources["paths"]["path_arts"], pack_name);
+        assert_eq!(data.sources["jobs"][&data.jobs[0].id], "base");
+    }
+
+    #[test]
+    fn test_yaml_and_toml_files_load_like_json() {
+        let dir = std::env::temp_dir().join(format!("life-sim-formats-{}", std::process::id()));
+        let source = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../data");
+        std::fs::create_dir_all(&dir).unwrap();
+        for entry in std::fs::read_dir(&source).unwrap() {
+            let entry = entry.unwrap();
+            std::fs::copy(entry.path(), dir.join(entry.file_name())).unwrap();
+        }
+        let json = |name: &str| -> serde_json::Value {
+            serde_json::from_str(&std::fs::read_to_string(source.join(name)).unwrap()).unwrap()
+        };
+        std::fs::remove_file(dir.join("events.json")).unwrap();
+        std::fs::write(dir.join("events.yaml"), serde_yaml::to_string(&json("events.json")).unwrap()).unwrap();
+        std::fs::remove_file(dir.join("stages.json")).unwrap();
+        std::fs::write(dir.join("stages.toml"), toml::to_string(&json("stages.json")).unwrap()).unwrap();
+        std::fs::remove_file(dir.join("economy.json")).unwrap();
+        std::fs::write(dir.join("economy.toml"), toml::to_string(&json("economy.json")).unwrap()).unwrap();
+
+        let converted = GameData::load_from_dir(&dir);
+        std::fs::copy(source.join("events.json"), dir.join("events.json")).unwrap();
+        let ambiguous = GameData::load_from_dir(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let converted = converted.unwrap();
+        let original = GameData::load_from_dir(&source).unwrap();
+        assert_eq!(converted.events.len(), original.events.len());
+        assert_eq!(converted.events[0].title, original.events[0].title);
+        assert_eq!(converted.stages.len(), original.stages.len());
+        assert_eq!(converted.stages[2].start_turn, original.stages[2].start_turn);
+        assert_eq!(converted.economy.debt_interest_rate, original.economy.debt_interest_rate);
+
+        let message = ambiguous.expect_err("Two events files should be rejected").to_string();
+        assert!(message.contains("events.json, events.yaml"), "{}", message);
+    }
+
+    #[test]
+    fn test_load_reports_every_bad_entry() {
+        let dir = std::env::temp_dir().join(format!("life-sim-bad-data-{}", std::process::id()));
+        let source = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../data");
+        std::fs::create_dir_all(&dir).unwrap();
+        for entry in std::fs::read_dir(&source).unwrap() {
+            let entry = entry.unwrap();
+            std::fs::copy(entry.path(), dir.join(entry.file_name())).unwrap();
+        }
+        std::fs::write(dir.join("paths.json"), r#"[
+            { "id": "path_a", "title": "A" },
+            { "id": "path_b", "title": "B" }
+        ]"#).unwrap();
+
+        let err = GameData::load_from_dir(&dir).expect_err("Bad data should fail to load");
+        let message = err.to_string();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(message.contains("paths.json [path_a]"), "{}", message);
+        assert!(message.contains("paths.json [path_b]"), "{}", message);
+        // With the paths gone, references to them are reported too
+        assert!(message.contains("path_military"), "{}", message);
+    }
+}
@@ -0,0 +1,192 @@
+//! Which Phase 1 actions the player can take this turn, and tracking their use.
+
+use schemars::JsonSchema;
+use serde::Serialize;
+use crate::data_loader::GameData;
+use crate::engine::game_state::GameState;
+use crate::engine::turn_runner;
+use crate::models::{Action, StateFlag};
+
+/// A stage action as offered this turn.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ActionStatus {
+    #[serde(flatten)]
+    pub action: Action,
+    /// Turns until the action can be taken again (0 if it can be taken now).
+    pub cooldown_remaining: u32,
+    /// Times the action can still be taken this run, if it's limited.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uses_left: Option<u32>,
+    /// Prerequisites the player doesn't meet yet, e.g. "Requires a job".
+    pub lock_reasons: Vec<String>,
+}
+
+/// Every action for the current stage, in file order, with whether it can be taken now.
+pub fn statuses(state: &GameState, data: &GameData) -> Vec<ActionStatus> {
+    data.stage_actions(&state.current_stage)
+        .map(|action| ActionStatus {
+            action: action.clone(),
+            cooldown_remaining: cooldown_remaining(state, action),
+            uses_left: uses_left(state, action),
+            lock_reasons: lock_reasons(state, data, action),
+        })
+        .collect()
+}
+
+/// Turns left before an action used recently comes off its cooldown.
+pub fn cooldown_remaining(state: &GameState, action: &Action) -> u32 {
+    match state.action_last_used.get(&action.id) {
+        Some(&last) if action.cooldown > 0 => (last + action.cooldown + 1).saturating_sub(state.current_turn),
+        _ => 0,
+    }
+}
+
+/// Times a limited action can still be taken this run; None if it's unlimited.
+pub fn uses_left(state: &GameState, action: &Action) -> Option<u32> {
+    let used = state.action_uses.get(&action.id).copied().unwrap_or(0);
+    action.max_uses.map(|max| max.saturating_sub(used))
+}
+
+/// Each of the action's prerequisites the player doesn't meet; empty if it's unlocked.
+pub fn lock_reasons(state: &GameState, data: &GameData, action: &Action) -> Vec<String> {
+    let Some(ref requires) = action.requires else {
+        return Vec::new();
+    };
+    let mut reasons = Vec::new();
+    if let Some(ref tag) = requires.tag {
+        if !state.credentials.contains(tag) {
+            reasons.push(format!("Requires {}", tag));
+        }
+    }
+    for (id, &min) in &requires.min_stats {
+        if state.stat(id) < min {
+            let label = data.stat(id).map_or(id.as_str(), |s| s.label.as_str());
+            reasons.push(format!("Requires {} of at least {}", label, min));
+        }
+    }
+    for &flag in &requires.flags {
+        if !flag_holds(state, data, flag) {
+            reasons.push(format!("Requires {}", flag.description()));
+        }
+    }
+    reasons
+}
+
+/// Whether the player's situation matches a flag right now.
+pub fn flag_holds(state: &GameState, data: &GameData, flag: StateFlag) -> bool {
+    match flag {
+        StateFlag::Employed => state.current_job.is_some(),
+        StateFlag::Unemployed => state.current_job.is_none(),
+        StateFlag::Enrolled => state.enrollment.is_some(),
+        StateFlag::PaysBills => turn_runner::stage_def(&data.stages, &state.current_stage)
+            .is_some_and(|s| s.pays_bills),
+    }
+}
+
+/// Why the player can't take an action this turn, if they can't.
+pub fn unavailable_reason(state: &GameState, data: &GameData, action: &Action) -> Option<String> {
+    let locks = lock_reasons(state, data, action);
+    if !locks.is_empty() {
+        return Some(format!("{} is locked. {}", action.label, locks.join(". ")));
+    }
+    if uses_left(state, action) == Some(0) {
+        return Some(match action.max_uses {
+            Some(1) => format!("{} can only be taken once", action.label),
+            _ => format!("{} has been used up", action.label),
+        });
+    }
+    match cooldown_remaining(state, action) {
+        0 => None,
+        1 => Some(format!("{} is available again next turn", action.label)),
+        turns => Some(format!("{} is available again in {} turns", action.label, turns)),
+    }
+}
+
+/// Note that the player took an action this turn.
+pub fn record_use(state: &mut GameState, action: &Action) {
+    state.action_last_used.insert(action.id.clone(), state.current_turn);
+    *state.action_uses.entry(action.id.clone()).or_insert(0) += 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_loader::test_data;
+    use crate::models::{ActionRequirements, Stage};
+
+    #[test]
+    fn test_cooldown_counts_down_turns() {
+        let data = test_data();
+        let mut action = data.action("act_family").unwrap().clone();
+        action.cooldown = 2;
+        let mut state = GameState::new("COOLDOWN".to_string());
+        state.current_turn = 5;
+        assert!(unavailable_reason(&state, &data, &action).is_none());
+
+        record_use(&mut state, &action);
+        state.current_turn = 6;
+        assert_eq!(cooldown_remaining(&state, &action), 2);
+        assert_eq!(unavailable_reason(&state, &data, &action).unwrap(), "Family is available again in 2 turns");
+        state.current_turn = 7;
+        assert_eq!(unavailable_reason(&state, &data, &action).unwrap(), "Family is available again next turn");
+        state.current_turn = 8;
+        assert_eq!(cooldown_remaining(&state, &action), 0);
+
+        // Without a cooldown, an action can be taken every turn
+        action.cooldown = 0;
+        state.current_turn = 5;
+        record_use(&mut state, &action);
+        assert!(unavailable_reason(&state, &data, &action).is_none());
+        assert!(statuses(&state, &data).iter().all(|s| s.cooldown_remaining == 0));
+    }
+
+    #[test]
+    fn test_max_uses_runs_out() {
+        let data = test_data();
+        let mut action = data.action("act_family").unwrap().clone();
+        let mut state = GameState::new("MAX_USES".to_string());
+        assert_eq!(uses_left(&state, &action), None);
+
+        action.max_uses = Some(2);
+        assert_eq!(uses_left(&state, &action), Some(2));
+        record_use(&mut state, &action);
+        assert_eq!(uses_left(&state, &action), Some(1));
+        assert!(unavailable_reason(&state, &data, &action).is_none());
+        record_use(&mut state, &action);
+        assert_eq!(uses_left(&state, &action), Some(0));
+        assert_eq!(unavailable_reason(&state, &data, &action).unwrap(), "Family has been used up");
+
+        action.max_uses = Some(1);
+        assert_eq!(unavailable_reason(&state, &data, &action).unwrap(), "Family can only be taken once");
+    }
+
+    #[test]
+    fn test_requirements_lock_until_met() {
+        let data = test_data();
+        let mut action = data.action("act_family").unwrap().clone();
+        action.requires = Some(ActionRequirements {
+            tag: Some("CPR".to_string()),
+            min_stats: [("support".to_string(), 60)].into_iter().collect(),
+            flags: vec![StateFlag::Employed, StateFlag::PaysBills],
+        });
+        let mut state = GameState::new("REQUIRES".to_string());
+        state.current_stage = Stage::MIDDLE_SCHOOL;
+        state.support = 40;
+        assert_eq!(lock_reasons(&state, &data, &action), vec![
+            "Requires CPR", "Requires Support of at least 60", "Requires a job", "Requires living on your own",
+        ]);
+        assert_eq!(
+            unavailable_reason(&state, &data, &action).unwrap(),
+            "Family is locked. Requires CPR. Requires Support of at least 60. Requires a job. Requires living on your own"
+        );
+
+        state.credentials.grant("CPR");
+        state.support = 60;
+        state.current_job = Some(data.jobs[0].clone());
+        state.current_stage = Stage::EARLY_ADULT;
+        assert!(lock_reasons(&state, &data, &action).is_empty());
+        assert!(unavailable_reason(&state, &data, &action).is_none());
+        assert!(!flag_holds(&state, &data, StateFlag::Unemployed));
+    }
+}
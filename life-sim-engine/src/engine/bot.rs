@@ -0,0 +1,348 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+use rand_chacha::ChaCha8Rng;
+use crate::data_loader::GameData;
+use crate::engine::actions;
+use crate::engine::choices;
+use crate::engine::game_state::GameState;
+use crate::engine::rng::GameRng;
+use crate::engine::stat_calculator;
+use crate::engine::turn_runner::{self, PlayerChoices, TurnResult};
+use crate::models::{Action, Decision, EventCard};
+use crate::models::event::{StatEffect, StatType};
+
+/// Names accepted by `by_name`, in the order they're listed to users.
+pub const STRATEGIES: &[&str] = &["random", "first", "greedy-money", "stress-averse"];
+
+/// How a bot player picks its choices each turn. Every method gets the bot's own RNG, so
+/// the game's draws don't depend on what the bot does with it.
+pub trait Strategy {
+    fn name(&self) -> &'static str;
+
+    /// Phase 1: actions to take, from those offered this stage. Time is checked by the
+    /// caller, which skips any action that no longer fits.
+    fn choose_actions<'a>(&self, state: &GameState, actions: Vec<&'a Action>, rng: &mut ChaCha8Rng) -> Vec<&'a Action>;
+
+    /// Phase 2: index of the option to pick, one of `open` (those the player qualifies for).
+    fn choose_decision(&self, state: &GameState, data: &GameData, decision: &Decision, open: &[usize], rng: &mut ChaCha8Rng) -> usize;
+
+    /// Phase 3: index of the card option to pick, one of `open` (never empty).
+    fn choose_event_option(&self, state: &GameState, event: &EventCard, open: &[usize], rng: &mut ChaCha8Rng) -> usize;
+}
+
+/// A built-in strategy by name.
+pub fn by_name(name: &str) -> Option<Box<dyn Strategy>> {
+    match name {
+        "random" => Some(Box::new(RandomStrategy)),
+        "first" => Some(Box::new(FirstOption)),
+        "greedy-money" => Some(Box::new(GreedyMoney)),
+        "stress-averse" => Some(Box::new(StressAverse)),
+        _ => None,
+    }
+}
+
+/// This turn's choices for a strategy, always valid for the turn: actions fit the time
+/// budget, and locked options are never offered to the strategy.
+pub fn choose_turn(
+    strategy: &dyn Strategy,
+    state: &GameState,
+    data: &GameData,
+    events: &[EventCard],
+    rng: &mut ChaCha8Rng,
+) -> PlayerChoices {
+    let offered: Vec<&Action> = data.stage_actions(&state.current_stage)
+        .filter(|a| actions::unavailable_reason(state, data, a).is_none())
+        .collect();
+    let mut time_left = state.available_time_slots();
+    let mut action_ids = Vec::new();
+    for action in strategy.choose_actions(state, offered, rng) {
+        if action.time_cost <= time_left && !action_ids.contains(&action.id) {
+            time_left -= action.time_cost;
+            action_ids.push(action.id.clone());
+        }
+    }
+
+    let decision = choices::current_decision(state, data);
+    let decision_option_index = decision.as_ref().map_or(0, |d| {
+        let open: Vec<usize> = (0..d.options.len())
+            .filter(|&i| d.options[i].requires_tag.as_ref().is_none_or(|tag| state.credentials.contains(tag)))
+            .collect();
+        if open.is_empty() { 0 } else { strategy.choose_decision(state, data, d, &open, rng) }
+    });
+
+    let mut answers = events.iter().map(|e| (!e.options.is_empty()).then(|| {
+        let mut open: Vec<usize> = (0..e.options.len())
+            .filter(|&i| e.options[i].requires_support.is_none_or(|min| state.support >= min))
+            .collect();
+        if open.is_empty() {
+            open = (0..e.options.len()).collect();
+        }
+        strategy.choose_event_option(state, e, &open, rng)
+    }));
+    let event_option_index = answers.next().flatten();
+    let extra_event_options = answers.collect();
+
+    PlayerChoices {
+        action_ids,
+        decision_id: decision.map(|d| d.id.clone()).unwrap_or_default(),
+        decision_option_index,
+        // Bots always answer; putting a decision off is left to players
+        defer_decision: false,
+        event_option_index,
+        event_id: None,
+        extra_event_options,
+    }
+}
+
+/// Draw this turn's cards (unless they're pending) and play the turn with a strategy.
+pub fn play_turn(
+    strategy: &dyn Strategy,
+    state: &mut GameState,
+    data: &GameData,
+    game_rng: &mut GameRng,
+    bot_rng: &mut ChaCha8Rng,
+    pending: Vec<EventCard>,
+) -> TurnResult {
+    let events = if pending.is_empty() { turn_runner::draw_turn_events(state, data, game_rng) } else { pending };
+    let choices = choose_turn(strategy, state, data, &events, bot_rng);
+    turn_runner::run_turn_with_events(state, &choices, data, game_rng, events)
+}
+
+/// Play a whole game from the start with a strategy. The bot's RNG is seeded from the
+/// game's seed, so a seed and strategy always play out the same way.
+pub fn play_game(strategy: &dyn Strategy, data: &GameData, seed: &str) -> GameState {
+    let mut state = turn_runner::start_game(seed.to_string(), data);
+    let mut game_rng = state.seed_rng();
+    let mut bot_rng = crate::engine::rng::create_rng(&format!("{}/bot", seed));
+    while !turn_runner::is_game_over(&state, &data.stages) {
+        play_turn(strategy, &mut state, data, &mut game_rng, &mut bot_rng, Vec::new());
+    }
+    state
+}
+
+/// Random actions, and a random open option for the decision and the card.
+pub struct RandomStrategy;
+
+impl Strategy for RandomStrategy {
+    fn name(&self) -> &'static str {
+        "random"
+    }
+
+    fn choose_actions<'a>(&self, _: &GameState, mut actions: Vec<&'a Action>, rng: &mut ChaCha8Rng) -> Vec<&'a Action> {
+        actions.shuffle(rng);
+        let wanted = rng.gen_range(0..=actions.len());
+        actions.truncate(wanted);
+        actions
+    }
+
+    fn choose_decision(&self, _: &GameState, _: &GameData, _: &Decision, open: &[usize], rng: &mut ChaCha8Rng) -> usize {
+        *open.choose(rng).unwrap_or(&0)
+    }
+
+    fn choose_event_option(&self, _: &GameState, _: &EventCard, open: &[usize], rng: &mut ChaCha8Rng) -> usize {
+        *open.choose(rng).unwrap_or(&0)
+    }
+}
+
+/// Actions in file order while time lasts, and the first open option every time.
+pub struct FirstOption;
+
+impl Strategy for FirstOption {
+    fn name(&self) -> &'static str {
+        "first"
+    }
+
+    fn choose_actions<'a>(&self, _: &GameState, actions: Vec<&'a Action>, _: &mut ChaCha8Rng) -> Vec<&'a Action> {
+        actions
+    }
+
+    fn choose_decision(&self, _: &GameState, _: &GameData, _: &Decision, open: &[usize], _: &mut ChaCha8Rng) -> usize {
+        open[0]
+    }
+
+    fn choose_event_option(&self, _: &GameState, _: &EventCard, open: &[usize], _: &mut ChaCha8Rng) -> usize {
+        open[0]
+    }
+}
+
+/// No actions, and the first open option every time: what a player who runs out of time
+/// in a timed game is left with. Not offered as a bot.
+pub struct Frozen;
+
+impl Strategy for Frozen {
+    fn name(&self) -> &'static str {
+        "frozen"
+    }
+
+    fn choose_actions<'a>(&self, _: &GameState, _: Vec<&'a Action>, _: &mut ChaCha8Rng) -> Vec<&'a Action> {
+        Vec::new()
+    }
+
+    fn choose_decision(&self, _: &GameState, _: &GameData, _: &Decision, open: &[usize], _: &mut ChaCha8Rng) -> usize {
+        open[0]
+    }
+
+    fn choose_event_option(&self, _: &GameState, _: &EventCard, open: &[usize], _: &mut ChaCha8Rng) -> usize {
+        open[0]
+    }
+}
+
+/// The choices a player who froze under pressure makes for this turn's `events`.
+pub fn frozen_choices(state: &GameState, data: &GameData, events: &[EventCard]) -> PlayerChoices {
+    // Frozen never draws from its RNG
+    choose_turn(&Frozen, state, data, events, &mut crate::engine::rng::create_rng(&state.seed))
+}
+
+/// Whatever leaves the most money right now: paying actions first, the decision option
+/// with the best money preview, and the cheapest card response.
+pub struct GreedyMoney;
+
+impl Strategy for GreedyMoney {
+    fn name(&self) -> &'static str {
+        "greedy-money"
+    }
+
+    fn choose_actions<'a>(&self, state: &GameState, mut actions: Vec<&'a Action>, _: &mut ChaCha8Rng) -> Vec<&'a Action> {
+        actions.retain(|a| stat_total(state, &a.effects, StatType::Money) >= 0);
+        actions.sort_by_key(|a| -stat_total(state, &a.effects, StatType::Money));
+        actions
+    }
+
+    fn choose_decision(&self, state: &GameState, data: &GameData, decision: &Decision, open: &[usize], _: &mut ChaCha8Rng) -> usize {
+        let previews = choices::preview_decision(state, decision, data);
+        best(open, |i| previews[i].money)
+    }
+
+    fn choose_event_option(&self, state: &GameState, event: &EventCard, open: &[usize], _: &mut ChaCha8Rng) -> usize {
+        best(open, |i| stat_total(state, &event.options[i].effects, StatType::Money))
+    }
+}
+
+/// Whatever keeps stress lowest: calming actions only, the decision option with the
+/// lowest stress preview, and the least stressful card response.
+pub struct StressAverse;
+
+impl Strategy for StressAverse {
+    fn name(&self) -> &'static str {
+        "stress-averse"
+    }
+
+    fn choose_actions<'a>(&self, state: &GameState, mut actions: Vec<&'a Action>, _: &mut ChaCha8Rng) -> Vec<&'a Action> {
+        actions.retain(|a| stat_total(state, &a.effects, StatType::Stress) < 0);
+        actions.sort_by_key(|a| stat_total(state, &a.effects, StatType::Stress));
+        actions
+    }
+
+    fn choose_decision(&self, state: &GameState, data: &GameData, decision: &Decision, open: &[usize], _: &mut ChaCha8Rng) -> usize {
+        let previews = choices::preview_decision(state, decision, data);
+        best(open, |i| -previews[i].stress)
+    }
+
+    fn choose_event_option(&self, state: &GameState, event: &EventCard, open: &[usize], _: &mut ChaCha8Rng) -> usize {
+        best(open, |i| -stat_total(state, &event.options[i].effects, StatType::Stress))
+    }
+}
+
+/// Net change to one stat across a list of effects, with percentages taken of its current value.
+fn stat_total(state: &GameState, effects: &[StatEffect], stat: StatType) -> i32 {
+    effects.iter().filter(|e| e.stat == stat).map(|e| stat_calculator::effect_delta(state, e)).sum()
+}
+
+/// The open index with the highest score (the earliest one on ties).
+fn best(open: &[usize], score: impl Fn(usize) -> i32) -> usize {
+    open.iter().copied().rev().max_by_key(|&i| score(i)).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_loader::test_data;
+
+    #[test]
+    fn test_every_strategy_plays_valid_turns_to_the_end() {
+        let data = test_data();
+        for name in STRATEGIES {
+            let strategy = by_name(name).unwrap();
+            assert_eq!(strategy.name(), *name);
+
+            let mut state = turn_runner::start_game("BOT_TEST".to_string(), &data);
+            let mut game_rng = crate::engine::rng::game_rng("BOT_TEST");
+            let mut bot_rng = crate::engine::rng::create_rng("BOT_TEST/bot");
+            while !turn_runner::is_game_over(&state, &data.stages) {
+                let events = turn_runner::draw_turn_events(&state, &data, &mut game_rng);
+                let choices = choose_turn(strategy.as_ref(), &state, &data, &events, &mut bot_rng);
+                assert_eq!(
+                    choices::validate_choices(&state, &choices, &data, &events),
+                    Ok(()),
+                    "{} on turn {}",
+                    name,
+                    state.current_turn
+                );
+                turn_runner::run_turn_with_events(&mut state, &choices, &data, &mut game_rng, events);
+            }
+        }
+        assert!(by_name("psychic").is_none());
+    }
+
+    #[test]
+    fn test_strategies_lean_the_way_they_say() {
+        let data = test_data();
+        let seeds: Vec<String> = (0..30).map(|i| format!("LEAN-{}", i)).collect();
+        let mean = |name: &str, stat: fn(&GameState) -> i32| {
+            let strategy = by_name(name).unwrap();
+            seeds.iter().map(|s| stat(&play_game(strategy.as_ref(), &data, s))).sum::<i32>() / seeds.len() as i32
+        };
+        assert!(mean("greedy-money", |s| s.money) > mean("stress-averse", |s| s.money));
+        assert!(mean("stress-averse", |s| s.stress) < mean("greedy-money", |s| s.stress));
+    }
+
+    #[test]
+    fn test_a_seed_plays_out_the_same_way() {
+        let data = test_data();
+        let a = play_game(&RandomStrategy, &data, "BOT_SEED");
+        let b = play_game(&RandomStrategy, &data, "BOT_SEED");
+        assert_eq!((a.money, a.stress, a.support), (b.money, b.stress, b.support));
+        assert_eq!(a.used_event_ids, b.used_event_ids);
+    }
+
+    #[test]
+    fn test_saved_game_carries_on_the_same_draws() {
+        let data = test_data();
+        let mut state = turn_runner::start_game("SAVED".to_string(), &data);
+        let mut game_rng = state.seed_rng();
+        let mut bot_rng = crate::engine::rng::create_rng("SAVED/bot");
+        for _ in 0..6 {
+            play_turn(&RandomStrategy, &mut state, &data, &mut game_rng, &mut bot_rng, Vec::new());
+        }
+
+        let mut loaded: GameState = serde_json::from_str(&serde_json::to_string(&state).unwrap()).unwrap();
+        let mut loaded_rng = loaded.resume_rng();
+        let mut loaded_bot_rng = bot_rng.clone();
+        while !turn_runner::is_game_over(&state, &data.stages) {
+            play_turn(&RandomStrategy, &mut state, &data, &mut game_rng, &mut bot_rng, Vec::new());
+            play_turn(&RandomStrategy, &mut loaded, &data, &mut loaded_rng, &mut loaded_bot_rng, Vec::new());
+        }
+        assert_eq!(loaded.used_event_ids, state.used_event_ids);
+        assert_eq!(serde_json::to_value(&loaded).unwrap(), serde_json::to_value(&state).unwrap());
+    }
+
+    #[test]
+    fn test_frozen_player_does_nothing_but_answer() {
+        let data = test_data();
+        let mut state = turn_runner::start_game("FROZEN".to_string(), &data);
+        let mut game_rng = crate::engine::rng::game_rng("FROZEN");
+        while !turn_runner::is_game_over(&state, &data.stages) {
+            let events = turn_runner::draw_turn_events(&state, &data, &mut game_rng);
+            let choices = frozen_choices(&state, &data, &events);
+            assert!(choices.action_ids.is_empty());
+            assert_eq!(choices::validate_choices(&state, &choices, &data, &events), Ok(()), "turn {}", state.current_turn);
+            turn_runner::run_turn_with_events(&mut state, &choices, &data, &mut game_rng, events);
+        }
+    }
+
+    #[test]
+    fn test_best_prefers_earliest_on_ties() {
+        assert_eq!(best(&[0, 1, 2], |i| [5, 9, 9][i]), 1);
+        assert_eq!(best(&[2], |_| 0), 2);
+    }
+}
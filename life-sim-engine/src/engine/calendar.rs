@@ -0,0 +1,85 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use crate::engine::game_state::GameState;
+use crate::engine::turn_runner;
+use crate::models::{Stage, StageDefinition};
+
+const MONTH_NAMES: [&str; 12] = [
+    "January", "February", "March", "April", "May", "June",
+    "July", "August", "September", "October", "November", "December",
+];
+
+/// When a turn falls in the player's life.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarDate {
+    /// 1 (January) to 12.
+    pub month: u32,
+    pub month_name: String,
+    pub age: u32,
+}
+
+/// The date of a turn in a stage, from the stage's `calendar` in stages.json. The age goes
+/// up once every twelve months from the stage's start. None if the stage has no calendar.
+pub fn date(stages: &[StageDefinition], stage: &Stage, turn: u32) -> Option<CalendarDate> {
+    let stage = turn_runner::stage_def(stages, stage)?;
+    let calendar = stage.calendar?;
+    let months = turn.saturating_sub(stage.start_turn) * calendar.months_per_turn;
+    let month = (calendar.start_month.saturating_sub(1) + months) % 12 + 1;
+    Some(CalendarDate {
+        month,
+        month_name: MONTH_NAMES[month as usize - 1].to_string(),
+        age: calendar.start_age + months / 12,
+    })
+}
+
+/// Date the state's current turn; call whenever the turn or stage moves.
+pub fn update(state: &mut GameState, stages: &[StageDefinition]) {
+    state.date = date(stages, &state.current_stage, state.current_turn);
+}
+
+/// The current turn's month, if it has a date.
+pub fn month(state: &GameState) -> Option<u32> {
+    state.date.as_ref().map(|d| d.month)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_loader::test_data;
+    use crate::engine::bot;
+    use crate::engine::rng::{create_rng, game_rng};
+
+    #[test]
+    fn test_turns_advance_the_calendar() {
+        let data = test_data();
+        let stage = &data.stages[0];
+        let calendar = stage.calendar.expect("The shipped stages have calendars");
+        let first = date(&data.stages, &stage.id, stage.start_turn).unwrap();
+        assert_eq!((first.month, first.age), (calendar.start_month, calendar.start_age));
+        assert_eq!(first.month_name, MONTH_NAMES[calendar.start_month as usize - 1]);
+
+        let later = stage.start_turn + 12;
+        let year_on = date(&data.stages, &stage.id, later).unwrap();
+        assert_eq!(year_on.month, first.month, "Twelve turns later is the same month");
+        assert_eq!(year_on.age, first.age + calendar.months_per_turn, "and a year older per month a turn");
+
+        let mut undated = data.stages.clone();
+        undated[0].calendar = None;
+        assert!(date(&undated, &stage.id, stage.start_turn).is_none());
+    }
+
+    #[test]
+    fn test_every_turn_played_is_dated() {
+        let data = test_data();
+        let mut state = turn_runner::start_game("CALENDAR".to_string(), &data);
+        let (mut game_rng, mut bot_rng) = (game_rng("CALENDAR"), create_rng("CALENDAR/bot"));
+        let mut ages = Vec::new();
+        while !turn_runner::is_game_over(&state, &data.stages) {
+            assert_eq!(state.date, date(&data.stages, &state.current_stage, state.current_turn));
+            ages.push(state.date.as_ref().expect("Every shipped stage is dated").age);
+            bot::play_turn(&bot::FirstOption, &mut state, &data, &mut game_rng, &mut bot_rng, Vec::new());
+        }
+        assert!(ages.windows(2).all(|pair| pair[0] <= pair[1]), "The player only gets older: {:?}", ages);
+    }
+}
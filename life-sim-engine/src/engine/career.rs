@@ -0,0 +1,394 @@
+use crate::engine::game_state::{DecisionEntry, GameState};
+use crate::engine::paths;
+use crate::engine::stat_calculator;
+use crate::data_loader::GameData;
+use crate::engine::modifiers::EffectSource;
+use crate::models::{Decision, Job, SideGig};
+use crate::models::event::{StatEffect, StatType};
+use crate::models::decision::DecisionOption;
+use crate::models::job::JobTier;
+
+/// Put the player in a new job, resetting growth, rung, and tenure.
+pub fn assign_job(state: &mut GameState, job: &Job) -> String {
+    state.current_job = Some(job.clone());
+    state.job_turns = 0; // Reset growth counter on job change
+    state.job_tier = 0;
+    state.job_tenure = 0;
+    format!("💼 Started working as: {}", job.title)
+}
+
+/// Quit the current job (`None`) or switch to another one outside of a scripted
+/// decision. Switching costs stress and the first paycheck, per economy.json.
+/// Returns an error message if the change isn't allowed.
+pub fn change_job(
+    state: &mut GameState,
+    new_job: Option<&Job>,
+    data: &GameData,
+) -> Result<Vec<String>, String> {
+    let economy = &data.economy;
+    let old_title = state.current_job.as_ref().map(|j| j.title.clone());
+
+    if let Some(job) = new_job {
+        if state.current_job.as_ref().is_some_and(|j| j.id == job.id) {
+            return Err(format!("You already work as {}.", job.title));
+        }
+        if !job.stages.contains(&state.current_stage) {
+            return Err(format!("{} isn't hiring during {}.", job.title, state.current_stage));
+        }
+        if !paths::on_path(state, &job.paths) {
+            return Err(format!("{} isn't open to people on your path.", job.title));
+        }
+        let missing = job.missing_requirements(&state.credentials);
+        if !missing.is_empty() {
+            return Err(format!("{} requires: {}", job.title, missing.join(", ")));
+        }
+    } else if old_title.is_none() {
+        return Err("You don't have a job to quit.".to_string());
+    }
+
+    let cost = [StatEffect { stat: StatType::Stress, delta: economy.job_change_stress, tag: None, percent: None, when: None }];
+    let mut feedback = stat_calculator::apply_effects(state, &cost, EffectSource::Upkeep, data);
+
+    let description = match new_job {
+        Some(job) => {
+            feedback.push(assign_job(state, job));
+            state.unpaid_turns = economy.job_change_unpaid_turns;
+            match old_title {
+                Some(old) => format!("Left {} for {}", old, job.title),
+                None => format!("Started as {}", job.title),
+            }
+        }
+        None => {
+            let old = old_title.unwrap_or_default();
+            state.current_job = None;
+            state.job_turns = 0;
+            state.job_tier = 0;
+            state.job_tenure = 0;
+            state.unpaid_turns = 0;
+            feedback.push(format!("🚪 Quit your job as {}", old));
+            format!("Quit {}", old)
+        }
+    };
+    if state.unpaid_turns > 0 {
+        feedback.push(format!("⏳ First paycheck arrives in {} turn(s)", state.unpaid_turns + 1));
+    }
+
+    state.decision_log.push(DecisionEntry {
+        turn: state.current_turn,
+        stage: state.current_stage.clone(),
+        decision_id: None,
+        option_index: None,
+        description,
+        impact: cost.iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>().join(", "),
+    });
+
+    Ok(feedback)
+}
+
+/// Time slots that must stay free for Phase 1 actions.
+const MIN_FREE_TIME_SLOTS: u32 = 1;
+
+/// Pick up a side gig on top of the primary job.
+/// Returns an error message if the gig isn't available or there's no time for it.
+pub fn start_side_gig(state: &mut GameState, gig: &SideGig) -> Result<String, String> {
+    if state.side_gigs.iter().any(|g| g.id == gig.id) {
+        return Err(format!("You already do {}.", gig.title));
+    }
+    if !gig.stages.contains(&state.current_stage) {
+        return Err(format!("{} isn't available during {}.", gig.title, state.current_stage));
+    }
+    let missing: Vec<&str> = gig.required_tags.iter()
+        .filter(|t| !state.credentials.contains(t))
+        .map(|t| t.as_str())
+        .collect();
+    if !missing.is_empty() {
+        return Err(format!("{} requires: {}", gig.title, missing.join(", ")));
+    }
+    if state.free_time_slots() < gig.time_cost + MIN_FREE_TIME_SLOTS {
+        return Err(format!("Not enough free time for {}.", gig.title));
+    }
+    state.side_gigs.push(gig.clone());
+    Ok(format!("🛵 Started side gig: {} (+${}/turn, -{} time)", gig.title, gig.pay_per_turn, gig.time_cost))
+}
+
+/// Drop a side gig, freeing its time slots.
+pub fn drop_side_gig(state: &mut GameState, gig_id: &str) -> Result<String, String> {
+    let Some(idx) = state.side_gigs.iter().position(|g| g.id == gig_id) else {
+        return Err(format!("You don't have the side gig {}.", gig_id));
+    };
+    let gig = state.side_gigs.remove(idx);
+    Ok(format!("🛵 Dropped side gig: {}", gig.title))
+}
+
+/// The next rung above the player's current position, if the ladder has one.
+pub fn next_tier(job: &Job, current_tier: u32) -> Option<&JobTier> {
+    job.tiers.get(current_tier as usize)
+}
+
+/// Whether the player has the tenure and tags for the next rung.
+pub fn qualifies_for_promotion(state: &GameState) -> bool {
+    let Some(ref job) = state.current_job else {
+        return false;
+    };
+    next_tier(job, state.job_tier).is_some_and(|tier| {
+        state.job_tenure >= tier.min_tenure
+            && tier.missing_requirements(&state.credentials).is_empty()
+    })
+}
+
+/// Build a promotion offer as a Phase 2 decision when the player qualifies.
+pub fn promotion_offer(state: &GameState) -> Option<Decision> {
+    if !qualifies_for_promotion(state) {
+        return None;
+    }
+    let job = state.current_job.as_ref()?;
+    let tier = next_tier(job, state.job_tier)?;
+
+    Some(Decision {
+        id: promotion_decision_id(job, state.job_tier),
+        stage: state.current_stage.clone(),
+        turn: state.current_turn,
+        path: None,
+        prompt: format!("Your manager offers you a promotion to {}.", tier.title),
+        options: vec![
+            DecisionOption {
+                label: format!("Accept: {}", tier.title),
+                description: format!(
+                    "${}/turn pay with +{} stress per turn. More responsibility, more reward.",
+                    tier.pay_per_turn, tier.stress_per_turn
+                ),
+                effects: vec![],
+                grants_tag: None,
+                sets_bills: None,
+                sets_path: None,
+                moves_into: None,
+                applies_for: None,
+                enrolls_in: None,
+                finance_with_loan: false,
+                accepts_promotion: true,
+                requires_tag: None,
+            },
+            DecisionOption {
+                label: format!("Stay as {}", job.title),
+                description: "Keep your current role and workload for now.".to_string(),
+                effects: vec![],
+                grants_tag: None,
+                sets_bills: None,
+                sets_path: None,
+                moves_into: None,
+                applies_for: None,
+                enrolls_in: None,
+                finance_with_loan: false,
+                accepts_promotion: false,
+                requires_tag: None,
+            },
+        ],
+        quiz: None,
+        defer_consequence: None,
+    })
+}
+
+/// Stable ID for the promotion decision out of a given rung.
+fn promotion_decision_id(job: &Job, tier: u32) -> String {
+    format!("dec_promotion_{}_{}", job.id, tier + 1)
+}
+
+/// Move the player up one rung: new title, pay, and stress on the held job.
+pub fn promote(state: &mut GameState) -> Vec<String> {
+    let mut feedback = Vec::new();
+    if !qualifies_for_promotion(state) {
+        return feedback;
+    }
+    let tier_index = state.job_tier;
+    if let Some(ref mut job) = state.current_job {
+        if let Some(tier) = job.tiers.get(tier_index as usize).cloned() {
+            feedback.push(format!("📈 Promoted from {} to {}!", job.title, tier.title));
+            job.title = tier.title;
+            job.pay_per_turn = tier.pay_per_turn;
+            job.stress_per_turn = tier.stress_per_turn;
+            state.job_tier += 1;
+            state.job_tenure = 0;
+        }
+    }
+    feedback
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_loader::test_data;
+    use crate::models::{EconomyConfig, Stage};
+
+    /// The shipped data with the job-change rules these tests expect.
+    fn load_test_data() -> GameData {
+        let mut data = test_data();
+        data.economy = make_economy();
+        data
+    }
+
+    fn make_ladder_job() -> Job {
+        Job {
+            id: "job_ladder".to_string(),
+            title: "Crew".to_string(),
+            required_tags: vec![],
+            required_levels: Default::default(),
+            recommended_tags: vec![],
+            pay_per_turn: 30,
+            stress_per_turn: 5,
+            growth_rate: 0,
+            growth_tag: None,
+            stages: vec![Stage::EARLY_ADULT],
+            description: "Test".to_string(),
+            family: None,
+            paths: vec![],
+            tiers: vec![
+                JobTier {
+                    title: "Shift Lead".to_string(),
+                    pay_per_turn: 45,
+                    stress_per_turn: 6,
+                    min_tenure: 2,
+                    required_tags: vec![],
+                    required_levels: Default::default(),
+                },
+                JobTier {
+                    title: "Manager".to_string(),
+                    pay_per_turn: 60,
+                    stress_per_turn: 7,
+                    min_tenure: 2,
+                    required_tags: vec!["Leadership".to_string()],
+                    required_levels: Default::default(),
+                },
+            ],
+        }
+    }
+
+    fn make_state() -> GameState {
+        let mut state = GameState::new("LADDER".to_string());
+        state.current_stage = Stage::EARLY_ADULT;
+        state.current_job = Some(make_ladder_job());
+        state
+    }
+
+    fn make_economy() -> EconomyConfig {
+        EconomyConfig {
+            job_change_stress: 5,
+            job_change_unpaid_turns: 1,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_switch_job_costs_stress_and_pay() {
+        let data = load_test_data();
+        let mut state = make_state();
+        state.job_tier = 1;
+        let mut other = make_ladder_job();
+        other.id = "job_other".to_string();
+        other.title = "Other".to_string();
+
+        change_job(&mut state, Some(&other), &data).unwrap();
+        assert_eq!(state.current_job.as_ref().unwrap().id, "job_other");
+        assert_eq!(state.stress, 25);
+        assert_eq!(state.unpaid_turns, 1);
+        assert_eq!(state.job_tier, 0, "Switching starts at the bottom rung");
+        assert!(state.decision_log.last().unwrap().description.contains("Left Crew"));
+    }
+
+    #[test]
+    fn test_quit_and_invalid_changes() {
+        let data = load_test_data();
+        let mut state = make_state();
+
+        let mut locked = make_ladder_job();
+        locked.id = "job_locked".to_string();
+        locked.required_tags = vec!["CPR".to_string()];
+        assert!(change_job(&mut state, Some(&locked), &data).is_err());
+        assert!(change_job(&mut state, Some(&make_ladder_job()), &data).is_err(), "Same job");
+
+        change_job(&mut state, None, &data).unwrap();
+        assert!(state.current_job.is_none());
+        assert!(change_job(&mut state, None, &data).is_err(), "Nothing left to quit");
+    }
+
+    #[test]
+    fn test_cannot_switch_into_a_job_off_your_path() {
+        let data = load_test_data();
+        let mut state = make_state();
+        let mut enlisted = make_ladder_job();
+        enlisted.id = "job_enlisted".to_string();
+        enlisted.paths = vec!["path_military".to_string()];
+
+        let err = change_job(&mut state, Some(&enlisted), &data).unwrap_err();
+        assert!(err.contains("path"), "{}", err);
+        assert_eq!(state.current_job.as_ref().unwrap().id, "job_ladder", "Still in the old job");
+
+        state.path = data.paths.iter().find(|p| p.id == "path_military").cloned();
+        change_job(&mut state, Some(&enlisted), &data).unwrap();
+    }
+
+    fn make_gig(id: &str) -> SideGig {
+        SideGig {
+            id: id.to_string(),
+            title: id.to_string(),
+            description: "Test".to_string(),
+            stages: vec![Stage::EARLY_ADULT],
+            required_tags: vec![],
+            pay_per_turn: 20,
+            stress_per_turn: 2,
+            time_cost: 1,
+        }
+    }
+
+    #[test]
+    fn test_side_gigs_limited_by_time() {
+        let mut state = make_state(); // 3 time slots
+        start_side_gig(&mut state, &make_gig("a")).unwrap();
+        assert!(start_side_gig(&mut state, &make_gig("a")).is_err(), "No duplicates");
+        start_side_gig(&mut state, &make_gig("b")).unwrap();
+        assert_eq!(state.available_time_slots(), 1);
+        assert!(start_side_gig(&mut state, &make_gig("c")).is_err(), "Must keep a free slot");
+
+        drop_side_gig(&mut state, "a").unwrap();
+        assert_eq!(state.available_time_slots(), 2);
+        assert!(drop_side_gig(&mut state, "a").is_err());
+    }
+
+    #[test]
+    fn test_offer_requires_tenure() {
+        let mut state = make_state();
+        state.job_tenure = 1;
+        assert!(promotion_offer(&state).is_none());
+        state.job_tenure = 2;
+        let offer = promotion_offer(&state).expect("Should offer Shift Lead");
+        assert!(offer.prompt.contains("Shift Lead"));
+        assert!(offer.options[0].accepts_promotion);
+    }
+
+    #[test]
+    fn test_promote_updates_job_and_resets_tenure() {
+        let mut state = make_state();
+        state.job_tenure = 2;
+        let fb = promote(&mut state);
+        let job = state.current_job.as_ref().unwrap();
+        assert_eq!(job.title, "Shift Lead");
+        assert_eq!(job.pay_per_turn, 45);
+        assert_eq!(state.job_tier, 1);
+        assert_eq!(state.job_tenure, 0);
+        assert!(fb[0].contains("Promoted"));
+    }
+
+    #[test]
+    fn test_higher_rung_requires_tags() {
+        let mut state = make_state();
+        state.job_tier = 1;
+        state.job_tenure = 5;
+        assert!(promotion_offer(&state).is_none(), "Manager needs Leadership");
+        state.credentials.grant("Leadership");
+        assert!(promotion_offer(&state).is_some());
+
+        state.job_tier = 2;
+        assert!(promotion_offer(&state).is_none(), "Top of the ladder");
+    }
+}
@@ -0,0 +1,421 @@
+use std::borrow::Cow;
+use std::collections::HashSet;
+use schemars::JsonSchema;
+use serde::Serialize;
+use crate::engine::game_state::GameState;
+use crate::engine::actions;
+use crate::engine::career;
+use crate::engine::paths;
+use crate::engine::turn_runner::{self, PlayerChoices};
+use crate::data_loader::GameData;
+use crate::models::{Decision, EventCard};
+
+/// A submitted choice that doesn't fit the current turn.
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub struct InvalidChoice {
+    /// The offending request field, e.g. "actionIds[1]" or "decisionOptionIndex".
+    pub field: String,
+    pub message: String,
+}
+
+impl InvalidChoice {
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self { field: field.into(), message: message.into() }
+    }
+}
+
+/// The decision the player faces this turn: one scheduled for this turn, then one put off
+/// earlier that's due back, then a pending promotion offer, otherwise the first for the
+/// stage (decisions for other life paths, any the scenario locked, and any put off that
+/// aren't due back yet are skipped). Borrowed from the data set unless it's an offer.
+pub fn current_decision<'a>(state: &GameState, data: &'a GameData) -> Option<Cow<'a, Decision>> {
+    let stage = &state.current_stage;
+    let deferred = |d: &Decision| state.deferred_decisions.iter().find(|x| x.decision_id == d.id);
+    let offered = |d: &&Decision| {
+        d.path.as_ref().is_none_or(|p| paths::on_path(state, std::slice::from_ref(p)))
+            && !state.locked_decisions.contains(&d.id)
+            && deferred(d).is_none_or(|x| x.returns_on.is_some_and(|t| t <= state.current_turn))
+    };
+    data.stage_decisions(stage)
+        .filter(offered)
+        .find(|d| d.turn == state.current_turn)
+        .or_else(|| data.stage_decisions(stage).filter(offered).find(|d| deferred(d).is_some()))
+        .map(Cow::Borrowed)
+        .or_else(|| career::promotion_offer(state).map(Cow::Owned))
+        .or_else(|| data.stage_decisions(stage).find(offered).map(Cow::Borrowed))
+}
+
+/// What picking one decision option would leave the player with, before anything
+/// else in the turn happens.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionPreview {
+    pub index: usize,
+    pub label: String,
+    /// The option needs a tag the player doesn't have, so it can't be picked.
+    pub locked: bool,
+    pub money: i32,
+    pub stress: i32,
+    pub support: i32,
+    /// Time slots left for Phase 1 (classes and side gigs take their share).
+    pub time_slots: u32,
+    pub savings: i32,
+    pub monthly_bills: i32,
+    pub student_loan: i32,
+    /// Credentials the option would add.
+    pub tags_granted: Vec<String>,
+    pub feedback: Vec<String>,
+}
+
+/// Preview every option of a decision against the current state. Each option is applied
+/// to its own copy of the state exactly as a turn would, so stat clamps, tag grants,
+/// moves, and enrollments all show up in the numbers.
+pub fn preview_decision(state: &GameState, decision: &Decision, data: &GameData) -> Vec<OptionPreview> {
+    decision.options.iter().enumerate().map(|(index, option)| {
+        let mut projected = state.clone();
+        let feedback = turn_runner::apply_decision_option(&mut projected, option, data);
+        OptionPreview {
+            index,
+            label: option.label.clone(),
+            locked: option.requires_tag.as_ref().is_some_and(|tag| !state.credentials.contains(tag)),
+            money: projected.money,
+            stress: projected.stress,
+            support: projected.support,
+            time_slots: projected.available_time_slots(),
+            savings: projected.savings,
+            monthly_bills: projected.monthly_bills,
+            student_loan: projected.student_loan,
+            tags_granted: projected.credentials.gained_since(&state.credentials),
+            feedback,
+        }
+    }).collect()
+}
+
+/// Check a turn's choices against the data and the current stage and turn before
+/// running them. `events` are the cards drawn for this turn, in draw order.
+pub fn validate_choices(
+    state: &GameState,
+    choices: &PlayerChoices,
+    data: &GameData,
+    events: &[EventCard],
+) -> Result<(), Vec<InvalidChoice>> {
+    let errors: Vec<InvalidChoice> = [
+        validate_actions(state, choices, data),
+        validate_decision(state, choices, data),
+        validate_event_answers(choices, events),
+    ].into_iter().filter_map(Result::err).flatten().collect();
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+/// Phase 1: every action must exist in this stage, once, within the time budget.
+pub fn validate_actions(state: &GameState, choices: &PlayerChoices, data: &GameData) -> Result<(), Vec<InvalidChoice>> {
+    let mut errors = Vec::new();
+    let mut seen = HashSet::new();
+    let mut time_used = 0;
+    for (i, action_id) in choices.action_ids.iter().enumerate() {
+        let field = format!("actionIds[{}]", i);
+        match data.action(action_id) {
+            None => errors.push(InvalidChoice::new(field, format!("Unknown action: {}", action_id))),
+            Some(action) if !action.stages.contains(&state.current_stage) => errors.push(InvalidChoice::new(
+                field,
+                format!("{} isn't available in stage {}", action.label, state.current_stage),
+            )),
+            Some(_) if !seen.insert(action_id) => {
+                errors.push(InvalidChoice::new(field, format!("{} is selected more than once", action_id)));
+            }
+            Some(action) => match actions::unavailable_reason(state, data, action) {
+                Some(reason) => errors.push(InvalidChoice::new(field, reason)),
+                None => time_used += action.time_cost,
+            },
+        }
+    }
+    let time_slots = state.available_time_slots();
+    if time_used > time_slots {
+        errors.push(InvalidChoice::new(
+            "actionIds",
+            format!("Actions need {} time slots but only {} are available", time_used, time_slots),
+        ));
+    }
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+/// Phase 2: the decision must be this turn's, with an option the player can pick.
+pub fn validate_decision(state: &GameState, choices: &PlayerChoices, data: &GameData) -> Result<(), Vec<InvalidChoice>> {
+    let mut errors = Vec::new();
+    match (current_decision(state, data), choices.decision_id.as_str()) {
+        (None, "") => {}
+        (None, id) => errors.push(InvalidChoice::new("decisionId", format!("There is no decision this turn (got {})", id))),
+        (Some(decision), "") if choices.defer_decision => errors.push(InvalidChoice::new(
+            "decisionId",
+            format!("Name the decision to put off ({})", decision.id),
+        )),
+        (Some(decision), "") => errors.push(InvalidChoice::new(
+            "decisionId",
+            format!("This turn's decision ({}) must be answered", decision.id),
+        )),
+        (Some(decision), id) if id != decision.id => errors.push(InvalidChoice::new(
+            "decisionId",
+            format!("{} is not this turn's decision ({})", id, decision.id),
+        )),
+        (Some(decision), _) if choices.defer_decision => {
+            if decision.defer_consequence.is_none() {
+                errors.push(InvalidChoice::new("deferDecision", format!("{} can't be put off", decision.id)));
+            }
+        }
+        (Some(decision), _) => match decision.options.get(choices.decision_option_index) {
+            None => errors.push(InvalidChoice::new(
+                "decisionOptionIndex",
+                format!("{} has {} options (got index {})", decision.id, decision.options.len(), choices.decision_option_index),
+            )),
+            Some(option) => {
+                if let Some(ref tag) = option.requires_tag {
+                    if !state.credentials.contains(tag) {
+                        errors.push(InvalidChoice::new(
+                            "decisionOptionIndex",
+                            format!("\"{}\" requires {}", option.label, tag),
+                        ));
+                    }
+                }
+            }
+        },
+    }
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+/// Phase 3: respond to each drawn card with one of its options. `events` are the cards
+/// drawn for this turn, in draw order.
+pub fn validate_event_answers(choices: &PlayerChoices, events: &[EventCard]) -> Result<(), Vec<InvalidChoice>> {
+    let mut errors = Vec::new();
+    for (i, event) in events.iter().enumerate() {
+        let field = if i == 0 { "eventOptionIndex".to_string() } else { format!("eventOptionIndices[{}]", i) };
+        match choices.event_answer(i) {
+            None if !event.options.is_empty() => errors.push(InvalidChoice::new(field, format!("{} must be answered", event.id))),
+            Some(index) if index >= event.options.len() => errors.push(InvalidChoice::new(
+                field,
+                format!("{} has {} options (got index {})", event.id, event.options.len(), index),
+            )),
+            _ => {}
+        }
+    }
+    if events.is_empty() && choices.event_option_index.is_some() {
+        errors.push(InvalidChoice::new("eventOptionIndex", "There is no event this turn"));
+    }
+    let answers = choices.extra_event_options.len() + 1;
+    if answers > 1 && answers > events.len() {
+        errors.push(InvalidChoice::new(
+            "eventOptionIndices",
+            format!("{} answers were sent but {} cards were drawn this turn", answers, events.len()),
+        ));
+    }
+    // A client answering a card other than the one drawn is out of step with the game
+    match (events.first(), choices.event_id.as_deref()) {
+        (Some(event), Some(id)) if id != event.id => errors.push(InvalidChoice::new(
+            "eventId",
+            format!("{} is not this turn's card ({})", id, event.id),
+        )),
+        (None, Some(id)) => errors.push(InvalidChoice::new("eventId", format!("There is no event this turn (got {})", id))),
+        _ => {}
+    }
+
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_loader::test_data;
+
+    fn start(data: &GameData) -> GameState {
+        let mut state = GameState::new("CHOICES_TEST".to_string());
+        let first = &data.stages[0];
+        state.current_turn = first.start_turn;
+        state.enter_stage(first);
+        state
+    }
+
+    /// Choices a well-behaved client would send: one action, the first open option.
+    fn valid_choices(state: &GameState, data: &GameData) -> PlayerChoices {
+        let action = data.actions.iter().find(|a| a.stages.contains(&state.current_stage)).unwrap();
+        let decision = current_decision(state, data);
+        let option = decision.as_ref().map_or(0, |d| {
+            d.options.iter().position(|o| o.requires_tag.is_none()).unwrap()
+        });
+        PlayerChoices {
+            action_ids: vec![action.id.clone()],
+            decision_id: decision.map(|d| d.id.clone()).unwrap_or_default(),
+            decision_option_index: option,
+            defer_decision: false,
+            event_option_index: Some(0),
+            event_id: None,
+            extra_event_options: Vec::new(),
+        }
+    }
+
+    fn fields(result: Result<(), Vec<InvalidChoice>>) -> Vec<String> {
+        result.expect_err("Choices should be rejected").into_iter().map(|e| e.field).collect()
+    }
+
+    #[test]
+    fn test_valid_choices_pass() {
+        let data = test_data();
+        let state = start(&data);
+        let choices = valid_choices(&state, &data);
+        assert_eq!(validate_choices(&state, &choices, &data, &data.events[..1]), Ok(()));
+    }
+
+    #[test]
+    fn test_bad_actions_are_rejected() {
+        let data = test_data();
+        let state = start(&data);
+        let other_stage = data.actions.iter().find(|a| !a.stages.contains(&state.current_stage)).unwrap();
+        let mut choices = valid_choices(&state, &data);
+        let first = choices.action_ids[0].clone();
+        choices.action_ids.extend(["no_such_action".to_string(), other_stage.id.clone(), first]);
+
+        let fields = fields(validate_choices(&state, &choices, &data, &data.events[..1]));
+        assert_eq!(fields, vec!["actionIds[1]", "actionIds[2]", "actionIds[3]"]);
+    }
+
+    #[test]
+    fn test_action_on_cooldown_is_rejected() {
+        let mut data = test_data();
+        let mut state = start(&data);
+        let choices = valid_choices(&state, &data);
+        let action_id = choices.action_ids[0].clone();
+        data.actions.iter_mut().find(|a| a.id == action_id).unwrap().cooldown = 1;
+        // Used last turn: still cooling down
+        state.action_last_used.insert(action_id.clone(), state.current_turn - 1);
+        assert_eq!(fields(validate_choices(&state, &choices, &data, &data.events[..1])), vec!["actionIds[0]"]);
+
+        // Without a cooldown it could be taken again right away
+        data.actions.iter_mut().find(|a| a.id == action_id).unwrap().cooldown = 0;
+        assert_eq!(validate_choices(&state, &choices, &data, &data.events[..1]), Ok(()));
+    }
+
+    #[test]
+    fn test_only_deferrable_decisions_can_be_put_off() {
+        let mut data = test_data();
+        let state = start(&data);
+        let mut choices = valid_choices(&state, &data);
+        choices.defer_decision = true;
+        assert_eq!(fields(validate_choices(&state, &choices, &data, &data.events[..1])), vec!["deferDecision"]);
+
+        let decision_id = choices.decision_id.clone();
+        data.decisions.iter_mut().find(|d| d.id == decision_id).unwrap().defer_consequence = Some(Default::default());
+        assert_eq!(validate_choices(&state, &choices, &data, &data.events[..1]), Ok(()));
+        choices.decision_id.clear();
+        assert_eq!(fields(validate_choices(&state, &choices, &data, &data.events[..1])), vec!["decisionId"]);
+    }
+
+    #[test]
+    fn test_overbooked_time_is_rejected() {
+        let data = test_data();
+        let mut state = start(&data);
+        let mut choices = valid_choices(&state, &data);
+        state.time_slots = 0;
+        choices.event_option_index = None;
+        assert_eq!(fields(validate_choices(&state, &choices, &data, &[])), vec!["actionIds"]);
+    }
+
+    #[test]
+    fn test_bad_decision_is_rejected() {
+        let data = test_data();
+        let state = start(&data);
+        let decision = current_decision(&state, &data).expect("First turn has a decision");
+
+        let mut choices = valid_choices(&state, &data);
+        choices.decision_id = String::new();
+        assert_eq!(fields(validate_choices(&state, &choices, &data, &data.events[..1])), vec!["decisionId"]);
+
+        let other = data.decisions.iter().find(|d| d.id != decision.id).unwrap();
+        choices.decision_id = other.id.clone();
+        assert_eq!(fields(validate_choices(&state, &choices, &data, &data.events[..1])), vec!["decisionId"]);
+
+        choices.decision_id = decision.id.clone();
+        choices.decision_option_index = decision.options.len();
+        assert_eq!(fields(validate_choices(&state, &choices, &data, &data.events[..1])), vec!["decisionOptionIndex"]);
+    }
+
+    #[test]
+    fn test_locked_decision_option_is_rejected() {
+        let mut data = test_data();
+        let state = start(&data);
+        let decision_id = current_decision(&state, &data).unwrap().id.clone();
+        let decision = data.decisions.iter_mut().find(|d| d.id == decision_id).unwrap();
+        decision.options[0].requires_tag = Some("Pilot License".to_string());
+
+        let mut choices = valid_choices(&state, &data);
+        choices.decision_option_index = 0;
+        let errors = validate_choices(&state, &choices, &data, &data.events[..1]).unwrap_err();
+        assert_eq!(errors[0].field, "decisionOptionIndex");
+        assert!(errors[0].message.contains("Pilot License"), "{:?}", errors);
+    }
+
+    #[test]
+    fn test_preview_decision_matches_applying_it() {
+        let data = test_data();
+        let mut state = start(&data);
+        state.stress = 0;
+        let decision = current_decision(&state, &data).unwrap();
+        let previews = preview_decision(&state, &decision, &data);
+        assert_eq!(previews.len(), decision.options.len());
+
+        for (preview, option) in previews.iter().zip(&decision.options) {
+            let mut applied = state.clone();
+            turn_runner::apply_decision_option(&mut applied, option, &data);
+            assert_eq!((preview.money, preview.stress, preview.support), (applied.money, applied.stress, applied.support));
+            assert!(preview.stress >= 0, "Stress should be clamped");
+            if let Some(ref tag) = option.grants_tag {
+                assert_eq!(preview.tags_granted, vec![tag.clone()]);
+            }
+        }
+        assert_eq!(state.stress, 0, "Previewing must not touch the state");
+    }
+
+    #[test]
+    fn test_bad_event_option_is_rejected() {
+        let data = test_data();
+        let state = start(&data);
+        let event = &data.events[0];
+        let mut choices = valid_choices(&state, &data);
+
+        choices.event_option_index = Some(event.options.len());
+        assert_eq!(fields(validate_choices(&state, &choices, &data, std::slice::from_ref(event))), vec!["eventOptionIndex"]);
+        choices.event_option_index = None;
+        assert_eq!(fields(validate_choices(&state, &choices, &data, std::slice::from_ref(event))), vec!["eventOptionIndex"]);
+        choices.event_option_index = Some(0);
+        assert_eq!(fields(validate_choices(&state, &choices, &data, &[])), vec!["eventOptionIndex"]);
+    }
+
+    #[test]
+    fn test_answering_another_card_is_rejected() {
+        let data = test_data();
+        let state = start(&data);
+        let event = &data.events[0];
+        let mut choices = valid_choices(&state, &data);
+        choices.event_option_index = Some(0);
+
+        choices.event_id = Some(event.id.clone());
+        assert!(validate_choices(&state, &choices, &data, std::slice::from_ref(event)).is_ok());
+        choices.event_id = Some(data.events[1].id.clone());
+        assert_eq!(fields(validate_choices(&state, &choices, &data, std::slice::from_ref(event))), vec!["eventId"]);
+        choices.event_option_index = None;
+        assert_eq!(fields(validate_choices(&state, &choices, &data, &[])), vec!["eventId"]);
+    }
+
+    #[test]
+    fn test_every_card_dealt_must_be_answered() {
+        let data = test_data();
+        let state = start(&data);
+        let events = &data.events[..2];
+        let mut choices = valid_choices(&state, &data);
+
+        assert_eq!(fields(validate_choices(&state, &choices, &data, events)), vec!["eventOptionIndices[1]"]);
+        choices.extra_event_options = vec![Some(events[1].options.len())];
+        assert_eq!(fields(validate_choices(&state, &choices, &data, events)), vec!["eventOptionIndices[1]"]);
+        choices.extra_event_options = vec![Some(0)];
+        assert_eq!(validate_choices(&state, &choices, &data, events), Ok(()));
+        choices.extra_event_options = vec![Some(0), Some(0)];
+        assert_eq!(fields(validate_choices(&state, &choices, &data, events)), vec!["eventOptionIndices"]);
+    }
+}
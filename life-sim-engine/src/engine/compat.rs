@@ -0,0 +1,144 @@
+use serde_json::Value;
+use crate::engine::choices::InvalidChoice;
+use crate::engine::turn_runner::PlayerChoices;
+
+/// Versions of the turn submission format the server accepts.
+/// Each version's wire format is converted into `PlayerChoices`, so the engine only ever
+/// sees one shape. A breaking change to turn submission adds a variant (served at
+/// /api/v2) and a parser here; older clients keep using theirs until it is retired.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiVersion {
+    V1,
+}
+
+impl ApiVersion {
+    /// Every version currently served, oldest first.
+    pub const ALL: &'static [ApiVersion] = &[ApiVersion::V1];
+
+    /// The version served at the unversioned /api prefix (deprecated).
+    pub const LEGACY: ApiVersion = ApiVersion::V1;
+
+    /// Path segment the version is served under, e.g. "v1".
+    pub fn segment(self) -> &'static str {
+        match self {
+            ApiVersion::V1 => "v1",
+        }
+    }
+
+    /// Read a turn submission written in this version's format.
+    pub fn parse_choices(self, body: &Value) -> Result<PlayerChoices, Vec<InvalidChoice>> {
+        match self {
+            ApiVersion::V1 => parse_v1(body),
+        }
+    }
+}
+
+/// v1: `{ actionIds, decisionId, decisionOptionIndex, deferDecision, eventOptionIndex, eventId }`,
+/// or `eventOptionIndices` in place of `eventOptionIndex` to answer every card a turn deals, in
+/// draw order. Absent or null fields mean "none".
+fn parse_v1(body: &Value) -> Result<PlayerChoices, Vec<InvalidChoice>> {
+    let mut invalid = Vec::new();
+    let mut field = |name: &str, expected: &str, valid: fn(&Value) -> bool| {
+        match body.get(name) {
+            None | Some(Value::Null) => None,
+            Some(v) if valid(v) => Some(v.clone()),
+            Some(_) => {
+                invalid.push(InvalidChoice::new(name, format!("{} must be {}", name, expected)));
+                None
+            }
+        }
+    };
+    let action_ids: Vec<String> = field("actionIds", "a list of action IDs", |v| {
+        v.as_array().is_some_and(|ids| ids.iter().all(|id| id.is_string()))
+    })
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default();
+    let decision_id = field("decisionId", "a decision ID", Value::is_string)
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default();
+    let decision_option_index = field("decisionOptionIndex", "an option index", Value::is_u64)
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0) as usize;
+    let defer_decision = field("deferDecision", "true or false", Value::is_boolean)
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    let event_option_index = field("eventOptionIndex", "an option index", Value::is_u64)
+        .and_then(|v| v.as_u64())
+        .map(|v| v as usize);
+    let event_id = field("eventId", "an event card ID", Value::is_string)
+        .and_then(|v| v.as_str().map(str::to_string));
+    let event_option_indices: Option<Vec<Option<usize>>> = field("eventOptionIndices", "a list of option indices", |v| {
+        v.as_array().is_some_and(|answers| answers.iter().all(|a| a.is_u64() || a.is_null()))
+    })
+        .and_then(|v| serde_json::from_value(v).ok());
+
+    let (event_option_index, extra_event_options) = match event_option_indices {
+        Some(_) if event_option_index.is_some() => {
+            invalid.push(InvalidChoice::new("eventOptionIndices", "Send eventOptionIndex or eventOptionIndices, not both"));
+            (None, Vec::new())
+        }
+        Some(answers) => (answers.first().copied().flatten(), answers.into_iter().skip(1).collect()),
+        None => (event_option_index, Vec::new()),
+    };
+    if !invalid.is_empty() {
+        return Err(invalid);
+    }
+    Ok(PlayerChoices {
+        action_ids,
+        decision_id,
+        decision_option_index,
+        defer_decision,
+        event_option_index,
+        event_id,
+        extra_event_options,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_v1_choices_parse() {
+        let choices = ApiVersion::V1.parse_choices(&json!({
+            "actionIds": ["act_study"],
+            "decisionId": "dec_club",
+            "decisionOptionIndex": 1,
+            "deferDecision": true,
+            "eventOptionIndex": null,
+            "eventId": "evt_flat_tire",
+        })).unwrap();
+        assert_eq!(choices.action_ids, vec!["act_study"]);
+        assert_eq!(choices.decision_id, "dec_club");
+        assert_eq!(choices.decision_option_index, 1);
+        assert!(choices.defer_decision);
+        assert_eq!(choices.event_option_index, None);
+        assert_eq!(choices.event_id.as_deref(), Some("evt_flat_tire"));
+
+        let empty = ApiVersion::V1.parse_choices(&json!({})).unwrap();
+        assert!(empty.action_ids.is_empty() && empty.decision_id.is_empty() && !empty.defer_decision);
+    }
+
+    #[test]
+    fn test_v1_answers_every_card_in_order() {
+        let choices = ApiVersion::V1.parse_choices(&json!({ "eventOptionIndices": [1, null, 0] })).unwrap();
+        assert_eq!(choices.event_option_index, Some(1));
+        assert_eq!(choices.extra_event_options, vec![None, Some(0)]);
+        assert_eq!((choices.event_answer(1), choices.event_answer(2), choices.event_answer(3)), (None, Some(0), None));
+
+        let errors = ApiVersion::V1.parse_choices(&json!({ "eventOptionIndex": 1, "eventOptionIndices": [1] })).unwrap_err();
+        assert_eq!(errors[0].field, "eventOptionIndices");
+    }
+
+    #[test]
+    fn test_v1_rejects_mistyped_fields() {
+        let errors = ApiVersion::V1.parse_choices(&json!({
+            "actionIds": "act_study",
+            "decisionOptionIndex": -1,
+            "deferDecision": "yes",
+        })).unwrap_err();
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, vec!["actionIds", "decisionOptionIndex", "deferDecision"]);
+    }
+}
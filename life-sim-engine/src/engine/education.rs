@@ -0,0 +1,142 @@
+use crate::engine::game_state::{Enrollment, GameState};
+use crate::models::EducationProgram;
+
+/// Enroll the player in a program (Phase 2).
+/// Players can only attend one program at a time.
+pub fn enroll(state: &mut GameState, program: &EducationProgram, finance_with_loan: bool) -> Vec<String> {
+    if let Some(ref current) = state.enrollment {
+        return vec![format!("🎓 Already enrolled in {}", current.program.title)];
+    }
+    if state.credentials.contains(&program.grants_tag) {
+        return vec![format!("🎓 You already hold {}", program.grants_tag)];
+    }
+
+    state.enrollment = Some(Enrollment {
+        program: program.clone(),
+        turns_completed: 0,
+        financed_by_loan: finance_with_loan,
+    });
+
+    let payment = if finance_with_loan { "student loans" } else { "tuition" };
+    vec![format!(
+        "🎓 Enrolled in {} ({} turns, ${}/turn {})",
+        program.title, program.duration_turns, program.tuition_per_turn, payment
+    )]
+}
+
+/// Attend one turn of school (Phase 4): charge tuition, count progress,
+/// and grant the degree once the program is complete.
+pub fn advance_education(state: &mut GameState) -> Vec<String> {
+    let mut feedback = Vec::new();
+    let Some(mut enrollment) = state.enrollment.take() else {
+        return feedback;
+    };
+
+    let tuition = enrollment.program.tuition_per_turn;
+    if enrollment.financed_by_loan {
+        state.student_loan += tuition;
+        feedback.push(format!("🎓 Tuition borrowed: ${} (loan balance: ${})", tuition, state.student_loan));
+    } else {
+        state.money -= tuition;
+        feedback.push(format!("🎓 Tuition: -${}", tuition));
+    }
+
+    enrollment.turns_completed += 1;
+    if enrollment.turns_completed >= enrollment.program.duration_turns {
+        let tag = &enrollment.program.grants_tag;
+        if !state.credentials.contains(tag) {
+            state.credentials.grant(tag);
+        }
+        feedback.push(format!("🎓 Graduated from {}! Earned: {}", enrollment.program.title, tag));
+    } else {
+        feedback.push(format!(
+            "🎓 {}: {}/{} turns complete",
+            enrollment.program.title, enrollment.turns_completed, enrollment.program.duration_turns
+        ));
+        state.enrollment = Some(enrollment);
+    }
+
+    feedback
+}
+
+/// Make the per-turn student loan payment (Stage D, Phase 4).
+pub fn apply_loan_repayment(state: &mut GameState, payment: i32) -> Vec<String> {
+    let mut feedback = Vec::new();
+    if state.student_loan > 0 && payment > 0 {
+        let paid = payment.min(state.student_loan);
+        state.student_loan -= paid;
+        state.money -= paid;
+        if state.student_loan == 0 {
+            feedback.push(format!("🎓 Loan payment: -${} — student loans paid off!", paid));
+        } else {
+            feedback.push(format!("🎓 Loan payment: -${} (remaining: ${})", paid, state.student_loan));
+        }
+    }
+    feedback
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Stage;
+
+    fn make_program() -> EducationProgram {
+        EducationProgram {
+            id: "edu_test".to_string(),
+            title: "Test College".to_string(),
+            description: "Test".to_string(),
+            stages: vec![Stage::POST_HIGH],
+            duration_turns: 2,
+            time_cost: 1,
+            tuition_per_turn: 40,
+            grants_tag: "Test Degree".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_enroll_and_graduate_paying_cash() {
+        let mut state = GameState::new("EDU".to_string());
+        enroll(&mut state, &make_program(), false);
+        assert_eq!(state.available_time_slots(), 2, "Classes should reserve a time slot");
+
+        advance_education(&mut state);
+        assert_eq!(state.money, 60);
+        assert!(state.enrollment.is_some());
+        assert!(!state.credentials.contains("Test Degree"));
+
+        let fb = advance_education(&mut state);
+        assert_eq!(state.money, 20);
+        assert!(state.enrollment.is_none(), "Enrollment should end on graduation");
+        assert!(state.credentials.contains("Test Degree"));
+        assert!(fb.iter().any(|f| f.contains("Graduated")));
+        assert_eq!(state.available_time_slots(), 3);
+    }
+
+    #[test]
+    fn test_loan_financing_and_repayment() {
+        let mut state = GameState::new("LOAN".to_string());
+        enroll(&mut state, &make_program(), true);
+        advance_education(&mut state);
+        advance_education(&mut state);
+        assert_eq!(state.money, 100, "Loan-financed tuition should not touch cash");
+        assert_eq!(state.student_loan, 80);
+
+        apply_loan_repayment(&mut state, 50);
+        assert_eq!(state.student_loan, 30);
+        assert_eq!(state.money, 50);
+        let fb = apply_loan_repayment(&mut state, 50);
+        assert_eq!(state.student_loan, 0, "Payment should cap at the remaining balance");
+        assert_eq!(state.money, 20);
+        assert!(fb[0].contains("paid off"));
+    }
+
+    #[test]
+    fn test_cannot_double_enroll() {
+        let mut state = GameState::new("DOUBLE".to_string());
+        enroll(&mut state, &make_program(), false);
+        advance_education(&mut state);
+        let fb = enroll(&mut state, &make_program(), false);
+        assert!(fb[0].contains("Already enrolled"));
+        assert_eq!(state.enrollment.as_ref().unwrap().turns_completed, 1, "Progress should be kept");
+    }
+}
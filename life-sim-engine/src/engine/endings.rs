@@ -0,0 +1,485 @@
+use std::cmp::Reverse;
+use schemars::JsonSchema;
+use serde::Serialize;
+use crate::engine::game_state::GameState;
+use crate::engine::paths;
+use crate::models::{Ending, LifePath};
+use crate::models::ending::{EndingConditions, ThresholdCondition};
+
+/// Where the current run stands against one ending.
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EndingStatus {
+    /// The player meets every condition right now.
+    pub satisfied: bool,
+    /// This is the ending the player gets (or would get if the game ended now).
+    pub reached: bool,
+    /// The ending can no longer be reached this run.
+    pub missed: bool,
+    /// Conditions the player doesn't meet, e.g. "Money ≥ $200 (have $150)".
+    pub unmet: Vec<String>,
+}
+
+/// The ending a run gets, with the badges it earned alongside it.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Resolution<'a> {
+    pub primary: Option<&'a Ending>,
+    /// Every badge whose conditions hold, in file order.
+    pub badges: Vec<&'a Ending>,
+}
+
+/// An ending the run only just missed: every condition it failed, it failed by a little.
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NearMiss {
+    pub ending_id: String,
+    pub title: String,
+    /// How far off each missed condition was, e.g. "$40" or "1 credential".
+    pub gaps: Vec<String>,
+    /// "You were $40 away from Stable + Credentialed."
+    pub message: String,
+}
+
+/// How far off a condition can be and still count as a near miss.
+const NEAR_MONEY: i32 = 50;
+const NEAR_STRESS: i32 = 5;
+const NEAR_SUPPORT: i32 = 2;
+const NEAR_CREDENTIALS: u32 = 1;
+
+/// The endings (not badges) the run nearly reached, in file order: ones it doesn't get
+/// whose every unmet condition is a number that was close. A missing path, tag or job
+/// family is never close.
+pub fn near_misses(state: &GameState, endings: &[Ending]) -> Vec<NearMiss> {
+    let reached = resolve(state, endings).map(|e| e.id.as_str());
+    endings.iter()
+        .filter(|e| !e.badge && reached != Some(e.id.as_str()))
+        .filter_map(|ending| {
+            let gaps = gaps(state, &ending.conditions)?;
+            if gaps.is_empty() {
+                return None;
+            }
+            let message = format!("You were {} away from {}.", join_and(&gaps), ending.title);
+            Some(NearMiss { ending_id: ending.id.clone(), title: ending.title.clone(), gaps, message })
+        })
+        .collect()
+}
+
+/// How far off each unmet condition is, or None if any is too far (or can't be measured).
+fn gaps(state: &GameState, conditions: &EndingConditions) -> Option<Vec<String>> {
+    let mut gaps = Vec::new();
+    let mut threshold = |condition: &Option<ThresholdCondition>, value: i32, near: i32, describe: &dyn Fn(i32) -> String| {
+        let Some(c) = condition else { return true };
+        let off = c.min.map_or(0, |min| min - value).max(c.max.map_or(0, |max| value - max));
+        if off > near {
+            return false;
+        }
+        if off > 0 {
+            gaps.push(describe(off));
+        }
+        true
+    };
+    let points = |name: &'static str| move |off: i32| format!("{} {} point{}", off, name, if off == 1 { "" } else { "s" });
+    let close = threshold(&conditions.money, state.money, NEAR_MONEY, &|off| format!("${}", off))
+        && threshold(&conditions.stress, state.stress, NEAR_STRESS, &points("stress"))
+        && threshold(&conditions.support, state.support, NEAR_SUPPORT, &points("support"))
+        && threshold(&conditions.savings, state.savings, NEAR_MONEY, &|off| format!("${} of savings", off))
+        && threshold(&conditions.emergency_fund, state.emergency_fund, NEAR_MONEY, &|off| format!("${} of emergency fund", off));
+    if !close {
+        return None;
+    }
+
+    if let Some(min) = conditions.credentials.as_ref().and_then(|c| c.min_count) {
+        let off = min.saturating_sub(state.credentials.len() as u32);
+        if off > NEAR_CREDENTIALS {
+            return None;
+        }
+        if off > 0 {
+            gaps.push(format!("{} credential{}", off, if off == 1 { "" } else { "s" }));
+        }
+    }
+    if conditions.debt_free && !state.is_debt_free() {
+        let owed = (-state.money).max(0) + state.student_loan.max(0);
+        if owed > NEAR_MONEY {
+            return None;
+        }
+        gaps.push(format!("${} of debt", owed));
+    }
+    let on_path = conditions.path.as_ref().is_none_or(|p| paths::on_path(state, std::slice::from_ref(p)));
+    let has_tags = conditions.required_tags.iter().all(|tag| state.credentials.contains(tag));
+    let in_family = conditions.job_family.as_ref().is_none_or(|family| {
+        state.current_job.as_ref().and_then(|j| j.family.as_ref()) == Some(family)
+    });
+    (on_path && has_tags && in_family).then_some(gaps)
+}
+
+/// "a", "a and b", "a, b and c".
+fn join_and(parts: &[String]) -> String {
+    match parts.split_last() {
+        None => String::new(),
+        Some((only, [])) => only.clone(),
+        Some((last, rest)) => format!("{} and {}", rest.join(", "), last),
+    }
+}
+
+/// The ending the player gets. Of the endings (not badges) whose conditions all hold, the
+/// most specific wins (the one setting the most bounds), then the highest priority, then
+/// the first in file order.
+pub fn resolve<'a>(state: &GameState, endings: &'a [Ending]) -> Option<&'a Ending> {
+    endings.iter()
+        .enumerate()
+        .filter(|(_, e)| !e.badge && unmet_conditions(state, &e.conditions).is_empty())
+        .max_by_key(|&(index, e)| (e.conditions.specificity(), e.priority, Reverse(index)))
+        .map(|(_, e)| e)
+}
+
+/// The ending the player gets, plus every badge they've earned.
+pub fn resolve_all<'a>(state: &GameState, endings: &'a [Ending]) -> Resolution<'a> {
+    Resolution {
+        primary: resolve(state, endings),
+        badges: endings.iter()
+            .filter(|e| e.badge && unmet_conditions(state, &e.conditions).is_empty())
+            .collect(),
+    }
+}
+
+/// How the run stands against every ending, in file order.
+/// Once the game is over, any ending not reached is missed.
+pub fn statuses(state: &GameState, endings: &[Ending], game_over: bool) -> Vec<EndingStatus> {
+    let reached = resolve(state, endings).map(|e| e.id.as_str());
+    endings.iter().map(|ending| {
+        let unmet = unmet_conditions(state, &ending.conditions);
+        // A life path is chosen once, so another path rules the ending out for good
+        let wrong_path = ending.conditions.path.as_ref()
+            .is_some_and(|p| state.path.is_some() && !paths::on_path(state, std::slice::from_ref(p)));
+        // Badges don't compete: every one whose conditions hold is reached
+        let is_reached = if ending.badge { unmet.is_empty() } else { reached == Some(ending.id.as_str()) };
+        EndingStatus {
+            satisfied: unmet.is_empty(),
+            reached: is_reached,
+            missed: wrong_path || (game_over && !is_reached),
+            unmet,
+        }
+    }).collect()
+}
+
+/// Human-readable descriptions of the conditions the player doesn't meet (empty if all hold).
+pub fn unmet_conditions(state: &GameState, conditions: &EndingConditions) -> Vec<String> {
+    let mut unmet = Vec::new();
+    let mut threshold = |name: &str, unit: &str, condition: &Option<ThresholdCondition>, value: i32| {
+        let Some(c) = condition else { return };
+        if let Some(min) = c.min.filter(|min| value < *min) {
+            unmet.push(format!("{} ≥ {}{} (have {}{})", name, unit, min, unit, value));
+        }
+        if let Some(max) = c.max.filter(|max| value > *max) {
+            unmet.push(format!("{} ≤ {}{} (have {}{})", name, unit, max, unit, value));
+        }
+    };
+    threshold("Money", "$", &conditions.money, state.money);
+    threshold("Stress", "", &conditions.stress, state.stress);
+    threshold("Support", "", &conditions.support, state.support);
+    threshold("Savings", "$", &conditions.savings, state.savings);
+    threshold("Emergency fund", "$", &conditions.emergency_fund, state.emergency_fund);
+
+    if let Some(min) = conditions.credentials.as_ref().and_then(|c| c.min_count) {
+        let have = state.credentials.len() as u32;
+        if have < min {
+            unmet.push(format!("Credentials ≥ {} (have {})", min, have));
+        }
+    }
+    if let Some(ref path) = conditions.path {
+        if !paths::on_path(state, std::slice::from_ref(path)) {
+            unmet.push(format!("On the {} path", path));
+        }
+    }
+    for tag in conditions.required_tags.iter().filter(|tag| !state.credentials.contains(tag)) {
+        unmet.push(format!("Holds {}", tag));
+    }
+    if let Some(ref family) = conditions.job_family {
+        let current = state.current_job.as_ref().and_then(|j| j.family.as_deref());
+        if current != Some(family.as_str()) {
+            unmet.push(format!("Working in {} (have {})", family, current.unwrap_or("none")));
+        }
+    }
+    if conditions.debt_free && !state.is_debt_free() {
+        unmet.push(format!("Debt-free (owe ${})", (-state.money).max(0) + state.student_loan.max(0)));
+    }
+    unmet
+}
+
+/// A locked ending's title as the gallery shows it: every letter and digit blacked out,
+/// keeping the spaces and punctuation so the shape still shows.
+pub fn silhouette(title: &str) -> String {
+    title.chars().map(|c| if c.is_alphanumeric() { '█' } else { c }).collect()
+}
+
+/// A nudge toward a locked ending: the author's hint, or else what its conditions ask for,
+/// e.g. "Finish with money of at least $200 and 3 or more credentials."
+pub fn hint(ending: &Ending, paths: &[LifePath]) -> String {
+    if let Some(ref hint) = ending.hint {
+        return hint.clone();
+    }
+    let conditions = &ending.conditions;
+    let mut parts = Vec::new();
+    let threshold = |name: &str, unit: &str, condition: &Option<ThresholdCondition>| {
+        match condition.as_ref().map(|c| (c.min, c.max)) {
+            Some((Some(min), Some(max))) => Some(format!("{} between {}{} and {}{}", name, unit, min, unit, max)),
+            Some((Some(min), None)) => Some(format!("{} of at least {}{}", name, unit, min)),
+            Some((None, Some(max))) => Some(format!("{} of at most {}{}", name, unit, max)),
+            _ => None,
+        }
+    };
+    parts.extend(threshold("money", "$", &conditions.money));
+    parts.extend(threshold("stress", "", &conditions.stress));
+    parts.extend(threshold("support", "", &conditions.support));
+    parts.extend(threshold("savings", "$", &conditions.savings));
+    if let Some(min) = conditions.credentials.as_ref().and_then(|c| c.min_count) {
+        parts.push(format!("{} or more credentials", min));
+    }
+    parts.extend(threshold("an emergency fund", "$", &conditions.emergency_fund));
+    parts.extend(conditions.required_tags.iter().map(|tag| format!("{} earned", tag)));
+    if let Some(ref family) = conditions.job_family {
+        parts.push(format!("a job in {}", family));
+    }
+    if conditions.debt_free {
+        parts.push("no debt".to_string());
+    }
+    if let Some(ref path) = conditions.path {
+        let title = paths.iter().find(|p| &p.id == path).map_or(path.as_str(), |p| p.title.as_str());
+        parts.push(format!("on the {} path", title));
+    }
+
+    match parts.split_last() {
+        None => "Reached when no other ending fits.".to_string(),
+        Some((only, [])) => format!("Finish with {}.", only),
+        Some((last, rest)) => format!("Finish with {} and {}.", rest.join(", "), last),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ending::CountCondition;
+
+    fn ending(id: &str, conditions: EndingConditions) -> Ending {
+        Ending {
+            id: id.to_string(),
+            title: id.to_string(),
+            conditions,
+            priority: 0,
+            narrative: String::new(),
+            reflections: vec![],
+            hint: None,
+            badge: false,
+        }
+    }
+
+    fn no_conditions() -> EndingConditions {
+        EndingConditions::default()
+    }
+
+    fn rich() -> Ending {
+        ending("rich", EndingConditions {
+            money: Some(ThresholdCondition { min: Some(200), max: None }),
+            credentials: Some(CountCondition { min_count: Some(2) }),
+            ..no_conditions()
+        })
+    }
+
+    #[test]
+    fn test_first_satisfied_ending_is_reached() {
+        let mut state = GameState::new("ENDINGS".to_string());
+        state.money = 50;
+        let endings = vec![rich(), ending("fallback", no_conditions())];
+        assert_eq!(resolve(&state, &endings).unwrap().id, "fallback");
+
+        state.money = 250;
+        state.credentials.grant("A");
+        state.credentials.grant("B");
+        assert_eq!(resolve(&state, &endings).unwrap().id, "rich");
+    }
+
+    #[test]
+    fn test_most_specific_ending_wins_then_priority() {
+        let mut state = GameState::new("ENDINGS".to_string());
+        state.money = 250;
+        state.stress = 20;
+        let calm = |id: &str| ending(id, EndingConditions {
+            stress: Some(ThresholdCondition { min: None, max: Some(30) }),
+            ..no_conditions()
+        });
+        let calm_and_rich = ending("calm_and_rich", EndingConditions {
+            money: Some(ThresholdCondition { min: Some(200), max: None }),
+            stress: Some(ThresholdCondition { min: None, max: Some(30) }),
+            ..no_conditions()
+        });
+        let endings = vec![ending("fallback", no_conditions()), calm("calm"), calm_and_rich];
+        assert_eq!(resolve(&state, &endings).unwrap().id, "calm_and_rich", "Narrower beats earlier");
+
+        // Equally specific: priority, then file order
+        let mut endings = vec![calm("first"), calm("second")];
+        assert_eq!(resolve(&state, &endings).unwrap().id, "first");
+        endings[1].priority = 1;
+        assert_eq!(resolve(&state, &endings).unwrap().id, "second");
+    }
+
+    #[test]
+    fn test_badges_are_awarded_beside_the_ending() {
+        let mut state = GameState::new("ENDINGS".to_string());
+        state.money = 250;
+        state.support = 9;
+        let badge = |id: &str, conditions| Ending { badge: true, ..ending(id, conditions) };
+        let endings = vec![
+            badge("debt_free", EndingConditions { debt_free: true, ..no_conditions() }),
+            rich(),
+            badge("social", EndingConditions { support: Some(ThresholdCondition { min: Some(8), max: None }), ..no_conditions() }),
+            badge("loner", EndingConditions { support: Some(ThresholdCondition { min: None, max: Some(3) }), ..no_conditions() }),
+            ending("fallback", no_conditions()),
+        ];
+
+        let resolution = resolve_all(&state, &endings);
+        assert_eq!(resolution.primary.unwrap().id, "fallback", "A badge is never the ending, however specific");
+        let ids: Vec<&str> = resolution.badges.iter().map(|b| b.id.as_str()).collect();
+        assert_eq!(ids, vec!["debt_free", "social"]);
+
+        let over = statuses(&state, &endings, true);
+        assert!(over[0].reached && over[2].reached && over[4].reached);
+        assert!(over[3].missed && !over[3].reached);
+    }
+
+    #[test]
+    fn test_unmet_conditions_describe_the_gap() {
+        let mut state = GameState::new("ENDINGS".to_string());
+        state.money = 150;
+        let unmet = unmet_conditions(&state, &rich().conditions);
+        assert_eq!(unmet, vec!["Money ≥ $200 (have $150)", "Credentials ≥ 2 (have 0)"]);
+    }
+
+    #[test]
+    fn test_tags_job_family_debt_and_emergency_fund_conditions() {
+        let conditions = EndingConditions {
+            required_tags: vec!["CPR".to_string()],
+            job_family: Some("healthcare".to_string()),
+            debt_free: true,
+            emergency_fund: Some(ThresholdCondition { min: Some(100), max: None }),
+            ..no_conditions()
+        };
+        assert_eq!(conditions.specificity(), 4);
+
+        let mut state = GameState::new("ENDINGS".to_string());
+        state.money = -20;
+        state.student_loan = 30;
+        state.emergency_fund = 40;
+        assert_eq!(unmet_conditions(&state, &conditions), vec![
+            "Emergency fund ≥ $100 (have $40)",
+            "Holds CPR",
+            "Working in healthcare (have none)",
+            "Debt-free (owe $50)",
+        ]);
+
+        state.money = 10;
+        state.student_loan = 0;
+        state.emergency_fund = 150;
+        state.credentials.grant("CPR");
+        let mut job = crate::models::Job {
+            id: "job_aide".to_string(),
+            title: "Aide".to_string(),
+            required_tags: vec![],
+            required_levels: Default::default(),
+            recommended_tags: vec![],
+            pay_per_turn: 0,
+            stress_per_turn: 0,
+            growth_rate: 0,
+            growth_tag: None,
+            stages: vec![],
+            paths: vec![],
+            description: String::new(),
+            family: Some("trades".to_string()),
+            tiers: vec![],
+        };
+        state.current_job = Some(job.clone());
+        assert_eq!(unmet_conditions(&state, &conditions), vec!["Working in healthcare (have trades)"]);
+        job.family = Some("healthcare".to_string());
+        state.current_job = Some(job);
+        assert!(unmet_conditions(&state, &conditions).is_empty());
+
+        assert_eq!(
+            hint(&ending("caregiver", conditions), &[]),
+            "Finish with an emergency fund of at least $100, CPR earned, a job in healthcare and no debt.",
+        );
+    }
+
+    #[test]
+    fn test_near_misses_are_close_on_every_missed_condition() {
+        let mut state = GameState::new("ENDINGS".to_string());
+        state.money = 160;
+        state.credentials.grant("A");
+        let calm = ending("calm", EndingConditions {
+            stress: Some(ThresholdCondition { min: None, max: Some(10) }),
+            ..no_conditions()
+        });
+        let on_arts = ending("arts", EndingConditions {
+            money: Some(ThresholdCondition { min: Some(150), max: None }),
+            path: Some("path_arts".to_string()),
+            ..no_conditions()
+        });
+        let endings = vec![rich(), calm, on_arts, ending("fallback", no_conditions())];
+
+        let near = near_misses(&state, &endings);
+        let ids: Vec<&str> = near.iter().map(|n| n.ending_id.as_str()).collect();
+        assert_eq!(ids, vec!["rich"], "Stress 20 is too far from 10; a path is never close");
+        assert_eq!(near[0].gaps, vec!["$40", "1 credential"]);
+        assert_eq!(near[0].message, "You were $40 and 1 credential away from rich.");
+
+        state.stress = 13;
+        let near = near_misses(&state, &endings);
+        assert_eq!(near[1].message, "You were 3 stress points away from calm.");
+
+        state.money = 200;
+        state.credentials.grant("B");
+        assert!(near_misses(&state, &endings).iter().all(|n| n.ending_id != "rich"), "The ending reached isn't a miss");
+    }
+
+    #[test]
+    fn test_statuses_track_missed_endings() {
+        let mut state = GameState::new("ENDINGS".to_string());
+        state.money = 50;
+        let on_arts = ending("arts", EndingConditions { path: Some("path_arts".to_string()), ..no_conditions() });
+        let endings = vec![rich(), on_arts, ending("fallback", no_conditions())];
+
+        let mid_game = statuses(&state, &endings, false);
+        assert!(!mid_game[0].missed && !mid_game[0].satisfied);
+        assert!(!mid_game[1].missed, "No path chosen yet");
+        assert!(mid_game[2].reached && mid_game[2].satisfied);
+
+        state.path = Some(LifePath {
+            id: "path_trades".to_string(),
+            title: "Trades".to_string(),
+            description: String::new(),
+        });
+        assert!(statuses(&state, &endings, false)[1].missed, "Another path was chosen");
+
+        let over = statuses(&state, &endings, true);
+        assert!(over[0].missed && !over[2].missed);
+    }
+
+    #[test]
+    fn test_locked_endings_show_a_silhouette_and_hint() {
+        assert_eq!(silhouette("Stable + Credentialed"), "██████ + ████████████");
+
+        let paths = vec![LifePath { id: "path_arts".to_string(), title: "Arts".to_string(), description: String::new() }];
+        assert_eq!(hint(&rich(), &paths), "Finish with money of at least $200 and 2 or more credentials.");
+        let calm_artist = ending("calm_artist", EndingConditions {
+            stress: Some(ThresholdCondition { min: Some(10), max: Some(40) }),
+            support: Some(ThresholdCondition { min: None, max: Some(3) }),
+            path: Some("path_arts".to_string()),
+            ..no_conditions()
+        });
+        assert_eq!(hint(&calm_artist, &paths), "Finish with stress between 10 and 40, support of at most 3 and on the Arts path.");
+        assert_eq!(hint(&ending("fallback", no_conditions()), &paths), "Reached when no other ending fits.");
+
+        let mut authored = rich();
+        authored.hint = Some("Money talks.".to_string());
+        assert_eq!(hint(&authored, &paths), "Money talks.");
+    }
+}
@@ -0,0 +1,212 @@
+use std::cmp::Reverse;
+use schemars::JsonSchema;
+use serde::Serialize;
+use crate::data_loader::GameData;
+use crate::engine::endings;
+use crate::engine::game_state::GameState;
+use crate::engine::score::{self, POINTS_PER_CREDENTIAL};
+use crate::models::decision::DecisionOption;
+
+/// The closing story for a finished run: the ending's narrative, then a few sentences
+/// built from what actually happened in it.
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Epilogue {
+    /// The ending's narrative (if an ending was reached), then one paragraph per run fact.
+    pub paragraphs: Vec<String>,
+    pub longest_job: Option<JobTenure>,
+    pub biggest_decision: Option<BiggestDecision>,
+    /// Money the emergency fund covered over the run.
+    pub emergency_fund_used: i32,
+}
+
+/// The job the player held for the most turns.
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JobTenure {
+    pub job_id: String,
+    pub title: String,
+    pub turns: usize,
+    /// How many different jobs the player held over the run.
+    pub jobs_held: usize,
+}
+
+/// The answered decision whose option moved the player's stats the most, weighed on
+/// the score's scale (see `score::effect_points`).
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BiggestDecision {
+    pub turn: u32,
+    pub decision_id: String,
+    pub prompt: String,
+    pub choice: String,
+}
+
+/// Write the epilogue for the run so far.
+pub fn write(state: &GameState, data: &GameData) -> Epilogue {
+    let longest_job = longest_job(state, data);
+    let biggest_decision = biggest_decision(state, data);
+
+    let mut paragraphs = Vec::new();
+    if let Some(ending) = endings::resolve(state, &data.endings) {
+        paragraphs.push(ending.narrative.clone());
+    }
+    paragraphs.push(match &longest_job {
+        Some(job) if job.jobs_held > 1 => format!(
+            "Of the {} jobs you held, {} lasted longest: {}.", job.jobs_held, job.title, turns(job.turns),
+        ),
+        Some(job) => format!("You worked as {} for {}.", job.title, turns(job.turns)),
+        None => "You never held a steady job.".to_string(),
+    });
+    if let Some(decision) = &biggest_decision {
+        paragraphs.push(format!(
+            "The choice that shaped the most came on turn {}. Asked \"{}\", you chose {}.",
+            decision.turn, decision.prompt, decision.choice,
+        ));
+    }
+    paragraphs.push(if state.emergency_fund_used > 0 {
+        format!("When money ran short, your emergency fund stepped in and covered ${}.", state.emergency_fund_used)
+    } else if state.emergency_fund > 0 {
+        format!("Your ${} emergency fund was never touched.", state.emergency_fund)
+    } else {
+        "You never built an emergency fund.".to_string()
+    });
+
+    Epilogue { paragraphs, longest_job, biggest_decision, emergency_fund_used: state.emergency_fund_used }
+}
+
+/// "1 turn", "3 turns".
+fn turns(count: usize) -> String {
+    if count == 1 { "1 turn".to_string() } else { format!("{} turns", count) }
+}
+
+/// Count the turns ending in each job; ties go to the job held first.
+fn longest_job(state: &GameState, data: &GameData) -> Option<JobTenure> {
+    let mut held: Vec<(&str, usize)> = Vec::new();
+    for job_id in state.stats_history.iter().filter_map(|s| s.job_id.as_deref()) {
+        match held.iter_mut().find(|(id, _)| *id == job_id) {
+            Some((_, count)) => *count += 1,
+            None => held.push((job_id, 1)),
+        }
+    }
+    let &(job_id, turns) = held.iter().min_by_key(|(_, count)| Reverse(*count))?;
+    Some(JobTenure {
+        job_id: job_id.to_string(),
+        title: data.job(job_id).map_or_else(|| job_id.to_string(), |job| job.title.clone()),
+        turns,
+        jobs_held: held.len(),
+    })
+}
+
+/// The answered decision with the weightiest option; ties go to the earliest.
+fn biggest_decision(state: &GameState, data: &GameData) -> Option<BiggestDecision> {
+    state.decision_log.iter()
+        .filter_map(|entry| {
+            let decision = data.decision(entry.decision_id.as_deref()?)?;
+            let option = decision.options.get(entry.option_index?)?;
+            Some((option_weight(option), entry, decision, option))
+        })
+        .min_by_key(|(weight, ..)| Reverse(*weight))
+        .map(|(_, entry, decision, option)| BiggestDecision {
+            turn: entry.turn,
+            decision_id: decision.id.clone(),
+            prompt: decision.prompt.clone(),
+            choice: option.label.clone(),
+        })
+}
+
+fn option_weight(option: &DecisionOption) -> i32 {
+    let credential = if option.grants_tag.is_some() { POINTS_PER_CREDENTIAL } else { 0 };
+    option.effects.iter().map(score::effect_points).sum::<i32>() + credential
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_loader::test_data;
+    use crate::engine::bot;
+    use crate::engine::game_state::{DecisionEntry, StatSnapshot};
+    use crate::models::Stage;
+
+    fn snapshot(turn: u32, job_id: Option<&str>) -> StatSnapshot {
+        StatSnapshot {
+            turn,
+            stage: Stage::EARLY_ADULT,
+            money: 0,
+            stress: 0,
+            support: 0,
+            time_slots: 0,
+            money_earned: 0,
+            money_spent: 0,
+            credentials_earned: vec![],
+            event_id: None,
+            event_option_index: None,
+            extra_events: Vec::new(),
+            job_id: job_id.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_epilogue_weaves_in_run_facts() {
+        let data = test_data();
+        let jobs: Vec<&str> = data.jobs.iter().take(2).map(|j| j.id.as_str()).collect();
+        let mut state = GameState::new("EPILOGUE".to_string());
+        state.stats_history = vec![
+            snapshot(1, None),
+            snapshot(2, Some(jobs[0])),
+            snapshot(3, Some(jobs[1])),
+            snapshot(4, Some(jobs[1])),
+            snapshot(5, Some(jobs[0])),
+        ];
+
+        let epilogue = write(&state, &data);
+        let job = epilogue.longest_job.as_ref().expect("Jobs were held");
+        assert_eq!((job.job_id.as_str(), job.turns, job.jobs_held), (jobs[0], 2, 2), "Ties go to the job held first");
+        assert!(epilogue.paragraphs.iter().any(|p| p.contains(&job.title) && p.contains("2 turns")));
+        assert!(epilogue.paragraphs.contains(&"You never built an emergency fund.".to_string()));
+        assert!(epilogue.biggest_decision.is_none(), "Nothing was decided");
+
+        state.emergency_fund = 40;
+        assert!(write(&state, &data).paragraphs.iter().any(|p| p.contains("$40 emergency fund was never touched")));
+        state.emergency_fund_used = 75;
+        let epilogue = write(&state, &data);
+        assert_eq!(epilogue.emergency_fund_used, 75);
+        assert!(epilogue.paragraphs.iter().any(|p| p.contains("covered $75")));
+    }
+
+    #[test]
+    fn test_biggest_decision_is_the_weightiest_option_picked() {
+        let data = test_data();
+        let decision = &data.decisions[0];
+        let weights: Vec<i32> = decision.options.iter().map(option_weight).collect();
+        let heaviest = (0..weights.len()).min_by_key(|&i| Reverse(weights[i])).unwrap();
+        let lightest = (0..weights.len()).min_by_key(|&i| weights[i]).unwrap();
+        let entry = |turn, option_index| DecisionEntry {
+            turn,
+            stage: decision.stage.clone(),
+            decision_id: Some(decision.id.clone()),
+            option_index,
+            description: String::new(),
+            impact: String::new(),
+        };
+
+        let mut state = GameState::new("EPILOGUE".to_string());
+        state.decision_log = vec![entry(1, Some(lightest)), entry(2, None), entry(3, Some(heaviest))];
+        let biggest = write(&state, &data).biggest_decision.expect("Decisions were answered");
+        let expected_turn = if weights[heaviest] > weights[lightest] { 3 } else { 1 };
+        assert_eq!(biggest.turn, expected_turn, "Put-off decisions don't count; ties go to the earliest");
+        assert_eq!(biggest.prompt, decision.prompt);
+    }
+
+    #[test]
+    fn test_finished_run_opens_with_the_ending() {
+        let data = test_data();
+        let state = bot::play_game(&bot::FirstOption, &data, "EPILOGUE");
+        let epilogue = write(&state, &data);
+
+        let ending = endings::resolve(&state, &data.endings).expect("A finished run reaches an ending");
+        assert_eq!(epilogue.paragraphs[0], ending.narrative);
+        assert!(epilogue.longest_job.is_some(), "The bot takes a job");
+        assert!(epilogue.biggest_decision.is_some());
+    }
+}
@@ -0,0 +1,360 @@
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use schemars::JsonSchema;
+use serde::Serialize;
+use crate::models::{EventCard, Stage, Rarity, RarityTier};
+
+
+/// The chance of one card being drawn.
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CardOdds {
+    pub id: String,
+    pub title: String,
+    pub rarity: Rarity,
+    /// Where the card comes from: "deck", or "tutorial", "interview", or "breakdown" for
+    /// cards that take the draw ahead of the deck.
+    pub source: String,
+    /// Rarity weight in the deck (0 for cards from outside the deck).
+    pub weight: f64,
+    /// Between 0 and 1.
+    pub probability: f64,
+}
+
+/// Draw a stage-appropriate event card from the deck, weighted by the rarity tiers,
+/// without repeating cards already used in this playthrough.
+/// Path-specific cards are only drawn by players on that path, and seasonal cards only
+/// in their months.
+pub fn draw_event<'a>(
+    all_events: impl IntoIterator<Item = &'a EventCard>,
+    stage: &Stage,
+    path: Option<&str>,
+    month: Option<u32>,
+    used_ids: &[String],
+    tiers: &[RarityTier],
+    rng: &mut ChaCha8Rng,
+) -> Option<&'a EventCard> {
+    // Filter to eligible cards: matching stage and path, not yet used
+    let eligible = available_events(all_events, stage, path, month, used_ids);
+
+    if eligible.is_empty() {
+        return None;
+    }
+
+    // Weighted draw by rarity
+    let weights: Vec<f64> = eligible.iter().map(|e| rarity_weight(&e.rarity, tiers)).collect();
+    let total: f64 = weights.iter().sum();
+    let mut roll: f64 = rng.gen::<f64>() * total;
+
+    for (i, weight) in weights.iter().enumerate() {
+        roll -= weight;
+        if roll <= 0.0 {
+            return Some(eligible[i]);
+        }
+    }
+
+    // Fallback (should not reach here due to float math, but just in case)
+    Some(eligible.last().unwrap())
+}
+
+/// Shuffle cards into the order a shuffled deck deals them. Heavier tiers tend to come
+/// first: each card's place is a weighted draw from those not yet placed.
+pub fn shuffle_deck<'a>(
+    cards: impl IntoIterator<Item = &'a EventCard>,
+    tiers: &[RarityTier],
+    rng: &mut ChaCha8Rng,
+) -> Vec<&'a EventCard> {
+    // Sorting on u^(1/weight) is the same as drawing one card at a time by weight
+    let mut keyed: Vec<(f64, &EventCard)> = cards.into_iter()
+        .map(|card| (rng.gen::<f64>().powf(1.0 / rarity_weight(&card.rarity, tiers)), card))
+        .collect();
+    keyed.sort_by(|a, b| b.0.total_cmp(&a.0));
+    keyed.into_iter().map(|(_, card)| card).collect()
+}
+
+/// The first card in a shuffled deck's `order` that can be dealt: on the stage, on the
+/// player's path, in season, and not used yet.
+pub fn draw_next<'a>(
+    order: &[&'a EventCard],
+    stage: &Stage,
+    path: Option<&str>,
+    month: Option<u32>,
+    used_ids: &[String],
+) -> Option<&'a EventCard> {
+    available_events(order.iter().copied(), stage, path, month, used_ids).first().copied()
+}
+
+/// Odds of each card `draw_event` could return, in deck order. The probabilities sum to 1
+/// unless the deck is empty.
+pub fn draw_odds<'a>(
+    all_events: impl IntoIterator<Item = &'a EventCard>,
+    stage: &Stage,
+    path: Option<&str>,
+    month: Option<u32>,
+    used_ids: &[String],
+    tiers: &[RarityTier],
+) -> Vec<CardOdds> {
+    let eligible = available_events(all_events, stage, path, month, used_ids);
+    let total: f64 = eligible.iter().map(|e| rarity_weight(&e.rarity, tiers)).sum();
+    eligible.iter().map(|e| CardOdds {
+        id: e.id.clone(),
+        title: e.title.clone(),
+        rarity: e.rarity.clone(),
+        source: "deck".to_string(),
+        weight: rarity_weight(&e.rarity, tiers),
+        probability: rarity_weight(&e.rarity, tiers) / total,
+    }).collect()
+}
+
+/// A rarity's draw weight from rarities.json. Validation rejects cards whose rarity
+/// isn't defined there, so the 0 for an unknown tier never reaches a real draw.
+pub fn rarity_weight(rarity: &Rarity, tiers: &[RarityTier]) -> f64 {
+    tiers.iter().find(|t| t.id == rarity.id()).map_or(0.0, |t| t.weight)
+}
+
+/// The rarity tiers with their weights scaled to a player's luck.
+pub fn lucky_tiers(tiers: &[RarityTier], luck: i32) -> Vec<RarityTier> {
+    tiers.iter()
+        .map(|t| RarityTier { weight: t.weight_at_luck(luck), ..t.clone() })
+        .collect()
+}
+
+/// Get available events for a given stage and path (for preview/debugging). `all_events`
+/// can be the whole deck or just the stage's cards.
+pub fn available_events<'a>(
+    all_events: impl IntoIterator<Item = &'a EventCard>,
+    stage: &Stage,
+    path: Option<&str>,
+    month: Option<u32>,
+    used_ids: &[String],
+) -> Vec<&'a EventCard> {
+    all_events
+        .into_iter()
+        .filter(|e| {
+            e.stages.contains(stage)
+                && (e.paths.is_empty() || path.is_some_and(|p| e.paths.iter().any(|ep| ep == p)))
+                && (e.months.is_empty() || month.is_some_and(|m| e.months.contains(&m)))
+                && !used_ids.contains(&e.id)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::rng::create_rng;
+
+    fn make_test_events() -> Vec<EventCard> {
+        vec![
+            EventCard {
+                id: "evt_1".to_string(),
+                title: "Event 1".to_string(),
+                flavor_text: "Test".to_string(),
+                stages: vec![Stage::MIDDLE_SCHOOL],
+                rarity: Rarity::Common,
+                categories: vec![],
+                paths: vec![],
+                transition: false,
+                months: vec![],
+                options: vec![],
+                quiz: None,
+            },
+            EventCard {
+                id: "evt_2".to_string(),
+                title: "Event 2".to_string(),
+                flavor_text: "Test".to_string(),
+                stages: vec![Stage::MIDDLE_SCHOOL, Stage::HIGH_SCHOOL],
+                rarity: Rarity::Uncommon,
+                categories: vec![],
+                paths: vec![],
+                transition: false,
+                months: vec![],
+                options: vec![],
+                quiz: None,
+            },
+            EventCard {
+                id: "evt_3".to_string(),
+                title: "Event 3".to_string(),
+                flavor_text: "Test".to_string(),
+                stages: vec![Stage::HIGH_SCHOOL],
+                rarity: Rarity::Rare,
+                categories: vec![],
+                paths: vec![],
+                transition: false,
+                months: vec![],
+                options: vec![],
+                quiz: None,
+            },
+            EventCard {
+                id: "evt_4".to_string(),
+                title: "Event 4".to_string(),
+                flavor_text: "Test".to_string(),
+                stages: vec![Stage::MIDDLE_SCHOOL],
+                rarity: Rarity::Common,
+                categories: vec![],
+                paths: vec![],
+                transition: false,
+                months: vec![],
+                options: vec![],
+                quiz: None,
+            },
+        ]
+    }
+
+    fn make_tiers() -> Vec<RarityTier> {
+        [("common", 6.0, 0.0), ("uncommon", 3.0, 0.0), ("rare", 1.0, 0.25), ("legendary", 0.25, 0.5)]
+            .into_iter()
+            .map(|(id, weight, luck_bonus)| RarityTier { id: id.to_string(), label: id.to_string(), weight, luck_bonus })
+            .collect()
+    }
+
+    #[test]
+    fn test_filter_by_stage() {
+        let events = make_test_events();
+        let available = available_events(&events, &Stage::MIDDLE_SCHOOL, None, None, &[]);
+        assert_eq!(available.len(), 3, "Should find 3 middle school events");
+
+        let available = available_events(&events, &Stage::HIGH_SCHOOL, None, None, &[]);
+        assert_eq!(available.len(), 2, "Should find 2 high school events");
+    }
+
+    #[test]
+    fn test_seasonal_cards_only_in_their_months() {
+        let mut events = make_test_events();
+        events[3].months = vec![6, 7, 8];
+        let ids = |month| -> Vec<&str> {
+            available_events(&events, &Stage::MIDDLE_SCHOOL, None, month, &[]).iter().map(|e| e.id.as_str()).collect()
+        };
+        assert_eq!(ids(Some(7)), vec!["evt_1", "evt_2", "evt_4"]);
+        assert_eq!(ids(Some(12)), vec!["evt_1", "evt_2"]);
+        assert_eq!(ids(None), vec!["evt_1", "evt_2"], "Undated turns deal no seasonal cards");
+    }
+
+    #[test]
+    fn test_no_repeat_draw() {
+        let events = make_test_events();
+        let mut rng = create_rng("TESTDRAW");
+        let used = vec!["evt_1".to_string()];
+
+        // Draw multiple times — evt_1 should never appear
+        for _ in 0..20 {
+            let card = draw_event(&events, &Stage::MIDDLE_SCHOOL, None, None, &used, &make_tiers(), &mut rng);
+            assert!(card.is_some());
+            assert_ne!(card.unwrap().id, "evt_1", "Used card should never be drawn");
+        }
+    }
+
+    #[test]
+    fn test_empty_deck_returns_none() {
+        let events = make_test_events();
+        let mut rng = create_rng("EMPTY");
+        // Mark all middle school events as used
+        let used = vec!["evt_1".to_string(), "evt_2".to_string(), "evt_4".to_string()];
+        let card = draw_event(&events, &Stage::MIDDLE_SCHOOL, None, None, &used, &make_tiers(), &mut rng);
+        assert!(card.is_none(), "Should return None when all cards used");
+    }
+
+    #[test]
+    fn test_deterministic_draw() {
+        let events = make_test_events();
+        let mut rng1 = create_rng("SAME_SEED");
+        let mut rng2 = create_rng("SAME_SEED");
+
+        let card1 = draw_event(&events, &Stage::MIDDLE_SCHOOL, None, None, &[], &make_tiers(), &mut rng1);
+        let card2 = draw_event(&events, &Stage::MIDDLE_SCHOOL, None, None, &[], &make_tiers(), &mut rng2);
+
+        assert_eq!(card1.unwrap().id, card2.unwrap().id, "Same seed should draw same card");
+    }
+
+    #[test]
+    fn test_draw_odds_follow_rarity_weights() {
+        let events = make_test_events();
+        let odds = draw_odds(&events, &Stage::MIDDLE_SCHOOL, None, None, &["evt_4".to_string()], &make_tiers());
+        let ids: Vec<&str> = odds.iter().map(|o| o.id.as_str()).collect();
+        assert_eq!(ids, vec!["evt_1", "evt_2"]);
+        assert!((odds[0].probability - 6.0 / 9.0).abs() < 1e-9, "Common weighs 6 against Uncommon's 3");
+        assert!((odds.iter().map(|o| o.probability).sum::<f64>() - 1.0).abs() < 1e-9);
+
+        let all_used: Vec<String> = events.iter().map(|e| e.id.clone()).collect();
+        assert!(draw_odds(&events, &Stage::MIDDLE_SCHOOL, None, None, &all_used, &make_tiers()).is_empty());
+    }
+
+    #[test]
+    fn test_weights_come_from_the_tiers() {
+        let mut events = make_test_events();
+        events[0].rarity = Rarity::Legendary;
+        events[3].rarity = Rarity::from("mythic".to_string());
+        let mut tiers = make_tiers();
+        tiers.push(RarityTier { id: "mythic".to_string(), label: "Mythic".to_string(), weight: 0.75, luck_bonus: 0.0 });
+
+        let odds = draw_odds(&events, &Stage::MIDDLE_SCHOOL, None, None, &[], &tiers);
+        let weights: Vec<f64> = odds.iter().map(|o| o.weight).collect();
+        assert_eq!(weights, vec![0.25, 3.0, 0.75]);
+        assert!((odds[0].probability - 0.25 / 4.0).abs() < 1e-9);
+
+        // Retuning a tier changes the odds without touching the cards
+        tiers[1].weight = 1.0;
+        let odds = draw_odds(&events, &Stage::MIDDLE_SCHOOL, None, None, &[], &tiers);
+        assert!((odds[1].probability - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_luck_scales_the_weights() {
+        let mut events = make_test_events();
+        events[0].rarity = Rarity::Legendary;
+        let tiers = make_tiers();
+        assert_eq!(lucky_tiers(&tiers, 0).iter().map(|t| t.weight).collect::<Vec<_>>(), vec![6.0, 3.0, 1.0, 0.25]);
+
+        let odds_at = |luck| draw_odds(&events, &Stage::MIDDLE_SCHOOL, None, None, &[], &lucky_tiers(&tiers, luck));
+        assert_eq!(odds_at(2)[0].weight, 0.25 * 1.5 * 1.5);
+        assert_eq!(odds_at(-1)[0].weight, 0.25 / 1.5);
+        assert_eq!(odds_at(2)[1].weight, 3.0, "A tier without a bonus ignores luck");
+        assert!(odds_at(3)[0].probability > odds_at(0)[0].probability);
+        assert!(odds_at(-3)[0].probability < odds_at(0)[0].probability);
+    }
+
+    #[test]
+    fn test_shuffled_deck_deals_in_order() {
+        let events = make_test_events();
+        let tiers = make_tiers();
+        let order = shuffle_deck(&events, &tiers, &mut create_rng("SHUFFLE"));
+        assert_eq!(order.len(), events.len());
+        let ids = |order: &[&EventCard]| order.iter().map(|e| e.id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&order), ids(&shuffle_deck(&events, &tiers, &mut create_rng("SHUFFLE"))));
+
+        let middle: Vec<&str> = order.iter().filter(|e| e.stages.contains(&Stage::MIDDLE_SCHOOL)).map(|e| e.id.as_str()).collect();
+        let first = draw_next(&order, &Stage::MIDDLE_SCHOOL, None, None, &[]).unwrap();
+        assert_eq!(first.id, middle[0]);
+        let second = draw_next(&order, &Stage::MIDDLE_SCHOOL, None, None, std::slice::from_ref(&first.id)).unwrap();
+        assert_eq!(second.id, middle[1], "Used cards are skipped, not reshuffled");
+        let all_used: Vec<String> = events.iter().map(|e| e.id.clone()).collect();
+        assert!(draw_next(&order, &Stage::MIDDLE_SCHOOL, None, None, &all_used).is_none());
+    }
+
+    #[test]
+    fn test_rarity_weighting() {
+        let events = make_test_events();
+        let tiers = make_tiers();
+        let mut common_count = 0;
+        let mut uncommon_count = 0;
+
+        // Draw 100 times from a fresh deck each time (no used tracking)
+        for i in 0..100 {
+            let mut rng_iter = create_rng(&format!("RARITY{}", i));
+            if let Some(card) = draw_event(&events, &Stage::MIDDLE_SCHOOL, None, None, &[], &tiers, &mut rng_iter) {
+                match card.rarity {
+                    Rarity::Common => common_count += 1,
+                    Rarity::Uncommon => uncommon_count += 1,
+                    _ => {} // evt_3 (rare) is high school only
+                }
+            }
+        }
+
+        assert!(
+            common_count > uncommon_count,
+            "Common cards ({}) should be drawn more often than uncommon ({})",
+            common_count,
+            uncommon_count
+        );
+    }
+}
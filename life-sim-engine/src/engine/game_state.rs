@@ -0,0 +1,426 @@
+use std::collections::BTreeMap;
+use schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+use crate::models::{Credentials, DeckMode, Stage, StageDefinition, Job, EducationProgram, SideGig, InsurancePlan, HousingTier, TransportOption, LifePath};
+use crate::engine::stage_report::{StageReport, StatLine};
+use crate::engine::phases::PartialTurn;
+use crate::engine::calendar::CalendarDate;
+use crate::engine::reroll::RerollPayment;
+use crate::engine::rng::{GameRng, RngPosition, SeedScheme};
+
+/// An entry in the player's decision log, used for the timeline recap.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DecisionEntry {
+    pub turn: u32,
+    pub stage: Stage,
+    /// The decision answered; None for choices made outside Phase 2 (e.g. changing jobs).
+    pub decision_id: Option<String>,
+    /// The option picked, for decisions answered in Phase 2.
+    #[serde(default)]
+    pub option_index: Option<usize>,
+    pub description: String,
+    pub impact: String,
+}
+
+/// A player's written reflection on a turn, linked to the decision made in it.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JournalEntry {
+    pub turn: u32,
+    pub stage: Stage,
+    pub decision_id: Option<String>,
+    /// The decision as logged, e.g. "Which club do you want to join?: Robotics".
+    pub decision: Option<String>,
+    pub text: String,
+}
+
+/// The player's enrollment in an education program.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Enrollment {
+    pub program: EducationProgram,
+    pub turns_completed: u32,
+    pub financed_by_loan: bool,
+}
+
+/// A job application submitted from the job board.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JobApplication {
+    pub job_id: String,
+    pub turn: u32,
+    pub hire_chance: f64,
+    pub got_interview: bool,
+}
+
+/// An interview earned by a successful application, held on a future turn.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledInterview {
+    pub job_id: String,
+    pub turn: u32,
+}
+
+/// A decision the player put off, and when it comes back.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DeferredDecision {
+    pub decision_id: String,
+    /// First turn it's offered again; None if it's been put off for good.
+    pub returns_on: Option<u32>,
+}
+
+/// The player's stats at the end of a turn, for charting a run.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StatSnapshot {
+    /// The turn just played.
+    pub turn: u32,
+    pub stage: Stage,
+    pub money: i32,
+    pub stress: i32,
+    pub support: i32,
+    /// Time slots the player had to spend that turn.
+    pub time_slots: u32,
+    /// Money that came in during the turn (income, gains) and went out (costs, bills),
+    /// counted step by step rather than netted.
+    #[serde(default)]
+    pub money_earned: i32,
+    #[serde(default)]
+    pub money_spent: i32,
+    /// Credentials gained that turn.
+    #[serde(default)]
+    pub credentials_earned: Vec<String>,
+    /// The card drawn that turn and the option the player answered it with.
+    #[serde(default)]
+    pub event_id: Option<String>,
+    #[serde(default)]
+    pub event_option_index: Option<usize>,
+    /// Cards dealt after the first, in stages that deal more than one a turn.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extra_events: Vec<EventAnswer>,
+    /// The job held at the end of the turn.
+    #[serde(default)]
+    pub job_id: Option<String>,
+}
+
+/// A card dealt after a turn's first, and the option it was answered with.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EventAnswer {
+    pub event_id: String,
+    pub option_index: Option<usize>,
+}
+
+/// The player's answer to a quiz question on an event card or decision.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QuizAnswer {
+    /// The latest turn played when the question was answered.
+    pub turn: u32,
+    /// The event card or decision the question is on.
+    pub source_id: String,
+    pub prompt: String,
+    /// Index of the choice picked.
+    pub choice: usize,
+    pub correct: bool,
+}
+
+/// Something the player did that changed the game, in the order it happened. Replaying a
+/// game's commands on a fresh game with the same seed plays it out the same way.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum GameCommand {
+    /// This turn's card was drawn ahead of the turn (GET /api/draw_event), so anything
+    /// done before submitting happened after the draw.
+    DrawEvent,
+    /// A turn was played with these choices.
+    Turn {
+        turn: u32,
+        action_ids: Vec<String>,
+        decision_id: String,
+        decision_option_index: usize,
+        /// The decision was put off rather than answered.
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        defer_decision: bool,
+        /// The card drawn, and the option it was answered with.
+        event_id: Option<String>,
+        event_option_index: Option<usize>,
+        /// The turn's other cards, when the stage deals more than one.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        extra_events: Vec<EventAnswer>,
+    },
+    ApplyJob { job_id: String },
+    /// Switched jobs, or quit (`job_id` None).
+    ChangeJob { job_id: Option<String> },
+    MoveHousing { housing_id: String },
+    AcquireTransport { transport_id: String },
+    BuyInsurance { plan_id: String },
+    CancelInsurance { plan_id: String },
+    StartSideGig { gig_id: String },
+    DropSideGig { gig_id: String },
+    /// The drawn card was rerolled, paid for with this.
+    RerollEvent { pay: RerollPayment },
+}
+
+/// The complete game state, held in server memory.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GameState {
+    pub current_stage: Stage,
+    pub current_turn: u32,
+    /// The current turn's month and the player's age, if the stage has a calendar.
+    #[serde(default)]
+    pub date: Option<CalendarDate>,
+    /// Life path chosen after high school, if any.
+    pub path: Option<LifePath>,
+    pub total_turns: u32,
+
+    // Resources
+    pub money: i32,
+    pub stress: i32,
+    pub support: i32,
+    pub time_slots: u32,
+    /// Time slots left unused last turn and carried into this one (see economy.json's
+    /// `timeBankCap`).
+    #[serde(default)]
+    pub banked_time_slots: u32,
+    pub credentials: Credentials,
+    /// Stats from stats.json beyond the engine's own, by ID.
+    #[serde(default)]
+    pub stats: BTreeMap<String, i32>,
+
+    // Tracking
+    pub current_job: Option<Job>,
+    pub job_turns: u32,
+    /// Rung on the current job's ladder (0 = entry level).
+    pub job_tier: u32,
+    /// Turns spent on the current rung, for promotion eligibility.
+    pub job_tenure: u32,
+    /// Turns left before the current job pays (after switching jobs).
+    pub unpaid_turns: u32,
+    pub side_gigs: Vec<SideGig>,
+    /// Insurance plans the player is currently paying for.
+    pub coverage: Vec<InsurancePlan>,
+    pub job_applications: Vec<JobApplication>,
+    pub scheduled_interview: Option<ScheduledInterview>,
+    pub monthly_bills: i32,
+    /// Where the player lives; sets monthly_bills and adds per-turn stress.
+    pub housing: Option<HousingTier>,
+    /// How the player gets around; may grant a tag and can break down.
+    pub transport: Option<TransportOption>,
+    pub emergency_fund: i32,
+    /// Money the emergency fund has covered over the run.
+    #[serde(default)]
+    pub emergency_fund_used: i32,
+    pub savings: i32,
+    pub enrollment: Option<Enrollment>,
+    pub student_loan: i32,
+    /// Decisions the game's scenario keeps from ever being offered.
+    pub locked_decisions: Vec<String>,
+    /// Decisions the player put off instead of answering.
+    #[serde(default)]
+    pub deferred_decisions: Vec<DeferredDecision>,
+    pub decision_log: Vec<DecisionEntry>,
+    /// One snapshot per turn played, oldest first.
+    pub stats_history: Vec<StatSnapshot>,
+    /// Where the current stage's stats started, noted on its first turn.
+    #[serde(default)]
+    pub stage_start: Option<StatLine>,
+    /// A recap of each stage the player has finished.
+    #[serde(default)]
+    pub stage_reports: Vec<StageReport>,
+    /// Milestone ID → the turn it was reached.
+    #[serde(default)]
+    pub milestones_reached: BTreeMap<String, u32>,
+    /// The player's reflections, in the order written.
+    pub journal: Vec<JournalEntry>,
+    /// Quiz questions answered, oldest first.
+    pub quiz_answers: Vec<QuizAnswer>,
+    pub used_event_ids: Vec<String>,
+    /// Turn each Phase 1 action was last taken, by action ID, for cooldowns.
+    #[serde(default)]
+    pub action_last_used: BTreeMap<String, u32>,
+    /// Times each Phase 1 action has been taken this run, by action ID, for maxUses.
+    #[serde(default)]
+    pub action_uses: BTreeMap<String, u32>,
+    /// The last turn an action or decision raised support (0 if none has yet), for
+    /// support decay.
+    #[serde(default)]
+    pub last_social_turn: u32,
+    /// Stages the player has rerolled an event card in (once per stage).
+    #[serde(default)]
+    pub rerolled_stages: Vec<Stage>,
+    /// Every turn and between-turn choice, oldest first (debug edits aren't included).
+    pub commands: Vec<GameCommand>,
+    /// A turn being played a step at a time, from its Plan step until its event is resolved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub turn_in_progress: Option<PartialTurn>,
+
+    // Meta
+    pub seed: String,
+    /// How `seed` seeds the game's RNG. Saves from before seeds were hashed load as legacy.
+    #[serde(default)]
+    pub seed_scheme: SeedScheme,
+    /// How far the game's random streams have drawn, so a saved game picks up the same
+    /// sequence (see `resume_rng`).
+    #[serde(default)]
+    pub rng_position: RngPosition,
+    /// For daily challenge runs, the UTC date (YYYY-MM-DD) whose seed the game is played on.
+    pub daily_date: Option<String>,
+    /// Tutorial games follow tutorial.json for their opening turns.
+    pub tutorial: bool,
+    /// The scenario the game started from, if any.
+    pub scenario: Option<String>,
+    /// Timed games: seconds the player gets for each turn before freezing under pressure.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub turn_seconds: Option<u32>,
+    /// How cards are dealt from each stage's deck.
+    #[serde(default)]
+    pub deck_mode: DeckMode,
+}
+
+impl GameState {
+    /// Create a new game with default starting values (Stage A: Middle School).
+    pub fn new(seed: String) -> Self {
+        Self {
+            current_stage: Stage::MIDDLE_SCHOOL,
+            current_turn: 1,
+            date: None,
+            path: None,
+            total_turns: 16, // 3-4 + 5-6 + 2-3 + 5-6 turns across stages
+
+            money: 100,
+            stress: 20,
+            support: 5,
+            time_slots: 3,
+            banked_time_slots: 0,
+            credentials: Credentials::default(),
+            stats: BTreeMap::new(),
+
+            current_job: None,
+            job_turns: 0,
+            job_tier: 0,
+            job_tenure: 0,
+            unpaid_turns: 0,
+            side_gigs: Vec::new(),
+            coverage: Vec::new(),
+            job_applications: Vec::new(),
+            scheduled_interview: None,
+            monthly_bills: 0,
+            housing: None,
+            transport: None,
+            emergency_fund: 0,
+            emergency_fund_used: 0,
+            savings: 0,
+            enrollment: None,
+            student_loan: 0,
+            locked_decisions: Vec::new(),
+            deferred_decisions: Vec::new(),
+            decision_log: Vec::new(),
+            stats_history: Vec::new(),
+            stage_start: None,
+            stage_reports: Vec::new(),
+            milestones_reached: BTreeMap::new(),
+            journal: Vec::new(),
+            quiz_answers: Vec::new(),
+            used_event_ids: Vec::new(),
+            action_last_used: BTreeMap::new(),
+            action_uses: BTreeMap::new(),
+            last_social_turn: 0,
+            rerolled_stages: Vec::new(),
+            commands: Vec::new(),
+            turn_in_progress: None,
+
+            seed,
+            seed_scheme: SeedScheme::Sha256,
+            rng_position: RngPosition::default(),
+            daily_date: None,
+            tutorial: false,
+            scenario: None,
+            turn_seconds: None,
+            deck_mode: DeckMode::Weighted,
+        }
+    }
+
+    /// Fresh random streams on the game's seed, as the game's first draw saw them.
+    pub fn seed_rng(&self) -> GameRng {
+        GameRng::new(self.seed_scheme, &self.seed)
+    }
+
+    /// The game's random streams where it left them, for a game restored from a save.
+    pub fn resume_rng(&self) -> GameRng {
+        GameRng::resume(self.seed_scheme, &self.seed, &self.rng_position)
+    }
+
+    /// Move into a stage, resetting time slots to the stage's allotment.
+    pub fn enter_stage(&mut self, stage: &StageDefinition) {
+        self.current_stage = stage.id.clone();
+        self.time_slots = stage.time_slots;
+        self.stage_start = None;
+    }
+
+    /// A stat's value by ID: the engine's own from their fields, any other from `stats`
+    /// (0 if never set).
+    pub fn stat(&self, id: &str) -> i32 {
+        match id {
+            "money" => self.money,
+            "stress" => self.stress,
+            "support" => self.support,
+            "timeSlots" => self.time_slots as i32,
+            _ => self.stats.get(id).copied().unwrap_or(0),
+        }
+    }
+
+    /// Set a stat by ID. No clamping: callers keep it within its definition's range.
+    pub fn set_stat(&mut self, id: &str, value: i32) {
+        match id {
+            "money" => self.money = value,
+            "stress" => self.stress = value,
+            "support" => self.support = value,
+            "timeSlots" => self.time_slots = value.max(0) as u32,
+            _ => {
+                self.stats.insert(id.to_string(), value);
+            }
+        }
+    }
+
+    /// Time slots left for Phase 1: the turn's free time plus any banked from last turn.
+    pub fn available_time_slots(&self) -> u32 {
+        self.free_time_slots() + self.banked_time_slots
+    }
+
+    /// Time slots every turn leaves after classes and side gigs take their share.
+    pub fn free_time_slots(&self) -> u32 {
+        let classes = self.enrollment.as_ref().map_or(0, |e| e.program.time_cost);
+        let gigs: u32 = self.side_gigs.iter().map(|g| g.time_cost).sum();
+        self.time_slots.saturating_sub(classes + gigs)
+    }
+
+    /// No debt left: money at or above zero and no student loan.
+    pub fn is_debt_free(&self) -> bool {
+        self.money >= 0 && self.student_loan <= 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_game_defaults() {
+        let state = GameState::new("TEST".to_string());
+        assert_eq!(state.current_stage, Stage::MIDDLE_SCHOOL);
+        assert_eq!(state.current_turn, 1);
+        assert_eq!(state.money, 100);
+        assert_eq!(state.stress, 20);
+        assert_eq!(state.support, 5);
+        assert_eq!(state.time_slots, 3);
+        assert!(state.credentials.is_empty());
+        assert!(state.current_job.is_none());
+        assert!(state.enrollment.is_none());
+        assert_eq!(state.student_loan, 0);
+        assert_eq!(state.seed, "TEST");
+    }
+}
@@ -0,0 +1,252 @@
+use rand::Rng;
+use crate::engine::game_state::{GameState, JobApplication, ScheduledInterview};
+use crate::engine::paths;
+use crate::engine::rng::{GameRng, Stream};
+use crate::models::{EventCard, Job, Rarity};
+use crate::models::event::{EventOption, StatEffect, StatType};
+
+/// Baseline chance an eligible application earns an interview.
+const BASE_HIRE_CHANCE: f64 = 0.35;
+/// Bonus per recommended tag the applicant holds.
+const RECOMMENDED_TAG_BONUS: f64 = 0.2;
+/// Bonus per point of Support (references, connections).
+const SUPPORT_HIRE_BONUS: f64 = 0.03;
+/// Hiring is never a sure thing, nor hopeless once eligible.
+const MIN_HIRE_CHANCE: f64 = 0.05;
+const MAX_HIRE_CHANCE: f64 = 0.95;
+/// Support needed to talk your way through an interview unprepared.
+const WING_IT_SUPPORT: i32 = 6;
+
+/// Chance (0.0–1.0) that an application for `job` earns an interview.
+/// Missing any required tag (or the job's life path) makes the application hopeless.
+pub fn hire_chance(job: &Job, state: &GameState) -> f64 {
+    if !paths::on_path(state, &job.paths) || !job.missing_requirements(&state.credentials).is_empty() {
+        return 0.0;
+    }
+    let held = job.recommended_tags.iter()
+        .filter(|t| state.credentials.contains(t))
+        .count();
+    let chance = BASE_HIRE_CHANCE
+        + held as f64 * RECOMMENDED_TAG_BONUS
+        + state.support as f64 * SUPPORT_HIRE_BONUS;
+    chance.clamp(MIN_HIRE_CHANCE, MAX_HIRE_CHANCE)
+}
+
+/// Submit an application for `job`, rolling the seeded RNG for the outcome.
+/// A successful roll schedules an interview card for the next turn.
+/// Returns an error message if the application isn't allowed.
+pub fn apply_for_job(
+    state: &mut GameState,
+    job: &Job,
+    rng: &mut GameRng,
+) -> Result<JobApplication, String> {
+    if !job.stages.contains(&state.current_stage) {
+        return Err(format!("{} isn't hiring during {}.", job.title, state.current_stage));
+    }
+    if !paths::on_path(state, &job.paths) {
+        return Err(format!("{} isn't open to people on your path.", job.title));
+    }
+    if state.current_job.as_ref().is_some_and(|j| j.id == job.id) {
+        return Err(format!("You already work as {}.", job.title));
+    }
+    let missing = job.missing_requirements(&state.credentials);
+    if !missing.is_empty() {
+        return Err(format!("{} requires: {}", job.title, missing.join(", ")));
+    }
+    if state.scheduled_interview.is_some() {
+        return Err("You already have an interview lined up.".to_string());
+    }
+    if state.job_applications.iter().any(|a| a.turn == state.current_turn) {
+        return Err("You've already applied for a job this turn.".to_string());
+    }
+
+    let chance = hire_chance(job, state);
+    let purpose = format!("interview for {}", job.title);
+    let got_interview = rng.draw(Stream::Rolls, state.current_turn, &purpose, |r| r.gen::<f64>()) < chance;
+    state.rng_position = rng.position();
+    if got_interview {
+        state.scheduled_interview = Some(ScheduledInterview {
+            job_id: job.id.clone(),
+            turn: state.current_turn + 1,
+        });
+    }
+
+    let application = JobApplication {
+        job_id: job.id.clone(),
+        turn: state.current_turn,
+        hire_chance: chance,
+        got_interview,
+    };
+    state.job_applications.push(application.clone());
+    Ok(application)
+}
+
+/// The interview card for a scheduled interview that is due this turn, if any.
+pub fn due_interview(state: &GameState, jobs: &[Job]) -> Option<EventCard> {
+    let interview = state.scheduled_interview.as_ref()?;
+    if interview.turn > state.current_turn {
+        return None;
+    }
+    jobs.iter().find(|j| j.id == interview.job_id).map(interview_card)
+}
+
+/// Clear an interview whose turn has come and gone (held or missed).
+pub fn expire_interview(state: &mut GameState) {
+    if state.scheduled_interview.as_ref().is_some_and(|i| i.turn <= state.current_turn) {
+        state.scheduled_interview = None;
+    }
+}
+
+/// Build the Phase 3 interview card for a job.
+pub fn interview_card(job: &Job) -> EventCard {
+    EventCard {
+        id: format!("evt_interview_{}", job.id),
+        title: format!("Interview: {}", job.title),
+        flavor_text: format!("Your application stood out. Today you interview for the {} position.", job.title),
+        stages: job.stages.clone(),
+        rarity: Rarity::Common,
+        categories: vec![],
+        paths: vec![],
+        transition: false,
+        months: vec![],
+        options: vec![
+            EventOption {
+                label: "Prepare thoroughly".to_string(),
+                description: "Research the company and rehearse answers. Nerve-wracking, but you land it.".to_string(),
+                effects: vec![
+                    StatEffect { stat: StatType::Stress, delta: 5, tag: None, percent: None, when: None },
+                    StatEffect { stat: StatType::TimeSlots, delta: -1, tag: None, percent: None, when: None },
+                ],
+                delayed_effects: None,
+                requires_support: None,
+                sets_job: Some(job.id.clone()),
+                sets_path: None,
+            },
+            EventOption {
+                label: "Lean on your references".to_string(),
+                description: "Your network vouches for you. Only works if people know you.".to_string(),
+                effects: vec![StatEffect { stat: StatType::Support, delta: -1, tag: None, percent: None, when: None }],
+                delayed_effects: None,
+                requires_support: Some(WING_IT_SUPPORT),
+                sets_job: Some(job.id.clone()),
+                sets_path: None,
+            },
+            EventOption {
+                label: "Withdraw your application".to_string(),
+                description: "It doesn't feel right. Stay where you are.".to_string(),
+                effects: vec![],
+                delayed_effects: None,
+                requires_support: None,
+                sets_job: None,
+                sets_path: None,
+            },
+        ],
+        quiz: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::rng::game_rng;
+    use crate::models::Stage;
+
+    fn make_job(required: &[&str], recommended: &[&str]) -> Job {
+        Job {
+            id: "job_test".to_string(),
+            title: "Test Job".to_string(),
+            required_tags: required.iter().map(|s| s.to_string()).collect(),
+            required_levels: Default::default(),
+            recommended_tags: recommended.iter().map(|s| s.to_string()).collect(),
+            pay_per_turn: 50,
+            stress_per_turn: 3,
+            growth_rate: 0,
+            growth_tag: None,
+            stages: vec![Stage::EARLY_ADULT],
+            description: "Test".to_string(),
+            family: None,
+            paths: vec![],
+            tiers: vec![],
+        }
+    }
+
+    fn make_state() -> GameState {
+        let mut state = GameState::new("HIRE".to_string());
+        state.current_stage = Stage::EARLY_ADULT;
+        state.current_turn = 14;
+        state
+    }
+
+    #[test]
+    fn test_hire_chance_rewards_tags_and_support() {
+        let job = make_job(&[], &["Customer Service"]);
+        let mut state = make_state();
+        let base = hire_chance(&job, &state);
+        state.credentials.grant("Customer Service");
+        let with_tag = hire_chance(&job, &state);
+        state.support = 10;
+        let with_support = hire_chance(&job, &state);
+        assert!(with_tag > base);
+        assert!(with_support > with_tag);
+        assert!(with_support <= MAX_HIRE_CHANCE);
+    }
+
+    #[test]
+    fn test_missing_required_tag_is_rejected() {
+        let job = make_job(&["CPR"], &[]);
+        let mut state = make_state();
+        let mut rng = game_rng("HIRE");
+        assert_eq!(hire_chance(&job, &state), 0.0);
+        assert!(apply_for_job(&mut state, &job, &mut rng).is_err());
+        assert!(state.job_applications.is_empty());
+    }
+
+    #[test]
+    fn test_required_level_must_be_reached() {
+        let mut job = make_job(&[], &[]);
+        job.required_levels.insert("Spanish".to_string(), 2);
+        let mut state = make_state();
+        let mut rng = game_rng("HIRE");
+        state.credentials.grant("Spanish");
+        assert_eq!(hire_chance(&job, &state), 0.0);
+        assert_eq!(apply_for_job(&mut state, &job, &mut rng).unwrap_err(), "Test Job requires: Spanish II");
+
+        state.credentials.grant("Spanish");
+        assert!(hire_chance(&job, &state) > 0.0);
+    }
+
+    #[test]
+    fn test_one_application_per_turn() {
+        let job = make_job(&[], &[]);
+        let mut state = make_state();
+        let mut rng = game_rng("ONCE");
+        let first = apply_for_job(&mut state, &job, &mut rng).unwrap();
+        if !first.got_interview {
+            assert!(apply_for_job(&mut state, &job, &mut rng).is_err());
+        }
+        assert_eq!(state.job_applications.len(), 1);
+    }
+
+    #[test]
+    fn test_successful_application_schedules_interview() {
+        let job = make_job(&[], &[]);
+        // Find a seed whose first roll lands an interview
+        let (mut state, mut rng) = (0..50)
+            .map(|i| (make_state(), game_rng(&format!("INTERVIEW{}", i))))
+            .find(|(s, r)| {
+                let (mut s, mut r) = (s.clone(), r.clone());
+                apply_for_job(&mut s, &job, &mut r).unwrap().got_interview
+            })
+            .expect("Some seed should land an interview");
+
+        apply_for_job(&mut state, &job, &mut rng).unwrap();
+        assert!(due_interview(&state, std::slice::from_ref(&job)).is_none(), "Interview is next turn");
+
+        state.current_turn += 1;
+        let card = due_interview(&state, std::slice::from_ref(&job)).expect("Interview should be due");
+        assert!(card.options.iter().any(|o| o.sets_job.as_deref() == Some("job_test")));
+
+        expire_interview(&mut state);
+        assert!(state.scheduled_interview.is_none());
+    }
+}
@@ -0,0 +1,89 @@
+use crate::data_loader::GameData;
+use crate::engine::game_state::GameState;
+use crate::engine::stat_calculator;
+use crate::engine::modifiers::EffectSource;
+use crate::models::HousingTier;
+use crate::models::event::{StatEffect, StatType};
+
+/// Move the player into a housing tier, replacing their monthly bills.
+/// The moving cost is only charged when leaving an existing home.
+/// Returns an error message if the move isn't allowed.
+pub fn move_into(state: &mut GameState, tier: &HousingTier) -> Result<Vec<String>, String> {
+    if state.housing.as_ref().is_some_and(|h| h.id == tier.id) {
+        return Err(format!("You already live in {}.", tier.title));
+    }
+    if !tier.stages.contains(&state.current_stage) {
+        return Err(format!("{} isn't an option during {}.", tier.title, state.current_stage));
+    }
+
+    let mut feedback = Vec::new();
+    if state.housing.is_some() && tier.moving_cost > 0 {
+        state.money -= tier.moving_cost;
+        feedback.push(format!("🚚 Moving costs: -${}", tier.moving_cost));
+    }
+
+    state.monthly_bills = tier.bills;
+    state.housing = Some(tier.clone());
+    if tier.bills > 0 {
+        feedback.push(format!("🏠 Moved into {} (bills: ${}/turn)", tier.title, tier.bills));
+    } else {
+        feedback.push(format!("🏠 Moved into {} (rent-free)", tier.title));
+    }
+    Ok(feedback)
+}
+
+/// Apply the current home's stress modifier (Phase 4).
+pub fn apply_housing_stress(state: &mut GameState, data: &GameData) -> Vec<String> {
+    let Some(delta) = state.housing.as_ref().map(|h| h.stress_per_turn) else {
+        return Vec::new();
+    };
+    stat_calculator::apply_effects(state, &[StatEffect { stat: StatType::Stress, delta, tag: None, percent: None, when: None }], EffectSource::Upkeep, data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_loader::test_data;
+    use crate::models::Stage;
+
+    fn make_tier(id: &str, bills: i32, stress_per_turn: i32) -> HousingTier {
+        HousingTier {
+            id: id.to_string(),
+            title: id.to_string(),
+            description: "Test".to_string(),
+            stages: vec![Stage::EARLY_ADULT],
+            bills,
+            stress_per_turn,
+            moving_cost: 50,
+        }
+    }
+
+    #[test]
+    fn test_first_move_is_free_then_costs() {
+        let mut state = GameState::new("HOUSING".to_string());
+        state.current_stage = Stage::EARLY_ADULT;
+
+        move_into(&mut state, &make_tier("cheap", 40, 2)).unwrap();
+        assert_eq!(state.money, 100, "First home has no moving cost");
+        assert_eq!(state.monthly_bills, 40);
+
+        assert!(move_into(&mut state, &make_tier("cheap", 40, 2)).is_err(), "Already living there");
+
+        move_into(&mut state, &make_tier("nice", 110, -2)).unwrap();
+        assert_eq!(state.money, 50);
+        assert_eq!(state.monthly_bills, 110);
+        assert_eq!(state.housing.as_ref().unwrap().id, "nice");
+    }
+
+    #[test]
+    fn test_housing_stress_modifier() {
+        let data = test_data();
+        let mut state = GameState::new("HOUSING".to_string());
+        state.current_stage = Stage::EARLY_ADULT;
+        assert!(apply_housing_stress(&mut state, &data).is_empty(), "No home, no modifier");
+
+        move_into(&mut state, &make_tier("nice", 110, -2)).unwrap();
+        apply_housing_stress(&mut state, &data);
+        assert_eq!(state.stress, 18);
+    }
+}
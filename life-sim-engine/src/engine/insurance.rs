@@ -0,0 +1,72 @@
+use crate::engine::game_state::GameState;
+use crate::models::InsurancePlan;
+
+/// Sign up for an insurance plan. The premium is billed from the next Phase 4 on.
+/// Returns an error message if the plan isn't available.
+pub fn buy_plan(state: &mut GameState, plan: &InsurancePlan) -> Result<String, String> {
+    if state.coverage.iter().any(|p| p.id == plan.id) {
+        return Err(format!("You already have {}.", plan.title));
+    }
+    if !plan.stages.contains(&state.current_stage) {
+        return Err(format!("{} isn't available during {}.", plan.title, state.current_stage));
+    }
+    state.coverage.push(plan.clone());
+    Ok(format!(
+        "🛡️ Signed up for {} (${}/turn, you pay at most ${} per emergency)",
+        plan.title, plan.premium_per_turn, plan.max_out_of_pocket
+    ))
+}
+
+/// Cancel an insurance plan, ending its premium and its coverage.
+pub fn cancel_plan(state: &mut GameState, plan_id: &str) -> Result<String, String> {
+    let Some(idx) = state.coverage.iter().position(|p| p.id == plan_id) else {
+        return Err(format!("You don't have the plan {}.", plan_id));
+    };
+    let plan = state.coverage.remove(idx);
+    Ok(format!("🛡️ Cancelled {}", plan.title))
+}
+
+/// Bill every held plan's premium (Phase 4).
+pub fn apply_premiums(state: &mut GameState) -> Vec<String> {
+    let mut feedback = Vec::new();
+    for plan in &state.coverage {
+        state.money -= plan.premium_per_turn;
+        feedback.push(format!("🛡️ {} premium: -${}", plan.title, plan.premium_per_turn));
+    }
+    feedback
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Stage;
+
+    fn make_plan() -> InsurancePlan {
+        InsurancePlan {
+            id: "ins_test".to_string(),
+            title: "Test Plan".to_string(),
+            description: "Test".to_string(),
+            stages: vec![Stage::EARLY_ADULT],
+            covers: vec!["medical".to_string()],
+            premium_per_turn: 15,
+            max_out_of_pocket: 25,
+        }
+    }
+
+    #[test]
+    fn test_buy_bill_and_cancel() {
+        let mut state = GameState::new("INSURE".to_string());
+        assert!(buy_plan(&mut state, &make_plan()).is_err(), "Not offered in middle school");
+
+        state.current_stage = Stage::EARLY_ADULT;
+        buy_plan(&mut state, &make_plan()).unwrap();
+        assert!(buy_plan(&mut state, &make_plan()).is_err(), "No duplicate plans");
+
+        apply_premiums(&mut state);
+        assert_eq!(state.money, 85);
+
+        cancel_plan(&mut state, "ins_test").unwrap();
+        assert!(apply_premiums(&mut state).is_empty());
+        assert_eq!(state.money, 85);
+    }
+}
@@ -0,0 +1,108 @@
+use crate::engine::game_state::{GameState, JournalEntry};
+
+/// Longest reflection accepted, in characters.
+pub const MAX_ENTRY_LENGTH: usize = 2000;
+
+/// Write a reflection on a played turn. `turn` defaults to the latest turn played;
+/// `decision_id` defaults to the decision answered that turn, if there was one.
+/// Returns an error message if the entry can't be linked to what was played.
+pub fn add_entry(
+    state: &mut GameState,
+    text: &str,
+    turn: Option<u32>,
+    decision_id: Option<&str>,
+) -> Result<JournalEntry, String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err("Write something to reflect on.".to_string());
+    }
+    if text.chars().count() > MAX_ENTRY_LENGTH {
+        return Err(format!("Reflections are at most {} characters.", MAX_ENTRY_LENGTH));
+    }
+
+    let latest = state.stats_history.last().map(|s| s.turn)
+        .ok_or_else(|| "Play a turn before writing a reflection.".to_string())?;
+    let turn = turn.unwrap_or(latest);
+    let snapshot = state.stats_history.iter().find(|s| s.turn == turn)
+        .ok_or_else(|| format!("Turn {} hasn't been played yet.", turn))?;
+
+    let mut decisions = state.decision_log.iter()
+        .filter(|d| d.turn == turn && d.decision_id.is_some());
+    let decision = match decision_id {
+        Some(id) => Some(decisions.find(|d| d.decision_id.as_deref() == Some(id))
+            .ok_or_else(|| format!("No decision '{}' was made on turn {}.", id, turn))?),
+        None => decisions.next(),
+    };
+
+    let entry = JournalEntry {
+        turn,
+        stage: snapshot.stage.clone(),
+        decision_id: decision.and_then(|d| d.decision_id.clone()),
+        decision: decision.map(|d| d.description.clone()),
+        text: text.to_string(),
+    };
+    state.journal.push(entry.clone());
+    Ok(entry)
+}
+
+/// Reflections written about a turn, oldest first.
+pub fn entries_for_turn(state: &GameState, turn: u32) -> impl Iterator<Item = &JournalEntry> {
+    state.journal.iter().filter(move |e| e.turn == turn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::game_state::{DecisionEntry, StatSnapshot};
+    use crate::models::Stage;
+
+    fn played(state: &mut GameState, turn: u32, decision_id: Option<&str>) {
+        state.stats_history.push(StatSnapshot {
+            turn, stage: Stage::MIDDLE_SCHOOL, money: 100, stress: 20, support: 5, time_slots: 3,
+            money_earned: 0, money_spent: 0, credentials_earned: Vec::new(), event_id: None, event_option_index: None, extra_events: Vec::new(), job_id: None,
+        });
+        if let Some(id) = decision_id {
+            state.decision_log.push(DecisionEntry {
+                turn,
+                stage: Stage::MIDDLE_SCHOOL,
+                decision_id: Some(id.to_string()),
+                option_index: None,
+                description: format!("{}: yes", id),
+                impact: "Stress +5".to_string(),
+            });
+        }
+    }
+
+    #[test]
+    fn test_entry_links_to_latest_turn_and_decision() {
+        let mut state = GameState::new("JOURNAL".to_string());
+        played(&mut state, 1, None);
+        played(&mut state, 2, Some("club"));
+
+        let entry = add_entry(&mut state, "  Robotics was worth the stress. ", None, None).unwrap();
+        assert_eq!(entry.turn, 2);
+        assert_eq!(entry.decision_id.as_deref(), Some("club"));
+        assert_eq!(entry.decision.as_deref(), Some("club: yes"));
+        assert_eq!(entry.text, "Robotics was worth the stress.");
+
+        let earlier = add_entry(&mut state, "Quiet week.", Some(1), None).unwrap();
+        assert_eq!(earlier.decision_id, None, "No decision that turn");
+        assert_eq!(entries_for_turn(&state, 2).count(), 1);
+        assert_eq!(state.journal.len(), 2);
+    }
+
+    #[test]
+    fn test_entry_must_match_what_was_played() {
+        let mut state = GameState::new("JOURNAL".to_string());
+        assert!(add_entry(&mut state, "Too soon", None, None).is_err(), "Nothing played yet");
+
+        played(&mut state, 1, Some("club"));
+        assert!(add_entry(&mut state, "   ", None, None).is_err());
+        assert!(add_entry(&mut state, &"a".repeat(MAX_ENTRY_LENGTH + 1), None, None).is_err());
+        assert!(add_entry(&mut state, "Ahead of myself", Some(2), None).is_err());
+        assert!(add_entry(&mut state, "Wrong choice", Some(1), Some("job")).is_err());
+        assert!(state.journal.is_empty());
+
+        assert!(add_entry(&mut state, "Right choice", Some(1), Some("club")).is_ok());
+    }
+}
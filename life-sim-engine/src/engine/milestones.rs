@@ -0,0 +1,178 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use crate::data_loader::GameData;
+use crate::engine::endings;
+use crate::engine::game_state::GameState;
+use crate::engine::paths;
+use crate::models::{Milestone, Stage};
+use crate::models::ending::{EndingConditions, ThresholdCondition};
+
+/// Where the run stands against one milestone.
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MilestoneStatus {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub stage: Stage,
+    /// The turn the milestone was reached, if it has been.
+    pub reached_turn: Option<u32>,
+    /// Its stage ended before it was reached.
+    pub missed: bool,
+    /// How close the player is right now, 0–100.
+    pub progress: u8,
+    /// Conditions the player doesn't meet right now, e.g. "Money ≥ $200 (have $150)".
+    pub unmet: Vec<String>,
+}
+
+/// Record the milestones reached this turn: each still open one whose conditions hold.
+/// Call at the end of a turn, before the stage moves on. Returns the ones just reached.
+pub fn check<'a>(state: &mut GameState, data: &'a GameData) -> Vec<&'a Milestone> {
+    let reached: Vec<&Milestone> = data.milestones.iter()
+        .filter(|m| !state.milestones_reached.contains_key(&m.id) && is_open(state, data, m))
+        .filter(|m| endings::unmet_conditions(state, &m.conditions).is_empty())
+        .collect();
+    for milestone in &reached {
+        state.milestones_reached.insert(milestone.id.clone(), state.current_turn);
+    }
+    reached
+}
+
+/// Every milestone in file order, with the run's progress toward it.
+pub fn statuses(state: &GameState, data: &GameData) -> Vec<MilestoneStatus> {
+    data.milestones.iter().map(|milestone| {
+        let reached_turn = state.milestones_reached.get(&milestone.id).copied();
+        let unmet = if reached_turn.is_some() { Vec::new() } else { endings::unmet_conditions(state, &milestone.conditions) };
+        MilestoneStatus {
+            id: milestone.id.clone(),
+            title: milestone.title.clone(),
+            description: milestone.description.clone(),
+            stage: milestone.stage.clone(),
+            reached_turn,
+            missed: reached_turn.is_none() && !is_open(state, data, milestone),
+            progress: if reached_turn.is_some() { 100 } else { progress(state, &milestone.conditions) },
+            unmet,
+        }
+    }).collect()
+}
+
+/// Whether the milestone's stage hasn't ended yet.
+fn is_open(state: &GameState, data: &GameData, milestone: &Milestone) -> bool {
+    let position = |stage| data.stages.iter().position(|s| &s.id == stage);
+    match (position(&state.current_stage), position(&milestone.stage)) {
+        (Some(current), Some(deadline)) => current <= deadline,
+        _ => false,
+    }
+}
+
+/// How much of the way to the conditions the player is, as a percentage: the average over
+/// every bound, where a minimum counts the share of it reached, a maximum the share of the
+/// way down to it, and anything else either holds or doesn't.
+fn progress(state: &GameState, conditions: &EndingConditions) -> u8 {
+    let mut parts: Vec<f64> = Vec::new();
+    let mut threshold = |condition: &Option<ThresholdCondition>, value: i32| {
+        let Some(c) = condition else { return };
+        if let Some(min) = c.min {
+            parts.push(if value >= min { 1.0 } else if min > 0 { value.max(0) as f64 / min as f64 } else { 0.0 });
+        }
+        if let Some(max) = c.max {
+            parts.push(if value <= max { 1.0 } else if max > 0 { max as f64 / value as f64 } else { 0.0 });
+        }
+    };
+    threshold(&conditions.money, state.money);
+    threshold(&conditions.stress, state.stress);
+    threshold(&conditions.support, state.support);
+    threshold(&conditions.savings, state.savings);
+    threshold(&conditions.emergency_fund, state.emergency_fund);
+
+    if let Some(min) = conditions.credentials.as_ref().and_then(|c| c.min_count).filter(|&min| min > 0) {
+        parts.push((state.credentials.len() as f64 / min as f64).min(1.0));
+    }
+    let holds = |met: bool| if met { 1.0 } else { 0.0 };
+    if let Some(ref path) = conditions.path {
+        parts.push(holds(paths::on_path(state, std::slice::from_ref(path))));
+    }
+    parts.extend(conditions.required_tags.iter().map(|tag| holds(state.credentials.contains(tag))));
+    if let Some(ref family) = conditions.job_family {
+        parts.push(holds(state.current_job.as_ref().and_then(|j| j.family.as_ref()) == Some(family)));
+    }
+    if conditions.debt_free {
+        parts.push(holds(state.is_debt_free()));
+    }
+
+    if parts.is_empty() {
+        return 100;
+    }
+    (parts.iter().sum::<f64>() / parts.len() as f64 * 100.0).floor() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_loader::test_data;
+
+    fn save_200(data: &mut GameData) {
+        data.milestones = vec![Milestone {
+            id: "save".to_string(),
+            title: "Save $200 by the end of High School".to_string(),
+            description: String::new(),
+            stage: Stage::HIGH_SCHOOL,
+            conditions: EndingConditions {
+                money: Some(ThresholdCondition { min: Some(200), max: None }),
+                ..Default::default()
+            },
+        }];
+    }
+
+    #[test]
+    fn test_milestone_is_reached_once_before_its_deadline() {
+        let mut data = test_data();
+        save_200(&mut data);
+        let mut state = GameState::new("MILESTONES".to_string());
+        state.money = 150;
+
+        assert!(check(&mut state, &data).is_empty());
+        let status = &statuses(&state, &data)[0];
+        assert_eq!((status.progress, status.missed), (75, false));
+        assert_eq!(status.unmet, vec!["Money ≥ $200 (have $150)"]);
+
+        state.money = 210;
+        state.current_turn = 3;
+        assert_eq!(check(&mut state, &data).len(), 1);
+        assert!(check(&mut state, &data).is_empty(), "Reached only once");
+
+        state.money = 0;
+        let status = &statuses(&state, &data)[0];
+        assert_eq!((status.reached_turn, status.progress), (Some(3), 100), "Reached stays reached");
+        assert!(status.unmet.is_empty());
+    }
+
+    #[test]
+    fn test_milestone_is_missed_once_its_stage_ends() {
+        let mut data = test_data();
+        save_200(&mut data);
+        let mut state = GameState::new("MILESTONES".to_string());
+        state.current_stage = Stage::POST_HIGH;
+        state.money = 500;
+
+        assert!(check(&mut state, &data).is_empty(), "Too late to count");
+        let status = &statuses(&state, &data)[0];
+        assert!(status.missed && status.reached_turn.is_none());
+    }
+
+    #[test]
+    fn test_progress_averages_every_bound() {
+        let mut state = GameState::new("MILESTONES".to_string());
+        state.money = 50;
+        state.stress = 60;
+        let conditions = EndingConditions {
+            money: Some(ThresholdCondition { min: Some(100), max: None }),
+            stress: Some(ThresholdCondition { min: None, max: Some(30) }),
+            required_tags: vec!["CPR".to_string()],
+            ..Default::default()
+        };
+        // Half the money, half the way down on stress, no CPR
+        assert_eq!(progress(&state, &conditions), 33);
+        assert_eq!(progress(&state, &EndingConditions::default()), 100);
+    }
+}
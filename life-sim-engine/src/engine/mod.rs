@@ -0,0 +1,35 @@
+pub mod game_state;
+pub mod rng;
+pub mod stat_calculator;
+pub mod event_deck;
+pub mod turn_runner;
+pub mod choices;
+pub mod compat;
+pub mod education;
+pub mod hiring;
+pub mod career;
+pub mod insurance;
+pub mod housing;
+pub mod transport;
+pub mod paths;
+pub mod endings;
+pub mod report;
+pub mod score;
+pub mod journal;
+pub mod tutorial;
+pub mod scenarios;
+pub mod quiz;
+pub mod bot;
+pub mod summary;
+pub mod replay;
+pub mod phases;
+pub mod scripting;
+pub mod modifiers;
+pub mod actions;
+pub mod epilogue;
+pub mod stage_report;
+pub mod milestones;
+pub mod stat_changes;
+pub mod turn_steps;
+pub mod calendar;
+pub mod reroll;
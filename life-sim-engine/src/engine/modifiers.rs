@@ -0,0 +1,233 @@
+//! Global rules that reshape stat effects before they're applied, whatever their source.
+//!
+//! Every batch of effects (an action's, a decision option's, an event option's, or a
+//! running cost) goes through the same [`ModifierPipeline`]. The standard one scales losses
+//! by difficulty, makes stress bite harder when the player is already stressed, lets a
+//! strong support network soften it, and finally lets insurance cap covered emergencies.
+
+use crate::data_loader::GameData;
+use crate::engine::game_state::GameState;
+use crate::engine::stat_calculator::{self, STRESS_DANGER};
+use crate::models::event::{StatEffect, StatType};
+use crate::models::insurance::EMERGENCY_CATEGORY;
+
+/// Where a batch of effects comes from.
+#[derive(Debug, Clone, Copy)]
+pub enum EffectSource<'a> {
+    /// A Phase 1 action.
+    Action,
+    /// A decision option.
+    Decision,
+    /// An event card option, with the card's categories.
+    Event(&'a [String]),
+    /// Running costs: housing, transport, changing jobs.
+    Upkeep,
+}
+
+/// What a modifier can look at: the game before the effects land, and the data set.
+pub struct EffectContext<'a> {
+    pub state: &'a GameState,
+    pub source: EffectSource<'a>,
+    pub data: &'a GameData,
+}
+
+/// One rule in the pipeline.
+pub trait Modifier {
+    /// Short name, for listing and positioning modifiers.
+    fn name(&self) -> &'static str;
+    /// Reshape the batch in place. Notes are shown after the effects' own feedback.
+    fn modify(&self, effects: &mut Vec<StatEffect>, ctx: &EffectContext, notes: &mut Vec<String>);
+}
+
+/// The modifiers effects pass through, in order.
+pub struct ModifierPipeline {
+    modifiers: Vec<Box<dyn Modifier>>,
+}
+
+impl ModifierPipeline {
+    /// Difficulty, high stress, support, then insurance, so coverage caps the final loss.
+    pub fn standard() -> Self {
+        Self::new(vec![Box::new(Difficulty), Box::new(HighStress), Box::new(SupportCushion), Box::new(Insurance)])
+    }
+
+    pub fn new(modifiers: Vec<Box<dyn Modifier>>) -> Self {
+        Self { modifiers }
+    }
+
+    pub fn names(&self) -> Vec<&'static str> {
+        self.modifiers.iter().map(|m| m.name()).collect()
+    }
+
+    /// The effects as every modifier leaves them, and the modifiers' notes.
+    pub fn run(&self, effects: &[StatEffect], ctx: &EffectContext) -> (Vec<StatEffect>, Vec<String>) {
+        let mut effects = effects.to_vec();
+        let mut notes = Vec::new();
+        for modifier in &self.modifiers {
+            modifier.modify(&mut effects, ctx, &mut notes);
+        }
+        (effects, notes)
+    }
+}
+
+/// Losses (money lost, stress gained, support lost) scaled by economy.json's `difficulty`.
+pub struct Difficulty;
+
+impl Modifier for Difficulty {
+    fn name(&self) -> &'static str {
+        "difficulty"
+    }
+
+    fn modify(&self, effects: &mut Vec<StatEffect>, ctx: &EffectContext, _: &mut Vec<String>) {
+        let extra = ctx.data.economy.difficulty;
+        if extra == 0.0 {
+            return;
+        }
+        for effect in effects.iter_mut().filter(|e| is_loss(e)) {
+            effect.delta = scale(effect.delta, extra);
+        }
+    }
+}
+
+/// Above the stress danger line, stress gains grow by economy.json's `highStressStrain`.
+pub struct HighStress;
+
+impl Modifier for HighStress {
+    fn name(&self) -> &'static str {
+        "high_stress"
+    }
+
+    fn modify(&self, effects: &mut Vec<StatEffect>, ctx: &EffectContext, _: &mut Vec<String>) {
+        let extra = ctx.data.economy.high_stress_strain;
+        if extra == 0.0 || ctx.state.stress <= STRESS_DANGER {
+            return;
+        }
+        for effect in effects.iter_mut().filter(|e| e.stat == StatType::Stress && e.delta > 0) {
+            effect.delta = scale(effect.delta, extra);
+        }
+    }
+}
+
+/// With a strong support network, each stress gain shrinks by economy.json's `supportCushion`.
+pub struct SupportCushion;
+
+impl Modifier for SupportCushion {
+    fn name(&self) -> &'static str {
+        "support_cushion"
+    }
+
+    fn modify(&self, effects: &mut Vec<StatEffect>, ctx: &EffectContext, notes: &mut Vec<String>) {
+        let cushion = ctx.data.economy.support_cushion;
+        if cushion <= 0 || !stat_calculator::has_support_bonus(ctx.state) {
+            return;
+        }
+        let mut eased = 0;
+        for effect in effects.iter_mut().filter(|e| e.stat == StatType::Stress && e.delta > 0) {
+            let cut = cushion.min(effect.delta);
+            effect.delta -= cut;
+            eased += cut;
+        }
+        if eased > 0 {
+            notes.push(format!("🤝 Your support eased {} stress", eased));
+        }
+    }
+}
+
+/// For emergency cards in a category the player is covered for, the total money lost is
+/// capped at the best plan's out-of-pocket maximum.
+pub struct Insurance;
+
+impl Modifier for Insurance {
+    fn name(&self) -> &'static str {
+        "insurance"
+    }
+
+    fn modify(&self, effects: &mut Vec<StatEffect>, ctx: &EffectContext, notes: &mut Vec<String>) {
+        let EffectSource::Event(categories) = ctx.source else {
+            return;
+        };
+        if !categories.iter().any(|c| c == EMERGENCY_CATEGORY) {
+            return;
+        }
+        let plan = ctx.state.coverage.iter()
+            .filter(|p| p.covers.iter().any(|c| categories.contains(c)))
+            .min_by_key(|p| p.max_out_of_pocket);
+        let Some(plan) = plan else {
+            return;
+        };
+
+        let mut remaining = plan.max_out_of_pocket.max(0);
+        let mut covered = 0;
+        for effect in effects.iter_mut().filter(|e| e.stat == StatType::Money && e.delta < 0) {
+            let paid = (-effect.delta).min(remaining);
+            remaining -= paid;
+            covered += -effect.delta - paid;
+            effect.delta = -paid;
+        }
+        if covered > 0 {
+            notes.push(format!("🛡️ {} covered ${}", plan.title, covered));
+        }
+    }
+}
+
+/// Whether an effect hurts the player.
+fn is_loss(effect: &StatEffect) -> bool {
+    match effect.stat {
+        StatType::Money | StatType::Support => effect.delta < 0,
+        StatType::Stress => effect.delta > 0,
+        _ => false,
+    }
+}
+
+/// `delta` grown (or shrunk, for a negative `extra`) by that share, rounded to the nearest point.
+fn scale(delta: i32, extra: f64) -> i32 {
+    (delta as f64 * (1.0 + extra)).round() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_loader::test_data;
+
+    fn effect(stat: StatType, delta: i32) -> StatEffect {
+        StatEffect { stat, delta, tag: None, percent: None, when: None }
+    }
+
+    #[test]
+    fn test_shipped_economy_leaves_effects_alone() {
+        let data = test_data();
+        let mut state = GameState::new("MODIFIERS".to_string());
+        state.stress = 90;
+        state.support = 9;
+        let effects = vec![effect(StatType::Money, -40), effect(StatType::Stress, 6), effect(StatType::Support, -1)];
+        let ctx = EffectContext { state: &state, source: EffectSource::Action, data: &data };
+
+        let (modified, notes) = ModifierPipeline::standard().run(&effects, &ctx);
+        assert_eq!(modified.iter().map(|e| e.delta).collect::<Vec<_>>(), vec![-40, 6, -1]);
+        assert!(notes.is_empty());
+        assert_eq!(ModifierPipeline::standard().names(), vec!["difficulty", "high_stress", "support_cushion", "insurance"]);
+    }
+
+    #[test]
+    fn test_rules_compose_in_order() {
+        let mut data = test_data();
+        data.economy.difficulty = 0.5;
+        data.economy.high_stress_strain = 1.0;
+        data.economy.support_cushion = 4;
+        let mut state = GameState::new("MODIFIERS".to_string());
+        state.stress = 80;
+        state.support = 9;
+        let effects = vec![effect(StatType::Money, -40), effect(StatType::Money, 10), effect(StatType::Stress, 5)];
+        let ctx = EffectContext { state: &state, source: EffectSource::Decision, data: &data };
+
+        let (modified, notes) = ModifierPipeline::standard().run(&effects, &ctx);
+        // Money: -40 × 1.5; gains untouched. Stress: 5 × 1.5 → 8, × 2 → 16, - 4 → 12.
+        assert_eq!(modified.iter().map(|e| e.delta).collect::<Vec<_>>(), vec![-60, 10, 12]);
+        assert_eq!(notes, vec!["🤝 Your support eased 4 stress".to_string()]);
+
+        state.stress = 20;
+        state.support = 5;
+        let ctx = EffectContext { state: &state, source: EffectSource::Decision, data: &data };
+        let (modified, _) = ModifierPipeline::standard().run(&effects, &ctx);
+        assert_eq!(modified[2].delta, 8, "Only difficulty applies when calm and unsupported");
+    }
+}
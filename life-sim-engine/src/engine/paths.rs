@@ -0,0 +1,43 @@
+use crate::engine::game_state::GameState;
+use crate::models::LifePath;
+
+/// Put the player on a life path. Paths are mutually exclusive and permanent.
+/// Returns an error message if the player already chose one.
+pub fn choose_path(state: &mut GameState, path: &LifePath) -> Result<String, String> {
+    if let Some(ref current) = state.path {
+        return Err(format!("You're already on the {} path.", current.title));
+    }
+    state.path = Some(path.clone());
+    Ok(format!("🧭 Path chosen: {}", path.title))
+}
+
+/// Whether content limited to `paths` is open to the player (empty = everyone).
+pub fn on_path(state: &GameState, paths: &[String]) -> bool {
+    paths.is_empty() || state.path.as_ref().is_some_and(|p| paths.contains(&p.id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_path(id: &str) -> LifePath {
+        LifePath {
+            id: id.to_string(),
+            title: id.to_string(),
+            description: "Test".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_paths_are_exclusive() {
+        let mut state = GameState::new("PATH".to_string());
+        let trade = vec!["path_trade".to_string()];
+        assert!(on_path(&state, &[]));
+        assert!(!on_path(&state, &trade), "No path yet");
+
+        choose_path(&mut state, &make_path("path_trade")).unwrap();
+        assert!(on_path(&state, &trade));
+        assert!(choose_path(&mut state, &make_path("path_military")).is_err());
+        assert_eq!(state.path.as_ref().unwrap().id, "path_trade");
+    }
+}
@@ -0,0 +1,735 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use crate::engine::game_state::{DecisionEntry, EventAnswer, GameCommand, GameState, StatSnapshot};
+use crate::engine::stat_calculator;
+use crate::engine::calendar;
+use crate::engine::education;
+use crate::engine::hiring;
+use crate::engine::career;
+use crate::engine::insurance;
+use crate::engine::housing;
+use crate::engine::transport;
+use crate::engine::rng::GameRng;
+use crate::engine::paths;
+use crate::engine::tutorial;
+use crate::engine::scripting;
+use crate::engine::modifiers::EffectSource;
+use crate::engine::actions;
+use crate::engine::stage_report;
+use crate::engine::milestones;
+use crate::engine::stat_changes::StatTracker;
+use crate::engine::turn_runner::{self, PlayerChoices, TurnResult};
+use crate::data_loader::GameData;
+use crate::models::{Credentials, EventCard};
+use crate::models::decision::DecisionOption;
+use crate::models::event::EventOption;
+
+/// One step of a turn. A phase reads the turn's inputs from the context, changes the
+/// state, and leaves its own results in the context for the phases after it.
+pub trait Phase {
+    /// Stable name, used to insert other phases around this one.
+    fn name(&self) -> &'static str;
+
+    fn run(&self, state: &mut GameState, turn: &mut TurnContext);
+}
+
+/// Everything a turn's phases share: the player's choices and the game's data and RNG,
+/// then what each phase has produced so far.
+pub struct TurnContext<'a> {
+    pub choices: &'a PlayerChoices,
+    pub data: &'a GameData,
+    pub rng: &'a mut GameRng,
+    /// Messages for the player, from every phase in order.
+    pub feedback: Vec<String>,
+    /// Money in and out so far; call `step` after anything that changes money.
+    pub money: MoneyFlow,
+    /// The cards the client already previewed, for the Event phase to use instead of drawing.
+    pub pre_drawn_events: Vec<EventCard>,
+    /// Plan: the actions that were taken.
+    pub actions_taken: Vec<String>,
+    /// Commit: the decision made, as logged.
+    pub decision: Option<DecisionEntry>,
+    /// Event: the cards drawn, and whether the player's response to each was applied.
+    pub event: EventOutcome,
+    /// Resolve: the stress warning, if stress crossed the threshold.
+    pub stress_warning: Option<String>,
+    time_slots: u32,
+    credentials_before: Credentials,
+}
+
+/// What the Event phase drew and did.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EventOutcome {
+    /// The cards in the order they were dealt; none if the deck ran out.
+    pub drawn: Vec<EventCard>,
+    /// One per card: false when the player didn't answer it or the option was locked.
+    pub applied: Vec<bool>,
+}
+
+impl<'a> TurnContext<'a> {
+    pub fn new(
+        state: &GameState,
+        choices: &'a PlayerChoices,
+        data: &'a GameData,
+        rng: &'a mut GameRng,
+        pre_drawn_events: Vec<EventCard>,
+    ) -> Self {
+        Self {
+            choices,
+            data,
+            rng,
+            feedback: Vec::new(),
+            money: MoneyFlow::start(state.money),
+            pre_drawn_events,
+            actions_taken: Vec::new(),
+            decision: None,
+            event: EventOutcome::default(),
+            stress_warning: None,
+            time_slots: state.available_time_slots(),
+            credentials_before: state.credentials.clone(),
+        }
+    }
+
+    /// Pick a turn back up where `partial` left off. Its results so far move into the context
+    /// until `suspend` hands them back.
+    fn resume(
+        partial: &mut PartialTurn,
+        choices: &'a PlayerChoices,
+        data: &'a GameData,
+        rng: &'a mut GameRng,
+    ) -> Self {
+        Self {
+            choices,
+            data,
+            rng,
+            feedback: std::mem::take(&mut partial.feedback),
+            money: partial.money.clone(),
+            pre_drawn_events: std::mem::take(&mut partial.events),
+            actions_taken: std::mem::take(&mut partial.actions_taken),
+            decision: partial.decision.take(),
+            event: std::mem::take(&mut partial.event),
+            stress_warning: partial.stress_warning.take(),
+            time_slots: partial.time_slots,
+            credentials_before: std::mem::take(&mut partial.credentials_before),
+        }
+    }
+
+    /// Stop between phases, handing what the phases so far produced back to `partial`.
+    fn suspend(self, partial: &mut PartialTurn) {
+        partial.feedback = self.feedback;
+        partial.money = self.money;
+        partial.events = self.pre_drawn_events;
+        partial.actions_taken = self.actions_taken;
+        partial.decision = self.decision;
+        partial.event = self.event;
+        partial.stress_warning = self.stress_warning;
+        partial.credentials_before = self.credentials_before;
+    }
+
+    /// Close the turn: record it in the stats history and command log, check milestones,
+    /// move to the next turn, and if this stage is over write its report and enter the next one.
+    fn finish(mut self, state: &mut GameState) -> TurnResult {
+        let event_id = self.event.drawn.first().map(|e| e.id.clone());
+        let event_option_index = self.event.drawn.first().and(self.choices.event_option_index);
+        let extra_events: Vec<EventAnswer> = self.event.drawn.iter().enumerate().skip(1)
+            .map(|(i, e)| EventAnswer { event_id: e.id.clone(), option_index: self.choices.event_answer(i) })
+            .collect();
+        state.stats_history.push(StatSnapshot {
+            turn: state.current_turn,
+            stage: state.current_stage.clone(),
+            money: state.money,
+            stress: state.stress,
+            support: state.support,
+            time_slots: self.time_slots,
+            money_earned: self.money.earned,
+            money_spent: self.money.spent,
+            credentials_earned: state.credentials.gained_since(&self.credentials_before),
+            event_id: event_id.clone(),
+            event_option_index,
+            extra_events: extra_events.clone(),
+            job_id: state.current_job.as_ref().map(|j| j.id.clone()),
+        });
+        state.commands.push(GameCommand::Turn {
+            turn: state.current_turn,
+            action_ids: self.choices.action_ids.clone(),
+            decision_id: self.choices.decision_id.clone(),
+            decision_option_index: self.choices.decision_option_index,
+            defer_decision: self.choices.defer_decision,
+            event_id,
+            event_option_index,
+            extra_events,
+        });
+
+        for milestone in milestones::check(state, self.data) {
+            self.feedback.push(format!("🏅 Milestone reached: {}", milestone.title));
+        }
+
+        state.current_turn += 1;
+        if state.current_turn > turn_runner::stage_end_turn(&self.data.stages, &state.current_stage) {
+            stage_report::close_stage(state, self.data);
+        }
+
+        let old_stage = state.current_stage.clone();
+        let stage_transitioned = turn_runner::check_and_transition_stage(state, &self.data.stages);
+        calendar::update(state, &self.data.stages);
+        let (new_stage, old_stage) = if stage_transitioned {
+            let name = turn_runner::stage_def(&self.data.stages, &state.current_stage)
+                .map_or_else(|| state.current_stage.to_string(), |s| s.name.clone());
+            self.feedback.push(format!("🎓 Advancing to {}!", name));
+            (Some(state.current_stage.clone()), Some(old_stage))
+        } else {
+            (None, None)
+        };
+
+        TurnResult {
+            events_drawn: self.event.drawn,
+            feedback: self.feedback,
+            stage_transitioned,
+            new_stage,
+            old_stage,
+            stress_warning: self.stress_warning,
+            stat_changes: Vec::new(),
+        }
+    }
+}
+
+/// A turn stopped between phases, so it can be played a step at a time across requests:
+/// the choices made so far, the phases already run, and what they produced. It's only
+/// ever picked up again by the pipeline that started it.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PartialTurn {
+    pub turn: u32,
+    /// Names of the phases already run, in order.
+    pub phases_run: Vec<String>,
+    /// The choices so far; later steps fill in the rest before their phases run.
+    pub choices: PlayerChoices,
+    /// The cards dealt when the turn started, until the Event phase takes them.
+    pub events: Vec<EventCard>,
+    /// Messages for the player from the phases run so far.
+    pub feedback: Vec<String>,
+    pub actions_taken: Vec<String>,
+    pub decision: Option<DecisionEntry>,
+    event: EventOutcome,
+    stress_warning: Option<String>,
+    money: MoneyFlow,
+    time_slots: u32,
+    credentials_before: Credentials,
+    stat_changes: StatTracker,
+}
+
+impl PartialTurn {
+    /// Open a turn before its first phase, with the cards already dealt for it.
+    pub fn start(state: &mut GameState, choices: PlayerChoices, events: Vec<EventCard>, data: &GameData) -> Self {
+        stage_report::mark_start(state);
+        Self {
+            turn: state.current_turn,
+            phases_run: Vec::new(),
+            choices,
+            events,
+            feedback: Vec::new(),
+            actions_taken: Vec::new(),
+            decision: None,
+            event: EventOutcome::default(),
+            stress_warning: None,
+            money: MoneyFlow::start(state.money),
+            time_slots: state.available_time_slots(),
+            credentials_before: state.credentials.clone(),
+            stat_changes: StatTracker::start(state, data),
+        }
+    }
+
+    /// Whether the phase with this name has run.
+    pub fn has_run(&self, phase: &str) -> bool {
+        self.phases_run.iter().any(|p| p == phase)
+    }
+}
+
+/// Money in and out over a turn, counted step by step so income and costs in the same
+/// turn don't cancel out.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MoneyFlow {
+    earned: i32,
+    spent: i32,
+    last: i32,
+}
+
+impl MoneyFlow {
+    fn start(money: i32) -> Self {
+        Self { earned: 0, spent: 0, last: money }
+    }
+
+    /// Count the change since the previous step.
+    pub fn step(&mut self, money: i32) {
+        let change = money - self.last;
+        if change > 0 {
+            self.earned += change;
+        } else {
+            self.spent -= change;
+        }
+        self.last = money;
+    }
+}
+
+/// The phases a turn runs, in order, followed by the turn's bookkeeping.
+pub struct TurnPipeline {
+    phases: Vec<Box<dyn Phase>>,
+}
+
+impl TurnPipeline {
+    /// Plan, Commit, Event, Resolve.
+    pub fn standard() -> Self {
+        Self { phases: vec![Box::new(Plan), Box::new(Commit), Box::new(Event), Box::new(Resolve)] }
+    }
+
+    /// The phase names, in the order they run.
+    pub fn names(&self) -> Vec<&'static str> {
+        self.phases.iter().map(|p| p.name()).collect()
+    }
+
+    /// Run `phase` right after the one named `after`.
+    ///
+    /// # Panics
+    /// If no phase has that name.
+    pub fn insert_after(mut self, after: &str, phase: impl Phase + 'static) -> Self {
+        let index = self.position(after) + 1;
+        self.phases.insert(index, Box::new(phase));
+        self
+    }
+
+    /// Run `phase` right before the one named `before`.
+    ///
+    /// # Panics
+    /// If no phase has that name.
+    pub fn insert_before(mut self, before: &str, phase: impl Phase + 'static) -> Self {
+        let index = self.position(before);
+        self.phases.insert(index, Box::new(phase));
+        self
+    }
+
+    fn position(&self, name: &str) -> usize {
+        self.phases.iter().position(|p| p.name() == name)
+            .unwrap_or_else(|| panic!("No phase named {} (phases: {:?})", name, self.names()))
+    }
+
+    /// Run one turn through every phase.
+    pub fn run(
+        &self,
+        state: &mut GameState,
+        choices: &PlayerChoices,
+        data: &GameData,
+        rng: &mut GameRng,
+        pre_drawn_events: Vec<EventCard>,
+    ) -> TurnResult {
+        let partial = PartialTurn::start(state, choices.clone(), pre_drawn_events, data);
+        self.finish(state, partial, data, rng)
+    }
+
+    /// Run an open turn's next phases, through the one named `through`, and leave it open.
+    ///
+    /// # Panics
+    /// If no phase has that name.
+    pub fn advance(&self, state: &mut GameState, partial: &mut PartialTurn, through: &str, data: &GameData, rng: &mut GameRng) {
+        let end = self.position(through) + 1;
+        let choices = partial.choices.clone();
+        let mut turn = TurnContext::resume(partial, &choices, data, rng);
+        self.run_phases(state, &mut turn, &mut partial.phases_run, &mut partial.stat_changes, end);
+        turn.suspend(partial);
+    }
+
+    /// Run an open turn's remaining phases and close it.
+    pub fn finish(&self, state: &mut GameState, mut partial: PartialTurn, data: &GameData, rng: &mut GameRng) -> TurnResult {
+        let choices = partial.choices.clone();
+        let mut turn = TurnContext::resume(&mut partial, &choices, data, rng);
+        self.run_phases(state, &mut turn, &mut partial.phases_run, &mut partial.stat_changes, self.phases.len());
+        let mut result = turn.finish(state);
+        result.stat_changes = partial.stat_changes.finish();
+        result
+    }
+
+    /// Run the phases that haven't run yet, up to (not including) the one at `end`.
+    fn run_phases(&self, state: &mut GameState, turn: &mut TurnContext, phases_run: &mut Vec<String>, tracker: &mut StatTracker, end: usize) {
+        for phase in self.phases.iter().take(end).skip(phases_run.len()) {
+            phase.run(state, turn);
+            tracker.record(phase.name(), state);
+            phases_run.push(phase.name().to_string());
+        }
+        state.rng_position = turn.rng.position();
+    }
+}
+
+/// Phase 1: spend the turn's time on actions.
+pub struct Plan;
+
+impl Phase for Plan {
+    fn name(&self) -> &'static str {
+        "plan"
+    }
+
+    fn run(&self, state: &mut GameState, turn: &mut TurnContext) {
+        let data = turn.data;
+        for action_id in &turn.choices.action_ids {
+            if let Some(action) = data.action(action_id) {
+                if let Some(reason) = actions::unavailable_reason(state, data, action) {
+                    turn.feedback.push(format!("⏳ {}", reason));
+                    continue;
+                }
+                actions::record_use(state, action);
+                if stat_calculator::raises_support(&action.effects) {
+                    state.last_social_turn = state.current_turn;
+                }
+                let msgs = stat_calculator::apply_effects(state, &action.effects, EffectSource::Action, data);
+                turn.feedback.extend(msgs);
+
+                // Scripted hooks from special_effects.json
+                if let Some(ref special) = action.special_effect {
+                    turn.feedback.extend(scripting::run_special_effect(state, special, data));
+                }
+                turn.actions_taken.push(action.id.clone());
+            }
+            turn.money.step(state.money);
+        }
+
+        // Bank what's left of the turn's time, up to the cap
+        let used: u32 = turn.actions_taken.iter().filter_map(|id| data.action(id)).map(|a| a.time_cost).sum();
+        let banked = turn.time_slots.saturating_sub(used).min(data.economy.time_bank_cap);
+        if banked > 0 {
+            let plural = if banked == 1 { "" } else { "s" };
+            turn.feedback.push(format!("⏰ Banked {} unused time slot{} for next turn", banked, plural));
+        }
+        state.banked_time_slots = banked;
+    }
+}
+
+/// Phase 2: make the turn's decision.
+pub struct Commit;
+
+impl Phase for Commit {
+    fn name(&self) -> &'static str {
+        "commit"
+    }
+
+    fn run(&self, state: &mut GameState, turn: &mut TurnContext) {
+        let (choices, data) = (turn.choices, turn.data);
+        // Promotion offers are engine-generated decisions, not part of the data files
+        let promotion = career::promotion_offer(state);
+        let decision = data.decision(&choices.decision_id)
+            .or(promotion.as_ref().filter(|d| d.id == choices.decision_id))
+            .filter(|d| d.path.as_ref().is_none_or(|p| paths::on_path(state, std::slice::from_ref(p))))
+            .filter(|d| !state.locked_decisions.contains(&d.id));
+        if let Some(decision) = decision {
+            if choices.defer_decision {
+                if let Some(ref consequence) = decision.defer_consequence {
+                    turn.feedback.extend(turn_runner::defer_decision(state, decision, data));
+                    let entry = DecisionEntry {
+                        turn: state.current_turn,
+                        stage: state.current_stage.clone(),
+                        decision_id: Some(decision.id.clone()),
+                        option_index: None,
+                        description: format!("{}: Put it off", decision.prompt),
+                        impact: consequence.to_string(),
+                    };
+                    state.decision_log.push(entry.clone());
+                    turn.decision = Some(entry);
+                }
+            } else if let Some(option) = decision.options.get(choices.decision_option_index) {
+                turn.feedback.extend(turn_runner::apply_decision_option(state, option, data));
+                turn.feedback.extend(apply_decision_application(state, option, data, turn.rng));
+                if stat_calculator::raises_support(&option.effects) {
+                    state.last_social_turn = state.current_turn;
+                }
+                state.deferred_decisions.retain(|d| d.decision_id != decision.id);
+
+                let entry = DecisionEntry {
+                    turn: state.current_turn,
+                    stage: state.current_stage.clone(),
+                    decision_id: Some(decision.id.clone()),
+                    option_index: Some(choices.decision_option_index),
+                    description: format!("{}: {}", decision.prompt, option.label),
+                    impact: option.effects.iter()
+                        .map(|e| e.to_string())
+                        .collect::<Vec<_>>().join(", "),
+                };
+                state.decision_log.push(entry.clone());
+                turn.decision = Some(entry);
+            }
+        }
+        turn.money.step(state.money);
+    }
+}
+
+/// Apply for a job if the chosen decision option starts an application (e.g. the first-job
+/// decision). Like applying between turns, it only lands an interview, never the job itself.
+fn apply_decision_application(state: &mut GameState, option: &DecisionOption, data: &GameData, rng: &mut GameRng) -> Option<String> {
+    let job = data.job(option.applies_for.as_ref()?)?;
+    Some(match hiring::apply_for_job(state, job, rng) {
+        Ok(application) if application.got_interview => format!("📞 {} wants to interview you next turn!", job.title),
+        Ok(_) => format!("📭 {} passed on your application this time.", job.title),
+        Err(msg) => format!("💼 {}", msg),
+    })
+}
+
+/// Phase 3: draw the turn's life cards and apply the player's response to each.
+pub struct Event;
+
+impl Phase for Event {
+    fn name(&self) -> &'static str {
+        "event"
+    }
+
+    fn run(&self, state: &mut GameState, turn: &mut TurnContext) {
+        let data = turn.data;
+        // Use the previewed cards if there are any, otherwise draw this turn's cards
+        let drawn = match std::mem::take(&mut turn.pre_drawn_events) {
+            cards if cards.is_empty() => turn_runner::draw_turn_events(state, data, turn.rng),
+            cards => cards,
+        };
+
+        let mut applied = Vec::with_capacity(drawn.len());
+        for (i, event) in drawn.iter().enumerate() {
+            // Mark as used (avoid duplication if already in the list)
+            if !state.used_event_ids.contains(&event.id) {
+                state.used_event_ids.push(event.id.clone());
+            }
+            let option = turn.choices.event_answer(i).and_then(|i| event.options.get(i));
+            applied.push(option.is_some_and(|option| answer_event(state, event, option, data, &mut turn.feedback)));
+        }
+        hiring::expire_interview(state);
+        turn.money.step(state.money);
+        turn.event = EventOutcome { drawn, applied };
+    }
+}
+
+/// Apply the option picked on a card, unless it needs more support than the player has.
+/// Returns whether it was applied.
+fn answer_event(state: &mut GameState, event: &EventCard, option: &EventOption, data: &GameData, feedback: &mut Vec<String>) -> bool {
+    if let Some(min_support) = option.requires_support.filter(|min| state.support < *min) {
+        feedback.push(format!(
+            "🔒 Option \"{}\" requires Support ≥ {} (you have {})",
+            option.label, min_support, state.support
+        ));
+        return false;
+    }
+    feedback.extend(stat_calculator::apply_effects(state, &option.effects, EffectSource::Event(&event.categories), data));
+    feedback.extend(apply_event_job(state, option, data));
+    feedback.extend(apply_event_path(state, option, data));
+    true
+}
+
+/// Hire the player if the chosen event option sets a job (e.g. an interview card).
+fn apply_event_job(state: &mut GameState, option: &EventOption, data: &GameData) -> Option<String> {
+    let job_id = option.sets_job.as_ref()?;
+    let job = data.job(job_id)?;
+    Some(career::assign_job(state, job))
+}
+
+/// Put the player on a life path if the chosen event option sets one (e.g. a transition card).
+fn apply_event_path(state: &mut GameState, option: &EventOption, data: &GameData) -> Option<String> {
+    let path = data.path(option.sets_path.as_ref()?)?;
+    Some(paths::choose_path(state, path).unwrap_or_else(|msg| format!("🧭 {}", msg)))
+}
+
+/// Phase 4: the month plays out. Income, growth, school, upkeep, bills, and interest.
+pub struct Resolve;
+
+impl Phase for Resolve {
+    fn name(&self) -> &'static str {
+        "resolve"
+    }
+
+    fn run(&self, state: &mut GameState, turn: &mut TurnContext) {
+        let data = turn.data;
+        let feedback = &mut turn.feedback;
+        let flow = &mut turn.money;
+
+        // Apply job income (with misalignment penalty)
+        feedback.extend(stat_calculator::apply_job_income(state, &data.stats));
+        flow.step(state.money);
+        feedback.extend(stat_calculator::apply_side_gig_income(state, &data.stats));
+        flow.step(state.money);
+
+        // Tenure on the current rung counts toward the next promotion
+        if state.current_job.is_some() {
+            state.job_tenure += 1;
+        }
+
+        // Job growth: track turns worked, grant growth tag when threshold reached
+        if let Some(ref job) = state.current_job {
+            if job.growth_rate > 0 {
+                state.job_turns += 1;
+                if state.job_turns >= job.growth_rate {
+                    if let Some(ref tag) = job.growth_tag {
+                        let held = state.credentials.grant(tag);
+                        feedback.push(format!("🌱 Growth! Earned: {}", held.name()));
+                    }
+                    state.job_turns = 0; // Reset after earning
+                }
+            }
+        }
+
+        // Attend classes: tuition, progress, graduation
+        feedback.extend(education::advance_education(state));
+        flow.step(state.money);
+
+        // Getting around costs money (and patience)
+        feedback.extend(transport::apply_transport_costs(state, data));
+        flow.step(state.money);
+
+        // Where you live weighs on you (or lets you breathe)
+        feedback.extend(housing::apply_housing_stress(state, data));
+
+        // Apply monthly bills (adult stages only)
+        if turn_runner::stage_def(&data.stages, &state.current_stage).is_some_and(|s| s.pays_bills) {
+            feedback.extend(stat_calculator::apply_monthly_bills(state));
+            flow.step(state.money);
+
+            // Student loans come due once school is behind you
+            feedback.extend(education::apply_loan_repayment(state, data.economy.student_loan_payment));
+            flow.step(state.money);
+
+            // Emergency fund auto-cover: if money went negative and we have a fund
+            feedback.extend(stat_calculator::apply_emergency_fund(state));
+            flow.step(state.money);
+
+            // Whatever debt remains accrues interest
+            feedback.extend(stat_calculator::apply_debt_interest(state, data.economy.debt_interest_rate));
+            flow.step(state.money);
+        }
+
+        // Insurance premiums are billed in every stage a plan is held
+        feedback.extend(insurance::apply_premiums(state));
+        flow.step(state.money);
+
+        // Savings earn interest in every stage
+        feedback.extend(stat_calculator::apply_savings_interest(state, data.economy.savings_interest_rate));
+        flow.step(state.money);
+
+        // Relationships fade without upkeep
+        feedback.extend(stat_calculator::apply_support_decay(state, data.economy.support_neglect_turns, &data.stats));
+
+        // Stress eases on its own, unless it's already in the danger zone
+        feedback.extend(stat_calculator::apply_stress_recovery(state, data.economy.stress_recovery, &data.stats));
+
+        // Check stress threshold
+        turn.stress_warning = stat_calculator::check_stress_threshold(state);
+        feedback.extend(turn.stress_warning.clone());
+
+        // Tutorial games explain what just happened
+        feedback.extend(tutorial::guidance(state, &data.tutorial));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_loader::test_data;
+    use crate::engine::rng::game_rng;
+    use crate::engine::stat_changes;
+
+    fn first_turn_choices() -> PlayerChoices {
+        PlayerChoices {
+            action_ids: vec!["act_study".to_string(), "act_unknown".to_string()],
+            decision_id: "dec_club_choice_a".to_string(),
+            decision_option_index: 0,
+            defer_decision: false,
+            event_option_index: Some(0),
+            event_id: None,
+            extra_event_options: Vec::new(),
+        }
+    }
+
+    /// Notes the player's stress once the Event phase is done.
+    struct StressCheck;
+
+    impl Phase for StressCheck {
+        fn name(&self) -> &'static str {
+            "stress_check"
+        }
+
+        fn run(&self, state: &mut GameState, turn: &mut TurnContext) {
+            assert!(!turn.event.drawn.is_empty(), "Runs after the Event phase");
+            turn.feedback.push(format!("Stress after the card: {}", state.stress));
+        }
+    }
+
+    #[test]
+    fn test_phases_leave_typed_results() {
+        let data = test_data();
+        let mut state = turn_runner::start_game("PHASES".to_string(), &data);
+        let mut rng = game_rng("PHASES");
+        let choices = first_turn_choices();
+
+        let mut turn = TurnContext::new(&state, &choices, &data, &mut rng, Vec::new());
+        for phase in [&Plan as &dyn Phase, &Commit, &Event] {
+            phase.run(&mut state, &mut turn);
+        }
+        assert_eq!(turn.actions_taken, vec!["act_study"], "Unknown actions are skipped");
+        assert_eq!(turn.decision.as_ref().and_then(|d| d.decision_id.as_deref()), Some("dec_club_choice_a"));
+        assert_eq!(turn.event.applied, vec![true]);
+    }
+
+    #[test]
+    fn test_inserted_phase_runs_in_place() {
+        let data = test_data();
+        let pipeline = TurnPipeline::standard().insert_after("event", StressCheck);
+        assert_eq!(pipeline.names(), vec!["plan", "commit", "event", "stress_check", "resolve"]);
+
+        let mut state = turn_runner::start_game("PHASES".to_string(), &data);
+        let mut standard = state.clone();
+        let result = pipeline.run(&mut state, &first_turn_choices(), &data, &mut game_rng("PHASES"), Vec::new());
+        let expected = TurnPipeline::standard().run(&mut standard, &first_turn_choices(), &data, &mut game_rng("PHASES"), Vec::new());
+
+        let note = result.feedback.iter().position(|m| m.starts_with("Stress after the card")).unwrap();
+        assert_eq!(result.feedback.len(), expected.feedback.len() + 1);
+        assert_eq!(result.feedback[..note], expected.feedback[..note]);
+        assert_eq!((state.money, state.stress, state.support), (standard.money, standard.stress, standard.support));
+    }
+
+    #[test]
+    fn test_stat_changes_add_up_to_the_turn() {
+        let data = test_data();
+        let mut state = turn_runner::start_game("PHASES".to_string(), &data);
+        let before = state.clone();
+        let result = TurnPipeline::standard().run(&mut state, &first_turn_choices(), &data, &mut game_rng("PHASES"), Vec::new());
+
+        assert!(!result.stat_changes.is_empty());
+        for change in &result.stat_changes {
+            assert_eq!(change.delta, change.by_phase.iter().map(|p| p.delta).sum::<i32>(), "{}", change.stat);
+            assert_eq!(change.before, stat_changes::value(&before, &change.stat));
+            assert_eq!(change.after, stat_changes::value(&state, &change.stat));
+        }
+        let money = result.stat_changes.iter().find(|c| c.stat == "money");
+        assert_eq!(money.map_or(0, |c| c.delta), state.money - before.money);
+    }
+
+    #[test]
+    fn test_unused_time_is_banked_up_to_the_cap() {
+        let mut data = test_data();
+        let mut state = turn_runner::start_game("PHASES".to_string(), &data);
+        let free = state.free_time_slots();
+        let rest = PlayerChoices { action_ids: Vec::new(), ..first_turn_choices() };
+
+        TurnPipeline::standard().run(&mut state, &rest, &data, &mut game_rng("PHASES"), Vec::new());
+        assert_eq!(state.banked_time_slots, 0, "Banking is off by default");
+
+        data.economy.time_bank_cap = 2;
+        let result = TurnPipeline::standard().run(&mut state, &rest, &data, &mut game_rng("PHASES"), Vec::new());
+        assert_eq!(state.banked_time_slots, 2.min(free));
+        assert!(result.feedback.iter().any(|m| m.starts_with("⏰ Banked")));
+        assert_eq!(state.available_time_slots(), state.free_time_slots() + state.banked_time_slots);
+
+        let busy = PlayerChoices { action_ids: vec!["act_study".to_string()], ..first_turn_choices() };
+        let available = state.available_time_slots();
+        TurnPipeline::standard().run(&mut state, &busy, &data, &mut game_rng("PHASES"), Vec::new());
+        let study = data.action("act_study").unwrap().time_cost;
+        assert_eq!(state.banked_time_slots, (available - study).min(2), "Banked time is spent like any other");
+    }
+
+    #[test]
+    #[should_panic(expected = "No phase named upkeep")]
+    fn test_inserting_next_to_an_unknown_phase_panics() {
+        let _ = TurnPipeline::standard().insert_before("upkeep", StressCheck);
+    }
+}
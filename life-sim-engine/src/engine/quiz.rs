@@ -0,0 +1,110 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use crate::engine::game_state::{GameState, QuizAnswer};
+use crate::data_loader::GameData;
+use crate::models::quiz::QuizQuestion;
+
+/// How well a player has answered the questions they've seen.
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QuizSummary {
+    pub answered: usize,
+    pub correct: usize,
+    /// Share answered correctly, 0.0–1.0 (None until something is answered).
+    pub accuracy: Option<f64>,
+    /// Prompts of the questions answered wrong, oldest first.
+    pub missed: Vec<String>,
+}
+
+/// The question on an event card or decision the player has already come across.
+pub fn question_for<'a>(state: &GameState, data: &'a GameData, source_id: &str) -> Option<&'a QuizQuestion> {
+    let decision = data.decision(source_id)
+        .filter(|_| state.decision_log.iter().any(|d| d.decision_id.as_deref() == Some(source_id)));
+    let event = data.event(source_id)
+        .filter(|_| state.used_event_ids.iter().any(|id| id == source_id));
+    decision.and_then(|d| d.quiz.as_ref()).or_else(|| event.and_then(|e| e.quiz.as_ref()))
+}
+
+/// Answer the question on an event card or decision from this game. Each question is
+/// answered once; the first answer is the one that counts.
+/// Returns an error message if there's no such question to answer.
+pub fn answer(state: &mut GameState, data: &GameData, source_id: &str, choice: usize) -> Result<QuizAnswer, String> {
+    let question = question_for(state, data, source_id)
+        .ok_or_else(|| format!("There's no question on {} to answer in this game.", source_id))?;
+    if state.quiz_answers.iter().any(|a| a.source_id == source_id) {
+        return Err(format!("The question on {} was already answered.", source_id));
+    }
+    if choice >= question.choices.len() {
+        return Err(format!("The question has {} choices (got index {}).", question.choices.len(), choice));
+    }
+
+    let answer = QuizAnswer {
+        turn: state.stats_history.last().map_or(0, |s| s.turn),
+        source_id: source_id.to_string(),
+        prompt: question.prompt.clone(),
+        choice,
+        correct: choice == question.answer,
+    };
+    state.quiz_answers.push(answer.clone());
+    Ok(answer)
+}
+
+/// Tally a player's answers.
+pub fn summary(state: &GameState) -> QuizSummary {
+    let answered = state.quiz_answers.len();
+    let correct = state.quiz_answers.iter().filter(|a| a.correct).count();
+    QuizSummary {
+        answered,
+        correct,
+        accuracy: (answered > 0).then(|| correct as f64 / answered as f64),
+        missed: state.quiz_answers.iter().filter(|a| !a.correct).map(|a| a.prompt.clone()).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_loader::test_data;
+    use crate::engine::game_state::DecisionEntry;
+    use crate::models::Stage;
+
+    fn decided(state: &mut GameState, decision_id: &str) {
+        state.decision_log.push(DecisionEntry {
+            turn: 1,
+            stage: Stage::MIDDLE_SCHOOL,
+            decision_id: Some(decision_id.to_string()),
+            option_index: None,
+            description: String::new(),
+            impact: String::new(),
+        });
+    }
+
+    #[test]
+    fn test_answers_are_checked_once() {
+        let data = test_data();
+        let decision = data.decisions.iter().find(|d| d.quiz.is_some()).expect("Shipped data has a decision quiz");
+        let right = decision.quiz.as_ref().unwrap().answer;
+        let mut state = GameState::new("QUIZ".to_string());
+        assert!(answer(&mut state, &data, &decision.id, right).is_err(), "Not decided yet");
+
+        decided(&mut state, &decision.id);
+        assert!(answer(&mut state, &data, &decision.id, 99).is_err());
+        assert!(answer(&mut state, &data, &decision.id, right).unwrap().correct);
+        assert!(answer(&mut state, &data, &decision.id, right).is_err(), "Already answered");
+    }
+
+    #[test]
+    fn test_summary_counts_accuracy() {
+        let data = test_data();
+        let event = data.events.iter().find(|e| e.quiz.is_some()).expect("Shipped data has an event quiz");
+        let wrong = (event.quiz.as_ref().unwrap().answer + 1) % event.quiz.as_ref().unwrap().choices.len();
+        let mut state = GameState::new("QUIZ".to_string());
+        assert_eq!(summary(&state).accuracy, None);
+
+        state.used_event_ids.push(event.id.clone());
+        answer(&mut state, &data, &event.id, wrong).unwrap();
+        let tally = summary(&state);
+        assert_eq!((tally.answered, tally.correct, tally.accuracy), (1, 0, Some(0.0)));
+        assert_eq!(tally.missed, vec![event.quiz.as_ref().unwrap().prompt.clone()]);
+    }
+}
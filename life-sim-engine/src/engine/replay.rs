@@ -0,0 +1,387 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use crate::data_loader::GameData;
+use crate::engine::actions;
+use crate::engine::bot;
+use crate::engine::choices::{self, InvalidChoice};
+use crate::engine::endings;
+use crate::engine::game_state::{GameCommand, GameState};
+use crate::engine::{career, hiring, housing, insurance, reroll, rng, scenarios, transport};
+use crate::engine::rng::GameRng;
+use crate::engine::turn_runner::{self, PlayerChoices};
+use crate::models::EventCard;
+use crate::remote_data::sha256_hex;
+
+/// Different choices for one turn already played. Fields left as None keep what was played.
+#[derive(Debug, Clone, Default)]
+pub struct Alternative {
+    pub turn: u32,
+    pub action_ids: Option<Vec<String>>,
+    pub decision_option_index: Option<usize>,
+    pub event_option_index: Option<usize>,
+}
+
+/// Where a game stands, for comparing two timelines.
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Outcome {
+    /// The ending the game reached (or would reach now, if it isn't over).
+    pub ending_id: Option<String>,
+    pub ending_title: Option<String>,
+    pub money: i32,
+    pub stress: i32,
+    pub support: i32,
+    pub savings: i32,
+    pub credentials: Vec<String>,
+    pub path: Option<String>,
+    pub job: Option<String>,
+}
+
+impl Outcome {
+    pub fn of(state: &GameState, data: &GameData) -> Self {
+        let ending = endings::resolve(state, &data.endings);
+        Self {
+            ending_id: ending.map(|e| e.id.clone()),
+            ending_title: ending.map(|e| e.title.clone()),
+            money: state.money,
+            stress: state.stress,
+            support: state.support,
+            savings: state.savings,
+            credentials: state.credentials.names(),
+            path: state.path.as_ref().map(|p| p.id.clone()),
+            job: state.current_job.as_ref().map(|j| j.id.clone()),
+        }
+    }
+}
+
+/// A game replayed from its seed.
+#[derive(Debug, Clone)]
+pub struct Replayed {
+    pub state: GameState,
+    /// Recorded choices that had to change after the alternative turn because they no
+    /// longer fit the new timeline, e.g. a different card was drawn.
+    pub adjustments: Vec<String>,
+}
+
+/// A fresh game set up the way `original` started: same seed, scenario, tutorial, and
+/// deck mode.
+pub fn fresh_start(original: &GameState, data: &GameData) -> GameState {
+    let mut state = turn_runner::start_game(original.seed.clone(), data);
+    if let Some(scenario) = original.scenario.as_deref().and_then(|id| data.scenario(id)) {
+        scenarios::apply(&mut state, scenario, data);
+    }
+    state.seed_scheme = original.seed_scheme;
+    state.deck_mode = original.deck_mode;
+    state.tutorial = original.tutorial;
+    state.daily_date = original.daily_date.clone();
+    state
+}
+
+/// Play `original`'s commands again on a fresh game with its seed. With an alternative,
+/// that turn is played with the alternative's choices (an error if they aren't valid
+/// then), and later turns keep their recorded choices wherever they still apply.
+pub fn replay(original: &GameState, data: &GameData, alternative: Option<&Alternative>) -> Result<Replayed, Vec<InvalidChoice>> {
+    let mut state = fresh_start(original, data);
+    let mut game_rng = original.seed_rng();
+    // Some(cards) once this turn's cards have been drawn ahead of the turn
+    let mut pending: Option<Vec<EventCard>> = None;
+    let mut diverged = false;
+    let mut adjustments = Vec::new();
+
+    for command in &original.commands {
+        match command {
+            GameCommand::DrawEvent => {
+                if pending.is_none() {
+                    pending = Some(turn_runner::draw_turn_events(&state, data, &mut game_rng));
+                    state.rng_position = game_rng.position();
+                    state.commands.push(GameCommand::DrawEvent);
+                }
+            }
+            GameCommand::Turn { action_ids, decision_id, decision_option_index, defer_decision, event_id, event_option_index, extra_events, .. } => {
+                let events = pending.take()
+                    .unwrap_or_else(|| turn_runner::draw_turn_events(&state, data, &mut game_rng));
+                let recorded = PlayerChoices {
+                    action_ids: action_ids.clone(),
+                    decision_id: decision_id.clone(),
+                    decision_option_index: *decision_option_index,
+                    defer_decision: *defer_decision,
+                    event_option_index: *event_option_index,
+                    event_id: None,
+                    extra_event_options: extra_events.iter().map(|e| e.option_index).collect(),
+                };
+                let choices = match alternative.filter(|a| a.turn == state.current_turn) {
+                    Some(alternative) => {
+                        let choices = PlayerChoices {
+                            action_ids: alternative.action_ids.clone().unwrap_or(recorded.action_ids),
+                            decision_option_index: alternative.decision_option_index.unwrap_or(recorded.decision_option_index),
+                            event_option_index: alternative.event_option_index.or(recorded.event_option_index),
+                            event_id: None,
+                            ..recorded
+                        };
+                        choices::validate_choices(&state, &choices, data, &events)?;
+                        diverged = true;
+                        choices
+                    }
+                    None if diverged => {
+                        let recorded_events: Vec<&str> = event_id.iter().chain(extra_events.iter().map(|e| &e.event_id))
+                            .map(String::as_str)
+                            .collect();
+                        refit(&state, data, &events, recorded, &recorded_events, &mut adjustments)
+                    }
+                    None => recorded,
+                };
+                turn_runner::run_turn_with_events(&mut state, &choices, data, &mut game_rng, events);
+            }
+            GameCommand::RerollEvent { pay } => {
+                let rerolled = match pending.as_mut() {
+                    Some(events) => reroll::reroll_event(&mut state, events, *pay, data, &mut game_rng).map(|_| ()),
+                    None => Err("no card had been drawn".to_string()),
+                };
+                match rerolled {
+                    Ok(()) => state.commands.push(command.clone()),
+                    Err(message) => adjustments.push(format!("Turn {}: skipped {} ({})", state.current_turn, describe(command), message)),
+                }
+            }
+            other => {
+                if let Err(message) = execute(&mut state, data, &mut game_rng, other) {
+                    adjustments.push(format!("Turn {}: skipped {} ({})", state.current_turn, describe(other), message));
+                }
+            }
+        }
+    }
+    Ok(Replayed { state, adjustments })
+}
+
+/// Keep as much of a turn's recorded choices as still fits the replayed game: actions
+/// still offered and within the time budget, the recorded decision option if the same
+/// decision comes up, and the recorded card response if the same card is drawn. Anything
+/// else falls back to the first open option.
+fn refit(
+    state: &GameState,
+    data: &GameData,
+    events: &[EventCard],
+    recorded: PlayerChoices,
+    recorded_events: &[&str],
+    adjustments: &mut Vec<String>,
+) -> PlayerChoices {
+    let turn = state.current_turn;
+    let mut unused_rng = rng::create_rng("");
+    let fallback = bot::choose_turn(&bot::FirstOption, state, data, events, &mut unused_rng);
+
+    let mut time_left = state.available_time_slots();
+    let mut action_ids = Vec::new();
+    for id in recorded.action_ids.iter().cloned() {
+        match data.action(&id) {
+            Some(action) if action.stages.contains(&state.current_stage)
+                && actions::unavailable_reason(state, data, action).is_none()
+                && action.time_cost <= time_left
+                && !action_ids.contains(&id) => {
+                time_left -= action.time_cost;
+                action_ids.push(id);
+            }
+            _ => adjustments.push(format!("Turn {}: dropped {}, which no longer fit", turn, id)),
+        }
+    }
+
+    let decision = choices::current_decision(state, data);
+    let keeps_decision = decision.as_ref().is_some_and(|d| {
+        d.id == recorded.decision_id && if recorded.defer_decision {
+            d.defer_consequence.is_some()
+        } else {
+            d.options.get(recorded.decision_option_index)
+                .is_some_and(|o| o.requires_tag.as_ref().is_none_or(|tag| state.credentials.contains(tag)))
+        }
+    });
+    let decision_option_index = if keeps_decision {
+        recorded.decision_option_index
+    } else {
+        if let Some(ref decision) = decision {
+            let label = decision.options.get(fallback.decision_option_index).map_or("", |o| o.label.as_str());
+            adjustments.push(format!("Turn {}: answered {} with \"{}\"", turn, decision.id, label));
+        }
+        fallback.decision_option_index
+    };
+
+    let mut answers = events.iter().enumerate().map(|(i, event)| {
+        let keeps_event = recorded_events.get(i) == Some(&event.id.as_str())
+            && recorded.event_answer(i).is_some_and(|answer| answer < event.options.len());
+        if keeps_event {
+            return recorded.event_answer(i);
+        }
+        let label = fallback.event_answer(i).and_then(|answer| event.options.get(answer)).map_or("", |o| o.label.as_str());
+        adjustments.push(format!("Turn {}: drew \"{}\" and answered \"{}\"", turn, event.title, label));
+        fallback.event_answer(i)
+    }).collect::<Vec<_>>().into_iter();
+
+    PlayerChoices {
+        action_ids,
+        decision_id: fallback.decision_id,
+        decision_option_index,
+        defer_decision: keeps_decision && recorded.defer_decision,
+        event_option_index: answers.next().flatten(),
+        event_id: None,
+        extra_event_options: answers.collect(),
+    }
+}
+
+/// Carry out a between-turn command, recording it if it succeeds.
+fn execute(state: &mut GameState, data: &GameData, rng: &mut GameRng, command: &GameCommand) -> Result<(), String> {
+    let unknown = |kind: &str, id: &str| format!("unknown {} {}", kind, id);
+    match command {
+        // Played in `replay`, which holds the drawn cards
+        GameCommand::DrawEvent | GameCommand::Turn { .. } | GameCommand::RerollEvent { .. } => return Ok(()),
+        GameCommand::ApplyJob { job_id } => {
+            let job = data.job(job_id).ok_or_else(|| unknown("job", job_id))?;
+            hiring::apply_for_job(state, job, rng)?;
+        }
+        GameCommand::ChangeJob { job_id } => {
+            let job = match job_id {
+                Some(id) => Some(data.job(id).ok_or_else(|| unknown("job", id))?),
+                None => None,
+            };
+            career::change_job(state, job, data)?;
+        }
+        GameCommand::MoveHousing { housing_id } => {
+            let tier = data.housing_tier(housing_id).ok_or_else(|| unknown("housing", housing_id))?;
+            housing::move_into(state, tier)?;
+        }
+        GameCommand::AcquireTransport { transport_id } => {
+            let option = data.transport_option(transport_id).ok_or_else(|| unknown("transport", transport_id))?;
+            transport::acquire(state, option)?;
+        }
+        GameCommand::BuyInsurance { plan_id } => {
+            let plan = data.insurance_plan(plan_id).ok_or_else(|| unknown("insurance plan", plan_id))?;
+            insurance::buy_plan(state, plan)?;
+        }
+        GameCommand::CancelInsurance { plan_id } => {
+            insurance::cancel_plan(state, plan_id)?;
+        }
+        GameCommand::StartSideGig { gig_id } => {
+            let gig = data.side_gig(gig_id).ok_or_else(|| unknown("side gig", gig_id))?;
+            career::start_side_gig(state, gig)?;
+        }
+        GameCommand::DropSideGig { gig_id } => {
+            career::drop_side_gig(state, gig_id)?;
+        }
+    }
+    state.commands.push(command.clone());
+    Ok(())
+}
+
+/// Hash of the sequence a game was played through: its seed, the cards it was dealt, and
+/// every entry in its decision log. Two games share it only if they drew the same cards
+/// and chose the same way, so a teacher can check two students played the same run.
+pub fn sequence_hash(state: &GameState) -> String {
+    let sequence = serde_json::json!({
+        "seed": state.seed,
+        "usedEventIds": state.used_event_ids,
+        "decisionLog": state.decision_log,
+    });
+    sha256_hex(sequence.to_string().as_bytes())
+}
+
+/// A between-turn command in words, for adjustment notes.
+fn describe(command: &GameCommand) -> String {
+    match command {
+        GameCommand::DrawEvent => "drawing the card".to_string(),
+        GameCommand::Turn { turn, .. } => format!("turn {}", turn),
+        GameCommand::ApplyJob { job_id } => format!("applying for {}", job_id),
+        GameCommand::ChangeJob { job_id: Some(id) } => format!("switching to {}", id),
+        GameCommand::ChangeJob { job_id: None } => "quitting the job".to_string(),
+        GameCommand::MoveHousing { housing_id } => format!("moving to {}", housing_id),
+        GameCommand::AcquireTransport { transport_id } => format!("getting {}", transport_id),
+        GameCommand::BuyInsurance { plan_id } => format!("buying {}", plan_id),
+        GameCommand::CancelInsurance { plan_id } => format!("cancelling {}", plan_id),
+        GameCommand::StartSideGig { gig_id } => format!("starting {}", gig_id),
+        GameCommand::DropSideGig { gig_id } => format!("dropping {}", gig_id),
+        GameCommand::RerollEvent { .. } => "rerolling the card".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_loader::test_data;
+    use crate::models::Stage;
+
+    #[test]
+    fn test_replay_reproduces_the_game() {
+        let data = test_data();
+        let original = bot::play_game(&bot::RandomStrategy, &data, "REPLAY");
+        let replayed = replay(&original, &data, None).unwrap();
+        assert!(replayed.adjustments.is_empty());
+        assert_eq!(Outcome::of(&replayed.state, &data), Outcome::of(&original, &data));
+        assert_eq!(replayed.state.stats_history.len(), original.stats_history.len());
+        assert_eq!(replayed.state.commands, original.commands);
+
+        // A between-turn command that can't be carried out is skipped
+        let mut edited = original.clone();
+        let job = data.jobs.iter().find(|j| !j.stages.contains(&Stage::MIDDLE_SCHOOL)).unwrap();
+        edited.commands.insert(0, GameCommand::ApplyJob { job_id: job.id.clone() });
+        let replayed = replay(&edited, &data, None).unwrap();
+        assert_eq!(replayed.adjustments.len(), 1);
+        assert!(replayed.adjustments[0].starts_with(&format!("Turn 1: skipped applying for {}", job.id)));
+    }
+
+    #[test]
+    fn test_replay_rerolls_the_same_card() {
+        let data = test_data();
+        let mut original = turn_runner::start_game("REROLL".to_string(), &data);
+        let mut game_rng = original.seed_rng();
+        let mut events = turn_runner::draw_turn_events(&original, &data, &mut game_rng);
+        original.commands.push(GameCommand::DrawEvent);
+        reroll::reroll_event(&mut original, &mut events, reroll::RerollPayment::Money, &data, &mut game_rng).unwrap();
+        original.commands.push(GameCommand::RerollEvent { pay: reroll::RerollPayment::Money });
+        let choices = bot::choose_turn(&bot::FirstOption, &original, &data, &events, &mut rng::create_rng("REROLL/bot"));
+        turn_runner::run_turn_with_events(&mut original, &choices, &data, &mut game_rng, events);
+
+        let replayed = replay(&original, &data, None).unwrap();
+        assert!(replayed.adjustments.is_empty());
+        assert_eq!(replayed.state.used_event_ids, original.used_event_ids);
+        assert_eq!(replayed.state.money, original.money);
+        assert_eq!(replayed.state.commands, original.commands);
+
+        // Without a drawn card there's nothing to reroll
+        original.commands.remove(0);
+        let replayed = replay(&original, &data, None).unwrap();
+        assert_eq!(replayed.adjustments, vec!["Turn 1: skipped rerolling the card (no card had been drawn)".to_string()]);
+    }
+
+    #[test]
+    fn test_alternative_changes_the_timeline() {
+        let data = test_data();
+        let original = bot::play_game(&bot::FirstOption, &data, "WHATIF");
+        let club_turn = original.decision_log.iter()
+            .find(|d| d.decision_id.as_deref() == Some("dec_club_choice_a"))
+            .unwrap().turn;
+
+        let alternative = Alternative { turn: club_turn, decision_option_index: Some(1), ..Default::default() };
+        let replayed = replay(&original, &data, Some(&alternative)).unwrap();
+        let club = replayed.state.decision_log.iter().find(|d| d.turn == club_turn).unwrap();
+        assert!(!club.description.contains("Tech Club"));
+        assert!(turn_runner::is_game_over(&replayed.state, &data.stages));
+        // Turns before the change are untouched
+        let before = |s: &GameState| s.stats_history.iter().take_while(|h| h.turn < club_turn).map(|h| h.money).collect::<Vec<_>>();
+        assert_eq!(before(&replayed.state), before(&original));
+
+        let invalid = Alternative { turn: club_turn, decision_option_index: Some(99), ..Default::default() };
+        let errors = replay(&original, &data, Some(&invalid)).unwrap_err();
+        assert_eq!(errors[0].field, "decisionOptionIndex");
+    }
+
+    #[test]
+    fn test_sequence_hash_matches_the_same_run() {
+        let data = test_data();
+        let original = bot::play_game(&bot::FirstOption, &data, "VERIFY");
+        let hash = sequence_hash(&original);
+        assert_eq!(hash, sequence_hash(&bot::play_game(&bot::FirstOption, &data, "VERIFY")));
+        assert_eq!(hash, sequence_hash(&replay(&original, &data, None).unwrap().state));
+
+        assert_ne!(hash, sequence_hash(&bot::play_game(&bot::FirstOption, &data, "VERIFY2")), "Another seed");
+        let club_turn = original.decision_log.iter()
+            .find(|d| d.decision_id.as_deref() == Some("dec_club_choice_a"))
+            .unwrap().turn;
+        let alternative = Alternative { turn: club_turn, decision_option_index: Some(1), ..Default::default() };
+        assert_ne!(hash, sequence_hash(&replay(&original, &data, Some(&alternative)).unwrap().state), "Other choices");
+    }
+}
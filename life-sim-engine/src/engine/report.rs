@@ -0,0 +1,168 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use crate::engine::game_state::GameState;
+use crate::engine::journal;
+use crate::models::Stage;
+
+/// Column headers for the CSV report, in order.
+pub const CSV_HEADER: &[&str] = &[
+    "player", "seed", "turn", "stage", "money", "stress", "support", "timeSlots", "decisions", "impact",
+    "reflections",
+];
+
+/// One played turn of one game: the stats it ended on and the decisions made during it.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportRow {
+    pub player: String,
+    pub seed: String,
+    pub turn: u32,
+    pub stage: Stage,
+    pub money: i32,
+    pub stress: i32,
+    pub support: i32,
+    pub time_slots: u32,
+    /// Decisions logged that turn, e.g. "Which club do you want to join?: Robotics".
+    pub decisions: Vec<String>,
+    /// Each decision's stat effects, in the same order.
+    pub impact: Vec<String>,
+    /// Journal entries the player wrote about the turn.
+    pub reflections: Vec<String>,
+}
+
+/// A row for every turn the game has played, oldest first.
+pub fn report_rows(player: &str, state: &GameState) -> Vec<ReportRow> {
+    state.stats_history.iter().map(|snapshot| {
+        let entries = state.decision_log.iter().filter(|d| d.turn == snapshot.turn);
+        ReportRow {
+            player: player.to_string(),
+            seed: state.seed.clone(),
+            turn: snapshot.turn,
+            stage: snapshot.stage.clone(),
+            money: snapshot.money,
+            stress: snapshot.stress,
+            support: snapshot.support,
+            time_slots: snapshot.time_slots,
+            decisions: entries.clone().map(|d| d.description.clone()).collect(),
+            impact: entries.map(|d| d.impact.clone()).collect(),
+            reflections: journal::entries_for_turn(state, snapshot.turn).map(|e| e.text.clone()).collect(),
+        }
+    }).collect()
+}
+
+/// Render rows as CSV (RFC 4180: comma-separated, CRLF line ends, quoted where needed).
+/// Several decisions (or reflections) in one turn share a cell, separated by " | ".
+pub fn to_csv(rows: &[ReportRow]) -> String {
+    let mut csv = CSV_HEADER.join(",");
+    csv.push_str("\r\n");
+    for row in rows {
+        let cells = [
+            row.player.clone(),
+            row.seed.clone(),
+            row.turn.to_string(),
+            row.stage.id().to_string(),
+            row.money.to_string(),
+            row.stress.to_string(),
+            row.support.to_string(),
+            row.time_slots.to_string(),
+            row.decisions.join(" | "),
+            row.impact.join(" | "),
+            row.reflections.join(" | "),
+        ];
+        let cells: Vec<String> = cells.iter().map(|c| csv_cell(c)).collect();
+        csv.push_str(&cells.join(","));
+        csv.push_str("\r\n");
+    }
+    csv
+}
+
+/// Quote a cell if it holds a comma, quote, or line break; spreadsheet formula
+/// prefixes are escaped so a player name can't run as a formula.
+fn csv_cell(value: &str) -> String {
+    let value = if value.starts_with(['=', '+', '-', '@']) && value.parse::<f64>().is_err() {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    };
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::game_state::{DecisionEntry, JournalEntry, StatSnapshot};
+
+    fn played(state: &mut GameState, turn: u32, money: i32) {
+        state.stats_history.push(StatSnapshot {
+            turn,
+            stage: Stage::MIDDLE_SCHOOL,
+            money,
+            stress: 20,
+            support: 5,
+            time_slots: 3,
+            money_earned: 0,
+            money_spent: 0,
+            credentials_earned: Vec::new(),
+            event_id: None,
+            event_option_index: None,
+            extra_events: Vec::new(),
+            job_id: None,
+        });
+    }
+
+    #[test]
+    fn test_rows_pair_stats_with_decisions() {
+        let mut state = GameState::new("REPORT".to_string());
+        played(&mut state, 1, 90);
+        played(&mut state, 2, 120);
+        state.decision_log.push(DecisionEntry {
+            turn: 2,
+            stage: Stage::MIDDLE_SCHOOL,
+            decision_id: None,
+            option_index: None,
+            description: "Which club?: Robotics".to_string(),
+            impact: "Stress +5".to_string(),
+        });
+
+        state.journal.push(JournalEntry {
+            turn: 2,
+            stage: Stage::MIDDLE_SCHOOL,
+            decision_id: None,
+            decision: Some("Which club?: Robotics".to_string()),
+            text: "Worth it".to_string(),
+        });
+
+        let rows = report_rows("Ana", &state);
+        assert_eq!(rows.len(), 2);
+        assert!(rows[0].decisions.is_empty());
+        assert_eq!(rows[1].decisions, vec!["Which club?: Robotics"]);
+        assert_eq!(rows[1].reflections, vec!["Worth it"]);
+        assert_eq!((rows[1].money, rows[1].seed.as_str()), (120, "REPORT"));
+    }
+
+    #[test]
+    fn test_csv_quotes_and_escapes_cells() {
+        let mut state = GameState::new("REPORT".to_string());
+        played(&mut state, 1, -15);
+        state.decision_log.push(DecisionEntry {
+            turn: 1,
+            stage: Stage::MIDDLE_SCHOOL,
+            decision_id: None,
+            option_index: None,
+            description: "Lunch, \"the usual\"".to_string(),
+            impact: "Money -15".to_string(),
+        });
+
+        let csv = to_csv(&report_rows("=HYPERLINK(1)", &state));
+        let lines: Vec<&str> = csv.split("\r\n").collect();
+        assert_eq!(lines[0], CSV_HEADER.join(","));
+        assert_eq!(
+            lines[1],
+            "'=HYPERLINK(1),REPORT,1,middle-school,-15,20,5,3,\"Lunch, \"\"the usual\"\"\",Money -15,"
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use crate::data_loader::GameData;
+use crate::engine::game_state::{DecisionEntry, GameState};
+use crate::engine::rng::{GameRng, Stream};
+use crate::engine::turn_runner;
+use crate::models::EventCard;
+use crate::models::event::{StatEffect, StatType};
+
+/// What the player pays for a reroll with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum RerollPayment {
+    Money,
+    Support,
+}
+
+impl RerollPayment {
+    /// The reroll's price in this payment, per economy.json (0 if it can't be paid this way).
+    pub fn cost(self, data: &GameData) -> i32 {
+        match self {
+            RerollPayment::Money => data.economy.reroll_money_cost,
+            RerollPayment::Support => data.economy.reroll_support_cost,
+        }
+    }
+
+    fn stat(self) -> StatType {
+        match self {
+            RerollPayment::Money => StatType::Money,
+            RerollPayment::Support => StatType::Support,
+        }
+    }
+}
+
+/// Whether the player still has this stage's reroll and some way to pay for it.
+pub fn available(state: &GameState, data: &GameData) -> bool {
+    !state.rerolled_stages.contains(&state.current_stage)
+        && [RerollPayment::Money, RerollPayment::Support].iter().any(|pay| pay.cost(data) > 0)
+}
+
+/// Swap the first of this turn's drawn `events` for another card from the stage's deck,
+/// paying with `pay`. Once per stage, and only for deck cards: scripted, transition,
+/// interview and breakdown cards stay. The new card comes from the reroll stream, so the
+/// cards later turns deal don't move; a shuffled deck deals its next card instead. Returns the card that was replaced.
+pub fn reroll_event(
+    state: &mut GameState,
+    events: &mut [EventCard],
+    pay: RerollPayment,
+    data: &GameData,
+    rng: &mut GameRng,
+) -> Result<EventCard, String> {
+    let stage = state.current_stage.clone();
+    let Some(drawn) = events.first() else {
+        return Err("Draw this turn's cards before rerolling one.".to_string());
+    };
+    if state.rerolled_stages.contains(&stage) {
+        return Err("You've already rerolled a card this stage.".to_string());
+    }
+    if !data.stage_deck(&stage).any(|e| e.id == drawn.id) {
+        return Err(format!("{} can't be rerolled.", drawn.title));
+    }
+    let cost = pay.cost(data);
+    if cost <= 0 {
+        return Err(format!("Rerolls can't be paid for with {}.", pay.stat()));
+    }
+    let balance = match pay {
+        RerollPayment::Money => state.money,
+        RerollPayment::Support => state.support,
+    };
+    if balance < cost {
+        return Err(format!("A reroll costs {} {}; you have {}.", cost, pay.stat(), balance));
+    }
+
+    let used: Vec<String> = state.used_event_ids.iter().cloned()
+        .chain(events.iter().map(|e| e.id.clone()))
+        .collect();
+    let card = rng.draw(Stream::Rerolls, state.current_turn, "reroll", |r| turn_runner::draw_deck_card(state, data, &used, r).cloned());
+    state.rng_position = rng.position();
+    let card = card.ok_or_else(|| "No other card is left to draw.".to_string())?;
+
+    match pay {
+        RerollPayment::Money => state.money -= cost,
+        RerollPayment::Support => state.support -= cost,
+    }
+    state.rerolled_stages.push(stage.clone());
+    let payment = StatEffect { stat: pay.stat(), delta: -cost, tag: None, percent: None, when: None };
+    state.decision_log.push(DecisionEntry {
+        turn: state.current_turn,
+        stage,
+        decision_id: None,
+        option_index: None,
+        description: format!("Rerolled {} and drew {}", drawn.title, card.title),
+        impact: payment.to_string(),
+    });
+    Ok(std::mem::replace(&mut events[0], card))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_loader::test_data;
+    use crate::engine::hiring;
+    use crate::engine::rng::game_rng;
+
+    #[test]
+    fn test_reroll_swaps_the_card_once_per_stage() {
+        let data = test_data();
+        let mut state = turn_runner::start_game("REROLL".to_string(), &data);
+        let mut rng = game_rng("REROLL");
+        let mut events = turn_runner::draw_turn_events(&state, &data, &mut rng);
+        let drawn = events[0].clone();
+        let (money, events_stream) = (state.money, rng.events().clone());
+
+        let replaced = reroll_event(&mut state, &mut events, RerollPayment::Money, &data, &mut rng).unwrap();
+        assert_eq!(replaced.id, drawn.id);
+        assert_ne!(events[0].id, drawn.id);
+        assert_eq!(state.money, money - data.economy.reroll_money_cost);
+        assert_eq!(*rng.events(), events_stream, "Only the reroll stream moved");
+        let entry = state.decision_log.last().unwrap();
+        assert!(entry.description.contains(&drawn.title) && entry.description.contains(&events[0].title));
+        assert!(!available(&state, &data));
+
+        let again = reroll_event(&mut state, &mut events, RerollPayment::Support, &data, &mut rng);
+        assert_eq!(again.unwrap_err(), "You've already rerolled a card this stage.");
+    }
+
+    #[test]
+    fn test_reroll_needs_a_deck_card_and_the_price() {
+        let data = test_data();
+        let mut state = turn_runner::start_game("REROLL".to_string(), &data);
+        let mut rng = game_rng("REROLL");
+        assert!(reroll_event(&mut state, &mut [], RerollPayment::Money, &data, &mut rng).is_err());
+
+        let mut events = turn_runner::draw_turn_events(&state, &data, &mut rng);
+        state.support = 0;
+        let broke = reroll_event(&mut state, &mut events, RerollPayment::Support, &data, &mut rng);
+        assert!(broke.unwrap_err().starts_with("A reroll costs"));
+
+        let mut events = vec![hiring::interview_card(&data.jobs[0])];
+        assert!(reroll_event(&mut state, &mut events, RerollPayment::Money, &data, &mut rng).unwrap_err().ends_with("can't be rerolled."));
+        assert!(state.decision_log.is_empty());
+    }
+}
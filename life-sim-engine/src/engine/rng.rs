@@ -0,0 +1,310 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// How a seed string becomes the 32 bytes ChaCha8Rng is seeded with.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum SeedScheme {
+    /// The string's bytes, zero-padded or cut to 32. Seeds sharing a 32-byte prefix
+    /// collide. Kept so games and classroom seeds from before hashing deal the same
+    /// cards; saves without a scheme are on it.
+    #[default]
+    Legacy,
+    /// The SHA-256 digest of the string.
+    Sha256,
+}
+
+impl SeedScheme {
+    /// A deterministic RNG on this seed string.
+    pub fn rng(self, seed_str: &str) -> ChaCha8Rng {
+        let seed_bytes: [u8; 32] = match self {
+            SeedScheme::Legacy => {
+                let mut seed_bytes = [0u8; 32];
+                for (i, &b) in seed_str.as_bytes().iter().enumerate().take(32) {
+                    seed_bytes[i] = b;
+                }
+                seed_bytes
+            }
+            SeedScheme::Sha256 => Sha256::digest(seed_str.as_bytes()).into(),
+        };
+        ChaCha8Rng::from_seed(seed_bytes)
+    }
+}
+
+/// Create a deterministic RNG from a seed string.
+///
+/// The seed string is hashed with SHA-256 into the 32-byte ChaCha8Rng seed. Two
+/// identical seed strings will always produce the same sequence of random numbers —
+/// this is the classroom seed-sharing feature that lets teachers ensure all students
+/// face the same events. Games on the old byte-copy scheme use `SeedScheme::Legacy`.
+pub fn create_rng(seed_str: &str) -> ChaCha8Rng {
+    SeedScheme::Sha256.rng(seed_str)
+}
+
+/// One of the independent sequences a game draws from. Each is its own ChaCha stream
+/// on the seed's key, so a new random mechanic drawing from one never moves another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum Stream {
+    /// Event cards: the deck, transition cards, breakdowns. The seed's first stream, so
+    /// a seed deals the cards it always has.
+    Events,
+    /// Dice rolls, such as whether a job application lands an interview.
+    Rolls,
+    /// Generating scenarios.
+    Scenarios,
+    /// The card a reroll deals in place of the one drawn.
+    Rerolls,
+}
+
+impl Stream {
+    pub const ALL: [Stream; 4] = [Stream::Events, Stream::Rolls, Stream::Scenarios, Stream::Rerolls];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// A game's random streams, derived from its seed.
+#[derive(Debug, Clone)]
+pub struct GameRng {
+    /// One RNG per stream, in `Stream::ALL` order. Legacy games share a single RNG
+    /// between every stream, as they did before streams were split.
+    streams: Vec<ChaCha8Rng>,
+    /// Every draw taken through `draw`, if auditing is on (see `audit`).
+    log: Option<Vec<RngDraw>>,
+}
+
+/// One draw a game took from its random streams, as the audit log records it.
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RngDraw {
+    /// The turn the draw was taken on.
+    pub turn: u32,
+    pub stream: Stream,
+    /// What the draw decided, such as "deck card" or "interview for Barista".
+    pub purpose: String,
+    /// The first number the draw took, in [0, 1): what a weighted card draw or a dice
+    /// roll compares against.
+    pub roll: f64,
+    /// How many 32-bit words the draw moved its stream on.
+    pub words: u64,
+}
+
+impl GameRng {
+    pub fn new(scheme: SeedScheme, seed_str: &str) -> Self {
+        let master = scheme.rng(seed_str);
+        let streams = match scheme {
+            SeedScheme::Legacy => vec![master],
+            SeedScheme::Sha256 => Stream::ALL.iter()
+                .map(|&stream| {
+                    let mut rng = master.clone();
+                    rng.set_stream(stream.index() as u64);
+                    rng
+                })
+                .collect(),
+        };
+        Self { streams, log: None }
+    }
+
+    /// Start recording every draw taken through `draw`. Off by default: the log grows
+    /// with the game, and only debugging wants it.
+    pub fn audit(&mut self) {
+        self.log.get_or_insert_with(Vec::new);
+    }
+
+    /// The draws recorded since `audit` was called, or `None` if it wasn't.
+    pub fn audit_log(&self) -> Option<&[RngDraw]> {
+        self.log.as_deref()
+    }
+
+    /// Run `f` on a stream's RNG, recording what it drew in the audit log if it drew
+    /// anything. The recorded roll is read back from a copy of the stream taken before
+    /// `f` ran, so auditing never moves the stream itself.
+    pub fn draw<T>(&mut self, stream: Stream, turn: u32, purpose: &str, f: impl FnOnce(&mut ChaCha8Rng) -> T) -> T {
+        let before = self.log.is_some().then(|| self.stream(stream).clone());
+        let drawn = f(self.stream(stream));
+        if let Some(mut before) = before {
+            let words = (self.stream(stream).get_word_pos() - before.get_word_pos()) as u64;
+            if words > 0 {
+                let draw = RngDraw { turn, stream, purpose: purpose.to_string(), roll: before.gen::<f64>(), words };
+                self.log.get_or_insert_with(Vec::new).push(draw);
+            }
+        }
+        drawn
+    }
+
+    /// The RNG a stream draws from.
+    pub fn stream(&mut self, stream: Stream) -> &mut ChaCha8Rng {
+        let last = self.streams.len() - 1;
+        &mut self.streams[stream.index().min(last)]
+    }
+
+    pub fn events(&mut self) -> &mut ChaCha8Rng {
+        self.stream(Stream::Events)
+    }
+
+    pub fn rolls(&mut self) -> &mut ChaCha8Rng {
+        self.stream(Stream::Rolls)
+    }
+
+    /// How far each stream has drawn, to save with the game.
+    pub fn position(&self) -> RngPosition {
+        RngPosition { words: self.streams.iter().map(|rng| rng.get_word_pos() as u64).collect() }
+    }
+
+    /// The streams on this seed, fast-forwarded to a saved position. Streams the position
+    /// doesn't cover (saves from before it was kept) start from the beginning.
+    pub fn resume(scheme: SeedScheme, seed_str: &str, position: &RngPosition) -> Self {
+        let mut rng = Self::new(scheme, seed_str);
+        for (stream, &words) in rng.streams.iter_mut().zip(&position.words) {
+            stream.set_word_pos(u128::from(words));
+        }
+        rng
+    }
+}
+
+/// Where a game's random streams are: the 32-bit words each has drawn, in `Stream::ALL`
+/// order (a single entry for legacy games).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RngPosition {
+    pub words: Vec<u64>,
+}
+
+/// Create a game's random streams from a seed string, hashed like `create_rng`.
+pub fn game_rng(seed_str: &str) -> GameRng {
+    GameRng::new(SeedScheme::Sha256, seed_str)
+}
+
+/// Generate a random 8-character alphanumeric seed string.
+pub fn generate_seed() -> String {
+    let mut rng = rand::thread_rng();
+    let chars: Vec<char> = (0..8)
+        .map(|_| {
+            let idx = rng.gen_range(0..36);
+            if idx < 10 {
+                (b'0' + idx) as char
+            } else {
+                (b'A' + idx - 10) as char
+            }
+        })
+        .collect();
+    chars.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deterministic_rng() {
+        let mut rng1 = create_rng("CLASSROOM2026");
+        let mut rng2 = create_rng("CLASSROOM2026");
+
+        let seq1: Vec<f64> = (0..10).map(|_| rng1.gen::<f64>()).collect();
+        let seq2: Vec<f64> = (0..10).map(|_| rng2.gen::<f64>()).collect();
+
+        assert_eq!(seq1, seq2, "Same seed must produce same sequence");
+    }
+
+    #[test]
+    fn test_different_seeds_differ() {
+        let mut rng1 = create_rng("SEED_A");
+        let mut rng2 = create_rng("SEED_B");
+
+        let val1: f64 = rng1.gen();
+        let val2: f64 = rng2.gen();
+
+        assert_ne!(val1, val2, "Different seeds should produce different values");
+    }
+
+    #[test]
+    fn test_seeds_sharing_a_prefix_differ() {
+        let prefix = "A".repeat(32);
+        let (a, b) = (format!("{}1", prefix), format!("{}2", prefix));
+
+        assert_ne!(create_rng(&a).gen::<u64>(), create_rng(&b).gen::<u64>());
+        assert_eq!(
+            SeedScheme::Legacy.rng(&a).gen::<u64>(),
+            SeedScheme::Legacy.rng(&b).gen::<u64>(),
+            "The legacy scheme only reads the first 32 bytes"
+        );
+    }
+
+    #[test]
+    fn test_legacy_scheme_copies_the_seed_bytes() {
+        let mut seed_bytes = [0u8; 32];
+        seed_bytes[..4].copy_from_slice(b"SEED");
+        let mut expected = ChaCha8Rng::from_seed(seed_bytes);
+        assert_eq!(SeedScheme::Legacy.rng("SEED").gen::<u64>(), expected.gen::<u64>());
+        assert_ne!(create_rng("SEED").gen::<u64>(), SeedScheme::Legacy.rng("SEED").gen::<u64>());
+    }
+
+    #[test]
+    fn test_streams_are_independent() {
+        let mut rng = game_rng("STREAMS");
+        let first_card: u64 = rng.events().gen();
+        assert_eq!(first_card, create_rng("STREAMS").gen::<u64>(), "Events draw from the seed's first stream");
+
+        let mut rolled = game_rng("STREAMS");
+        for _ in 0..5 {
+            rolled.rolls().gen::<u64>();
+        }
+        assert_eq!(rolled.events().gen::<u64>(), first_card, "Rolling dice doesn't move the event stream");
+        assert_ne!(game_rng("STREAMS").rolls().gen::<u64>(), first_card);
+    }
+
+    #[test]
+    fn test_legacy_streams_share_one_rng() {
+        let mut rng = GameRng::new(SeedScheme::Legacy, "SEED");
+        let mut master = SeedScheme::Legacy.rng("SEED");
+        assert_eq!(rng.events().gen::<u64>(), master.gen::<u64>());
+        assert_eq!(rng.rolls().gen::<u64>(), master.gen::<u64>(), "A roll takes the next value, as before streams");
+    }
+
+    #[test]
+    fn test_resumed_streams_carry_on_the_sequence() {
+        for scheme in [SeedScheme::Legacy, SeedScheme::Sha256] {
+            let mut rng = GameRng::new(scheme, "RESUME");
+            rng.events().gen::<f64>();
+            rng.rolls().gen::<u64>();
+            rng.events().gen::<u32>();
+
+            let saved = serde_json::to_string(&rng.position()).unwrap();
+            let mut resumed = GameRng::resume(scheme, "RESUME", &serde_json::from_str(&saved).unwrap());
+            assert_eq!(resumed.position(), rng.position());
+            for stream in Stream::ALL {
+                assert_eq!(resumed.stream(stream).gen::<u64>(), rng.stream(stream).gen::<u64>(), "{:?}", stream);
+            }
+        }
+    }
+
+    #[test]
+    fn test_audit_records_draws_without_moving_the_streams() {
+        let mut plain = game_rng("AUDIT");
+        let mut audited = game_rng("AUDIT");
+        audited.audit();
+
+        let expected = plain.events().gen::<f64>();
+        let rolled = audited.draw(Stream::Events, 3, "deck card", |rng| rng.gen::<f64>());
+        assert_eq!(rolled, expected, "Auditing draws the same numbers");
+        audited.draw(Stream::Rolls, 3, "nothing", |_| ());
+        assert_eq!(audited.position(), plain.position());
+
+        let log = audited.audit_log().unwrap();
+        assert_eq!(log.len(), 1, "A draw that takes nothing isn't logged");
+        assert_eq!(log[0], RngDraw { turn: 3, stream: Stream::Events, purpose: "deck card".to_string(), roll: expected, words: 2 });
+        assert!(plain.audit_log().is_none());
+    }
+
+    #[test]
+    fn test_generate_seed_length() {
+        let seed = generate_seed();
+        assert_eq!(seed.len(), 8);
+        assert!(seed.chars().all(|c| c.is_ascii_alphanumeric()));
+    }
+}
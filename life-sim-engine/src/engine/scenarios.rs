@@ -0,0 +1,82 @@
+use crate::engine::calendar;
+use crate::engine::game_state::GameState;
+use crate::engine::turn_runner;
+use crate::data_loader::GameData;
+use crate::models::scenario::Scenario;
+
+/// Set a fresh game up for a scenario: move it to the start of the scenario's stage,
+/// then apply its stats, tags, path, and locked decisions.
+pub fn apply(state: &mut GameState, scenario: &Scenario, data: &GameData) {
+    if let Some(stage) = scenario.stage.as_ref().and_then(|s| turn_runner::stage_def(&data.stages, s)) {
+        state.current_turn = stage.start_turn;
+        state.enter_stage(stage);
+        calendar::update(state, &data.stages);
+    }
+
+    let stats = &scenario.stats;
+    let overrides = [
+        (&mut state.money, stats.money),
+        (&mut state.stress, stats.stress),
+        (&mut state.support, stats.support),
+        (&mut state.savings, stats.savings),
+        (&mut state.monthly_bills, stats.monthly_bills),
+        (&mut state.emergency_fund, stats.emergency_fund),
+        (&mut state.student_loan, stats.student_loan),
+    ];
+    for (field, value) in overrides {
+        if let Some(value) = value {
+            *field = value;
+        }
+    }
+
+    for tag in &scenario.tags {
+        state.credentials.grant(tag);
+    }
+    if let Some(ref path_id) = scenario.path {
+        state.path = data.path(path_id).cloned();
+    }
+    state.locked_decisions = scenario.locked_decisions.clone();
+    state.scenario = Some(scenario.id.clone());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_loader::test_data;
+    use crate::engine::choices;
+    use crate::models::Stage;
+
+    #[test]
+    fn test_scenario_sets_up_the_start() {
+        let data = test_data();
+        let scenario = data.scenarios.iter().find(|s| s.id == "scn_broke_baby").unwrap();
+        let mut state = turn_runner::start_game("SCENARIO".to_string(), &data);
+        let support = state.support;
+        apply(&mut state, scenario, &data);
+
+        let stage = turn_runner::stage_def(&data.stages, &Stage::EARLY_ADULT).unwrap();
+        assert_eq!((state.current_stage.clone(), state.current_turn), (Stage::EARLY_ADULT, stage.start_turn));
+        assert_eq!(state.time_slots, stage.time_slots);
+        assert_eq!((state.money, state.monthly_bills), (40, 180));
+        assert_ne!(state.support, support);
+        assert_eq!(state.path.as_ref().map(|p| p.id.as_str()), Some("path_workforce"));
+        assert_eq!(state.scenario.as_deref(), Some("scn_broke_baby"));
+    }
+
+    #[test]
+    fn test_locked_decisions_are_never_offered() {
+        let data = test_data();
+        let scenario = data.scenarios.iter().find(|s| s.id == "scn_broke_baby").unwrap();
+        let locked = &scenario.locked_decisions[0];
+        let turn = data.decisions.iter().find(|d| d.id == *locked).unwrap().turn;
+
+        let mut state = turn_runner::start_game("SCENARIO".to_string(), &data);
+        apply(&mut state, scenario, &data);
+        state.current_turn = turn;
+        let offered = choices::current_decision(&state, &data).map(|d| d.id.clone());
+        assert_ne!(offered.as_ref(), Some(locked));
+
+        state.locked_decisions.clear();
+        assert_eq!(choices::current_decision(&state, &data).map(|d| d.id.clone()).as_ref(), Some(locked));
+    }
+}
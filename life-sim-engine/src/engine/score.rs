@@ -0,0 +1,163 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use crate::engine::endings;
+use crate::engine::game_state::GameState;
+use crate::models::Ending;
+use crate::models::event::{StatEffect, StatType};
+
+/// Money counts up to this much; past it, extra cash doesn't climb the leaderboard.
+const MONEY_CAP: i32 = 1000;
+/// Points per $ of money (as a divisor: 1 point per $5).
+const DOLLARS_PER_POINT: i32 = 5;
+pub(crate) const POINTS_PER_CREDENTIAL: i32 = 20;
+const POINTS_PER_SUPPORT: i32 = 5;
+/// Bonus for finishing with no debt: money at or above zero and no student loan left.
+const DEBT_FREE_BONUS: i32 = 50;
+/// Points per ending tier above the fallback ending.
+const POINTS_PER_ENDING_TIER: i32 = 50;
+
+/// Lowest total for each letter grade, best first; anything below the last is an F.
+const GRADE_CUTOFFS: [(Grade, i32); 4] = [(Grade::A, 450), (Grade::B, 350), (Grade::C, 250), (Grade::D, 150)];
+
+/// A letter grade for a run's total score.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum Grade {
+    A,
+    B,
+    C,
+    D,
+    #[default]
+    F,
+}
+
+impl Grade {
+    pub fn for_total(total: i32) -> Grade {
+        GRADE_CUTOFFS.iter()
+            .find(|&&(_, cutoff)| total >= cutoff)
+            .map_or(Grade::F, |&(grade, _)| grade)
+    }
+}
+
+/// A finished run's composite score, with what each part contributed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Score {
+    /// 1 point per $5, up to $1000.
+    pub money: i32,
+    /// 1 point per point of stress below 100.
+    pub low_stress: i32,
+    /// 20 points per credential.
+    pub credentials: i32,
+    /// 5 points per point of support.
+    #[serde(default)]
+    pub support: i32,
+    /// 50 points for finishing with no debt.
+    #[serde(default)]
+    pub debt_free: i32,
+    /// 50 points per tier. endings.json lists the harder endings first: the last (fallback)
+    /// ending is tier 0, the one before it tier 1, and so on. Badges don't count as tiers.
+    pub ending: i32,
+    pub total: i32,
+    /// A for a total of 450 or more, B for 350, C for 250, D for 150, otherwise F.
+    #[serde(default)]
+    pub grade: Grade,
+}
+
+/// Score a run against the endings it could have reached.
+pub fn score(state: &GameState, endings_list: &[Ending]) -> Score {
+    let money = state.money.clamp(0, MONEY_CAP) / DOLLARS_PER_POINT;
+    let low_stress = (100 - state.stress).clamp(0, 100);
+    let credentials = state.credentials.len() as i32 * POINTS_PER_CREDENTIAL;
+    let support = state.support.max(0) * POINTS_PER_SUPPORT;
+    let debt_free = if state.is_debt_free() { DEBT_FREE_BONUS } else { 0 };
+    let tiers: Vec<&Ending> = endings_list.iter().filter(|e| !e.badge).collect();
+    let ending = endings::resolve(state, endings_list)
+        .and_then(|reached| tiers.iter().position(|e| e.id == reached.id))
+        .map_or(0, |index| (tiers.len() - 1 - index) as i32 * POINTS_PER_ENDING_TIER);
+    let total = money + low_stress + credentials + support + debt_free + ending;
+    Score { money, low_stress, credentials, support, debt_free, ending, total, grade: Grade::for_total(total) }
+}
+
+/// Roughly what an effect is worth in score points, ignoring caps: money at 1 point per $5,
+/// stress and support at what they'd add or take off the total, a credential at 20.
+pub fn effect_points(effect: &StatEffect) -> i32 {
+    let points = match effect.stat {
+        StatType::Money => effect.delta / DOLLARS_PER_POINT,
+        StatType::Stress => -effect.delta,
+        StatType::Support => effect.delta * POINTS_PER_SUPPORT,
+        StatType::Credentials => POINTS_PER_CREDENTIAL,
+        StatType::TimeSlots | StatType::Custom(_) => 0,
+    };
+    points.abs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ending::{EndingConditions, ThresholdCondition};
+
+    fn ending(id: &str, min_money: Option<i32>) -> Ending {
+        Ending {
+            id: id.to_string(),
+            title: id.to_string(),
+            conditions: EndingConditions {
+                money: min_money.map(|min| ThresholdCondition { min: Some(min), max: None }),
+                ..EndingConditions::default()
+            },
+            priority: 0,
+            narrative: String::new(),
+            reflections: vec![],
+            hint: None,
+            badge: false,
+        }
+    }
+
+    #[test]
+    fn test_score_adds_up_its_parts() {
+        let endings_list = vec![ending("rich", Some(500)), ending("fallback", None)];
+        let mut state = GameState::new("SCORE".to_string());
+        state.money = 250;
+        state.stress = 30;
+        state.support = 4;
+        state.credentials.grant("A");
+        state.credentials.grant("B");
+
+        let modest = score(&state, &endings_list);
+        assert_eq!(modest, Score {
+            money: 50, low_stress: 70, credentials: 40, support: 20, debt_free: 50, ending: 0, total: 230, grade: Grade::D,
+        });
+
+        state.student_loan = 100;
+        assert_eq!(score(&state, &endings_list).debt_free, 0, "A student loan is still debt");
+        state.student_loan = 0;
+
+        state.money = 5000;
+        let rich = score(&state, &endings_list);
+        assert_eq!((rich.money, rich.ending), (200, 50), "Money is capped; the harder ending is a tier up");
+        assert_eq!((rich.total, rich.grade), (430, Grade::B));
+
+        let mut with_badge = endings_list.clone();
+        with_badge.insert(1, Ending { badge: true, ..ending("badge", None) });
+        assert_eq!(score(&state, &with_badge).ending, 50, "Badges aren't tiers");
+    }
+
+    #[test]
+    fn test_grades_follow_the_cutoffs() {
+        assert_eq!(Grade::for_total(450), Grade::A);
+        assert_eq!(Grade::for_total(449), Grade::B);
+        assert_eq!(Grade::for_total(250), Grade::C);
+        assert_eq!(Grade::for_total(150), Grade::D);
+        assert_eq!(Grade::for_total(149), Grade::F);
+    }
+
+    #[test]
+    fn test_debt_and_burnout_floor_at_zero() {
+        let mut state = GameState::new("SCORE".to_string());
+        state.money = -300;
+        state.stress = 120;
+        state.support = 0;
+        let broke = score(&state, &[]);
+        assert_eq!((broke.money, broke.low_stress, broke.debt_free, broke.total), (0, 0, 0, 0));
+        assert_eq!(broke.grade, Grade::F);
+    }
+}
@@ -0,0 +1,268 @@
+//! Special effects written in Rhai, from special_effects.json.
+//!
+//! A script sees the game through a `state` object with a fixed set of properties and
+//! functions, plus the economy.json settings as a read-only `economy` map. It can't import
+//! modules, evaluate strings, or print, and a runaway loop hits an operation limit instead
+//! of hanging the turn. Scripts run on a copy of the game, so one that fails changes nothing.
+//!
+//! | On `state` | |
+//! |---|---|
+//! | `money`, `stress`, `support` | Read and write; stress and support stay within their stats.json range. |
+//! | `monthly_bills`, `emergency_fund`, `savings`, `student_loan` | Read and write. |
+//! | `time_slots`, `turn`, `stage` | Read only. |
+//! | `stat(id)`, `set_stat(id, value)` | Any stat in stats.json, kept within its range. |
+//! | `has_tag(tag)`, `tag_level(tag)`, `grant_tag(tag)` | Credentials; granting a held one levels it up. |
+//! | `feedback(message)` | Add a line to the turn's feedback. |
+
+use std::collections::HashMap;
+use rhai::module_resolvers::DummyModuleResolver;
+use rhai::{Engine, EvalAltResult, Scope, AST, INT};
+use crate::data_loader::GameData;
+use crate::engine::game_state::GameState;
+use crate::models::{SpecialEffect, StatDefinition};
+
+/// Most operations one script run may take.
+const MAX_OPERATIONS: u64 = 10_000;
+
+/// The special-effect scripts of a data set, compiled once when it loads.
+#[derive(Debug)]
+pub struct Scripts {
+    engine: Engine,
+    compiled: HashMap<String, AST>,
+}
+
+impl Scripts {
+    /// Compile every special effect. Returns one message per script that doesn't compile.
+    pub fn compile(effects: &[SpecialEffect]) -> (Self, Vec<String>) {
+        let engine = sandbox();
+        let mut compiled = HashMap::new();
+        let mut errors = Vec::new();
+        for effect in effects {
+            match engine.compile(&effect.script) {
+                Ok(ast) => {
+                    compiled.insert(effect.id.clone(), ast);
+                }
+                Err(e) => errors.push(format!("special_effects.json [{}]: script error: {}", effect.id, e)),
+            }
+        }
+        (Self { engine, compiled }, errors)
+    }
+
+    /// Run one script, with `data` supplying the stat ranges and economy settings.
+    pub fn run(&self, id: &str, state: &mut GameState, data: &GameData) -> Vec<String> {
+        // Validation rejects actions naming a special effect that isn't defined
+        let Some(ast) = self.compiled.get(id) else {
+            return Vec::new();
+        };
+        let economy = match rhai::serde::to_dynamic(&data.economy) {
+            Ok(economy) => economy,
+            Err(e) => return vec![format!("⚠️ {} failed: {}", id, e)],
+        };
+
+        let mut scope = Scope::new();
+        scope.push("state", ScriptState { state: state.clone(), stats: data.stats.clone(), feedback: Vec::new() });
+        scope.push_constant("economy", economy);
+        let outcome = self.engine.run_ast_with_scope(&mut scope, ast)
+            .and_then(|_| scope.get_value::<ScriptState>("state")
+                .ok_or_else(|| "`state` was replaced".into()));
+        match outcome {
+            Ok(done) => {
+                *state = done.state;
+                done.feedback
+            }
+            Err(e) => vec![format!("⚠️ {} failed: {}", id, e)],
+        }
+    }
+}
+
+/// Run a special effect's script on the game and return its feedback. A script that fails
+/// leaves the game as it was, and the feedback says why.
+pub fn run_special_effect(state: &mut GameState, id: &str, data: &GameData) -> Vec<String> {
+    data.scripts().run(id, state, data)
+}
+
+/// A Rhai engine that only reaches what a script is handed.
+fn sandbox() -> Engine {
+    let mut engine = Engine::new();
+    engine
+        .set_max_operations(MAX_OPERATIONS)
+        .set_max_call_levels(8)
+        .set_max_expr_depths(32, 16)
+        .set_max_string_size(1_000)
+        .set_max_array_size(100)
+        .set_max_map_size(100)
+        .set_module_resolver(DummyModuleResolver::new())
+        .on_print(|_| {})
+        .on_debug(|_, _, _| {})
+        .disable_symbol("eval");
+
+    engine
+        .register_type_with_name::<ScriptState>("GameState")
+        .register_get_set("money", |s: &mut ScriptState| s.state.money as INT, |s: &mut ScriptState, v: INT| s.set("money", v))
+        .register_get_set("stress", |s: &mut ScriptState| s.state.stress as INT, |s: &mut ScriptState, v: INT| s.set("stress", v))
+        .register_get_set("support", |s: &mut ScriptState| s.state.support as INT, |s: &mut ScriptState, v: INT| s.set("support", v))
+        .register_get_set(
+            "monthly_bills",
+            |s: &mut ScriptState| s.state.monthly_bills as INT,
+            |s: &mut ScriptState, v: INT| s.state.monthly_bills = to_i32(v),
+        )
+        .register_get_set(
+            "emergency_fund",
+            |s: &mut ScriptState| s.state.emergency_fund as INT,
+            |s: &mut ScriptState, v: INT| s.state.emergency_fund = to_i32(v),
+        )
+        .register_get_set(
+            "savings",
+            |s: &mut ScriptState| s.state.savings as INT,
+            |s: &mut ScriptState, v: INT| s.state.savings = to_i32(v),
+        )
+        .register_get_set(
+            "student_loan",
+            |s: &mut ScriptState| s.state.student_loan as INT,
+            |s: &mut ScriptState, v: INT| s.state.student_loan = to_i32(v),
+        )
+        .register_get("time_slots", |s: &mut ScriptState| s.state.time_slots as INT)
+        .register_get("turn", |s: &mut ScriptState| s.state.current_turn as INT)
+        .register_get("stage", |s: &mut ScriptState| s.state.current_stage.id().to_string())
+        .register_fn("stat", |s: &mut ScriptState, id: &str| s.stat(id))
+        .register_fn("set_stat", |s: &mut ScriptState, id: &str, v: INT| s.set_stat(id, v))
+        .register_fn("has_tag", |s: &mut ScriptState, tag: &str| s.state.credentials.contains(tag))
+        .register_fn("tag_level", |s: &mut ScriptState, tag: &str| s.state.credentials.level(tag) as INT)
+        .register_fn("grant_tag", |s: &mut ScriptState, tag: &str| {
+            s.state.credentials.grant(tag);
+        })
+        .register_fn("feedback", |s: &mut ScriptState, message: &str| s.feedback.push(message.to_string()));
+    engine
+}
+
+/// The `state` object a script sees: a copy of the game and the feedback written so far.
+#[derive(Debug, Clone)]
+struct ScriptState {
+    state: GameState,
+    stats: Vec<StatDefinition>,
+    feedback: Vec<String>,
+}
+
+impl ScriptState {
+    fn definition(&self, id: &str) -> Result<&StatDefinition, Box<EvalAltResult>> {
+        self.stats.iter().find(|s| s.id == id).ok_or_else(|| format!("unknown stat \"{}\"", id).into())
+    }
+
+    fn stat(&mut self, id: &str) -> Result<INT, Box<EvalAltResult>> {
+        self.definition(id)?;
+        Ok(self.state.stat(id) as INT)
+    }
+
+    fn set_stat(&mut self, id: &str, value: INT) -> Result<(), Box<EvalAltResult>> {
+        let value = self.definition(id)?.clamp(to_i32(value));
+        self.state.set_stat(id, value);
+        Ok(())
+    }
+
+    /// Set one of the engine's own stats, which stats.json always defines.
+    fn set(&mut self, id: &str, value: INT) {
+        let value = self.stats.iter().find(|s| s.id == id).map_or(to_i32(value), |s| s.clamp(to_i32(value)));
+        self.state.set_stat(id, value);
+    }
+}
+
+fn to_i32(value: INT) -> i32 {
+    value.clamp(i32::MIN as INT, i32::MAX as INT) as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_loader::test_data;
+    use crate::models::Stage;
+
+    fn make_state() -> GameState {
+        let mut state = GameState::new("SCRIPT".to_string());
+        state.current_stage = Stage::EARLY_ADULT;
+        state
+    }
+
+    #[test]
+    fn test_savings_deposit_and_withdraw() {
+        let data = test_data();
+        let mut state = make_state();
+        run_special_effect(&mut state, "savings_deposit", &data);
+        assert_eq!(state.money, 75);
+        assert_eq!(state.savings, 25);
+
+        // Withdrawals are capped at the balance
+        state.savings = 10;
+        let fb = run_special_effect(&mut state, "savings_withdraw", &data);
+        assert_eq!(state.money, 85);
+        assert_eq!(state.savings, 0);
+        assert_eq!(fb, vec!["🐖 Withdrew $10 from savings (balance: $0)".to_string()]);
+        let fb = run_special_effect(&mut state, "savings_withdraw", &data);
+        assert_eq!(state.money, 85);
+        assert!(fb[0].contains("Nothing"));
+    }
+
+    #[test]
+    fn test_savings_deposit_is_capped_at_cash_on_hand() {
+        let data = test_data();
+        let mut state = make_state();
+        state.money = 10;
+        let fb = run_special_effect(&mut state, "savings_deposit", &data);
+        assert_eq!((state.money, state.savings), (0, 10));
+        assert_eq!(fb, vec!["🐖 Deposited $10 to savings (balance: $10)".to_string()]);
+
+        // Broke (or in the red): nothing moves
+        for money in [0, -40] {
+            state.money = money;
+            let fb = run_special_effect(&mut state, "savings_deposit", &data);
+            assert_eq!((state.money, state.savings), (money, 10));
+            assert!(fb[0].contains("No cash"), "{:?}", fb);
+        }
+    }
+
+    #[test]
+    fn test_reduce_bills_and_emergency_fund() {
+        let data = test_data();
+        let mut state = make_state();
+        assert!(run_special_effect(&mut state, "reduce_bills", &data).is_empty(), "No bills to cut");
+
+        state.monthly_bills = 6;
+        let fb = run_special_effect(&mut state, "reduce_bills", &data);
+        assert_eq!(state.monthly_bills, 0);
+        assert_eq!(fb, vec!["📉 Bills reduced by $6 (now $0)".to_string()]);
+
+        let fb = run_special_effect(&mut state, "emergency_fund_deposit", &data);
+        assert_eq!(state.emergency_fund, 20);
+        assert_eq!(fb, vec!["🏦 Emergency fund: +$20 (total: $20)".to_string()]);
+    }
+
+    #[test]
+    fn test_scripts_are_sandboxed() {
+        let data = test_data();
+        let effects = vec![
+            SpecialEffect { id: "spin".to_string(), description: String::new(), script: "state.money = 0; loop {}".to_string() },
+            SpecialEffect { id: "calm".to_string(), description: String::new(), script: "state.stress -= 500; state.grant_tag(\"Zen\");".to_string() },
+            SpecialEffect { id: "charm".to_string(), description: String::new(), script: "state.set_stat(\"charm\", 1);".to_string() },
+        ];
+        let (scripts, errors) = Scripts::compile(&effects);
+        assert!(errors.is_empty(), "{:?}", errors);
+
+        let mut state = make_state();
+        let fb = scripts.run("spin", &mut state, &data);
+        assert_eq!(state.money, 100, "A failed script changes nothing");
+        assert!(fb[0].starts_with("⚠️ spin failed"), "{:?}", fb);
+
+        scripts.run("calm", &mut state, &data);
+        assert_eq!(state.stress, 0, "Stress stays in range");
+        assert!(state.credentials.contains("Zen"));
+
+        let fb = scripts.run("charm", &mut state, &data);
+        assert!(fb[0].contains("unknown stat \"charm\""), "{:?}", fb);
+
+        let (_, errors) = Scripts::compile(&[SpecialEffect {
+            id: "broken".to_string(),
+            description: String::new(),
+            script: "state.money +=".to_string(),
+        }]);
+        assert!(errors[0].starts_with("special_effects.json [broken]: script error"), "{:?}", errors);
+    }
+}
@@ -0,0 +1,164 @@
+use std::cmp::Reverse;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use crate::data_loader::GameData;
+use crate::engine::game_state::GameState;
+use crate::engine::score;
+use crate::engine::turn_runner;
+use crate::models::Stage;
+
+/// The stats a stage report compares from the stage's start to its end.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StatLine {
+    pub money: i32,
+    pub stress: i32,
+    pub support: i32,
+    pub savings: i32,
+}
+
+impl StatLine {
+    pub fn of(state: &GameState) -> Self {
+        Self { money: state.money, stress: state.stress, support: state.support, savings: state.savings }
+    }
+
+    /// How far each stat moved from `start` to `self`.
+    pub fn since(&self, start: &StatLine) -> StatLine {
+        StatLine {
+            money: self.money - start.money,
+            stress: self.stress - start.stress,
+            support: self.support - start.support,
+            savings: self.savings - start.savings,
+        }
+    }
+}
+
+/// The recap of a finished life stage, written when the player leaves it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StageReport {
+    pub stage: Stage,
+    pub stage_name: String,
+    pub first_turn: u32,
+    pub last_turn: u32,
+    pub start: StatLine,
+    pub end: StatLine,
+    pub change: StatLine,
+    pub credentials_earned: Vec<String>,
+    /// Phase 2 decisions answered during the stage.
+    pub decisions_made: usize,
+    pub biggest_event: Option<StageEvent>,
+}
+
+/// The answered event card whose option moved the player's stats the most during the
+/// stage, weighed on the score's scale (see `score::effect_points`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StageEvent {
+    pub turn: u32,
+    pub event_id: String,
+    pub title: String,
+    pub choice: String,
+}
+
+/// Note where the current stage started, on its first turn.
+pub fn mark_start(state: &mut GameState) {
+    if state.stage_start.is_none() {
+        state.stage_start = Some(StatLine::of(state));
+    }
+}
+
+/// Write the report for the stage the player is in and store it on the state.
+pub fn close_stage(state: &mut GameState, data: &GameData) {
+    let report = write(state, data);
+    state.stage_reports.push(report);
+    state.stage_start = None;
+}
+
+/// The report for the current stage, from its start up to now.
+pub fn write(state: &GameState, data: &GameData) -> StageReport {
+    let stage = state.current_stage.clone();
+    let snapshots: Vec<_> = state.stats_history.iter().filter(|s| s.stage == stage).collect();
+    let end = StatLine::of(state);
+    let start = state.stage_start.unwrap_or(end);
+
+    let biggest_event = snapshots.iter()
+        .flat_map(|snapshot| {
+            let first = snapshot.event_id.as_deref().map(|id| (id, snapshot.event_option_index));
+            let extra = snapshot.extra_events.iter().map(|e| (e.event_id.as_str(), e.option_index));
+            first.into_iter().chain(extra).map(move |answer| (snapshot.turn, answer))
+        })
+        .filter_map(|(turn, (event_id, option_index))| {
+            let event = data.event(event_id)?;
+            let option = event.options.get(option_index?)?;
+            let weight: i32 = option.effects.iter().map(score::effect_points).sum();
+            Some((weight, StageEvent {
+                turn,
+                event_id: event.id.clone(),
+                title: event.title.clone(),
+                choice: option.label.clone(),
+            }))
+        })
+        .min_by_key(|(weight, _)| Reverse(*weight))
+        .map(|(_, event)| event);
+
+    StageReport {
+        stage_name: turn_runner::stage_def(&data.stages, &stage).map_or_else(|| stage.to_string(), |s| s.name.clone()),
+        first_turn: snapshots.first().map_or(state.current_turn, |s| s.turn),
+        last_turn: snapshots.last().map_or(state.current_turn, |s| s.turn),
+        start,
+        end,
+        change: end.since(&start),
+        credentials_earned: snapshots.iter().flat_map(|s| s.credentials_earned.iter().cloned()).collect(),
+        decisions_made: state.decision_log.iter()
+            .filter(|entry| entry.stage == stage && entry.option_index.is_some())
+            .count(),
+        biggest_event,
+        stage,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_loader::test_data;
+    use crate::engine::bot;
+
+    #[test]
+    fn test_finished_game_has_a_report_per_stage() {
+        let data = test_data();
+        let state = bot::play_game(&bot::FirstOption, &data, "REPORTS");
+
+        let stages: Vec<&Stage> = state.stage_reports.iter().map(|r| &r.stage).collect();
+        assert_eq!(stages, data.stages.iter().map(|s| &s.id).collect::<Vec<_>>());
+        assert!(state.stage_start.is_none(), "The last stage was closed at game over");
+
+        for pair in state.stage_reports.windows(2) {
+            assert_eq!(pair[1].first_turn, pair[0].last_turn + 1, "Stages follow each other");
+            assert_eq!(pair[1].start, pair[0].end, "A stage starts where the last one ended");
+        }
+        let last = state.stage_reports.last().unwrap();
+        assert_eq!(last.end, StatLine::of(&state));
+        for report in &state.stage_reports {
+            assert_eq!(report.change, report.end.since(&report.start));
+        }
+    }
+
+    #[test]
+    fn test_report_covers_only_its_stage() {
+        let data = test_data();
+        let state = bot::play_game(&bot::FirstOption, &data, "REPORTS");
+        let total_credentials: usize = state.stats_history.iter().map(|s| s.credentials_earned.len()).sum();
+        let reported: usize = state.stage_reports.iter().map(|r| r.credentials_earned.len()).sum();
+        assert_eq!(reported, total_credentials);
+
+        let answered = state.decision_log.iter().filter(|d| d.option_index.is_some()).count();
+        assert_eq!(state.stage_reports.iter().map(|r| r.decisions_made).sum::<usize>(), answered);
+
+        for report in &state.stage_reports {
+            if let Some(event) = &report.biggest_event {
+                assert!((report.first_turn..=report.last_turn).contains(&event.turn));
+            }
+        }
+    }
+}
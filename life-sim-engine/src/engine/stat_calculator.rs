@@ -0,0 +1,775 @@
+use crate::data_loader::GameData;
+use crate::engine::game_state::GameState;
+use crate::engine::modifiers::{EffectContext, EffectSource, ModifierPipeline};
+use crate::models::StatDefinition;
+use crate::models::event::{StatEffect, StatType};
+
+/// Stress threshold: above this, outcomes degrade.
+pub const STRESS_DANGER: i32 = 75;
+/// Support threshold: above this, free mitigation available.
+pub const SUPPORT_BONUS: i32 = 7;
+
+/// Apply a batch of stat effects after passing them through the standard modifier
+/// pipeline, each stat kept within its range. Returns human-readable feedback describing
+/// what changed, followed by any notes from the modifiers (e.g. an insurance payout).
+pub fn apply_effects(state: &mut GameState, effects: &[StatEffect], source: EffectSource, data: &GameData) -> Vec<String> {
+    let effects: Vec<StatEffect> = effects.iter()
+        .filter(|e| effect_applies(state, e))
+        .map(|e| StatEffect { delta: effect_delta(state, e), ..e.clone() })
+        .collect();
+    let ctx = EffectContext { state, source, data };
+    let (effects, notes) = ModifierPipeline::standard().run(&effects, &ctx);
+    let mut feedback = apply_deltas(state, &effects, &data.stats);
+    feedback.extend(notes);
+    feedback
+}
+
+/// Apply effects exactly as given, with clamping.
+fn apply_deltas(state: &mut GameState, effects: &[StatEffect], stats: &[StatDefinition]) -> Vec<String> {
+    let mut feedback = Vec::new();
+
+    for effect in effects {
+        if effect.stat == StatType::Credentials {
+            if let Some(ref tag) = effect.tag {
+                feedback.push(grant_credential(state, tag));
+            }
+            continue;
+        }
+        // Validation rejects effects on stats that aren't defined
+        let Some(stat) = stats.iter().find(|s| s.id == effect.stat.id()) else {
+            continue;
+        };
+        let before = state.stat(&stat.id);
+        state.set_stat(&stat.id, stat.clamp(before.saturating_add(effect.delta)));
+        let actual = state.stat(&stat.id) - before;
+        if actual != 0 {
+            match effect.percent {
+                Some(percent) => feedback.push(format!("{} {} {:+} ({:+}%)", stat.icon, stat.label, actual, percent)),
+                None => feedback.push(format!("{} {} {:+}", stat.icon, stat.label, actual)),
+            }
+        }
+    }
+
+    feedback
+}
+
+/// Whether an effect's `when` condition holds right now (always, if it has none).
+pub fn effect_applies(state: &GameState, effect: &StatEffect) -> bool {
+    let Some(ref when) = effect.when else {
+        return true;
+    };
+    when.stats.iter().all(|(id, range)| range.holds(state.stat(id)))
+        && when.has_tag.as_ref().is_none_or(|tag| state.credentials.contains(tag))
+        && when.lacks_tag.as_ref().is_none_or(|tag| !state.credentials.contains(tag))
+}
+
+/// How much an effect would change its stat right now: nothing if its condition doesn't
+/// hold. A percentage effect takes its share of the stat's current value, rounded to the
+/// nearest point (halves away from zero); a stat at or below zero has nothing to take a
+/// share of, so it's left alone.
+pub fn effect_delta(state: &GameState, effect: &StatEffect) -> i32 {
+    if !effect_applies(state, effect) {
+        return 0;
+    }
+    match effect.percent {
+        Some(percent) => {
+            let base = state.stat(effect.stat.id()).max(0);
+            (base as f64 * percent / 100.0).round() as i32
+        }
+        None => effect.delta,
+    }
+}
+
+/// Earn a credential, or level it up if it's already held. Returns the feedback line.
+pub fn grant_credential(state: &mut GameState, tag: &str) -> String {
+    let held = state.credentials.grant(tag);
+    if held.level == 1 {
+        format!("📚 Earned: {}", tag)
+    } else {
+        format!("📚 Leveled up: {}", held.name())
+    }
+}
+
+/// Pull a stat back within its range after changing it directly.
+fn clamp_stat(state: &mut GameState, stats: &[StatDefinition], id: &str) {
+    if let Some(stat) = stats.iter().find(|s| s.id == id) {
+        let value = stat.clamp(state.stat(id));
+        state.set_stat(id, value);
+    }
+}
+
+/// Misalignment stress penalty (missing recommended tags).
+const MISALIGN_STRESS: i32 = 3;
+/// Misalignment pay multiplier (75% of normal pay).
+const MISALIGN_PAY_MULT: f64 = 0.75;
+
+/// Apply job income to the game state (Phase 4).
+/// If the player is missing recommendedTags, they get reduced pay and extra stress.
+pub fn apply_job_income(state: &mut GameState, stats: &[StatDefinition]) -> Vec<String> {
+    let mut feedback = Vec::new();
+    if let Some(ref job) = state.current_job {
+        // Check misalignment: missing any recommended tags?
+        let missing_recommended: Vec<&String> = job.recommended_tags.iter()
+            .filter(|tag| !state.credentials.contains(tag))
+            .collect();
+        let misaligned = !missing_recommended.is_empty();
+
+        // Calculate pay (reduced if misaligned, nothing while waiting on a first paycheck)
+        let unpaid = state.unpaid_turns > 0;
+        let pay = if unpaid {
+            0
+        } else if misaligned {
+            (job.pay_per_turn as f64 * MISALIGN_PAY_MULT) as i32
+        } else {
+            job.pay_per_turn
+        };
+
+        // Calculate stress (extra if misaligned)
+        let stress = job.stress_per_turn + if misaligned { MISALIGN_STRESS } else { 0 };
+
+        state.money += pay;
+        state.stress += stress;
+
+        if unpaid {
+            state.unpaid_turns -= 1;
+            feedback.push(format!("💼 {}: no paycheck yet this turn", job.title));
+        } else {
+            feedback.push(format!("💼 {} pay: +${}", job.title, pay));
+        }
+        if stress > 0 {
+            feedback.push(format!("😰 Work stress: +{}", stress));
+        }
+        if misaligned {
+            let tags: Vec<&str> = missing_recommended.iter().map(|s| s.as_str()).collect();
+            feedback.push(format!("⚠️ Misaligned — missing: {}", tags.join(", ")));
+        }
+    }
+    clamp_stat(state, stats, "stress");
+    feedback
+}
+
+/// Apply side gig income and stress (Phase 4).
+pub fn apply_side_gig_income(state: &mut GameState, stats: &[StatDefinition]) -> Vec<String> {
+    let mut feedback = Vec::new();
+    for gig in &state.side_gigs {
+        state.money += gig.pay_per_turn;
+        state.stress += gig.stress_per_turn;
+        feedback.push(format!("🛵 {} pay: +${}", gig.title, gig.pay_per_turn));
+    }
+    clamp_stat(state, stats, "stress");
+    feedback
+}
+
+/// Apply monthly bills (Stage D only, Phase 4).
+pub fn apply_monthly_bills(state: &mut GameState) -> Vec<String> {
+    let mut feedback = Vec::new();
+    if state.monthly_bills > 0 {
+        state.money -= state.monthly_bills;
+        feedback.push(format!("🏠 Bills: -${}", state.monthly_bills));
+        if state.money < 0 {
+            feedback.push("⚠️ You're in debt! Bills exceeded your cash.".to_string());
+        }
+    }
+    feedback
+}
+
+/// Apply emergency fund to cover debt (Stage D, after bills).
+/// If money is negative and we have an emergency fund, draw from it.
+pub fn apply_emergency_fund(state: &mut GameState) -> Vec<String> {
+    let mut feedback = Vec::new();
+    if state.money < 0 && state.emergency_fund > 0 {
+        let shortfall = -state.money;
+        let covered = shortfall.min(state.emergency_fund);
+        state.money += covered;
+        state.emergency_fund -= covered;
+        state.emergency_fund_used += covered;
+        feedback.push(format!("🏦 Emergency fund covered ${} (remaining: ${})", covered, state.emergency_fund));
+        if state.money >= 0 {
+            feedback.push("✅ Debt cleared by emergency fund!".to_string());
+        }
+    }
+    feedback
+}
+
+/// Pay interest on a positive savings balance (Phase 4, every stage).
+/// Interest rounds down, like a real bank.
+pub fn apply_savings_interest(state: &mut GameState, rate: f64) -> Vec<String> {
+    let mut feedback = Vec::new();
+    if state.savings > 0 && rate > 0.0 {
+        let interest = (state.savings as f64 * rate).floor() as i32;
+        if interest > 0 {
+            state.savings += interest;
+            feedback.push(format!("🐖 Savings interest: +${} (balance: ${})", interest, state.savings));
+        }
+    }
+    feedback
+}
+
+/// Charge interest on a negative money balance (Stage D, after the emergency fund).
+/// Interest rounds up so even small debts visibly compound.
+pub fn apply_debt_interest(state: &mut GameState, rate: f64) -> Vec<String> {
+    let mut feedback = Vec::new();
+    if state.money < 0 && rate > 0.0 {
+        let debt = -state.money;
+        let interest = (debt as f64 * rate).ceil() as i32;
+        state.money -= interest;
+        feedback.push(format!(
+            "💳 Debt interest: -${} ({:.0}% of ${} owed)",
+            interest, rate * 100.0, debt
+        ));
+    }
+    feedback
+}
+
+/// Natural stress recovery (Phase 4): stress eases by `recovery` points a turn, but not
+/// once it's above the danger line.
+pub fn apply_stress_recovery(state: &mut GameState, recovery: i32, stats: &[StatDefinition]) -> Vec<String> {
+    let mut feedback = Vec::new();
+    if recovery > 0 && state.stress <= STRESS_DANGER {
+        let before = state.stress;
+        state.stress -= recovery;
+        clamp_stat(state, stats, "stress");
+        if state.stress < before {
+            feedback.push(format!("😌 Stress eased: -{}", before - state.stress));
+        }
+    }
+    feedback
+}
+
+/// Whether any of the effects raises support, which is what makes an action or decision
+/// count as keeping up with people.
+pub fn raises_support(effects: &[StatEffect]) -> bool {
+    effects.iter().any(|e| e.stat == StatType::Support && (e.delta > 0 || e.percent.is_some_and(|p| p > 0.0)))
+}
+
+/// Support decay (Phase 4): every `neglect_turns` turns in a row without an action or
+/// decision that raises support costs a point of support.
+pub fn apply_support_decay(state: &mut GameState, neglect_turns: u32, stats: &[StatDefinition]) -> Vec<String> {
+    let mut feedback = Vec::new();
+    let neglected = state.current_turn.saturating_sub(state.last_social_turn);
+    if neglect_turns > 0 && neglected > 0 && neglected.is_multiple_of(neglect_turns) {
+        let before = state.support;
+        state.support -= 1;
+        clamp_stat(state, stats, "support");
+        if state.support < before {
+            feedback.push(format!("🤝 Drifting apart after {} turns without reaching out: Support -1", neglected));
+        }
+    }
+    feedback
+}
+
+/// Check stress threshold and return warning if applicable.
+pub fn check_stress_threshold(state: &GameState) -> Option<String> {
+    if state.stress > STRESS_DANGER {
+        Some("⚠️ Stress is dangerously high! Risk of missed day and degraded outcomes.".to_string())
+    } else {
+        None
+    }
+}
+
+/// Check if support is high enough for bonus mitigation.
+pub fn has_support_bonus(state: &GameState) -> bool {
+    state.support > SUPPORT_BONUS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_loader::test_data;
+    use crate::models::ending::ThresholdCondition;
+    use crate::models::event::EffectCondition;
+
+    fn make_state() -> GameState {
+        GameState::new("TEST".to_string())
+    }
+
+    fn money_effect(delta: i32) -> StatEffect {
+        StatEffect { stat: StatType::Money, delta, tag: None, percent: None, when: None }
+    }
+
+    fn stress_effect(delta: i32) -> StatEffect {
+        StatEffect { stat: StatType::Stress, delta, tag: None, percent: None, when: None }
+    }
+
+    fn support_effect(delta: i32) -> StatEffect {
+        StatEffect { stat: StatType::Support, delta, tag: None, percent: None, when: None }
+    }
+
+    fn time_effect(delta: i32) -> StatEffect {
+        StatEffect { stat: StatType::TimeSlots, delta, tag: None, percent: None, when: None }
+    }
+
+    fn credential_effect(tag: &str) -> StatEffect {
+        StatEffect { stat: StatType::Credentials, delta: 0, tag: Some(tag.to_string()), percent: None, when: None }
+    }
+
+    #[test]
+    fn test_apply_money_positive() {
+        let data = test_data();
+        let mut state = make_state();
+        let fb = apply_effects(&mut state, &[money_effect(50)], EffectSource::Action, &data);
+        assert_eq!(state.money, 150);
+        assert!(fb[0].contains("+50"));
+    }
+
+    #[test]
+    fn test_apply_money_negative() {
+        let data = test_data();
+        let mut state = make_state();
+        let fb = apply_effects(&mut state, &[money_effect(-80)], EffectSource::Action, &data);
+        assert_eq!(state.money, 20);
+        assert!(fb[0].contains("-80"));
+    }
+
+    #[test]
+    fn test_money_can_go_negative() {
+        let data = test_data();
+        let mut state = make_state(); // money = 100
+        apply_effects(&mut state, &[money_effect(-200)], EffectSource::Action, &data);
+        assert_eq!(state.money, -100, "Money should be able to go negative (debt)");
+    }
+
+    #[test]
+    fn test_stress_clamps_at_100() {
+        let data = test_data();
+        let mut state = make_state(); // stress = 20
+        apply_effects(&mut state, &[stress_effect(90)], EffectSource::Action, &data);
+        assert_eq!(state.stress, 100, "Stress should clamp at 100");
+    }
+
+    #[test]
+    fn test_stress_clamps_at_zero() {
+        let data = test_data();
+        let mut state = make_state(); // stress = 20
+        apply_effects(&mut state, &[stress_effect(-50)], EffectSource::Action, &data);
+        assert_eq!(state.stress, 0, "Stress should clamp at 0");
+    }
+
+    #[test]
+    fn test_support_clamps_at_10() {
+        let data = test_data();
+        let mut state = make_state(); // support = 5
+        apply_effects(&mut state, &[support_effect(8)], EffectSource::Action, &data);
+        assert_eq!(state.support, 10, "Support should clamp at 10");
+    }
+
+    #[test]
+    fn test_support_clamps_at_zero() {
+        let data = test_data();
+        let mut state = make_state(); // support = 5
+        apply_effects(&mut state, &[support_effect(-10)], EffectSource::Action, &data);
+        assert_eq!(state.support, 0, "Support should clamp at 0");
+    }
+
+    #[test]
+    fn test_time_slots_clamp_at_4() {
+        let data = test_data();
+        let mut state = make_state(); // time_slots = 3
+        apply_effects(&mut state, &[time_effect(5)], EffectSource::Action, &data);
+        assert_eq!(state.time_slots, 4, "Time should clamp at 4");
+    }
+
+    #[test]
+    fn test_time_slots_clamp_at_zero() {
+        let data = test_data();
+        let mut state = make_state(); // time_slots = 3
+        apply_effects(&mut state, &[time_effect(-10)], EffectSource::Action, &data);
+        assert_eq!(state.time_slots, 0, "Time should clamp at 0");
+    }
+
+    #[test]
+    fn test_credential_added() {
+        let data = test_data();
+        let mut state = make_state();
+        let fb = apply_effects(&mut state, &[credential_effect("IT Fundamentals")], EffectSource::Action, &data);
+        assert_eq!(state.credentials.names(), vec!["IT Fundamentals"]);
+        assert!(fb[0].contains("IT Fundamentals"));
+    }
+
+    #[test]
+    fn test_repeated_credential_levels_up() {
+        let data = test_data();
+        let mut state = make_state();
+        apply_effects(&mut state, &[credential_effect("CPR")], EffectSource::Action, &data);
+        let fb = apply_effects(&mut state, &[credential_effect("CPR")], EffectSource::Action, &data);
+        assert_eq!(state.credentials.len(), 1, "Should not add duplicate credential");
+        assert_eq!(state.credentials.level("CPR"), 2);
+        assert_eq!(fb, vec!["📚 Leveled up: CPR II".to_string()]);
+    }
+
+    #[test]
+    fn test_multiple_effects() {
+        let data = test_data();
+        let mut state = make_state();
+        let effects = vec![money_effect(-25), stress_effect(5), support_effect(-1)];
+        let fb = apply_effects(&mut state, &effects, EffectSource::Action, &data);
+        assert_eq!(state.money, 75);
+        assert_eq!(state.stress, 25);
+        assert_eq!(state.support, 4);
+        assert_eq!(fb.len(), 3);
+    }
+
+    #[test]
+    fn test_stress_threshold_warning() {
+        let mut state = make_state();
+        state.stress = 76;
+        assert!(check_stress_threshold(&state).is_some());
+    }
+
+    #[test]
+    fn test_stress_threshold_ok() {
+        let mut state = make_state();
+        state.stress = 50;
+        assert!(check_stress_threshold(&state).is_none());
+    }
+
+    #[test]
+    fn test_support_bonus() {
+        let mut state = make_state();
+        state.support = 8;
+        assert!(has_support_bonus(&state));
+        state.support = 7;
+        assert!(!has_support_bonus(&state));
+    }
+
+    #[test]
+    fn test_job_income() {
+        let data = test_data();
+        let mut state = make_state();
+        state.current_job = Some(crate::models::Job {
+            id: "test".to_string(),
+            title: "Test Job".to_string(),
+            required_tags: vec![],
+            required_levels: Default::default(),
+            recommended_tags: vec![],
+            pay_per_turn: 50,
+            stress_per_turn: 3,
+            growth_rate: 0,
+            growth_tag: None,
+            stages: vec![],
+            description: "Test".to_string(),
+            family: None,
+            paths: vec![],
+            tiers: vec![],
+        });
+        let fb = apply_job_income(&mut state, &data.stats);
+        assert_eq!(state.money, 150);
+        assert_eq!(state.stress, 23);
+        assert!(!fb.is_empty());
+    }
+
+    fn make_plan(covers: &str, max_out_of_pocket: i32) -> crate::models::InsurancePlan {
+        crate::models::InsurancePlan {
+            id: "ins_test".to_string(),
+            title: "Test Plan".to_string(),
+            description: "Test".to_string(),
+            stages: vec![],
+            covers: vec![covers.to_string()],
+            premium_per_turn: 10,
+            max_out_of_pocket,
+        }
+    }
+
+    #[test]
+    fn test_insurance_caps_covered_emergency() {
+        let data = test_data();
+        let mut state = make_state();
+        state.coverage.push(make_plan("medical", 25));
+        let categories = vec!["emergency".to_string(), "medical".to_string()];
+        let fb = apply_effects(&mut state, &[money_effect(-75), stress_effect(5)], EffectSource::Event(&categories), &data);
+        assert_eq!(state.money, 75, "Loss should be capped at the out-of-pocket max");
+        assert_eq!(state.stress, 25, "Non-money effects pass through");
+        assert!(fb.iter().any(|f| f.contains("covered $50")));
+    }
+
+    #[test]
+    fn test_insurance_ignores_uncovered_events() {
+        let data = test_data();
+        let mut state = make_state();
+        state.coverage.push(make_plan("medical", 25));
+        let property = vec!["emergency".to_string(), "property".to_string()];
+        apply_effects(&mut state, &[money_effect(-60)], EffectSource::Event(&property), &data);
+        assert_eq!(state.money, 40, "Wrong category isn't covered");
+
+        let not_emergency = vec!["medical".to_string()];
+        apply_effects(&mut state, &[money_effect(-30)], EffectSource::Event(&not_emergency), &data);
+        assert_eq!(state.money, 10, "Only emergency cards are covered");
+    }
+
+    #[test]
+    fn test_side_gig_income() {
+        let data = test_data();
+        let mut state = make_state();
+        state.side_gigs.push(crate::models::SideGig {
+            id: "gig".to_string(),
+            title: "Test Gig".to_string(),
+            description: "Test".to_string(),
+            stages: vec![],
+            required_tags: vec![],
+            pay_per_turn: 30,
+            stress_per_turn: 3,
+            time_cost: 1,
+        });
+        let fb = apply_side_gig_income(&mut state, &data.stats);
+        assert_eq!(state.money, 130);
+        assert_eq!(state.stress, 23);
+        assert!(fb[0].contains("Test Gig"));
+    }
+
+    #[test]
+    fn test_monthly_bills() {
+        let mut state = make_state();
+        state.monthly_bills = 75;
+        let fb = apply_monthly_bills(&mut state);
+        assert_eq!(state.money, 25);
+        assert!(fb[0].contains("75"));
+    }
+
+    #[test]
+    fn test_monthly_bills_triggers_debt() {
+        let mut state = make_state();
+        state.monthly_bills = 200;
+        let fb = apply_monthly_bills(&mut state);
+        assert!(state.money <= 0);
+        assert!(fb.iter().any(|f| f.contains("debt")));
+    }
+
+    #[test]
+    fn test_debt_interest_compounds() {
+        let mut state = make_state();
+        state.money = -100;
+        let fb = apply_debt_interest(&mut state, 0.05);
+        assert_eq!(state.money, -105);
+        assert!(fb[0].contains("interest"));
+        apply_debt_interest(&mut state, 0.05);
+        assert_eq!(state.money, -111, "Interest should accrue on the grown balance (rounded up)");
+    }
+
+    #[test]
+    fn test_no_debt_interest_when_solvent() {
+        let mut state = make_state();
+        let fb = apply_debt_interest(&mut state, 0.05);
+        assert_eq!(state.money, 100);
+        assert!(fb.is_empty());
+    }
+
+    #[test]
+    fn test_savings_interest() {
+        let mut state = make_state();
+        state.savings = 110;
+        let fb = apply_savings_interest(&mut state, 0.04);
+        assert_eq!(state.savings, 114, "4% of 110 rounds down to 4");
+        assert!(fb[0].contains("+$4"));
+
+        state.savings = 10;
+        let fb = apply_savings_interest(&mut state, 0.04);
+        assert_eq!(state.savings, 10, "Sub-dollar interest is not paid");
+        assert!(fb.is_empty());
+    }
+
+    #[test]
+    fn test_stress_recovery() {
+        let data = test_data();
+        let mut state = make_state();
+        state.stress = 30;
+        let fb = apply_stress_recovery(&mut state, 2, &data.stats);
+        assert_eq!(state.stress, 28);
+        assert_eq!(fb, vec!["😌 Stress eased: -2"]);
+
+        state.stress = 1;
+        apply_stress_recovery(&mut state, 2, &data.stats);
+        assert_eq!(state.stress, 0, "Recovery stops at the stat's floor");
+        assert!(apply_stress_recovery(&mut state, 2, &data.stats).is_empty());
+
+        state.stress = STRESS_DANGER + 1;
+        assert!(apply_stress_recovery(&mut state, 2, &data.stats).is_empty(), "No recovery in the danger zone");
+        assert_eq!(state.stress, STRESS_DANGER + 1);
+    }
+
+    #[test]
+    fn test_support_decays_when_neglected() {
+        let data = test_data();
+        let mut state = make_state();
+        let mut decayed = Vec::new();
+        for turn in 1..=7 {
+            state.current_turn = turn;
+            if !apply_support_decay(&mut state, 3, &data.stats).is_empty() {
+                decayed.push(turn);
+            }
+        }
+        assert_eq!(decayed, vec![3, 6], "A point every third neglected turn");
+        assert_eq!(state.support, 3);
+
+        state.last_social_turn = 7;
+        state.current_turn = 9;
+        assert!(apply_support_decay(&mut state, 3, &data.stats).is_empty(), "Reaching out restarts the count");
+        state.current_turn = 10;
+        assert!(!apply_support_decay(&mut state, 3, &data.stats).is_empty());
+        assert!(apply_support_decay(&mut state, 0, &data.stats).is_empty(), "0 turns decay off");
+
+        assert!(raises_support(&[money_effect(-5), support_effect(1)]));
+        assert!(!raises_support(&[support_effect(-1), stress_effect(2)]));
+    }
+
+    #[test]
+    fn test_misalignment_penalty() {
+        let data = test_data();
+        let mut state = make_state();
+        state.current_job = Some(crate::models::Job {
+            id: "test".to_string(),
+            title: "Test Job".to_string(),
+            required_tags: vec![],
+            required_levels: Default::default(),
+            recommended_tags: vec!["Customer Service".to_string()],
+            pay_per_turn: 40,
+            stress_per_turn: 4,
+            growth_rate: 0,
+            growth_tag: None,
+            stages: vec![],
+            description: "Test".to_string(),
+            family: None,
+            paths: vec![],
+            tiers: vec![],
+        });
+        // Player does NOT have "Customer Service" → misaligned
+        let fb = apply_job_income(&mut state, &data.stats);
+        // Pay should be 75% of 40 = 30
+        assert_eq!(state.money, 130); // 100 + 30
+        // Stress should be 4 + 3 = 7
+        assert_eq!(state.stress, 27); // 20 + 7
+        assert!(fb.iter().any(|f| f.contains("Misaligned")));
+    }
+
+    #[test]
+    fn test_unpaid_turn_skips_pay() {
+        let data = test_data();
+        let mut state = make_state();
+        state.unpaid_turns = 1;
+        state.current_job = Some(crate::models::Job {
+            id: "test".to_string(),
+            title: "Test Job".to_string(),
+            required_tags: vec![],
+            required_levels: Default::default(),
+            recommended_tags: vec![],
+            pay_per_turn: 50,
+            stress_per_turn: 3,
+            growth_rate: 0,
+            growth_tag: None,
+            stages: vec![],
+            description: "Test".to_string(),
+            family: None,
+            paths: vec![],
+            tiers: vec![],
+        });
+        let fb = apply_job_income(&mut state, &data.stats);
+        assert_eq!(state.money, 100, "No pay during the unpaid turn");
+        assert_eq!(state.stress, 23, "Work stress still applies");
+        assert_eq!(state.unpaid_turns, 0);
+        assert!(fb[0].contains("no paycheck"));
+
+        apply_job_income(&mut state, &data.stats);
+        assert_eq!(state.money, 150);
+    }
+
+    #[test]
+    fn test_aligned_job_no_penalty() {
+        let data = test_data();
+        let mut state = make_state();
+        state.credentials.grant("Customer Service");
+        state.current_job = Some(crate::models::Job {
+            id: "test".to_string(),
+            title: "Test Job".to_string(),
+            required_tags: vec![],
+            required_levels: Default::default(),
+            recommended_tags: vec!["Customer Service".to_string()],
+            pay_per_turn: 40,
+            stress_per_turn: 4,
+            growth_rate: 0,
+            growth_tag: None,
+            stages: vec![],
+            description: "Test".to_string(),
+            family: None,
+            paths: vec![],
+            tiers: vec![],
+        });
+        let fb = apply_job_income(&mut state, &data.stats);
+        assert_eq!(state.money, 140); // 100 + 40 (full pay)
+        assert_eq!(state.stress, 24); // 20 + 4 (no extra)
+        assert!(!fb.iter().any(|f| f.contains("Misaligned")));
+    }
+
+    #[test]
+    fn test_percent_effects_scale_with_the_stat() {
+        let data = test_data();
+        let mut state = make_state();
+        state.money = 250;
+        let lose_quarter = StatEffect { stat: StatType::Money, delta: 0, tag: None, percent: Some(-25.0), when: None };
+        let fb = apply_effects(&mut state, std::slice::from_ref(&lose_quarter), EffectSource::Action, &data);
+        // 62.5 rounds away from zero
+        assert_eq!(state.money, 187);
+        assert_eq!(fb, vec!["💰 Money -63 (-25%)".to_string()]);
+
+        // Resolved against the value before the batch, then clamped like any other effect
+        state.stress = 90;
+        let fb = apply_effects(&mut state, &[
+            StatEffect { stat: StatType::Stress, delta: 0, tag: None, percent: Some(50.0), when: None },
+            lose_quarter.clone(),
+        ], EffectSource::Action, &data);
+        assert_eq!(state.stress, 100);
+        assert_eq!(state.money, 140);
+        assert_eq!(fb[0], "😰 Stress +10 (+50%)");
+
+        // Nothing to lose when already broke
+        state.money = -40;
+        assert!(apply_effects(&mut state, &[lose_quarter], EffectSource::Action, &data).is_empty());
+        assert_eq!(state.money, -40);
+    }
+
+    #[test]
+    fn test_conditional_effects_check_the_state_they_land_on() {
+        let data = test_data();
+        let when = |stress_min: Option<i32>, has_tag: Option<&str>| Some(EffectCondition {
+            stats: stress_min.map(|min| ("stress".to_string(), ThresholdCondition { min: Some(min), max: None }))
+                .into_iter().collect(),
+            has_tag: has_tag.map(str::to_string),
+            lacks_tag: None,
+        });
+        let effects = [
+            StatEffect { when: when(Some(61), None), ..stress_effect(10) },
+            StatEffect { when: when(None, Some("CPR")), ..money_effect(-30) },
+            credential_effect("Grit"),
+        ];
+
+        let mut state = make_state();
+        let fb = apply_effects(&mut state, &effects, EffectSource::Action, &data);
+        assert_eq!((state.stress, state.money), (20, 100), "Neither condition holds");
+        assert_eq!(fb, vec!["📚 Earned: Grit".to_string()]);
+
+        state.stress = 61;
+        state.credentials.grant("CPR");
+        apply_effects(&mut state, &effects, EffectSource::Action, &data);
+        assert_eq!((state.stress, state.money), (71, 70));
+        assert_eq!(effects[0].to_string(), "Stress +10 if stress ≥ 61");
+        assert_eq!(effect_delta(&make_state(), &effects[1]), 0);
+    }
+
+    #[test]
+    fn test_custom_stat_from_data() {
+        let mut data = test_data();
+        data.stats.push(StatDefinition {
+            id: "grit".to_string(),
+            label: "Grit".to_string(),
+            icon: "💪".to_string(),
+            min: Some(0),
+            max: Some(5),
+            start: 1,
+        });
+        let grit: StatEffect = serde_json::from_value(serde_json::json!({ "stat": "grit", "delta": 8 })).unwrap();
+        assert_eq!(grit.stat, StatType::Custom("grit".to_string()));
+
+        let mut state = make_state();
+        let fb = apply_effects(&mut state, &[grit], EffectSource::Action, &data);
+        assert_eq!(state.stat("grit"), 5, "Custom stats clamp to their range");
+        assert_eq!(fb, vec!["💪 Grit +5".to_string()]);
+    }
+}
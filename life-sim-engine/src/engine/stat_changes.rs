@@ -0,0 +1,113 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use crate::data_loader::GameData;
+use crate::engine::game_state::GameState;
+
+/// Stats tracked besides the ones in stats.json.
+const MONEY_STATS: [&str; 2] = ["savings", "emergencyFund"];
+
+/// How one stat moved over a turn, and which phases moved it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StatChange {
+    /// A stat ID from stats.json, or "savings" or "emergencyFund".
+    pub stat: String,
+    pub before: i32,
+    pub after: i32,
+    pub delta: i32,
+    /// Each phase that moved the stat, in the order they ran.
+    pub by_phase: Vec<PhaseDelta>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PhaseDelta {
+    pub phase: String,
+    pub delta: i32,
+}
+
+/// Follows the tracked stats through a turn's phases.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StatTracker {
+    ids: Vec<String>,
+    before: Vec<i32>,
+    last: Vec<i32>,
+    changes: Vec<Vec<PhaseDelta>>,
+}
+
+impl StatTracker {
+    /// Start tracking from the stats as they are before the first phase.
+    pub fn start(state: &GameState, data: &GameData) -> Self {
+        let ids: Vec<String> = data.stats.iter().map(|s| s.id.clone())
+            .chain(MONEY_STATS.iter().map(|id| id.to_string()))
+            .collect();
+        let before: Vec<i32> = ids.iter().map(|id| value(state, id)).collect();
+        Self { changes: vec![Vec::new(); ids.len()], last: before.clone(), before, ids }
+    }
+
+    /// Note what the phase just run did to each stat.
+    pub fn record(&mut self, phase: &str, state: &GameState) {
+        for (i, id) in self.ids.iter().enumerate() {
+            let now = value(state, id);
+            if now != self.last[i] {
+                self.changes[i].push(PhaseDelta { phase: phase.to_string(), delta: now - self.last[i] });
+                self.last[i] = now;
+            }
+        }
+    }
+
+    /// Every stat some phase moved, in tracking order. A stat that went up and back down
+    /// again is still listed, with a delta of 0.
+    pub fn finish(self) -> Vec<StatChange> {
+        self.ids.into_iter()
+            .zip(self.before)
+            .zip(self.last)
+            .zip(self.changes)
+            .filter(|(_, by_phase)| !by_phase.is_empty())
+            .map(|(((stat, before), after), by_phase)| StatChange { stat, before, after, delta: after - before, by_phase })
+            .collect()
+    }
+}
+
+/// A tracked stat's value.
+pub fn value(state: &GameState, id: &str) -> i32 {
+    match id {
+        "savings" => state.savings,
+        "emergencyFund" => state.emergency_fund,
+        _ => state.stat(id),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_loader::test_data;
+
+    #[test]
+    fn test_changes_are_attributed_to_phases() {
+        let data = test_data();
+        let mut state = GameState::new("CHANGES".to_string());
+        let mut tracker = StatTracker::start(&state, &data);
+
+        state.money += 30;
+        state.stress += 5;
+        tracker.record("plan", &state);
+        tracker.record("commit", &state);
+        state.money -= 10;
+        state.stress -= 5;
+        state.savings += 10;
+        tracker.record("resolve", &state);
+
+        let changes = tracker.finish();
+        let stats: Vec<&str> = changes.iter().map(|c| c.stat.as_str()).collect();
+        assert_eq!(stats, vec!["money", "stress", "savings"]);
+        let money = &changes[0];
+        assert_eq!((money.before, money.after, money.delta), (100, 120, 20));
+        assert_eq!(money.by_phase, vec![
+            PhaseDelta { phase: "plan".to_string(), delta: 30 },
+            PhaseDelta { phase: "resolve".to_string(), delta: -10 },
+        ]);
+        assert_eq!(changes[1].delta, 0, "Moved and moved back");
+    }
+}
@@ -0,0 +1,121 @@
+use std::collections::BTreeMap;
+use schemars::JsonSchema;
+use serde::Serialize;
+use crate::engine::game_state::GameState;
+use crate::models::{Stage, StageDefinition};
+
+/// Totals across a run, for the results screen.
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RunSummary {
+    pub turns_played: usize,
+    /// Money that came in over the run and money that went out, not netted turn by turn.
+    pub money_earned: i32,
+    pub money_spent: i32,
+    pub final_money: i32,
+    /// The lowest money at the end of any turn.
+    pub lowest_money: i32,
+    /// The highest stress at the end of any turn, and the first turn it was reached.
+    pub peak_stress: i32,
+    pub peak_stress_turn: Option<u32>,
+    /// Turns that ended with money below zero.
+    pub turns_in_debt: usize,
+    pub decisions_made: usize,
+    pub events_answered: usize,
+    /// How often each option position was picked on event cards: "A" for the first
+    /// option, "B" for the second, and so on.
+    pub event_options: BTreeMap<String, usize>,
+    /// Credentials earned in each stage played, in stage order.
+    pub credentials_by_stage: Vec<StageCredentials>,
+    pub reflections_written: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StageCredentials {
+    pub stage: Stage,
+    pub credentials: Vec<String>,
+}
+
+/// Summarize every turn played so far.
+pub fn summarize(state: &GameState, stages: &[StageDefinition]) -> RunSummary {
+    let history = &state.stats_history;
+    let peak = history.iter().map(|s| s.stress).max().unwrap_or(state.stress);
+
+    let mut event_options = BTreeMap::new();
+    for index in history.iter().filter_map(|s| s.event_option_index) {
+        *event_options.entry(option_letter(index)).or_insert(0) += 1;
+    }
+
+    let credentials_by_stage = stages.iter()
+        .filter(|stage| history.iter().any(|s| s.stage == stage.id))
+        .map(|stage| StageCredentials {
+            stage: stage.id.clone(),
+            credentials: history.iter()
+                .filter(|s| s.stage == stage.id)
+                .flat_map(|s| s.credentials_earned.iter().cloned())
+                .collect(),
+        })
+        .collect();
+
+    RunSummary {
+        turns_played: history.len(),
+        money_earned: history.iter().map(|s| s.money_earned).sum(),
+        money_spent: history.iter().map(|s| s.money_spent).sum(),
+        final_money: state.money,
+        lowest_money: history.iter().map(|s| s.money).min().unwrap_or(state.money),
+        peak_stress: peak,
+        peak_stress_turn: history.iter().find(|s| s.stress == peak).map(|s| s.turn),
+        turns_in_debt: history.iter().filter(|s| s.money < 0).count(),
+        decisions_made: state.decision_log.len(),
+        events_answered: history.iter().filter(|s| s.event_option_index.is_some()).count(),
+        event_options,
+        credentials_by_stage,
+        reflections_written: state.journal.len(),
+    }
+}
+
+/// "A" for index 0, "B" for 1, ... ("Z" and beyond are numbered).
+fn option_letter(index: usize) -> String {
+    match u8::try_from(index).ok().filter(|i| *i < 26) {
+        Some(i) => char::from(b'A' + i).to_string(),
+        None => (index + 1).to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_loader::test_data;
+    use crate::engine::bot;
+    use crate::engine::turn_runner;
+
+    #[test]
+    fn test_summary_totals_a_whole_run() {
+        let data = test_data();
+        let state = bot::play_game(&bot::FirstOption, &data, "SUMMARY");
+        let summary = summarize(&state, &data.stages);
+
+        assert_eq!(summary.turns_played, state.stats_history.len());
+        assert_eq!(summary.final_money, state.money);
+        // Step-by-step flows still net out to the run's change in money
+        let start = turn_runner::start_game("SUMMARY".to_string(), &data).money;
+        assert_eq!(summary.money_earned - summary.money_spent, state.money - start);
+        assert!(summary.money_spent > 0);
+
+        assert_eq!(summary.events_answered, summary.event_options.values().sum::<usize>());
+        assert_eq!(summary.event_options.keys().collect::<Vec<_>>(), vec!["A"], "The bot always picks the first option");
+        let earned: usize = summary.credentials_by_stage.iter().map(|s| s.credentials.len()).sum();
+        // Level-ups are listed too, so each level counts once
+        assert_eq!(earned, state.credentials.iter().map(|c| c.level as usize).sum::<usize>());
+        assert_eq!(summary.credentials_by_stage[0].stage, Stage::MIDDLE_SCHOOL);
+        assert!(summary.peak_stress >= state.stats_history[0].stress);
+    }
+
+    #[test]
+    fn test_option_letters() {
+        assert_eq!(option_letter(0), "A");
+        assert_eq!(option_letter(1), "B");
+        assert_eq!(option_letter(26), "27");
+    }
+}
@@ -0,0 +1,162 @@
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use crate::data_loader::GameData;
+use crate::engine::game_state::GameState;
+use crate::engine::stat_calculator;
+use crate::engine::modifiers::EffectSource;
+use crate::models::{EventCard, TransportOption};
+use crate::models::event::{StatEffect, StatType};
+
+/// Buy into a transport option, replacing whatever the player used before.
+/// Gives up the old option's tag and grants the new one's.
+/// Returns an error message if the purchase isn't allowed.
+pub fn acquire(state: &mut GameState, option: &TransportOption) -> Result<Vec<String>, String> {
+    if state.transport.as_ref().is_some_and(|t| t.id == option.id) {
+        return Err(format!("You already have a {}.", option.title));
+    }
+    if !option.stages.contains(&state.current_stage) {
+        return Err(format!("{} isn't an option during {}.", option.title, state.current_stage));
+    }
+
+    let mut feedback = Vec::new();
+    if let Some(old) = state.transport.take() {
+        if let Some(ref tag) = old.grants_tag {
+            state.credentials.remove(tag);
+        }
+        feedback.push(format!("🚏 Gave up your {}", old.title));
+    }
+
+    if option.purchase_cost > 0 {
+        state.money -= option.purchase_cost;
+        feedback.push(format!("🚗 {}: -${}", option.title, option.purchase_cost));
+    } else {
+        feedback.push(format!("🚗 Now getting around by {}", option.title));
+    }
+    if let Some(ref tag) = option.grants_tag {
+        if !state.credentials.contains(tag) {
+            state.credentials.grant(tag);
+            feedback.push(format!("📚 Earned: {}", tag));
+        }
+    }
+    state.transport = Some(option.clone());
+    Ok(feedback)
+}
+
+/// Charge the per-turn running cost and stress of the player's transport (Phase 4).
+pub fn apply_transport_costs(state: &mut GameState, data: &GameData) -> Vec<String> {
+    let Some((title, cost, stress)) = state.transport.as_ref()
+        .map(|t| (t.title.clone(), t.cost_per_turn, t.stress_per_turn))
+    else {
+        return Vec::new();
+    };
+
+    let mut feedback = Vec::new();
+    if cost > 0 {
+        state.money -= cost;
+        feedback.push(format!("🚗 {}: -${}", title, cost));
+    }
+    feedback.extend(stat_calculator::apply_effects(
+        state,
+        &[StatEffect { stat: StatType::Stress, delta: stress, tag: None, percent: None, when: None }],
+        EffectSource::Upkeep,
+        data,
+    ));
+    feedback
+}
+
+/// Roll for a breakdown this turn (Phase 3). Returns the breakdown card if one happens.
+/// Only consumes RNG when the player owns something that can break down.
+pub fn breakdown_event(state: &GameState, events: &[EventCard], rng: &mut ChaCha8Rng) -> Option<EventCard> {
+    let transport = state.transport.as_ref()?;
+    let event_id = transport.breakdown_event.as_ref()?;
+    if transport.breakdown_chance <= 0.0 || rng.gen::<f64>() >= transport.breakdown_chance {
+        return None;
+    }
+    events.iter().find(|e| e.id == *event_id).cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_loader::test_data;
+    use crate::engine::rng::create_rng;
+    use crate::models::Stage;
+
+    fn make_car(breakdown_chance: f64) -> TransportOption {
+        TransportOption {
+            id: "car".to_string(),
+            title: "Car".to_string(),
+            description: "Test".to_string(),
+            stages: vec![Stage::EARLY_ADULT],
+            purchase_cost: 150,
+            cost_per_turn: 35,
+            stress_per_turn: 0,
+            grants_tag: Some("Reliable Car".to_string()),
+            breakdown_chance,
+            breakdown_event: Some("evt_breakdown".to_string()),
+        }
+    }
+
+    fn make_bus() -> TransportOption {
+        TransportOption {
+            id: "bus".to_string(),
+            title: "Bus".to_string(),
+            description: "Test".to_string(),
+            stages: vec![Stage::EARLY_ADULT],
+            purchase_cost: 0,
+            cost_per_turn: 15,
+            stress_per_turn: 2,
+            grants_tag: None,
+            breakdown_chance: 0.0,
+            breakdown_event: None,
+        }
+    }
+
+    fn make_state() -> GameState {
+        let mut state = GameState::new("TRANSPORT".to_string());
+        state.current_stage = Stage::EARLY_ADULT;
+        state.money = 500;
+        state
+    }
+
+    #[test]
+    fn test_car_grants_tag_until_replaced() {
+        let data = test_data();
+        let mut state = make_state();
+        acquire(&mut state, &make_car(0.0)).unwrap();
+        assert_eq!(state.money, 350);
+        assert!(state.credentials.contains("Reliable Car"));
+        assert!(acquire(&mut state, &make_car(0.0)).is_err());
+
+        acquire(&mut state, &make_bus()).unwrap();
+        assert!(!state.credentials.contains("Reliable Car"), "Selling the car loses the tag");
+        apply_transport_costs(&mut state, &data);
+        assert_eq!(state.money, 335);
+        assert_eq!(state.stress, 22);
+    }
+
+    #[test]
+    fn test_breakdown_rolls() {
+        let events = vec![EventCard {
+            id: "evt_breakdown".to_string(),
+            title: "Breakdown".to_string(),
+            flavor_text: "Test".to_string(),
+            stages: vec![],
+            rarity: crate::models::Rarity::Common,
+            categories: vec![],
+            paths: vec![],
+            transition: false,
+            months: vec![],
+            options: vec![],
+            quiz: None,
+        }];
+        let mut rng = create_rng("BREAKDOWN");
+        let mut state = make_state();
+        assert!(breakdown_event(&state, &events, &mut rng).is_none(), "No vehicle, no breakdown");
+
+        state.transport = Some(make_car(1.0));
+        assert!(breakdown_event(&state, &events, &mut rng).is_some());
+        state.transport = Some(make_car(0.0));
+        assert!(breakdown_event(&state, &events, &mut rng).is_none());
+    }
+}
@@ -0,0 +1,219 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+use crate::data_loader::GameData;
+use crate::engine::choices::{self, InvalidChoice};
+use crate::engine::game_state::GameState;
+use crate::engine::phases::{PartialTurn, TurnPipeline};
+use crate::engine::rng::GameRng;
+use crate::engine::turn_runner::{self, PlayerChoices, TurnResult};
+use crate::models::EventCard;
+
+/// The step a turn played one step at a time is waiting on. Between turns it's Plan;
+/// once the actions are locked in, Commit; once the decision is made, ResolveEvent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum TurnStep {
+    Plan,
+    Commit,
+    ResolveEvent,
+}
+
+impl TurnStep {
+    /// The endpoint that takes the step.
+    pub fn endpoint(self) -> &'static str {
+        match self {
+            TurnStep::Plan => "plan",
+            TurnStep::Commit => "commit",
+            TurnStep::ResolveEvent => "resolve_event",
+        }
+    }
+}
+
+/// Why a step can't be taken.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StepError {
+    /// The turn is waiting on a different step.
+    OutOfOrder(String),
+    /// The step's choices don't fit the turn.
+    Invalid(Vec<InvalidChoice>),
+}
+
+/// The step the game is waiting on.
+pub fn next_step(state: &GameState) -> TurnStep {
+    match &state.turn_in_progress {
+        None => TurnStep::Plan,
+        Some(partial) if partial.has_run("commit") => TurnStep::ResolveEvent,
+        Some(_) => TurnStep::Commit,
+    }
+}
+
+fn expect_step(state: &GameState, step: TurnStep) -> Result<(), StepError> {
+    match next_step(state) {
+        next if next == step => Ok(()),
+        next => Err(StepError::OutOfOrder(format!(
+            "The turn is waiting for {}, not {}",
+            next.endpoint(), step.endpoint()
+        ))),
+    }
+}
+
+/// Step 1: lock in the turn's actions and run Phase 1. The turn's cards are dealt now,
+/// or taken from `pending` if they were drawn for a preview, and stay with the turn until
+/// it's resolved. Returns the phase's feedback.
+pub fn plan(
+    state: &mut GameState,
+    action_ids: Vec<String>,
+    pending: &mut Vec<EventCard>,
+    data: &GameData,
+    rng: &mut GameRng,
+) -> Result<Vec<String>, StepError> {
+    expect_step(state, TurnStep::Plan)?;
+    let choices = PlayerChoices { action_ids, ..PlayerChoices::default() };
+    choices::validate_actions(state, &choices, data).map_err(StepError::Invalid)?;
+
+    if pending.is_empty() {
+        *pending = turn_runner::draw_turn_events(state, data, rng);
+    }
+    let mut partial = PartialTurn::start(state, choices, std::mem::take(pending), data);
+    TurnPipeline::standard().advance(state, &mut partial, "plan", data, rng);
+    let feedback = partial.feedback.clone();
+    state.turn_in_progress = Some(partial);
+    Ok(feedback)
+}
+
+/// Step 2: make the turn's decision (or put it off) and run Phase 2. Only the decision
+/// fields of `choices` are read. Returns the phase's feedback.
+pub fn commit(state: &mut GameState, choices: &PlayerChoices, data: &GameData, rng: &mut GameRng) -> Result<Vec<String>, StepError> {
+    expect_step(state, TurnStep::Commit)?;
+    choices::validate_decision(state, choices, data).map_err(StepError::Invalid)?;
+
+    let mut partial = state.turn_in_progress.take().expect("A turn waiting for commit is in progress");
+    let already = partial.feedback.len();
+    partial.choices.decision_id = choices.decision_id.clone();
+    partial.choices.decision_option_index = choices.decision_option_index;
+    partial.choices.defer_decision = choices.defer_decision;
+    TurnPipeline::standard().advance(state, &mut partial, "commit", data, rng);
+    let feedback = partial.feedback[already..].to_vec();
+    state.turn_in_progress = Some(partial);
+    Ok(feedback)
+}
+
+/// Step 3: answer the turn's cards and run the rest of the turn. Only the event fields of
+/// `choices` are read. Returns every choice the turn was played with, and its result.
+pub fn resolve_event(
+    state: &mut GameState,
+    choices: &PlayerChoices,
+    data: &GameData,
+    rng: &mut GameRng,
+) -> Result<(PlayerChoices, TurnResult), StepError> {
+    expect_step(state, TurnStep::ResolveEvent)?;
+    let events = state.turn_in_progress.as_ref().map_or(&[][..], |p| &p.events);
+    choices::validate_event_answers(choices, events).map_err(StepError::Invalid)?;
+
+    let mut partial = state.turn_in_progress.take().expect("A turn waiting for resolve_event is in progress");
+    partial.choices.event_option_index = choices.event_option_index;
+    partial.choices.event_id = choices.event_id.clone();
+    partial.choices.extra_event_options = choices.extra_event_options.clone();
+    let played = partial.choices.clone();
+    let result = TurnPipeline::standard().finish(state, partial, data, rng);
+    Ok((played, result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_loader::test_data;
+    use crate::engine::rng::game_rng;
+
+    fn first_turn_choices() -> PlayerChoices {
+        PlayerChoices {
+            action_ids: vec!["act_study".to_string()],
+            decision_id: "dec_club_choice_a".to_string(),
+            decision_option_index: 0,
+            defer_decision: false,
+            event_option_index: Some(0),
+            event_id: None,
+            extra_event_options: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_steps_play_the_same_turn_as_submitting_it() {
+        let data = test_data();
+        let choices = first_turn_choices();
+        let mut whole = turn_runner::start_game("STEPS".to_string(), &data);
+        let mut stepped = whole.clone();
+
+        let mut rng = game_rng("STEPS");
+        let expected = turn_runner::run_turn(&mut whole, &choices, &data, &mut rng);
+
+        let mut rng = game_rng("STEPS");
+        let mut pending = Vec::new();
+        let mut feedback = plan(&mut stepped, choices.action_ids.clone(), &mut pending, &data, &mut rng).unwrap();
+        assert_eq!(next_step(&stepped), TurnStep::Commit);
+        feedback.extend(commit(&mut stepped, &choices, &data, &mut rng).unwrap());
+        assert_eq!(next_step(&stepped), TurnStep::ResolveEvent);
+        let (played, result) = resolve_event(&mut stepped, &choices, &data, &mut rng).unwrap();
+
+        assert_eq!(next_step(&stepped), TurnStep::Plan);
+        assert_eq!(played.action_ids, choices.action_ids);
+        assert_eq!(result.feedback, expected.feedback);
+        assert!(result.feedback.starts_with(&feedback));
+        assert_eq!(result.stat_changes, expected.stat_changes);
+        assert_eq!(serde_json::to_value(&stepped).unwrap(), serde_json::to_value(&whole).unwrap());
+    }
+
+    #[test]
+    fn test_steps_must_come_in_order() {
+        let data = test_data();
+        let choices = first_turn_choices();
+        let mut state = turn_runner::start_game("STEPS".to_string(), &data);
+        let mut rng = game_rng("STEPS");
+
+        assert!(matches!(commit(&mut state, &choices, &data, &mut rng), Err(StepError::OutOfOrder(_))));
+        assert!(matches!(resolve_event(&mut state, &choices, &data, &mut rng), Err(StepError::OutOfOrder(_))));
+        plan(&mut state, choices.action_ids.clone(), &mut Vec::new(), &data, &mut rng).unwrap();
+        let replan = plan(&mut state, Vec::new(), &mut Vec::new(), &data, &mut rng);
+        assert_eq!(replan, Err(StepError::OutOfOrder("The turn is waiting for commit, not plan".to_string())));
+        assert!(matches!(resolve_event(&mut state, &choices, &data, &mut rng), Err(StepError::OutOfOrder(_))));
+    }
+
+    #[test]
+    fn test_rejected_step_leaves_the_turn_alone() {
+        let data = test_data();
+        let choices = first_turn_choices();
+        let mut state = turn_runner::start_game("STEPS".to_string(), &data);
+        let mut rng = game_rng("STEPS");
+
+        let errors = plan(&mut state, vec!["act_unknown".to_string()], &mut Vec::new(), &data, &mut rng);
+        assert!(matches!(errors, Err(StepError::Invalid(ref e)) if e[0].field == "actionIds[0]"));
+        assert!(state.turn_in_progress.is_none());
+
+        plan(&mut state, choices.action_ids.clone(), &mut Vec::new(), &data, &mut rng).unwrap();
+        let wrong = PlayerChoices { decision_id: "dec_missing".to_string(), ..choices.clone() };
+        assert!(matches!(commit(&mut state, &wrong, &data, &mut rng), Err(StepError::Invalid(_))));
+        assert_eq!(next_step(&state), TurnStep::Commit);
+        commit(&mut state, &choices, &data, &mut rng).unwrap();
+        let unanswered = PlayerChoices { event_option_index: None, ..choices };
+        assert!(matches!(resolve_event(&mut state, &unanswered, &data, &mut rng), Err(StepError::Invalid(_))));
+        assert_eq!(next_step(&state), TurnStep::ResolveEvent);
+    }
+
+    #[test]
+    fn test_turn_in_progress_survives_a_save() {
+        let data = test_data();
+        let choices = first_turn_choices();
+        let mut state = turn_runner::start_game("STEPS".to_string(), &data);
+        let mut rng = game_rng("STEPS");
+        plan(&mut state, choices.action_ids.clone(), &mut Vec::new(), &data, &mut rng).unwrap();
+        commit(&mut state, &choices, &data, &mut rng).unwrap();
+
+        let saved = serde_json::to_string(&state).unwrap();
+        let mut loaded: GameState = serde_json::from_str(&saved).unwrap();
+        let mut loaded_rng = loaded.resume_rng();
+        let (_, result) = resolve_event(&mut loaded, &choices, &data, &mut loaded_rng).unwrap();
+        let (_, expected) = resolve_event(&mut state, &choices, &data, &mut rng).unwrap();
+        assert_eq!(result.feedback, expected.feedback);
+        assert_eq!(serde_json::to_value(&loaded).unwrap(), serde_json::to_value(&state).unwrap());
+    }
+}
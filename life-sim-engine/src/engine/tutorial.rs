@@ -0,0 +1,51 @@
+use crate::engine::game_state::GameState;
+use crate::data_loader::GameData;
+use crate::models::EventCard;
+use crate::models::tutorial::TutorialStep;
+
+/// The script step for the current turn, if this is a tutorial game and the turn is scripted.
+pub fn current_step<'a>(state: &GameState, steps: &'a [TutorialStep]) -> Option<&'a TutorialStep> {
+    if !state.tutorial {
+        return None;
+    }
+    steps.iter().find(|s| s.turn == state.current_turn)
+}
+
+/// The event the script draws this turn, in place of a random card.
+pub fn scripted_event(state: &GameState, data: &GameData) -> Option<EventCard> {
+    let event_id = current_step(state, &data.tutorial)?.event_id.as_ref()?;
+    data.event(event_id).cloned()
+}
+
+/// Guidance to add to this turn's feedback.
+pub fn guidance(state: &GameState, steps: &[TutorialStep]) -> Vec<String> {
+    current_step(state, steps)
+        .map(|step| step.messages.iter().map(|m| format!("🧭 {}", m)).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(turn: u32) -> TutorialStep {
+        TutorialStep {
+            id: format!("step_{}", turn),
+            turn,
+            event_id: None,
+            messages: vec![format!("Turn {} tip", turn)],
+        }
+    }
+
+    #[test]
+    fn test_guidance_only_in_tutorial_games() {
+        let steps = vec![step(1), step(2)];
+        let mut state = GameState::new("TUTORIAL".to_string());
+        assert!(guidance(&state, &steps).is_empty(), "Not a tutorial game");
+
+        state.tutorial = true;
+        assert_eq!(guidance(&state, &steps), vec!["🧭 Turn 1 tip"]);
+        state.current_turn = 3;
+        assert!(guidance(&state, &steps).is_empty(), "Past the script");
+    }
+}
@@ -0,0 +1,40 @@
+//! The game logic of Life Roguelite, without a server: the content model, loading and
+//! validating a data set, and the rules that play a game out turn by turn.
+//!
+//! Everything is deterministic for a seed. A game is a [`GameState`](engine::game_state::GameState)
+//! plus the random streams seeded from it; each turn draws an event card, takes the player's
+//! choices, and advances the state.
+//!
+//! ```
+//! use life_sim_engine::data_loader::{DataSource, GameData};
+//! use life_sim_engine::engine::{bot, endings, rng, turn_runner};
+//!
+//! let data = GameData::load(&DataSource::Dir("../data".into()), &[])?;
+//! let mut state = turn_runner::start_game("SEED".to_string(), &data);
+//! let mut game_rng = state.seed_rng();
+//! let mut bot_rng = rng::create_rng("SEED/bot");
+//!
+//! while !turn_runner::is_game_over(&state, &data.stages) {
+//!     let events = turn_runner::draw_turn_events(&state, &data, &mut game_rng);
+//!     let choices = bot::choose_turn(&bot::FirstOption, &state, &data, &events, &mut bot_rng);
+//!     turn_runner::run_turn_with_events(&mut state, &choices, &data, &mut game_rng, events);
+//! }
+//! assert!(endings::resolve(&state, &data.endings).is_some());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! - [`models`]: the content types read from the data files (events, decisions, jobs, ...).
+//! - [`data_loader`]: reading a data set from disk, a remote host, or the built-in copy
+//!   (the `embedded-data` feature), with data packs layered on top, and the [`DataStore`](data_loader::DataStore) a server
+//!   swaps reloads into.
+//! - [`engine`]: turn resolution, choices and their validation, the event deck, careers,
+//!   endings, bots, and replays.
+//! - [`validation`] and [`migrations`]: checks run on every load, and upgrades for data
+//!   files written against older schema versions.
+
+pub mod models;
+pub mod engine;
+pub mod data_loader;
+pub mod validation;
+pub mod migrations;
+pub mod remote_data;
@@ -0,0 +1,142 @@
+use serde_json::{Map, Value};
+
+/// The data file format this build reads and writes.
+pub const CURRENT_FORMAT_VERSION: u64 = 3;
+
+/// Key holding a data file's format version.
+pub const FORMAT_VERSION_KEY: &str = "formatVersion";
+
+/// One upgrade step: turns a version `from` file into a version `from + 1` file.
+/// Each step gets the data file name (e.g. "events") and the whole parsed file.
+struct Migration {
+    from: u64,
+    upgrade: fn(name: &str, file: Value) -> Value,
+}
+
+/// Every upgrade step, oldest first. When a model change would break existing
+/// content (renaming a field, say), bump CURRENT_FORMAT_VERSION and add a step here
+/// that rewrites older files into the new shape.
+const MIGRATIONS: &[Migration] = &[
+    Migration { from: 1, upgrade: wrap_list },
+    Migration { from: 2, upgrade: rename_sets_job },
+];
+
+/// Version 1 list files were a bare array of entries. Version 2 wraps them in an
+/// object with the format version and the entries under the file's name.
+fn wrap_list(name: &str, file: Value) -> Value {
+    match file {
+        Value::Array(items) => {
+            let mut wrapped = Map::new();
+            wrapped.insert(name.to_string(), Value::Array(items));
+            Value::Object(wrapped)
+        }
+        other => other,
+    }
+}
+
+/// Version 3 renamed a decision option's `setsJob` to `appliesFor`, since picking a job
+/// now applies for it instead of hiring the player outright.
+fn rename_sets_job(name: &str, mut file: Value) -> Value {
+    if name != "decisions" {
+        return file;
+    }
+    let decisions = file.get_mut(name).and_then(Value::as_array_mut).into_iter().flatten();
+    let options = decisions.filter_map(|d| d.get_mut("options").and_then(Value::as_array_mut)).flatten();
+    for option in options.filter_map(Value::as_object_mut) {
+        if let Some(job) = option.remove("setsJob") {
+            option.entry("appliesFor").or_insert(job);
+        }
+    }
+    file
+}
+
+/// Upgrade a parsed data file to the current format and strip the version marker.
+/// List files come back as their array of entries; settings files as their object.
+/// Also returns the version the file was written in.
+pub fn upgrade(name: &str, file: Value) -> Result<(Value, u64), String> {
+    let version = match &file {
+        Value::Object(fields) => match fields.get(FORMAT_VERSION_KEY) {
+            Some(v) => v.as_u64().ok_or_else(|| format!("{} must be a whole number", FORMAT_VERSION_KEY))?,
+            None => 1,
+        },
+        _ => 1,
+    };
+    if version > CURRENT_FORMAT_VERSION {
+        return Err(format!(
+            "written for format version {}, but this build reads up to version {}",
+            version, CURRENT_FORMAT_VERSION
+        ));
+    }
+
+    let mut file = file;
+    for migration in MIGRATIONS.iter().filter(|m| m.from >= version) {
+        file = (migration.upgrade)(name, file);
+    }
+
+    let Value::Object(mut fields) = file else {
+        return Err("expected an object".to_string());
+    };
+    fields.remove(FORMAT_VERSION_KEY);
+    // List files keep their entries under the file's name; settings files are the object itself
+    let contents = match fields.remove(name) {
+        Some(list @ Value::Array(_)) if fields.is_empty() => list,
+        Some(other) => {
+            fields.insert(name.to_string(), other);
+            Value::Object(fields)
+        }
+        None => Value::Object(fields),
+    };
+    Ok((contents, version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_current_files_unwrap() {
+        let file = json!({ "formatVersion": 3, "paths": [{ "id": "path_arts" }] });
+        let (contents, version) = upgrade("paths", file).unwrap();
+        assert_eq!(version, 3);
+        assert_eq!(contents, json!([{ "id": "path_arts" }]));
+
+        let (settings, _) = upgrade("economy", json!({ "formatVersion": 3, "jobChangeStress": 5 })).unwrap();
+        assert_eq!(settings, json!({ "jobChangeStress": 5 }));
+    }
+
+    #[test]
+    fn test_version_one_files_upgrade() {
+        let (contents, version) = upgrade("paths", json!([{ "id": "path_arts" }])).unwrap();
+        assert_eq!(version, 1);
+        assert_eq!(contents, json!([{ "id": "path_arts" }]));
+
+        let (settings, version) = upgrade("economy", json!({ "jobChangeStress": 5 })).unwrap();
+        assert_eq!(version, 1);
+        assert_eq!(settings, json!({ "jobChangeStress": 5 }));
+    }
+
+    #[test]
+    fn test_version_two_job_choices_become_applications() {
+        let file = json!({ "formatVersion": 2, "decisions": [{
+            "id": "dec_first_job",
+            "options": [{ "label": "Fast Food", "setsJob": "job_fast_food" }, { "label": "Wait" }],
+        }] });
+        let (contents, version) = upgrade("decisions", file).unwrap();
+        assert_eq!(version, 2);
+        assert_eq!(contents[0]["options"], json!([{ "label": "Fast Food", "appliesFor": "job_fast_food" }, { "label": "Wait" }]));
+
+        // Version 1 lists get both steps; other files keep their setsJob (interview cards)
+        let (contents, _) = upgrade("decisions", json!([{ "options": [{ "setsJob": "job_retail" }] }])).unwrap();
+        assert_eq!(contents[0]["options"][0], json!({ "appliesFor": "job_retail" }));
+        let events = json!({ "formatVersion": 2, "events": [{ "options": [{ "setsJob": "job_retail" }] }] });
+        let (contents, _) = upgrade("events", events).unwrap();
+        assert_eq!(contents[0]["options"][0], json!({ "setsJob": "job_retail" }));
+    }
+
+    #[test]
+    fn test_newer_files_are_rejected() {
+        let err = upgrade("paths", json!({ "formatVersion": 99, "paths": [] })).unwrap_err();
+        assert!(err.contains("format version 99"), "{}", err);
+    }
+}
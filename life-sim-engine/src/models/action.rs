@@ -0,0 +1,70 @@
+use std::collections::BTreeMap;
+use schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+use super::Stage;
+use super::event::StatEffect;
+
+/// An action the player can select during Phase 1 (Plan).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Action {
+    pub id: String,
+    pub label: String,
+    pub description: String,
+    pub stages: Vec<Stage>,
+    pub effects: Vec<StatEffect>,
+    pub time_cost: u32,
+    /// Turns the action can't be taken again after it's used (0: every turn).
+    #[serde(default)]
+    pub cooldown: u32,
+    /// Times the action can be taken in a run; unlimited if omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_uses: Option<u32>,
+    /// What the player needs before the action can be taken.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requires: Option<ActionRequirements>,
+    /// ID of a scripted hook in special_effects.json, run after the effects.
+    #[serde(default)]
+    pub special_effect: Option<String>,
+}
+
+/// Prerequisites for an action; every part that's set must hold.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ActionRequirements {
+    /// A credential the player must have.
+    #[serde(default)]
+    pub tag: Option<String>,
+    /// Stat ID → the lowest value allowed, e.g. `{"support": 40}`.
+    #[serde(default)]
+    pub min_stats: BTreeMap<String, i32>,
+    /// Situations the player must be in, e.g. `["employed"]`.
+    #[serde(default)]
+    pub flags: Vec<StateFlag>,
+}
+
+/// A yes-or-no fact about the player's situation that an action can require.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum StateFlag {
+    /// Has a job.
+    Employed,
+    /// Doesn't have a job.
+    Unemployed,
+    /// Is enrolled in an education program.
+    Enrolled,
+    /// Is in a stage where monthly bills apply (`paysBills` in stages.json).
+    PaysBills,
+}
+
+impl StateFlag {
+    /// Completes "Requires …" in lock reasons.
+    pub fn description(self) -> &'static str {
+        match self {
+            StateFlag::Employed => "a job",
+            StateFlag::Unemployed => "being out of work",
+            StateFlag::Enrolled => "being enrolled in school",
+            StateFlag::PaysBills => "living on your own",
+        }
+    }
+}
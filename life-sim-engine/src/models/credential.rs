@@ -0,0 +1,169 @@
+use std::collections::BTreeMap;
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// A credential the player holds, and its level: how many times it's been earned.
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Credential {
+    pub tag: String,
+    /// 1 when first earned; each repeated grant adds one.
+    pub level: u32,
+}
+
+impl Credential {
+    /// The tag alone at level 1, then with a Roman numeral: "Spanish", "Spanish II", "Spanish III".
+    pub fn name(&self) -> String {
+        if self.level <= 1 {
+            self.tag.clone()
+        } else {
+            format!("{} {}", self.tag, roman(self.level))
+        }
+    }
+}
+
+/// Saves from before credentials had levels store bare tags; read those as level 1.
+impl<'de> Deserialize<'de> for Credential {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Stored {
+            Tag(String),
+            Entry { tag: String, level: u32 },
+        }
+        Ok(match Stored::deserialize(deserializer)? {
+            Stored::Tag(tag) => Credential { tag, level: 1 },
+            Stored::Entry { tag, level } => Credential { tag, level: level.max(1) },
+        })
+    }
+}
+
+/// The player's credentials, in the order they were first earned.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(transparent)]
+pub struct Credentials(Vec<Credential>);
+
+impl Credentials {
+    pub fn contains(&self, tag: &str) -> bool {
+        self.level(tag) > 0
+    }
+
+    pub fn get(&self, tag: &str) -> Option<&Credential> {
+        self.0.iter().find(|c| c.tag == tag)
+    }
+
+    /// The tag's level, or 0 if the player doesn't hold it.
+    pub fn level(&self, tag: &str) -> u32 {
+        self.get(tag).map_or(0, |c| c.level)
+    }
+
+    /// Earn a tag, or level it up if it's already held.
+    pub fn grant(&mut self, tag: &str) -> &Credential {
+        match self.0.iter().position(|c| c.tag == tag) {
+            Some(i) => {
+                self.0[i].level += 1;
+                &self.0[i]
+            }
+            None => {
+                self.0.push(Credential { tag: tag.to_string(), level: 1 });
+                &self.0[self.0.len() - 1]
+            }
+        }
+    }
+
+    /// Give a tag up entirely, whatever its level.
+    pub fn remove(&mut self, tag: &str) {
+        self.0.retain(|c| c.tag != tag);
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Credential> {
+        self.0.iter()
+    }
+
+    /// Each credential's display name, e.g. ["CPR", "Spanish II"].
+    pub fn names(&self) -> Vec<String> {
+        self.0.iter().map(Credential::name).collect()
+    }
+
+    /// What's missing from a requirement of `tags` held at any level plus minimum `levels`,
+    /// named at the level needed (e.g. ["CPR", "Spanish II"]). Empty if it's met.
+    pub fn missing(&self, tags: &[String], levels: &BTreeMap<String, u32>) -> Vec<String> {
+        let needed = tags.iter()
+            .filter(|t| !levels.contains_key(*t))
+            .map(|t| (t, 1))
+            .chain(levels.iter().map(|(t, &level)| (t, level.max(1))));
+        needed
+            .filter(|&(tag, level)| self.level(tag) < level)
+            .map(|(tag, level)| Credential { tag: tag.clone(), level }.name())
+            .collect()
+    }
+
+    /// Names of the credentials earned or leveled up since `before`.
+    pub fn gained_since(&self, before: &Credentials) -> Vec<String> {
+        self.0.iter()
+            .filter(|c| c.level > before.level(&c.tag))
+            .map(Credential::name)
+            .collect()
+    }
+}
+
+fn roman(mut n: u32) -> String {
+    const NUMERALS: [(u32, &str); 13] = [
+        (1000, "M"), (900, "CM"), (500, "D"), (400, "CD"), (100, "C"), (90, "XC"),
+        (50, "L"), (40, "XL"), (10, "X"), (9, "IX"), (5, "V"), (4, "IV"), (1, "I"),
+    ];
+    let mut out = String::new();
+    for (value, numeral) in NUMERALS {
+        while n >= value {
+            out.push_str(numeral);
+            n -= value;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repeated_grants_level_up() {
+        let mut held = Credentials::default();
+        assert_eq!(held.grant("Spanish").level, 1);
+        assert_eq!(held.grant("CPR").level, 1);
+        assert_eq!(held.grant("Spanish").level, 2);
+        assert_eq!(held.grant("Spanish").name(), "Spanish III");
+        assert_eq!(held.len(), 2);
+        assert_eq!(held.level("Spanish"), 3);
+        assert_eq!(held.level("Welding"), 0);
+        assert_eq!(held.names(), vec!["Spanish III".to_string(), "CPR".to_string()]);
+
+        let levels = BTreeMap::from([("Spanish".to_string(), 2), ("CPR".to_string(), 2)]);
+        assert_eq!(held.missing(&["Welding".to_string(), "Spanish".to_string()], &levels), vec!["Welding".to_string(), "CPR II".to_string()]);
+
+        let before = held.clone();
+        held.grant("CPR");
+        held.grant("Welding");
+        assert_eq!(held.gained_since(&before), vec!["CPR II".to_string(), "Welding".to_string()]);
+
+        held.remove("Spanish");
+        assert!(!held.contains("Spanish"));
+        assert_eq!(roman(14), "XIV");
+    }
+
+    #[test]
+    fn test_reads_saves_with_bare_tags() {
+        let held: Credentials = serde_json::from_str(r#"["CPR", {"tag": "Spanish", "level": 2}]"#).unwrap();
+        assert_eq!(held.level("CPR"), 1);
+        assert_eq!(held.level("Spanish"), 2);
+        assert_eq!(serde_json::to_string(&held).unwrap(), r#"[{"tag":"CPR","level":1},{"tag":"Spanish","level":2}]"#);
+    }
+}
@@ -0,0 +1,87 @@
+use std::fmt;
+use schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+use super::Stage;
+use super::event::StatEffect;
+use super::quiz::QuizQuestion;
+
+/// A decision the player makes during Phase 2 (Commit).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Decision {
+    pub id: String,
+    pub stage: Stage,
+    pub turn: u32,
+    /// If set, only players on this life path see this decision.
+    #[serde(default)]
+    pub path: Option<String>,
+    pub prompt: String,
+    pub options: Vec<DecisionOption>,
+    /// Optional question checking what the decision teaches.
+    #[serde(default)]
+    pub quiz: Option<QuizQuestion>,
+    /// If set, the player may put the decision off instead of answering it, at this cost.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub defer_consequence: Option<DeferConsequence>,
+}
+
+/// What putting a decision off costs, and whether it comes back.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DeferConsequence {
+    /// Applied in Phase 2 of the turn the decision is put off.
+    #[serde(default)]
+    pub effects: Vec<StatEffect>,
+    /// Turns until the decision is offered again; put off for good if omitted.
+    #[serde(default)]
+    pub returns_after: Option<u32>,
+}
+
+/// E.g. "Stress +5, comes back in 2 turns".
+impl fmt::Display for DeferConsequence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts: Vec<String> = self.effects.iter().map(|e| e.to_string()).collect();
+        parts.push(match self.returns_after {
+            Some(1) => "comes back next turn".to_string(),
+            Some(turns) => format!("comes back in {} turns", turns),
+            None => "won't come back".to_string(),
+        });
+        f.write_str(&parts.join(", "))
+    }
+}
+
+/// One option within a decision.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DecisionOption {
+    pub label: String,
+    pub description: String,
+    pub effects: Vec<StatEffect>,
+    #[serde(default)]
+    pub grants_tag: Option<String>,
+    /// If set, assigns monthly_bills to this value on the game state.
+    #[serde(default)]
+    pub sets_bills: Option<i32>,
+    /// If set, puts the player on the life path with this ID.
+    #[serde(default)]
+    pub sets_path: Option<String>,
+    /// If set, moves the player into the housing tier with this ID.
+    #[serde(default)]
+    pub moves_into: Option<String>,
+    /// If set, applies for the job with this ID; a seeded roll decides whether an
+    /// interview follows next turn.
+    #[serde(default)]
+    pub applies_for: Option<String>,
+    /// If set, enrolls the player in the education program with this ID.
+    #[serde(default)]
+    pub enrolls_in: Option<String>,
+    /// When enrolling, add tuition to student loans instead of paying cash.
+    #[serde(default)]
+    pub finance_with_loan: bool,
+    /// If true, accepts the promotion this decision offers.
+    #[serde(default)]
+    pub accepts_promotion: bool,
+    /// If set, this option is only available if the player has this credential.
+    #[serde(default)]
+    pub requires_tag: Option<String>,
+}
@@ -0,0 +1,50 @@
+use schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+/// Tunable economy rules, loaded from economy.json.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EconomyConfig {
+    /// Per-turn interest charged on a negative money balance (0.05 = 5%).
+    pub debt_interest_rate: f64,
+    /// Per-turn interest paid on a positive savings balance (0.04 = 4%).
+    pub savings_interest_rate: f64,
+    /// Amount moved from money into savings by a deposit action.
+    pub savings_deposit_amount: i32,
+    /// Maximum amount moved from savings back to money by a withdraw action.
+    pub savings_withdraw_amount: i32,
+    /// Student loan payment due each turn in Early Adult (capped at the balance).
+    pub student_loan_payment: i32,
+    /// Stress added when the player quits or switches jobs mid-stage.
+    pub job_change_stress: i32,
+    /// Turns without a paycheck after switching jobs.
+    pub job_change_unpaid_turns: u32,
+    /// Extra share added to every loss (money or support lost, stress gained): 0.25 makes
+    /// losses a quarter bigger, -0.25 a quarter smaller. 0 plays effects as written.
+    #[serde(default)]
+    pub difficulty: f64,
+    /// Extra share added to stress gains while stress is above the danger line.
+    #[serde(default)]
+    pub high_stress_strain: f64,
+    /// Points taken off each stress gain while the player has the support bonus.
+    #[serde(default)]
+    pub support_cushion: i32,
+    /// Unused time slots a turn can carry into the next, at most this many. 0 turns
+    /// banking off.
+    #[serde(default)]
+    pub time_bank_cap: u32,
+    /// Stress shed each turn on its own, as long as stress isn't above the danger line.
+    #[serde(default)]
+    pub stress_recovery: i32,
+    /// Every this many turns in a row without an action or decision that raises support,
+    /// support drops by 1. 0 turns decay off.
+    #[serde(default)]
+    pub support_neglect_turns: u32,
+    /// Money a reroll of the drawn event card costs. 0 means rerolls can't be paid in money.
+    #[serde(default)]
+    pub reroll_money_cost: i32,
+    /// Support a reroll of the drawn event card costs. 0 means rerolls can't be paid in
+    /// support.
+    #[serde(default)]
+    pub reroll_support_cost: i32,
+}
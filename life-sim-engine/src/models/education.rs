@@ -0,0 +1,21 @@
+use schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+use super::Stage;
+
+/// A multi-turn education program the player can enroll in.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EducationProgram {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub stages: Vec<Stage>,
+    /// Number of turns of attendance needed to graduate.
+    pub duration_turns: u32,
+    /// Time slots the program occupies every turn while enrolled.
+    pub time_cost: u32,
+    /// Tuition charged each turn (paid in cash or added to student loans).
+    pub tuition_per_turn: i32,
+    /// Credential granted on completion.
+    pub grants_tag: String,
+}
@@ -0,0 +1,95 @@
+use schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+/// An ending the player can reach at the end of the game.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Ending {
+    pub id: String,
+    pub title: String,
+    pub conditions: EndingConditions,
+    /// Breaks ties between endings whose conditions are equally specific; higher wins.
+    #[serde(default)]
+    pub priority: i32,
+    pub narrative: String,
+    pub reflections: Vec<String>,
+    /// Shown in the endings gallery while the ending is still locked. Without one, the
+    /// gallery describes the conditions instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+    /// A badge is never the run's ending. It's awarded next to the ending whenever its
+    /// conditions hold, e.g. "Debt-Free".
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub badge: bool,
+}
+
+/// Threshold conditions that determine which ending applies.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EndingConditions {
+    #[serde(default)]
+    pub money: Option<ThresholdCondition>,
+    #[serde(default)]
+    pub stress: Option<ThresholdCondition>,
+    #[serde(default)]
+    pub support: Option<ThresholdCondition>,
+    #[serde(default)]
+    pub credentials: Option<CountCondition>,
+    #[serde(default)]
+    pub savings: Option<ThresholdCondition>,
+    /// If set, the player must be on this life path.
+    #[serde(default)]
+    pub path: Option<String>,
+    /// Credential tags the player must hold, at any level.
+    #[serde(default)]
+    pub required_tags: Vec<String>,
+    /// If set, the player must finish in a job of this family (a job's `family`).
+    #[serde(default)]
+    pub job_family: Option<String>,
+    /// If true, the player must finish with money at or above zero and no student loan.
+    #[serde(default)]
+    pub debt_free: bool,
+    #[serde(default)]
+    pub emergency_fund: Option<ThresholdCondition>,
+}
+
+impl EndingConditions {
+    /// How many bounds the conditions set (each min, max, count, path, required tag, job
+    /// family, or debt-free check is one), so a narrower ending can win over a broader one
+    /// that also matches.
+    pub fn specificity(&self) -> usize {
+        let bounds = |c: &Option<ThresholdCondition>| c.as_ref().map_or(0, |c| c.min.is_some() as usize + c.max.is_some() as usize);
+        bounds(&self.money) + bounds(&self.stress) + bounds(&self.support) + bounds(&self.savings)
+            + bounds(&self.emergency_fund)
+            + self.credentials.as_ref().is_some_and(|c| c.min_count.is_some()) as usize
+            + self.path.is_some() as usize
+            + self.required_tags.len()
+            + self.job_family.is_some() as usize
+            + self.debt_free as usize
+    }
+}
+
+/// A numeric min/max threshold.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ThresholdCondition {
+    #[serde(default)]
+    pub min: Option<i32>,
+    #[serde(default)]
+    pub max: Option<i32>,
+}
+
+impl ThresholdCondition {
+    /// Whether a value is within the bounds, both inclusive.
+    pub fn holds(&self, value: i32) -> bool {
+        self.min.is_none_or(|min| value >= min) && self.max.is_none_or(|max| value <= max)
+    }
+}
+
+/// A count-based condition (e.g., minimum number of credentials).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CountCondition {
+    #[serde(default)]
+    pub min_count: Option<u32>,
+}
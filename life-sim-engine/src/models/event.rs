@@ -0,0 +1,277 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt;
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+use serde::{Serialize, Deserialize};
+use super::Stage;
+use super::ending::ThresholdCondition;
+use super::quiz::QuizQuestion;
+
+/// A life event card drawn during Phase 3 of each turn.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EventCard {
+    pub id: String,
+    pub title: String,
+    pub flavor_text: String,
+    pub stages: Vec<Stage>,
+    pub rarity: Rarity,
+    /// Tags such as "emergency" or "medical" that other systems (insurance) key off.
+    #[serde(default)]
+    pub categories: Vec<String>,
+    /// If non-empty, only players on one of these life paths can draw this card.
+    #[serde(default)]
+    pub paths: Vec<String>,
+    /// Opens its stage rather than sitting in the deck: dealt on the stage's first turn, after
+    /// the player moves up from the stage before.
+    #[serde(default)]
+    pub transition: bool,
+    /// If non-empty, the card is only drawn on turns falling in one of these months
+    /// (1 = January), e.g. a summer job in June to August.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub months: Vec<u32>,
+    pub options: Vec<EventOption>,
+    /// Optional question checking what the card teaches.
+    #[serde(default)]
+    pub quiz: Option<QuizQuestion>,
+}
+
+/// One response option on an event card.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EventOption {
+    pub label: String,
+    pub description: String,
+    pub effects: Vec<StatEffect>,
+    #[serde(default)]
+    pub delayed_effects: Option<Vec<DelayedEffect>>,
+    #[serde(default)]
+    pub requires_support: Option<i32>,
+    /// If set, choosing this option hires the player into the job with this ID.
+    #[serde(default)]
+    pub sets_job: Option<String>,
+    /// If set, choosing this option puts the player on the life path with this ID.
+    #[serde(default)]
+    pub sets_path: Option<String>,
+}
+
+/// A single stat modification.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StatEffect {
+    pub stat: StatType,
+    #[serde(default)]
+    pub delta: i32,
+    #[serde(default)]
+    pub tag: Option<String>,
+    /// Change the stat by this percentage of its current value instead (e.g. -25 to lose a
+    /// quarter of your money). `delta` is worked out from it when the effect applies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub percent: Option<f64>,
+    /// Only apply the effect if this holds when it lands.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub when: Option<EffectCondition>,
+}
+
+/// An effect as logged in the decision history, e.g. "Money -25%" or "Stress +5 if stress ≥ 61".
+impl fmt::Display for StatEffect {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.percent {
+            Some(percent) => write!(f, "{} {:+}%", self.stat, percent)?,
+            None => write!(f, "{} {:+}", self.stat, self.delta)?,
+        }
+        match self.when {
+            Some(ref when) => write!(f, " if {}", when),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The situation an effect needs; every part that's set must hold.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EffectCondition {
+    /// Stat ID → the range it must be in, e.g. `{"stress": {"min": 61}}`.
+    #[serde(default)]
+    pub stats: BTreeMap<String, ThresholdCondition>,
+    /// A credential the player must have.
+    #[serde(default)]
+    pub has_tag: Option<String>,
+    /// A credential the player must not have.
+    #[serde(default)]
+    pub lacks_tag: Option<String>,
+}
+
+/// E.g. "stress ≥ 61 and has Degree".
+impl fmt::Display for EffectCondition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts = Vec::new();
+        for (stat, range) in &self.stats {
+            if let Some(min) = range.min {
+                parts.push(format!("{} ≥ {}", stat, min));
+            }
+            if let Some(max) = range.max {
+                parts.push(format!("{} ≤ {}", stat, max));
+            }
+        }
+        if let Some(ref tag) = self.has_tag {
+            parts.push(format!("has {}", tag));
+        }
+        if let Some(ref tag) = self.lacks_tag {
+            parts.push(format!("lacks {}", tag));
+        }
+        f.write_str(&parts.join(" and "))
+    }
+}
+
+/// A stat effect that triggers N turns in the future.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DelayedEffect {
+    pub turns_until: u32,
+    pub effects: Vec<StatEffect>,
+}
+
+/// Which player stat is affected: one of the engine's own, or another stat from stats.json.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(from = "String", into = "String")]
+pub enum StatType {
+    Money,
+    Stress,
+    Support,
+    TimeSlots,
+    Credentials,
+    /// A stat defined only in stats.json, by ID.
+    Custom(String),
+}
+
+impl StatType {
+    /// The stat's ID as written in the data files.
+    pub fn id(&self) -> &str {
+        match self {
+            StatType::Money => "money",
+            StatType::Stress => "stress",
+            StatType::Support => "support",
+            StatType::TimeSlots => "timeSlots",
+            StatType::Credentials => "credentials",
+            StatType::Custom(id) => id,
+        }
+    }
+}
+
+/// Names a stat in logs: the engine's own by variant name (e.g. "TimeSlots"), others by ID.
+impl fmt::Display for StatType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StatType::Custom(id) => f.write_str(id),
+            other => write!(f, "{:?}", other),
+        }
+    }
+}
+
+impl From<String> for StatType {
+    fn from(id: String) -> Self {
+        match id.as_str() {
+            "money" => StatType::Money,
+            "stress" => StatType::Stress,
+            "support" => StatType::Support,
+            "timeSlots" => StatType::TimeSlots,
+            "credentials" => StatType::Credentials,
+            _ => StatType::Custom(id),
+        }
+    }
+}
+
+impl From<StatType> for String {
+    fn from(stat: StatType) -> Self {
+        match stat {
+            StatType::Custom(id) => id,
+            other => other.id().to_string(),
+        }
+    }
+}
+
+impl JsonSchema for StatType {
+    fn schema_name() -> Cow<'static, str> {
+        "StatType".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": "money, stress, support, timeSlots, credentials, or the id of a stat in stats.json.",
+        })
+    }
+}
+
+/// How a game deals cards from a stage's deck.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum DeckMode {
+    /// Each card is a fresh weighted draw from the event stream.
+    #[default]
+    Weighted,
+    /// The stage's cards are shuffled once, by rarity weight, when the stage starts and
+    /// dealt in that order. The order comes from the seed and stage alone, so games on
+    /// one seed deal the same sequence however often their streams were drawn from.
+    Shuffled,
+}
+
+/// Card rarity tier: one of the shipped tiers, or another tier from rarities.json.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(from = "String", into = "String")]
+pub enum Rarity {
+    Common,
+    Uncommon,
+    Rare,
+    Legendary,
+    /// A tier defined only in rarities.json, by ID.
+    Custom(String),
+}
+
+impl Rarity {
+    /// The tier's ID as written in the data files.
+    pub fn id(&self) -> &str {
+        match self {
+            Rarity::Common => "common",
+            Rarity::Uncommon => "uncommon",
+            Rarity::Rare => "rare",
+            Rarity::Legendary => "legendary",
+            Rarity::Custom(id) => id,
+        }
+    }
+}
+
+impl From<String> for Rarity {
+    fn from(id: String) -> Self {
+        match id.as_str() {
+            "common" => Rarity::Common,
+            "uncommon" => Rarity::Uncommon,
+            "rare" => Rarity::Rare,
+            "legendary" => Rarity::Legendary,
+            _ => Rarity::Custom(id),
+        }
+    }
+}
+
+impl From<Rarity> for String {
+    fn from(rarity: Rarity) -> Self {
+        match rarity {
+            Rarity::Custom(id) => id,
+            other => other.id().to_string(),
+        }
+    }
+}
+
+impl JsonSchema for Rarity {
+    fn schema_name() -> Cow<'static, str> {
+        "Rarity".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": "common, uncommon, rare, legendary, or the id of another tier in rarities.json.",
+        })
+    }
+}
@@ -0,0 +1,19 @@
+use schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+use super::Stage;
+
+/// A place the player can live, with its own bills and day-to-day stress.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HousingTier {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub stages: Vec<Stage>,
+    /// Monthly bills while living here (charged in Early Adult).
+    pub bills: i32,
+    /// Stress added each turn; negative values relieve stress.
+    pub stress_per_turn: i32,
+    /// One-time cost to move in from another place.
+    pub moving_cost: i32,
+}
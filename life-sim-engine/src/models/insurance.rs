@@ -0,0 +1,23 @@
+use schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+use super::Stage;
+
+/// Event category that insurance can respond to. Only cards tagged with it
+/// (plus one of a plan's covered categories) have their losses capped.
+pub const EMERGENCY_CATEGORY: &str = "emergency";
+
+/// An insurance plan, paid as a recurring premium, that caps the money lost
+/// to emergency events in the categories it covers.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct InsurancePlan {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub stages: Vec<Stage>,
+    /// Event categories this plan covers (e.g. "medical", "property").
+    pub covers: Vec<String>,
+    pub premium_per_turn: i32,
+    /// Most a covered event can cost the player; the plan pays the rest.
+    pub max_out_of_pocket: i32,
+}
@@ -0,0 +1,61 @@
+use std::collections::BTreeMap;
+use schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+use super::{Credentials, Stage};
+
+/// A job the player can hold for income and growth.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Job {
+    pub id: String,
+    pub title: String,
+    pub required_tags: Vec<String>,
+    /// Credentials needed at a minimum level, e.g. `{"Spanish": 2}` for Spanish II.
+    #[serde(default)]
+    pub required_levels: BTreeMap<String, u32>,
+    pub recommended_tags: Vec<String>,
+    pub pay_per_turn: i32,
+    pub stress_per_turn: i32,
+    pub growth_rate: u32,
+    #[serde(default)]
+    pub growth_tag: Option<String>,
+    pub stages: Vec<Stage>,
+    /// If non-empty, only players on one of these life paths can apply.
+    #[serde(default)]
+    pub paths: Vec<String>,
+    pub description: String,
+    /// The kind of work, e.g. "healthcare" or "trades", for endings that ask for one.
+    #[serde(default)]
+    pub family: Option<String>,
+    /// Promotion ladder above the entry-level position, lowest rung first.
+    #[serde(default)]
+    pub tiers: Vec<JobTier>,
+}
+
+impl Job {
+    /// The required credentials (at their levels) the player doesn't hold.
+    pub fn missing_requirements(&self, held: &Credentials) -> Vec<String> {
+        held.missing(&self.required_tags, &self.required_levels)
+    }
+}
+
+/// One rung of a job's career ladder.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JobTier {
+    pub title: String,
+    pub pay_per_turn: i32,
+    pub stress_per_turn: i32,
+    /// Turns spent on the previous rung before this promotion is offered.
+    pub min_tenure: u32,
+    #[serde(default)]
+    pub required_tags: Vec<String>,
+    #[serde(default)]
+    pub required_levels: BTreeMap<String, u32>,
+}
+
+impl JobTier {
+    pub fn missing_requirements(&self, held: &Credentials) -> Vec<String> {
+        held.missing(&self.required_tags, &self.required_levels)
+    }
+}
@@ -0,0 +1,20 @@
+use schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+use crate::models::Stage;
+use crate::models::ending::EndingConditions;
+
+/// An intermediate goal with a deadline, e.g. "Save $200 by the end of High School".
+/// Reached the first time its conditions hold at the end of a turn, up to the last turn
+/// of its stage.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Milestone {
+    pub id: String,
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    /// The stage the milestone must be reached by.
+    pub stage: Stage,
+    /// The same conditions an ending can set.
+    pub conditions: EndingConditions,
+}
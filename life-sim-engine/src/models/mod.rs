@@ -0,0 +1,45 @@
+pub mod action;
+pub mod decision;
+pub mod economy;
+pub mod education;
+pub mod ending;
+pub mod event;
+pub mod job;
+pub mod side_gig;
+pub mod insurance;
+pub mod housing;
+pub mod transport;
+pub mod stage;
+pub mod path;
+pub mod tutorial;
+pub mod scenario;
+pub mod stat;
+pub mod credential;
+pub mod rarity;
+pub mod special_effect;
+pub mod quiz;
+pub mod milestone;
+pub mod seed;
+
+// Re-export common types
+pub use action::{Action, ActionRequirements, StateFlag};
+pub use decision::{DeferConsequence, Decision};
+pub use economy::EconomyConfig;
+pub use education::EducationProgram;
+pub use ending::Ending;
+pub use event::{DeckMode, EventCard, Rarity};
+pub use job::Job;
+pub use side_gig::SideGig;
+pub use insurance::InsurancePlan;
+pub use housing::HousingTier;
+pub use transport::TransportOption;
+pub use stage::{Stage, StageCalendar, StageDefinition};
+pub use path::LifePath;
+pub use tutorial::TutorialStep;
+pub use scenario::Scenario;
+pub use stat::StatDefinition;
+pub use credential::{Credential, Credentials};
+pub use rarity::RarityTier;
+pub use special_effect::SpecialEffect;
+pub use milestone::Milestone;
+pub use seed::CuratedSeed;
@@ -0,0 +1,12 @@
+use schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+/// A mutually exclusive life track chosen after high school (college, trade,
+/// workforce, military). Decisions, events, jobs, and endings can be limited to it.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LifePath {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+}
@@ -0,0 +1,26 @@
+use schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+/// The `answer` of a question that was given without one.
+pub const NO_ANSWER: usize = usize::MAX;
+
+fn no_answer() -> usize {
+    NO_ANSWER
+}
+
+/// A multiple-choice check attached to an event card or decision, answered after the
+/// turn it comes up in. The answer and explanation are never sent to players with the
+/// question; they come back once the question is answered.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QuizQuestion {
+    pub prompt: String,
+    pub choices: Vec<String>,
+    /// Index of the correct choice. A card read back from a saved game doesn't carry it,
+    /// since answers are checked against the data set, so it's `NO_ANSWER` there.
+    #[serde(default = "no_answer", skip_serializing)]
+    pub answer: usize,
+    /// Why the answer is right, shown after answering.
+    #[serde(default, skip_serializing)]
+    pub explanation: Option<String>,
+}
@@ -0,0 +1,28 @@
+use schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+/// A card rarity tier and how heavily its cards weigh in the event draw. A card's chance
+/// is its tier's weight over the total weight of every card that could be drawn.
+/// The player's luck scales each weight by the tier's `luck_bonus`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RarityTier {
+    /// The value event cards give as `rarity`, e.g. "legendary".
+    pub id: String,
+    pub label: String,
+    /// Relative draw weight; must be above 0.
+    pub weight: f64,
+    /// How much each point of luck multiplies the weight by, on top of 1: at luck 2 and
+    /// a bonus of 0.25 the weight is 1.25² times its base, at luck -2 1.25⁻² times. Must be
+    /// above -1; 0 leaves the tier unaffected by luck.
+    #[serde(default)]
+    pub luck_bonus: f64,
+}
+
+impl RarityTier {
+    /// The tier's draw weight at a luck value. Luck 0 leaves it as written, so players with
+    /// the same luck on the same seed draw the same cards.
+    pub fn weight_at_luck(&self, luck: i32) -> f64 {
+        self.weight * (1.0 + self.luck_bonus).powi(luck)
+    }
+}
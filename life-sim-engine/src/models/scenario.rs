@@ -0,0 +1,41 @@
+use schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+use crate::models::Stage;
+
+/// A preconfigured start for a targeted lesson, e.g. starting broke in Early Adult
+/// with a baby on the way. Picked with `scenario` on new_game.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Scenario {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    /// Stage the game starts in, at its first turn. Defaults to the first stage.
+    #[serde(default)]
+    pub stage: Option<Stage>,
+    /// Starting stats; anything left out keeps its usual starting value.
+    #[serde(default)]
+    pub stats: ScenarioStats,
+    /// Credentials the player starts with.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Life path the player is already on.
+    #[serde(default)]
+    pub path: Option<String>,
+    /// Decisions never offered in this scenario.
+    #[serde(default)]
+    pub locked_decisions: Vec<String>,
+}
+
+/// Starting values a scenario overrides.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ScenarioStats {
+    pub money: Option<i32>,
+    pub stress: Option<i32>,
+    pub support: Option<i32>,
+    pub savings: Option<i32>,
+    pub monthly_bills: Option<i32>,
+    pub emergency_fund: Option<i32>,
+    pub student_loan: Option<i32>,
+}
@@ -0,0 +1,21 @@
+use schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+use crate::models::DeckMode;
+
+/// A playtested seed from the catalog, labeled with what its deck is like, so a teacher
+/// can pick a known deck order instead of a random seed.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CuratedSeed {
+    pub id: String,
+    /// The seed string to pass to new_game or a class.
+    pub seed: String,
+    /// Short tag for pickers, e.g. "Gentle intro".
+    pub label: String,
+    #[serde(default)]
+    pub description: String,
+    /// The deck mode the seed was playtested in. Only a shuffled deck keeps the described
+    /// order whatever else the game draws.
+    #[serde(default)]
+    pub deck_mode: DeckMode,
+}
@@ -1,19 +1,19 @@
+use schemars::JsonSchema;
 use serde::{Serialize, Deserialize};
 use super::Stage;
 
-/// A job the player can hold for income and growth.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// A side gig held alongside the primary job, paid every turn at the cost of time.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct Job {
+pub struct SideGig {
     pub id: String,
     pub title: String,
+    pub description: String,
+    pub stages: Vec<Stage>,
+    #[serde(default)]
     pub required_tags: Vec<String>,
-    pub recommended_tags: Vec<String>,
     pub pay_per_turn: i32,
     pub stress_per_turn: i32,
-    pub growth_rate: u32,
-    #[serde(default)]
-    pub growth_tag: Option<String>,
-    pub stages: Vec<Stage>,
-    pub description: String,
+    /// Time slots the gig occupies every turn while held.
+    pub time_cost: u32,
 }
@@ -0,0 +1,13 @@
+use schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+/// A scripted hook an action runs after its effects, named by the action's `specialEffect`.
+/// The script is Rhai, and sees the game only through a `state` object (see
+/// `engine::scripting`) and the `economy` settings.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SpecialEffect {
+    pub id: String,
+    pub description: String,
+    pub script: String,
+}
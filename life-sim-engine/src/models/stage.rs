@@ -0,0 +1,95 @@
+use std::borrow::Cow;
+use schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+/// A life stage, identified by its kebab-case ID (e.g. "early-adult").
+/// The built-in stages are available as constants; any other ID defined in
+/// stages.json works the same way.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash)]
+#[serde(transparent)]
+pub struct Stage(Cow<'static, str>);
+
+impl Stage {
+    pub const MIDDLE_SCHOOL: Stage = Stage(Cow::Borrowed("middle-school"));
+    pub const HIGH_SCHOOL: Stage = Stage(Cow::Borrowed("high-school"));
+    pub const POST_HIGH: Stage = Stage(Cow::Borrowed("post-high"));
+    pub const EARLY_ADULT: Stage = Stage(Cow::Borrowed("early-adult"));
+
+    pub fn new(id: impl Into<String>) -> Self {
+        Stage(Cow::Owned(id.into()))
+    }
+
+    pub fn id(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for Stage {
+    /// Title-cases the ID: "early-adult" → "Early Adult".
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let words: Vec<String> = self.0.split('-')
+            .map(|w| {
+                let mut chars = w.chars();
+                match chars.next() {
+                    Some(c) => c.to_uppercase().chain(chars).collect(),
+                    None => String::new(),
+                }
+            })
+            .collect();
+        write!(f, "{}", words.join(" "))
+    }
+}
+
+/// A stage definition from stages.json. Stages run in file order.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StageDefinition {
+    pub id: Stage,
+    /// Display name shown to players.
+    pub name: String,
+    pub start_turn: u32,
+    /// Last turn of the stage (inclusive).
+    pub end_turn: u32,
+    /// Time slots the player starts the stage with.
+    pub time_slots: u32,
+    /// Whether monthly bills, loan payments, and debt interest apply.
+    #[serde(default)]
+    pub pays_bills: bool,
+    /// Event cards dealt each turn. Later stages can deal more than one to feel busier.
+    #[serde(default = "default_events_per_turn")]
+    pub events_per_turn: u32,
+    /// Where the stage sits in the player's life; without it, the stage's turns have no date.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub calendar: Option<StageCalendar>,
+}
+
+/// How a stage's turns map onto months and the player's age.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StageCalendar {
+    /// The player's age on the stage's first turn.
+    pub start_age: u32,
+    /// The month of the stage's first turn, 1 (January) to 12.
+    pub start_month: u32,
+    /// Months that pass between one turn and the next.
+    pub months_per_turn: u32,
+}
+
+fn default_events_per_turn() -> u32 {
+    1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stage_round_trip() {
+        let stage: Stage = serde_json::from_str("\"gap-year\"").unwrap();
+        assert_eq!(stage, Stage::new("gap-year"));
+        assert_eq!(stage.to_string(), "Gap Year");
+        assert_eq!(serde_json::to_string(&Stage::EARLY_ADULT).unwrap(), "\"early-adult\"");
+        let built_in: Stage = serde_json::from_str("\"post-high\"").unwrap();
+        assert_eq!(built_in, Stage::POST_HIGH);
+    }
+}
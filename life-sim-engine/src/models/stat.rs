@@ -0,0 +1,37 @@
+use schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+/// Stats the engine reads by name; stats.json must define each of them.
+pub const ENGINE_STATS: [&str; 4] = ["money", "stress", "support", "timeSlots"];
+
+/// The stat that skews card rarity weights (see `RarityTier::luck_bonus`). Optional: a
+/// game without it plays at luck 0.
+pub const LUCK_STAT: &str = "luck";
+
+/// A player stat: how it's shown, the range it's kept in, and where it starts.
+/// Stats beyond the engine's own are tracked in `GameState::stats` and changed by
+/// effects that name them.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StatDefinition {
+    pub id: String,
+    pub label: String,
+    /// Shown before the label in feedback, e.g. "😰 Stress +5".
+    pub icon: String,
+    /// Lowest and highest values; left out, the stat is unbounded that way (money can go negative).
+    #[serde(default)]
+    pub min: Option<i32>,
+    #[serde(default)]
+    pub max: Option<i32>,
+    /// Value at the start of a game. Time slots are reset by each stage instead.
+    #[serde(default)]
+    pub start: i32,
+}
+
+impl StatDefinition {
+    /// Keep a value within the stat's range.
+    pub fn clamp(&self, value: i32) -> i32 {
+        let value = self.min.map_or(value, |min| value.max(min));
+        self.max.map_or(value, |max| value.min(max))
+    }
+}
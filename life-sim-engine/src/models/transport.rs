@@ -0,0 +1,27 @@
+use schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+use super::Stage;
+
+/// A way of getting around, owned until the player switches to another.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TransportOption {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub stages: Vec<Stage>,
+    /// One-time cost to buy in.
+    pub purchase_cost: i32,
+    /// Gas, fares, insurance, upkeep — charged every Phase 4.
+    pub cost_per_turn: i32,
+    pub stress_per_turn: i32,
+    /// Tag held while owned (e.g. "Reliable Car" for jobs that need one).
+    #[serde(default)]
+    pub grants_tag: Option<String>,
+    /// Chance each turn (0.0–1.0) that the breakdown event replaces the normal draw.
+    #[serde(default)]
+    pub breakdown_chance: f64,
+    /// Event card ID drawn when a breakdown happens.
+    #[serde(default)]
+    pub breakdown_event: Option<String>,
+}
@@ -0,0 +1,17 @@
+use schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+/// One scripted turn of tutorial mode. A tutorial game draws the step's event instead
+/// of a random card and adds its messages to the turn's feedback.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TutorialStep {
+    pub id: String,
+    /// The turn this step scripts.
+    pub turn: u32,
+    /// Event card drawn this turn instead of a random one.
+    #[serde(default)]
+    pub event_id: Option<String>,
+    /// Guidance shown after the turn's results, in order.
+    pub messages: Vec<String>,
+}
@@ -0,0 +1,277 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Name of the file listing every remote data file and its checksum.
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// Seconds to wait on each remote request before giving up.
+const REQUEST_TIMEOUT_SECS: u64 = 15;
+
+/// The remote content set: each data file name mapped to its SHA-256 (hex).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Manifest {
+    pub files: BTreeMap<String, String>,
+}
+
+/// Pull the content set at `base_url` into `cache_dir`, verifying every file
+/// against the manifest's checksums. Files already cached with the right
+/// checksum are not downloaded again. The new set is assembled beside the cache
+/// and only moved into place once every file verifies, so a failed sync never
+/// leaves a half-updated cache. If the server can't be reached or a file won't
+/// download, a previously synced cache that still verifies is used instead.
+pub fn sync(base_url: &str, cache_dir: &Path) -> Result<(), String> {
+    let base_url = base_url.trim_end_matches('/');
+    let manifest_bytes = match fetch(&format!("{}/{}", base_url, MANIFEST_FILE)) {
+        Ok(bytes) => bytes,
+        Err(e) => return use_cache(cache_dir, e),
+    };
+    let manifest: Manifest = serde_json::from_slice(&manifest_bytes)
+        .map_err(|e| format!("Invalid {} at {}: {}", MANIFEST_FILE, base_url, e))?;
+    check_file_names(&manifest)?;
+
+    let staging = beside(cache_dir, "partial");
+    let _ = std::fs::remove_dir_all(&staging);
+    std::fs::create_dir_all(&staging)
+        .map_err(|e| format!("Failed to create {}: {}", staging.display(), e))?;
+    let downloaded = match download(base_url, &manifest, cache_dir, &staging) {
+        Ok(downloaded) => downloaded,
+        Err(e) => {
+            let _ = std::fs::remove_dir_all(&staging);
+            return use_cache(cache_dir, e);
+        }
+    };
+    std::fs::write(staging.join(MANIFEST_FILE), &manifest_bytes)
+        .map_err(|e| format!("Failed to write {} cache: {}", MANIFEST_FILE, e))?;
+    replace_dir(&staging, cache_dir)?;
+
+    tracing::info!(
+        "Synced {} data files from {} ({} downloaded, {} cached)",
+        manifest.files.len(), base_url, downloaded, manifest.files.len() - downloaded
+    );
+    Ok(())
+}
+
+/// Fill `staging` with every file in the manifest, copying ones already cached with the
+/// right checksum and fetching the rest. Returns how many were fetched.
+fn download(base_url: &str, manifest: &Manifest, cache_dir: &Path, staging: &Path) -> Result<usize, String> {
+    let mut downloaded = 0;
+    for (file, checksum) in &manifest.files {
+        let bytes = match std::fs::read(cache_dir.join(file)) {
+            Ok(bytes) if sha256_hex(&bytes).eq_ignore_ascii_case(checksum) => bytes,
+            _ => {
+                let bytes = fetch(&format!("{}/{}", base_url, file))?;
+                let actual = sha256_hex(&bytes);
+                if !actual.eq_ignore_ascii_case(checksum) {
+                    return Err(format!("Checksum mismatch for {}: expected {}, got {}", file, checksum, actual));
+                }
+                downloaded += 1;
+                bytes
+            }
+        };
+        let path = staging.join(file);
+        std::fs::write(&path, &bytes)
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    }
+    Ok(downloaded)
+}
+
+/// Fall back to the previously synced cache after `error`, if it still verifies.
+fn use_cache(cache_dir: &Path, error: String) -> Result<(), String> {
+    let cached = read_cached_manifest(cache_dir)
+        .ok_or_else(|| format!("{} (and no cached copy in {})", error, cache_dir.display()))?;
+    verify_cache(&cached, cache_dir)
+        .map_err(|bad| format!("{} (and the cached copy is incomplete: {})", error, bad))?;
+    tracing::warn!("{}; using cached data in {}", error, cache_dir.display());
+    Ok(())
+}
+
+/// A sibling of `dir` with `suffix` added to its name, e.g. `data-cache.partial`.
+fn beside(dir: &Path, suffix: &str) -> PathBuf {
+    let name = dir.file_name().map_or_else(|| "data-cache".into(), |n| n.to_string_lossy());
+    dir.with_file_name(format!("{}.{}", name, suffix))
+}
+
+/// Swap the fully verified `staging` directory in for `cache_dir`, putting the old cache
+/// back if the move fails.
+fn replace_dir(staging: &Path, cache_dir: &Path) -> Result<(), String> {
+    let old = beside(cache_dir, "old");
+    let _ = std::fs::remove_dir_all(&old);
+    let had_cache = cache_dir.exists();
+    if had_cache {
+        std::fs::rename(cache_dir, &old)
+            .map_err(|e| format!("Failed to replace {}: {}", cache_dir.display(), e))?;
+    }
+    if let Err(e) = std::fs::rename(staging, cache_dir) {
+        if had_cache {
+            let _ = std::fs::rename(&old, cache_dir);
+        }
+        return Err(format!("Failed to move synced data into {}: {}", cache_dir.display(), e));
+    }
+    let _ = std::fs::remove_dir_all(&old);
+    Ok(())
+}
+
+/// SHA-256 of some bytes as lowercase hex.
+pub fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn fetch(url: &str) -> Result<Vec<u8>, String> {
+    let response = ureq::get(url)
+        .timeout(std::time::Duration::from_secs(REQUEST_TIMEOUT_SECS))
+        .call()
+        .map_err(|e| format!("Failed to fetch {}: {}", url, e))?;
+    let mut bytes = Vec::new();
+    std::io::Read::read_to_end(&mut response.into_reader(), &mut bytes)
+        .map_err(|e| format!("Failed to read {}: {}", url, e))?;
+    Ok(bytes)
+}
+
+/// Manifest entries must be plain file names so they can't escape the cache directory.
+fn check_file_names(manifest: &Manifest) -> Result<(), String> {
+    for file in manifest.files.keys() {
+        let plain = Path::new(file).file_name().and_then(|n| n.to_str()) == Some(file.as_str());
+        if !plain || file == MANIFEST_FILE {
+            return Err(format!("{} lists an invalid file name \"{}\"", MANIFEST_FILE, file));
+        }
+    }
+    Ok(())
+}
+
+fn cached_checksum(path: &Path) -> Option<String> {
+    std::fs::read(path).ok().map(|bytes| sha256_hex(&bytes))
+}
+
+fn read_cached_manifest(cache_dir: &Path) -> Option<Manifest> {
+    let bytes = std::fs::read(cache_dir.join(MANIFEST_FILE)).ok()?;
+    let manifest = serde_json::from_slice(&bytes).ok()?;
+    check_file_names(&manifest).ok()?;
+    Some(manifest)
+}
+
+/// Confirm every file in the manifest is cached with its expected checksum.
+/// Returns the first file that is missing or doesn't match.
+fn verify_cache(manifest: &Manifest, cache_dir: &Path) -> Result<(), String> {
+    for (file, checksum) in &manifest.files {
+        let actual = cached_checksum(&cache_dir.join(file));
+        if !actual.is_some_and(|a| a.eq_ignore_ascii_case(checksum)) {
+            return Err(file.clone());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+
+    /// Serve fixed files over HTTP for `requests` requests, then stop.
+    fn serve(files: BTreeMap<String, Vec<u8>>, requests: usize) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for stream in listener.incoming().take(requests) {
+                let mut stream = stream.unwrap();
+                let mut request_line = String::new();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                reader.read_line(&mut request_line).unwrap();
+                let mut header = String::new();
+                while reader.read_line(&mut header).unwrap() > 2 {
+                    header.clear();
+                }
+                let path = request_line.split_whitespace().nth(1).unwrap_or("/").trim_start_matches('/');
+                let response = match files.get(path) {
+                    Some(body) => [format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n", body.len()).into_bytes(), body.clone()].concat(),
+                    None => b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_vec(),
+                };
+                stream.write_all(&response).unwrap();
+            }
+        });
+        url
+    }
+
+    fn temp_dir(label: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("life-sim-remote-{}-{}", label, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn manifest_for(files: &BTreeMap<String, Vec<u8>>) -> Vec<u8> {
+        let manifest = Manifest {
+            files: files.iter().map(|(name, body)| (name.clone(), sha256_hex(body))).collect(),
+        };
+        serde_json::to_vec(&manifest).unwrap()
+    }
+
+    #[test]
+    fn test_sync_downloads_then_reuses_cache() {
+        let mut files = BTreeMap::new();
+        files.insert("paths.json".to_string(), br#"[{ "id": "path_arts", "title": "Arts" }]"#.to_vec());
+        let mut served = files.clone();
+        served.insert(MANIFEST_FILE.to_string(), manifest_for(&files));
+        let cache = temp_dir("sync");
+
+        // First sync: manifest + one file; second sync: manifest only
+        let url = serve(served, 3);
+        sync(&url, &cache).unwrap();
+        assert_eq!(std::fs::read(cache.join("paths.json")).unwrap(), files["paths.json"]);
+        sync(&url, &cache).unwrap();
+
+        // Server gone: the verified cache is used
+        sync("http://127.0.0.1:1", &cache).unwrap();
+        std::fs::write(cache.join("paths.json"), "[]").unwrap();
+        let err = sync("http://127.0.0.1:1", &cache).unwrap_err();
+        std::fs::remove_dir_all(&cache).unwrap();
+        assert!(err.contains("cached copy is incomplete: paths.json"), "{}", err);
+    }
+
+    #[test]
+    fn test_failed_download_keeps_the_verified_cache() {
+        let mut files = BTreeMap::new();
+        files.insert("paths.json".to_string(), b"[]".to_vec());
+        let mut served = files.clone();
+        served.insert(MANIFEST_FILE.to_string(), manifest_for(&files));
+        let cache = temp_dir("partial");
+        sync(&serve(served, 2), &cache).unwrap();
+
+        // A new set whose file 404s: the old cache is used, untouched
+        let mut updated = BTreeMap::new();
+        updated.insert("paths.json".to_string(), br#"[{ "id": "path_arts", "title": "Arts" }]"#.to_vec());
+        let mut served = BTreeMap::new();
+        served.insert(MANIFEST_FILE.to_string(), manifest_for(&updated));
+        sync(&serve(served.clone(), 2), &cache).unwrap();
+        assert_eq!(std::fs::read(cache.join("paths.json")).unwrap(), b"[]");
+        assert_eq!(std::fs::read(cache.join(MANIFEST_FILE)).unwrap(), manifest_for(&files));
+        assert!(!beside(&cache, "partial").exists(), "The half-built set is cleaned up");
+
+        // With no cache to fall back on, the download error is reported
+        std::fs::remove_dir_all(&cache).unwrap();
+        let err = sync(&serve(served, 2), &cache).unwrap_err();
+        assert!(err.contains("paths.json") && err.contains("no cached copy"), "{}", err);
+        assert!(!cache.exists());
+    }
+
+    #[test]
+    fn test_sync_rejects_bad_checksums_and_paths() {
+        let mut files = BTreeMap::new();
+        files.insert("paths.json".to_string(), b"[]".to_vec());
+        let mut served = files.clone();
+        served.insert(MANIFEST_FILE.to_string(), manifest_for(&files));
+        served.insert("paths.json".to_string(), b"[ tampered ]".to_vec());
+        let cache = temp_dir("tampered");
+        let err = sync(&serve(served, 2), &cache).unwrap_err();
+        assert!(err.contains("Checksum mismatch for paths.json"), "{}", err);
+
+        let mut escaping = BTreeMap::new();
+        escaping.insert("../outside.json".to_string(), b"[]".to_vec());
+        let mut served = BTreeMap::new();
+        served.insert(MANIFEST_FILE.to_string(), manifest_for(&escaping));
+        let err = sync(&serve(served, 1), &cache).unwrap_err();
+        let _ = std::fs::remove_dir_all(&cache);
+        assert!(err.contains("invalid file name"), "{}", err);
+    }
+}
@@ -0,0 +1,636 @@
+use std::collections::HashSet;
+use crate::data_loader::GameData;
+use crate::engine::calendar;
+use crate::engine::turn_runner;
+use crate::models::{Stage, StageDefinition, StatDefinition};
+use crate::models::event::{StatEffect, StatType};
+use crate::models::quiz::{QuizQuestion, NO_ANSWER};
+use crate::models::stat::ENGINE_STATS;
+
+/// Cross-reference checks over a fully loaded data set.
+/// Returns one human-readable message per problem (empty if the data is sound).
+pub fn validate(data: &GameData) -> Vec<String> {
+    let mut errors = Vec::new();
+    check_stages(&data.stages, &mut errors);
+
+    let stage_ids: HashSet<&Stage> = data.stages.iter().map(|s| &s.id).collect();
+    let job_ids = unique_ids("jobs.json", data.jobs.iter().map(|j| j.id.as_str()), &mut errors);
+    let event_ids = unique_ids("events.json", data.events.iter().map(|e| e.id.as_str()), &mut errors);
+    let program_ids = unique_ids("education.json", data.education.iter().map(|p| p.id.as_str()), &mut errors);
+    let housing_ids = unique_ids("housing.json", data.housing.iter().map(|h| h.id.as_str()), &mut errors);
+    let path_ids = unique_ids("paths.json", data.paths.iter().map(|p| p.id.as_str()), &mut errors);
+    let decision_ids = unique_ids("decisions.json", data.decisions.iter().map(|d| d.id.as_str()), &mut errors);
+    unique_ids("actions.json", data.actions.iter().map(|a| a.id.as_str()), &mut errors);
+    unique_ids("endings.json", data.endings.iter().map(|e| e.id.as_str()), &mut errors);
+    unique_ids("side_gigs.json", data.side_gigs.iter().map(|g| g.id.as_str()), &mut errors);
+    unique_ids("insurance.json", data.insurance.iter().map(|p| p.id.as_str()), &mut errors);
+    unique_ids("transport.json", data.transport.iter().map(|t| t.id.as_str()), &mut errors);
+    unique_ids("tutorial.json", data.tutorial.iter().map(|s| s.id.as_str()), &mut errors);
+    unique_ids("scenarios.json", data.scenarios.iter().map(|s| s.id.as_str()), &mut errors);
+    unique_ids("milestones.json", data.milestones.iter().map(|m| m.id.as_str()), &mut errors);
+    unique_ids("seeds.json", data.seeds.iter().map(|s| s.id.as_str()), &mut errors);
+    for s in data.seeds.iter().filter(|s| s.seed.trim().is_empty()) {
+        errors.push(format!("seeds.json [{}]: seed must not be blank", s.id));
+    }
+    let special_effect_ids = unique_ids("special_effects.json", data.special_effects.iter().map(|s| s.id.as_str()), &mut errors);
+    let stat_ids = unique_ids("stats.json", data.stats.iter().map(|s| s.id.as_str()), &mut errors);
+    check_stats(&data.stats, &stat_ids, &mut errors);
+    let rarity_ids = unique_ids("rarities.json", data.rarities.iter().map(|r| r.id.as_str()), &mut errors);
+    for tier in &data.rarities {
+        if !(tier.weight > 0.0 && tier.weight.is_finite()) {
+            errors.push(format!("rarities.json [{}]: weight {} must be above 0", tier.id, tier.weight));
+        }
+        if !(tier.luck_bonus > -1.0 && tier.luck_bonus.is_finite()) {
+            errors.push(format!("rarities.json [{}]: luckBonus {} must be above -1", tier.id, tier.luck_bonus));
+        }
+    }
+
+    let mut check_ref = |context: String, kind: &str, id: &str, known: &HashSet<&str>| {
+        if !known.contains(id) {
+            errors.push(format!("{}: unknown {} \"{}\"", context, kind, id));
+        }
+    };
+
+    for event in &data.events {
+        let context = format!("events.json [{}]", event.id);
+        check_ref(context.clone(), "rarity", event.rarity.id(), &rarity_ids);
+        for path in &event.paths {
+            check_ref(context.clone(), "path", path, &path_ids);
+        }
+        for option in &event.options {
+            let context = format!("{} \"{}\"", context, option.label);
+            if let Some(ref job) = option.sets_job {
+                check_ref(context.clone(), "job", job, &job_ids);
+            }
+            if let Some(ref path) = option.sets_path {
+                check_ref(context, "path", path, &path_ids);
+            }
+        }
+    }
+    for decision in &data.decisions {
+        let context = format!("decisions.json [{}]", decision.id);
+        if let Some(ref path) = decision.path {
+            check_ref(context.clone(), "path", path, &path_ids);
+        }
+        for option in &decision.options {
+            let context = format!("{} \"{}\"", context, option.label);
+            if let Some(ref job) = option.applies_for {
+                check_ref(context.clone(), "job", job, &job_ids);
+            }
+            if let Some(ref program) = option.enrolls_in {
+                check_ref(context.clone(), "education program", program, &program_ids);
+            }
+            if let Some(ref housing) = option.moves_into {
+                check_ref(context.clone(), "housing", housing, &housing_ids);
+            }
+            if let Some(ref path) = option.sets_path {
+                check_ref(context.clone(), "path", path, &path_ids);
+            }
+        }
+    }
+    for job in &data.jobs {
+        for path in &job.paths {
+            check_ref(format!("jobs.json [{}]", job.id), "path", path, &path_ids);
+        }
+    }
+    let job_families: HashSet<&str> = data.jobs.iter().filter_map(|j| j.family.as_deref()).collect();
+    let conditions = data.endings.iter().map(|e| (format!("endings.json [{}]", e.id), &e.conditions))
+        .chain(data.milestones.iter().map(|m| (format!("milestones.json [{}]", m.id), &m.conditions)));
+    for (context, conditions) in conditions {
+        if let Some(ref path) = conditions.path {
+            check_ref(context.clone(), "path", path, &path_ids);
+        }
+        if let Some(ref family) = conditions.job_family {
+            check_ref(context, "job family", family, &job_families);
+        }
+    }
+    for option in &data.transport {
+        if let Some(ref event) = option.breakdown_event {
+            check_ref(format!("transport.json [{}]", option.id), "breakdown event", event, &event_ids);
+        }
+    }
+    for step in &data.tutorial {
+        if let Some(ref event) = step.event_id {
+            check_ref(format!("tutorial.json [{}]", step.id), "event", event, &event_ids);
+        }
+    }
+    for scenario in &data.scenarios {
+        let context = format!("scenarios.json [{}]", scenario.id);
+        if let Some(ref path) = scenario.path {
+            check_ref(context.clone(), "path", path, &path_ids);
+        }
+        for decision in &scenario.locked_decisions {
+            check_ref(context.clone(), "decision", decision, &decision_ids);
+        }
+    }
+
+    for job in &data.jobs {
+        let tier_levels = job.tiers.iter().flat_map(|t| &t.required_levels);
+        for (tag, _) in job.required_levels.iter().chain(tier_levels).filter(|(_, &level)| level == 0) {
+            errors.push(format!("jobs.json [{}]: required level of {} must be 1 or more", job.id, tag));
+        }
+    }
+
+    // Every stage reference must name a defined stage
+    let mut check_stage = |context: String, stage: &Stage| {
+        if !stage_ids.contains(stage) {
+            errors.push(format!("{}: unknown stage \"{}\"", context, stage.id()));
+        }
+    };
+    for e in &data.events {
+        e.stages.iter().for_each(|s| check_stage(format!("events.json [{}]", e.id), s));
+    }
+    for a in &data.actions {
+        a.stages.iter().for_each(|s| check_stage(format!("actions.json [{}]", a.id), s));
+    }
+    for d in &data.decisions {
+        check_stage(format!("decisions.json [{}]", d.id), &d.stage);
+    }
+    for m in &data.milestones {
+        check_stage(format!("milestones.json [{}]", m.id), &m.stage);
+    }
+    for j in &data.jobs {
+        j.stages.iter().for_each(|s| check_stage(format!("jobs.json [{}]", j.id), s));
+    }
+    for p in &data.education {
+        p.stages.iter().for_each(|s| check_stage(format!("education.json [{}]", p.id), s));
+    }
+    for g in &data.side_gigs {
+        g.stages.iter().for_each(|s| check_stage(format!("side_gigs.json [{}]", g.id), s));
+    }
+    for p in &data.insurance {
+        p.stages.iter().for_each(|s| check_stage(format!("insurance.json [{}]", p.id), s));
+    }
+    for h in &data.housing {
+        h.stages.iter().for_each(|s| check_stage(format!("housing.json [{}]", h.id), s));
+    }
+    for t in &data.transport {
+        t.stages.iter().for_each(|s| check_stage(format!("transport.json [{}]", t.id), s));
+    }
+    for s in &data.scenarios {
+        s.stage.iter().for_each(|stage| check_stage(format!("scenarios.json [{}]", s.id), stage));
+    }
+
+    // Decisions must be scheduled inside their stage
+    for d in &data.decisions {
+        if let Some(stage) = turn_runner::stage_def(&data.stages, &d.stage) {
+            if d.turn < stage.start_turn || d.turn > stage.end_turn {
+                errors.push(format!(
+                    "decisions.json [{}]: turn {} is outside {} (turns {}-{})",
+                    d.id, d.turn, stage.name, stage.start_turn, stage.end_turn
+                ));
+            }
+        }
+    }
+
+    // Nobody moves up into the first stage, so no transition card can open it
+    if let Some(first) = data.stages.first() {
+        for e in data.events.iter().filter(|e| e.transition && e.stages.contains(&first.id)) {
+            errors.push(format!("events.json [{}]: transition card can't open the first stage, {}", e.id, first.name));
+        }
+    }
+
+    // A seasonal card needs one of its months to come up in one of its stages
+    for e in data.events.iter().filter(|e| !e.months.is_empty()) {
+        if let Some(month) = e.months.iter().find(|m| !(1..=12).contains(*m)) {
+            errors.push(format!("events.json [{}]: month {} isn't a month (1-12)", e.id, month));
+            continue;
+        }
+        let dealt = data.stages.iter()
+            .filter(|s| e.stages.contains(&s.id))
+            .flat_map(|s| (s.start_turn..=s.end_turn).filter_map(|turn| calendar::date(&data.stages, &s.id, turn)))
+            .any(|date| e.months.contains(&date.month));
+        if !dealt {
+            errors.push(format!("events.json [{}]: none of its stages has a turn in its months, so it's never dealt", e.id));
+        }
+    }
+
+    // Tutorial steps script distinct turns, and a scripted card must suit that turn's stage
+    let mut scripted_turns = HashSet::new();
+    for step in &data.tutorial {
+        let context = format!("tutorial.json [{}]", step.id);
+        if !scripted_turns.insert(step.turn) {
+            errors.push(format!("{}: turn {} is already scripted", context, step.turn));
+        }
+        let stage = data.stages.iter().find(|s| (s.start_turn..=s.end_turn).contains(&step.turn));
+        let event = step.event_id.as_ref().and_then(|id| data.events.iter().find(|e| e.id == *id));
+        match (stage, event) {
+            (None, _) => errors.push(format!("{}: turn {} is outside every stage", context, step.turn)),
+            (Some(stage), Some(event)) if !event.stages.contains(&stage.id) => errors.push(format!(
+                "{}: event \"{}\" isn't drawn in {}", context, event.id, stage.name
+            )),
+            _ => {}
+        }
+    }
+
+    // Effects must be complete and engine hooks must exist
+    for a in &data.actions {
+        check_effects(&format!("actions.json [{}]", a.id), &a.effects, &stat_ids, &mut errors);
+        if a.max_uses == Some(0) {
+            errors.push(format!("actions.json [{}]: maxUses must be at least 1", a.id));
+        }
+        for stat in a.requires.iter().flat_map(|r| r.min_stats.keys()) {
+            if !stat_ids.contains(stat.as_str()) {
+                errors.push(format!("actions.json [{}]: requires unknown stat \"{}\"", a.id, stat));
+            }
+        }
+        if let Some(ref special) = a.special_effect {
+            if !special_effect_ids.contains(special.as_str()) {
+                errors.push(format!("actions.json [{}]: unknown specialEffect \"{}\"", a.id, special));
+            }
+        }
+    }
+    for d in &data.decisions {
+        for o in &d.options {
+            check_effects(&format!("decisions.json [{}] \"{}\"", d.id, o.label), &o.effects, &stat_ids, &mut errors);
+        }
+        if let Some(ref quiz) = d.quiz {
+            check_quiz(&format!("decisions.json [{}]", d.id), quiz, &mut errors);
+        }
+        if let Some(ref deferral) = d.defer_consequence {
+            let context = format!("decisions.json [{}] deferConsequence", d.id);
+            check_effects(&context, &deferral.effects, &stat_ids, &mut errors);
+            if deferral.returns_after == Some(0) {
+                errors.push(format!("{}: returnsAfter must be at least 1", context));
+            }
+        }
+    }
+    for e in &data.events {
+        for o in &e.options {
+            let context = format!("events.json [{}] \"{}\"", e.id, o.label);
+            check_effects(&context, &o.effects, &stat_ids, &mut errors);
+            for delayed in o.delayed_effects.iter().flatten() {
+                check_effects(&context, &delayed.effects, &stat_ids, &mut errors);
+            }
+        }
+        if let Some(ref quiz) = e.quiz {
+            check_quiz(&format!("events.json [{}]", e.id), quiz, &mut errors);
+        }
+    }
+
+    errors
+}
+
+/// Stages must have unique IDs and contiguous, non-empty turn ranges.
+fn check_stages(stages: &[StageDefinition], errors: &mut Vec<String>) {
+    if stages.is_empty() {
+        errors.push("stages.json: no stages defined".to_string());
+    }
+    unique_ids("stages.json", stages.iter().map(|s| s.id.id()), errors);
+    for stage in stages {
+        if stage.start_turn > stage.end_turn {
+            errors.push(format!(
+                "stages.json [{}]: startTurn {} is after endTurn {}",
+                stage.id.id(), stage.start_turn, stage.end_turn
+            ));
+        }
+    }
+    for pair in stages.windows(2) {
+        if pair[1].start_turn != pair[0].end_turn + 1 {
+            errors.push(format!(
+                "stages.json [{}]: starts at turn {}, but {} ends at turn {}",
+                pair[1].id.id(), pair[1].start_turn, pair[0].id.id(), pair[0].end_turn
+            ));
+        }
+    }
+    for (stage, calendar) in stages.iter().filter_map(|s| Some((s, s.calendar?))) {
+        if !(1..=12).contains(&calendar.start_month) {
+            errors.push(format!("stages.json [{}]: startMonth {} isn't a month (1-12)", stage.id.id(), calendar.start_month));
+        }
+        if calendar.months_per_turn == 0 {
+            errors.push(format!("stages.json [{}]: monthsPerTurn must be at least 1", stage.id.id()));
+        }
+    }
+}
+
+/// Collect IDs, reporting any that appear more than once.
+fn unique_ids<'a>(
+    file: &str,
+    ids: impl Iterator<Item = &'a str>,
+    errors: &mut Vec<String>,
+) -> HashSet<&'a str> {
+    let mut seen = HashSet::new();
+    for id in ids {
+        if !seen.insert(id) {
+            errors.push(format!("{}: duplicate id \"{}\"", file, id));
+        }
+    }
+    seen
+}
+
+/// The engine's own stats must be defined, and every stat's start must sit in its range.
+fn check_stats(stats: &[StatDefinition], ids: &HashSet<&str>, errors: &mut Vec<String>) {
+    for id in ENGINE_STATS {
+        if !ids.contains(id) {
+            errors.push(format!("stats.json: missing the engine's \"{}\" stat", id));
+        }
+    }
+    for stat in stats {
+        let context = format!("stats.json [{}]", stat.id);
+        if stat.id == StatType::Credentials.id() {
+            errors.push(format!("{}: credentials are tags, not a stat", context));
+        }
+        if let (Some(min), Some(max)) = (stat.min, stat.max) {
+            if min > max {
+                errors.push(format!("{}: min {} is above max {}", context, min, max));
+            }
+        }
+        if stat.clamp(stat.start) != stat.start {
+            errors.push(format!("{}: start {} is outside its range", context, stat.start));
+        }
+    }
+}
+
+/// Credential effects need a tag to grant, and other effects a defined stat. Conditions
+/// must name defined stats with ranges that can hold. A percentage replaces the delta,
+/// and can't take more than all of a stat.
+fn check_effects(context: &str, effects: &[StatEffect], stat_ids: &HashSet<&str>, errors: &mut Vec<String>) {
+    for effect in effects {
+        if effect.stat == StatType::Credentials {
+            if effect.tag.is_none() {
+                errors.push(format!("{}: credentials effect is missing a tag", context));
+            }
+            if effect.percent.is_some() {
+                errors.push(format!("{}: credentials effect can't have a percent", context));
+            }
+        } else if !stat_ids.contains(effect.stat.id()) {
+            errors.push(format!("{}: unknown stat \"{}\"", context, effect.stat.id()));
+        }
+        for (id, range) in effect.when.iter().flat_map(|w| &w.stats) {
+            if !stat_ids.contains(id.as_str()) {
+                errors.push(format!("{}: {} effect's condition names unknown stat \"{}\"", context, effect.stat.id(), id));
+            }
+            if let (Some(min), Some(max)) = (range.min, range.max) {
+                if min > max {
+                    errors.push(format!("{}: {} effect's condition on {} can never hold (min {} is above max {})", context, effect.stat.id(), id, min, max));
+                }
+            }
+        }
+        if let Some(percent) = effect.percent {
+            if effect.delta != 0 {
+                errors.push(format!("{}: {} effect has both a delta and a percent", context, effect.stat.id()));
+            }
+            if !(percent >= -100.0 && percent.is_finite()) {
+                errors.push(format!("{}: {} effect's percent {} must be -100 or more", context, effect.stat.id(), percent));
+            }
+        }
+    }
+}
+
+/// Quiz questions need a real choice to make and an answer among the choices.
+fn check_quiz(context: &str, quiz: &QuizQuestion, errors: &mut Vec<String>) {
+    if quiz.choices.len() < 2 {
+        errors.push(format!("{}: quiz needs at least 2 choices", context));
+    }
+    if quiz.answer == NO_ANSWER {
+        errors.push(format!("{}: quiz is missing its answer", context));
+    } else if quiz.answer >= quiz.choices.len() {
+        errors.push(format!(
+            "{}: quiz answer {} is out of range ({} choices)",
+            context, quiz.answer, quiz.choices.len()
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_loader::test_data;
+    use crate::models::{ActionRequirements, DeferConsequence, Rarity};
+    use crate::models::ending::ThresholdCondition;
+    use crate::models::event::EffectCondition;
+
+    #[test]
+    fn test_shipped_data_is_valid() {
+        let data = test_data();
+        assert!(validate(&data).is_empty(), "{:?}", validate(&data));
+    }
+
+    #[test]
+    fn test_milestones_are_checked() {
+        let mut data = test_data();
+        data.milestones[0].stage = Stage::new("gap-yr");
+        data.milestones[1].conditions.path = Some("path_space".to_string());
+
+        let errors = validate(&data);
+        assert_eq!(errors.len(), 2, "{:?}", errors);
+        assert!(errors.iter().any(|e| e == "milestones.json [ms_first_credential]: unknown stage \"gap-yr\""));
+        assert!(errors.iter().any(|e| e == "milestones.json [ms_save_200]: unknown path \"path_space\""));
+    }
+
+    #[test]
+    fn test_reports_all_broken_references() {
+        let mut data = test_data();
+        data.decisions[0].options[0].applies_for = Some("job_astronaut".to_string());
+        data.decisions[1].turn = 99;
+        data.events[0].stages.push(Stage::new("gap-yr"));
+        data.events[1].options[0].effects.push(StatEffect { stat: StatType::Credentials, delta: 0, tag: None, percent: None, when: None });
+        let dup = data.jobs[0].clone();
+        data.jobs.push(dup);
+        data.endings[0].conditions.job_family = Some("astronautics".to_string());
+
+        let errors = validate(&data);
+        assert_eq!(errors.len(), 6, "{:?}", errors);
+        assert!(errors.iter().any(|e| e.contains("unknown job \"job_astronaut\"")));
+        assert!(errors.iter().any(|e| e.contains("turn 99 is outside")));
+        assert!(errors.iter().any(|e| e.contains("unknown stage \"gap-yr\"")));
+        assert!(errors.iter().any(|e| e.contains("missing a tag")));
+        assert!(errors.iter().any(|e| e.contains("duplicate id")));
+        assert!(errors.iter().any(|e| e.contains("unknown job family \"astronautics\"")));
+    }
+
+    #[test]
+    fn test_transition_cards_are_checked() {
+        let mut data = test_data();
+        let card = data.events.iter_mut().find(|e| e.transition).expect("The shipped data has transition cards");
+        card.stages.push(data.stages[0].id.clone());
+        card.options[0].sets_path = Some("path_astronaut".to_string());
+
+        let errors = validate(&data);
+        assert_eq!(errors.len(), 2, "{:?}", errors);
+        assert!(errors.iter().any(|e| e.contains("can't open the first stage")));
+        assert!(errors.iter().any(|e| e.contains("unknown path \"path_astronaut\"")));
+    }
+
+    #[test]
+    fn test_calendars_and_seasonal_cards_are_checked() {
+        let mut data = test_data();
+        let calendar = data.stages[0].calendar.as_mut().expect("The shipped stages have calendars");
+        calendar.start_month = 13;
+        data.stages[1].calendar.as_mut().unwrap().months_per_turn = 0;
+        let seasonal = data.events.iter().position(|e| !e.months.is_empty()).expect("The shipped data has seasonal cards");
+        data.events[seasonal].months.push(0);
+        let mut never = data.events[seasonal].clone();
+        never.id = "evt_never".to_string();
+        never.months = vec![2];
+        never.stages = vec![data.stages[3].id.clone()];
+        data.events.push(never);
+
+        let errors = validate(&data);
+        assert_eq!(errors.len(), 4, "{:?}", errors);
+        assert!(errors.iter().any(|e| e.contains("startMonth 13 isn't a month")));
+        assert!(errors.iter().any(|e| e.contains("monthsPerTurn must be at least 1")));
+        assert!(errors.iter().any(|e| e.contains("month 0 isn't a month")));
+        assert!(errors.iter().any(|e| e.starts_with("events.json [evt_never]") && e.contains("never dealt")));
+    }
+
+    #[test]
+    fn test_tutorial_steps_must_fit_their_turn() {
+        let mut data = test_data();
+        let mut step = data.tutorial[0].clone();
+        step.id = "tutorial_extra".to_string();
+        step.event_id = Some("evt_missing".to_string());
+        data.tutorial.push(step);
+        let late = data.stages.last().unwrap().end_turn;
+        data.tutorial[1].turn = late;
+
+        let errors = validate(&data);
+        assert_eq!(errors.len(), 3, "{:?}", errors);
+        assert!(errors.iter().any(|e| e.contains("unknown event \"evt_missing\"")));
+        assert!(errors.iter().any(|e| e.contains("is already scripted")));
+        assert!(errors.iter().any(|e| e.contains("isn't drawn in")));
+    }
+
+    #[test]
+    fn test_scenario_references_are_checked() {
+        let mut data = test_data();
+        data.scenarios[0].stage = Some(Stage::new("gap-yr"));
+        data.scenarios[0].path = Some("path_astronaut".to_string());
+        data.scenarios[0].locked_decisions.push("dec_missing".to_string());
+
+        let errors = validate(&data);
+        assert_eq!(errors.len(), 3, "{:?}", errors);
+        assert!(errors.iter().all(|e| e.starts_with("scenarios.json [")));
+        assert!(errors.iter().any(|e| e.contains("unknown decision \"dec_missing\"")));
+    }
+
+    #[test]
+    fn test_stats_are_checked() {
+        let mut data = test_data();
+        let mut grit = data.stats[1].clone();
+        grit.id = "grit".to_string();
+        grit.min = Some(10);
+        grit.max = Some(0);
+        data.stats.push(grit);
+        data.actions[0].effects.push(StatEffect { stat: StatType::Custom("charm".to_string()), delta: 1, tag: None, percent: None, when: None });
+        data.actions[0].effects.push(StatEffect { stat: StatType::Custom("grit".to_string()), delta: 1, tag: None, percent: None, when: None });
+
+        let errors = validate(&data);
+        assert_eq!(errors.len(), 3, "{:?}", errors);
+        assert!(errors.iter().any(|e| e.contains("[grit]: min 10 is above max 0")));
+        assert!(errors.iter().any(|e| e.contains("[grit]: start 20 is outside its range")));
+        assert!(errors.iter().any(|e| e.contains("unknown stat \"charm\"")));
+
+        data.stats.retain(|s| s.id != "support");
+        let errors = validate(&data);
+        assert!(errors.iter().any(|e| e == "stats.json: missing the engine's \"support\" stat"), "{:?}", errors);
+    }
+
+    #[test]
+    fn test_effect_percents_and_conditions_are_checked() {
+        let mut data = test_data();
+        let percent = |stat, delta, percent| StatEffect { stat, delta, tag: None, percent: Some(percent), when: None };
+        data.actions[0].effects.push(percent(StatType::Money, 0, -25.0));
+        data.actions[0].effects.push(percent(StatType::Money, 5, 10.0));
+        data.actions[0].effects.push(percent(StatType::Stress, 0, -150.0));
+
+        data.actions[0].effects[0].when = Some(EffectCondition {
+            stats: [
+                ("charm".to_string(), ThresholdCondition { min: Some(1), max: None }),
+                ("stress".to_string(), ThresholdCondition { min: Some(80), max: Some(60) }),
+            ].into_iter().collect(),
+            has_tag: None,
+            lacks_tag: None,
+        });
+
+        let errors = validate(&data);
+        assert_eq!(errors.len(), 4, "{:?}", errors);
+        assert!(errors.iter().any(|e| e.ends_with("condition names unknown stat \"charm\"")), "{:?}", errors);
+        assert!(errors.iter().any(|e| e.ends_with("condition on stress can never hold (min 80 is above max 60)")), "{:?}", errors);
+        assert!(errors.iter().any(|e| e.ends_with("money effect has both a delta and a percent")), "{:?}", errors);
+        assert!(errors.iter().any(|e| e.ends_with("stress effect's percent -150 must be -100 or more")), "{:?}", errors);
+    }
+
+    #[test]
+    fn test_action_limits_are_checked() {
+        let mut data = test_data();
+        data.actions[0].max_uses = Some(0);
+        data.actions[0].requires = Some(ActionRequirements {
+            min_stats: [("charm".to_string(), 5)].into_iter().collect(),
+            ..Default::default()
+        });
+        let id = data.actions[0].id.clone();
+        let errors = validate(&data);
+        assert_eq!(errors, vec![
+            format!("actions.json [{}]: maxUses must be at least 1", id),
+            format!("actions.json [{}]: requires unknown stat \"charm\"", id),
+        ]);
+    }
+
+    #[test]
+    fn test_defer_consequences_are_checked() {
+        let mut data = test_data();
+        data.decisions[0].defer_consequence = Some(DeferConsequence {
+            effects: vec![StatEffect { stat: StatType::Custom("charm".to_string()), delta: -1, tag: None, percent: None, when: None }],
+            returns_after: Some(0),
+        });
+        let context = format!("decisions.json [{}] deferConsequence", data.decisions[0].id);
+        let errors = validate(&data);
+        assert_eq!(errors.len(), 2, "{:?}", errors);
+        assert!(errors.iter().any(|e| e.starts_with(&context) && e.contains("unknown stat \"charm\"")), "{:?}", errors);
+        assert!(errors.contains(&format!("{}: returnsAfter must be at least 1", context)), "{:?}", errors);
+    }
+
+    #[test]
+    fn test_rarities_are_checked() {
+        let mut data = test_data();
+        data.rarities[3].weight = 0.0;
+        data.rarities[2].luck_bonus = -1.0;
+        data.events[0].rarity = Rarity::from("mythic".to_string());
+
+        let errors = validate(&data);
+        assert_eq!(errors.len(), 3, "{:?}", errors);
+        assert!(errors.iter().any(|e| e == "rarities.json [rare]: luckBonus -1 must be above -1"), "{:?}", errors);
+        assert!(errors.iter().any(|e| e == "rarities.json [legendary]: weight 0 must be above 0"), "{:?}", errors);
+        assert!(errors.iter().any(|e| e.ends_with("unknown rarity \"mythic\"")), "{:?}", errors);
+    }
+
+    #[test]
+    fn test_seeds_are_checked() {
+        let mut data = test_data();
+        data.seeds[1].id = data.seeds[0].id.clone();
+        data.seeds[2].seed = " ".to_string();
+        let id = data.seeds[2].id.clone();
+
+        let errors = validate(&data);
+        assert_eq!(errors.len(), 2, "{:?}", errors);
+        assert!(errors.iter().any(|e| e.starts_with("seeds.json: duplicate")), "{:?}", errors);
+        assert!(errors.contains(&format!("seeds.json [{}]: seed must not be blank", id)), "{:?}", errors);
+    }
+
+    #[test]
+    fn test_quiz_answers_must_be_a_choice() {
+        let mut data = test_data();
+        let event = data.events.iter_mut().find(|e| e.quiz.is_some()).unwrap();
+        let quiz = event.quiz.as_mut().unwrap();
+        quiz.answer = quiz.choices.len();
+        let decision = data.decisions.iter_mut().find(|d| d.quiz.is_some()).unwrap();
+        let quiz = decision.quiz.as_mut().unwrap();
+        quiz.choices.truncate(1);
+        quiz.answer = NO_ANSWER;
+
+        let errors = validate(&data);
+        assert_eq!(errors.len(), 3, "{:?}", errors);
+        assert!(errors.iter().any(|e| e.contains("is out of range")));
+        assert!(errors.iter().any(|e| e.contains("at least 2 choices")));
+        assert!(errors.iter().any(|e| e.contains("missing its answer")));
+    }
+
+    #[test]
+    fn test_stage_gaps_are_reported() {
+        let mut data = test_data();
+        data.stages[1].start_turn += 1;
+        let errors = validate(&data);
+        assert!(errors.iter().any(|e| e.contains("starts at turn")), "{:?}", errors);
+    }
+}
@@ -0,0 +1,217 @@
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+use serde::Serialize;
+use life_sim_engine::data_loader::GameData;
+use life_sim_engine::engine::endings;
+use life_sim_engine::engine::game_state::GameState;
+use life_sim_engine::engine::turn_runner::{self, PlayerChoices, TurnResult};
+use life_sim_engine::models::Stage;
+use life_sim_engine::remote_data::sha256_hex;
+
+/// One thing that happened in a game, as written to the analytics log.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum AnalyticsEvent {
+    #[serde(rename_all = "camelCase")]
+    TurnSubmitted {
+        turn: u32,
+        stage: Stage,
+        action_ids: Vec<String>,
+        /// Stats at the end of the turn.
+        money: i32,
+        stress: i32,
+        support: i32,
+    },
+    /// A decision option or event card response.
+    #[serde(rename_all = "camelCase")]
+    OptionChosen {
+        turn: u32,
+        stage: Stage,
+        /// The decision or event card ID.
+        source_id: String,
+        /// "decision" or "event".
+        kind: String,
+        option_index: usize,
+        label: Option<String>,
+    },
+    #[serde(rename_all = "camelCase")]
+    EndingReached {
+        ending_id: Option<String>,
+        money: i32,
+        stress: i32,
+        support: i32,
+        credential_count: usize,
+    },
+}
+
+/// A log line: the event plus which game it came from and when.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LogLine<'a> {
+    /// Seconds since the Unix epoch.
+    at: u64,
+    /// Opaque key for the game, so its lines can be grouped without naming the player.
+    game: &'a str,
+    seed: &'a str,
+    #[serde(flatten)]
+    event: &'a AnalyticsEvent,
+}
+
+/// Opt-in gameplay log: one JSON object per line, appended to a local file. Nothing is
+/// recorded unless the server was started with `--analytics <path>`, and player names and
+/// student secrets are never written.
+pub struct Analytics {
+    file: Option<Mutex<File>>,
+}
+
+impl Analytics {
+    /// Record nothing.
+    pub fn disabled() -> Self {
+        Self { file: None }
+    }
+
+    /// Append to the log at `path`, creating it if needed.
+    pub fn open(path: &Path) -> Result<Self, String> {
+        let file = OpenOptions::new().create(true).append(true).open(path)
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+        Ok(Self { file: Some(Mutex::new(file)) })
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.file.is_some()
+    }
+
+    /// Log a turn that was just played: the submission, each option chosen, and the
+    /// ending if the turn finished the game. `player` tells games on the same seed apart
+    /// (e.g. a class code and student ID); it's hashed before it's written.
+    pub fn turn_played(&self, player: &str, state: &GameState, data: &GameData, choices: &PlayerChoices, result: &TurnResult) {
+        if !self.is_enabled() {
+            return;
+        }
+        let game = sha256_hex(format!("{}/{}", state.seed, player).as_bytes())[..12].to_string();
+        for event in turn_events(state, data, choices, result) {
+            self.append(&game, &state.seed, &event);
+        }
+    }
+
+    fn append(&self, game: &str, seed: &str, event: &AnalyticsEvent) {
+        let Some(ref file) = self.file else { return };
+        let at = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        let line = match serde_json::to_string(&LogLine { at, game, seed, event }) {
+            Ok(line) => line,
+            Err(e) => return tracing::error!("Analytics: {}", e),
+        };
+        // The game goes on even if the log can't be written
+        if let Err(e) = writeln!(file.lock().unwrap(), "{}", line) {
+            tracing::error!("Analytics: failed to write: {}", e);
+        }
+    }
+}
+
+/// The events describing a turn just played with `choices`.
+fn turn_events(state: &GameState, data: &GameData, choices: &PlayerChoices, result: &TurnResult) -> Vec<AnalyticsEvent> {
+    let Some(played) = state.stats_history.last() else { return Vec::new() };
+    let mut events = vec![AnalyticsEvent::TurnSubmitted {
+        turn: played.turn,
+        stage: played.stage.clone(),
+        action_ids: choices.action_ids.clone(),
+        money: played.money,
+        stress: played.stress,
+        support: played.support,
+    }];
+
+    let decided = state.decision_log.iter()
+        .any(|d| d.turn == played.turn && d.decision_id.as_deref() == Some(choices.decision_id.as_str()));
+    if decided {
+        let label = data.decision(&choices.decision_id)
+            .and_then(|d| d.options.get(choices.decision_option_index))
+            .map(|o| o.label.clone());
+        events.push(AnalyticsEvent::OptionChosen {
+            turn: played.turn,
+            stage: played.stage.clone(),
+            source_id: choices.decision_id.clone(),
+            kind: "decision".to_string(),
+            option_index: choices.decision_option_index,
+            label,
+        });
+    }
+    for (i, event) in result.events_drawn.iter().enumerate() {
+        let Some(index) = choices.event_answer(i) else { continue };
+        events.push(AnalyticsEvent::OptionChosen {
+            turn: played.turn,
+            stage: played.stage.clone(),
+            source_id: event.id.clone(),
+            kind: "event".to_string(),
+            option_index: index,
+            label: event.options.get(index).map(|o| o.label.clone()),
+        });
+    }
+
+    if turn_runner::is_game_over(state, &data.stages) {
+        events.push(AnalyticsEvent::EndingReached {
+            ending_id: endings::resolve(state, &data.endings).map(|e| e.id.clone()),
+            money: state.money,
+            stress: state.stress,
+            support: state.support,
+            credential_count: state.credentials.len(),
+        });
+    }
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data;
+    use life_sim_engine::engine::bot;
+    use life_sim_engine::engine::rng::{create_rng, game_rng};
+
+    /// Play a whole game with the first-option bot, logging every turn.
+    fn play_logged(analytics: &Analytics, data: &GameData) {
+        let strategy = bot::by_name("first").unwrap();
+        let mut state = turn_runner::start_game("ANALYTICS".to_string(), data);
+        let mut game_rng = game_rng("ANALYTICS");
+        let mut bot_rng = create_rng("ANALYTICS/bot");
+        while !turn_runner::is_game_over(&state, &data.stages) {
+            let events = turn_runner::draw_turn_events(&state, data, &mut game_rng);
+            let choices = bot::choose_turn(strategy.as_ref(), &state, data, &events, &mut bot_rng);
+            let result = turn_runner::run_turn_with_events(&mut state, &choices, data, &mut game_rng, events);
+            analytics.turn_played("ABC234/secret", &state, data, &choices, &result);
+        }
+    }
+
+    #[test]
+    fn test_log_records_turns_choices_and_ending() {
+        let data = test_data();
+        let path = std::env::temp_dir().join(format!("life-sim-analytics-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        play_logged(&Analytics::open(&path).unwrap(), &data);
+        let text = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let lines: Vec<serde_json::Value> = text.lines().map(|l| serde_json::from_str(l).unwrap()).collect();
+        let of_type = |t: &str| lines.iter().filter(|l| l["type"] == t).count();
+        let turns = data.stages.last().unwrap().end_turn as usize;
+        assert_eq!(of_type("turnSubmitted"), turns);
+        assert!(of_type("optionChosen") > turns, "Decisions and event responses");
+        assert_eq!(of_type("endingReached"), 1);
+        assert_eq!(lines.last().unwrap()["type"], "endingReached");
+
+        let club = lines.iter().find(|l| l["sourceId"] == "dec_club_choice_a").unwrap();
+        assert_eq!((club["kind"].as_str(), club["label"].as_str()), (Some("decision"), Some("Tech Club")));
+        assert!(lines.iter().all(|l| l["seed"] == "ANALYTICS" && l["game"] == lines[0]["game"]));
+        assert!(!text.contains("secret"), "The player key is hashed");
+    }
+
+    #[test]
+    fn test_disabled_log_writes_nothing() {
+        let data = test_data();
+        let analytics = Analytics::disabled();
+        assert!(!analytics.is_enabled());
+        play_logged(&analytics, &data);
+    }
+}
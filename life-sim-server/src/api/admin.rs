@@ -0,0 +1,42 @@
+use std::net::IpAddr;
+use actix_web::HttpRequest;
+use super::error::ApiError;
+
+/// Header admin requests carry the server's admin key in.
+pub const ADMIN_KEY_HEADER: &str = "X-Admin-Key";
+
+/// Check a request may use the admin endpoints. With an admin key configured the request
+/// must send it; without one, only requests from this machine are let through.
+pub fn check_admin(admin_key: Option<&str>, req: &HttpRequest) -> Result<(), ApiError> {
+    let sent = req.headers().get(ADMIN_KEY_HEADER).and_then(|v| v.to_str().ok());
+    authorize(admin_key, sent, req.peer_addr().map(|addr| addr.ip()))
+}
+
+fn authorize(admin_key: Option<&str>, sent: Option<&str>, peer: Option<IpAddr>) -> Result<(), ApiError> {
+    match (admin_key, sent) {
+        (Some(key), Some(sent)) if sent == key => Ok(()),
+        (Some(_), Some(_)) => Err(ApiError::forbidden("Wrong admin key")),
+        (Some(_), None) => Err(ApiError::forbidden(format!("Send the server's admin key in {}", ADMIN_KEY_HEADER))),
+        (None, _) if peer.is_some_and(|ip| ip.is_loopback()) => Ok(()),
+        (None, _) => Err(ApiError::forbidden("Admin endpoints only answer this machine unless the server has an admin key (adminKey)")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_admin_key_or_loopback() {
+        let local = Some(IpAddr::from([127, 0, 0, 1]));
+        let remote = Some(IpAddr::from([10, 0, 0, 7]));
+
+        assert!(authorize(None, None, local).is_ok());
+        assert!(authorize(None, None, remote).is_err());
+        assert!(authorize(None, Some("guess"), remote).is_err(), "No key configured, so no key works");
+
+        assert!(authorize(Some("s3cret"), Some("s3cret"), remote).is_ok());
+        assert!(authorize(Some("s3cret"), Some("guess"), local).is_err(), "A configured key is needed even locally");
+        assert!(authorize(Some("s3cret"), None, local).is_err());
+    }
+}
@@ -0,0 +1,637 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use actix_web::{web, HttpRequest, HttpResponse};
+use rand::Rng;
+use serde::Deserialize;
+use life_sim_engine::data_loader::GameData;
+use life_sim_engine::engine::compat::ApiVersion;
+use life_sim_engine::engine::game_state::GameState;
+use life_sim_engine::engine::replay;
+use life_sim_engine::engine::rng::{self, GameRng, SeedScheme};
+use life_sim_engine::engine::turn_runner;
+use life_sim_engine::models::{DeckMode, EventCard};
+use super::error::ApiError;
+use super::gallery;
+use super::routes::{self, AppState};
+
+/// Join codes skip look-alike characters (0/O, 1/I) so they survive being read off a projector.
+const CODE_CHARS: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+const CODE_LENGTH: usize = 6;
+const TOKEN_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+/// A student who hasn't submitted a turn for this long is flagged as stuck.
+const STUCK_IDLE: Duration = Duration::from_secs(5 * 60);
+/// A student this many turns behind the middle of the class is flagged as stuck.
+const STUCK_TURNS_BEHIND: u32 = 3;
+
+/// Header the teacher sends their key in to see the class.
+pub const TEACHER_KEY_HEADER: &str = "X-Teacher-Key";
+
+/// One student's game in a class.
+pub struct Student {
+    /// Secret the student plays with; only the student and the teacher see it.
+    pub id: String,
+    pub name: String,
+    pub state: GameState,
+    pub rng: GameRng,
+    /// The event cards drawn for the student's current turn.
+    pub pending_events: Vec<EventCard>,
+    /// When the student joined or last submitted a turn.
+    pub last_active: Instant,
+}
+
+impl Student {
+    /// Why the student looks stuck, if they do: idle too long, or far behind the class.
+    /// Students who are `done_for_now` (game over, or waiting on a lockstep turn) never are.
+    pub fn stuck_reason(&self, now: Instant, median_turn: u32, done_for_now: bool) -> Option<String> {
+        if done_for_now {
+            return None;
+        }
+        let idle = now.saturating_duration_since(self.last_active);
+        if idle >= STUCK_IDLE {
+            return Some(format!("No turn submitted for {} minutes", idle.as_secs() / 60));
+        }
+        let behind = median_turn.saturating_sub(self.state.current_turn);
+        if behind >= STUCK_TURNS_BEHIND {
+            return Some(format!("{} turns behind the class", behind));
+        }
+        None
+    }
+}
+
+/// A class: one seed shared by every student, each playing their own game.
+pub struct Classroom {
+    pub code: String,
+    pub name: Option<String>,
+    pub seed: String,
+    /// How the class seed seeds each student's RNG; legacy for seeds shared before hashing.
+    pub seed_scheme: SeedScheme,
+    /// How students' games deal each stage's deck.
+    pub deck_mode: DeckMode,
+    /// Secret that lets the teacher who created the class see it.
+    pub teacher_key: String,
+    /// The data set the class was created with (unaffected by reloads, like a single game's).
+    pub game_data: Arc<GameData>,
+    pub students: Vec<Student>,
+    /// Lockstep mode: the last turn students may play until the teacher opens the next.
+    /// None lets everyone play at their own pace.
+    pub allowed_turn: Option<u32>,
+    pub created: Instant,
+}
+
+impl Classroom {
+    pub fn new(code: String, name: Option<String>, seed: String, game_data: Arc<GameData>) -> Self {
+        Self {
+            code,
+            name,
+            seed,
+            seed_scheme: SeedScheme::Sha256,
+            deck_mode: DeckMode::Weighted,
+            teacher_key: random_token(24),
+            game_data,
+            students: Vec::new(),
+            allowed_turn: None,
+            created: Instant::now(),
+        }
+    }
+
+    /// When a student last played, or when the class was made if nobody has yet.
+    pub fn last_active(&self) -> Instant {
+        self.students.iter().map(|s| s.last_active).max().unwrap_or(self.created)
+    }
+
+    /// Add a student with a fresh game on the class seed, so every student faces the same deck.
+    pub fn join(&mut self, name: &str) -> Result<&Student, ApiError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(ApiError::bad_request("Missing 'name' field"));
+        }
+        if self.students.iter().any(|s| s.name.eq_ignore_ascii_case(name)) {
+            return Err(ApiError::conflict(format!("A student named {} has already joined", name)));
+        }
+        let mut state = turn_runner::start_game(self.seed.clone(), &self.game_data);
+        state.seed_scheme = self.seed_scheme;
+        state.deck_mode = self.deck_mode;
+        self.students.push(Student {
+            id: random_token(12),
+            name: name.to_string(),
+            rng: state.seed_rng(),
+            state,
+            pending_events: Vec::new(),
+            last_active: Instant::now(),
+        });
+        Ok(self.students.last().unwrap())
+    }
+
+    /// Turn lockstep on or off. Turned on without a turn, it holds everyone at the turn
+    /// the furthest-behind student is on, so the room regroups there.
+    pub fn set_lockstep(&mut self, enabled: bool, allowed_turn: Option<u32>) {
+        self.allowed_turn = enabled.then(|| {
+            allowed_turn.unwrap_or_else(|| {
+                self.students.iter().map(|s| s.state.current_turn).min()
+                    .or_else(|| self.game_data.stages.first().map(|s| s.start_turn))
+                    .unwrap_or(1)
+            })
+        });
+    }
+
+    /// Open the next turn for the class; returns the new allowed turn.
+    pub fn advance_turn(&mut self) -> Result<u32, ApiError> {
+        let allowed = self.allowed_turn
+            .ok_or_else(|| ApiError::conflict("Lockstep is off; students already play at their own pace"))?;
+        let last_turn = self.game_data.stages.last().map_or(0, |s| s.end_turn);
+        if allowed >= last_turn {
+            return Err(ApiError::conflict("Every turn is already open"));
+        }
+        self.allowed_turn = Some(allowed + 1);
+        Ok(allowed + 1)
+    }
+
+    /// Whether the student has played up to the allowed turn and must wait for the teacher.
+    pub fn is_waiting(&self, student: &Student) -> bool {
+        self.allowed_turn.is_some_and(|allowed| student.state.current_turn > allowed)
+    }
+
+    /// The middle student's current turn (0 with no students).
+    pub fn median_turn(&self) -> u32 {
+        let mut turns: Vec<u32> = self.students.iter().map(|s| s.state.current_turn).collect();
+        turns.sort_unstable();
+        turns.get(turns.len() / 2).copied().unwrap_or(0)
+    }
+
+    pub fn student(&self, id: &str) -> Result<&Student, ApiError> {
+        self.students.iter().find(|s| s.id == id).ok_or_else(|| ApiError::not_found("student", id))
+    }
+
+    pub fn student_mut(&mut self, id: &str) -> Result<&mut Student, ApiError> {
+        self.students.iter_mut().find(|s| s.id == id).ok_or_else(|| ApiError::not_found("student", id))
+    }
+
+    /// Check the request carries this class's teacher key.
+    pub fn check_teacher(&self, req: &HttpRequest) -> Result<(), ApiError> {
+        let key = req.headers().get(TEACHER_KEY_HEADER).and_then(|v| v.to_str().ok());
+        match key {
+            Some(key) if key == self.teacher_key => Ok(()),
+            Some(_) => Err(ApiError::forbidden("Wrong teacher key for this class")),
+            None => Err(ApiError::forbidden(format!("Send the class's teacher key in {}", TEACHER_KEY_HEADER))),
+        }
+    }
+}
+
+/// Every class on this server, by join code.
+#[derive(Default)]
+pub struct Classrooms(RwLock<HashMap<String, Classroom>>);
+
+impl Classrooms {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a class under a fresh join code; returns the code and the teacher key.
+    pub fn create(&self, name: Option<String>, seed: String, seed_scheme: SeedScheme, deck_mode: DeckMode, game_data: Arc<GameData>) -> (String, String) {
+        let mut classes = self.0.write().unwrap();
+        let code = loop {
+            let code = random_code();
+            if !classes.contains_key(&code) {
+                break code;
+            }
+        };
+        let mut class = Classroom::new(code.clone(), name, seed, game_data);
+        class.seed_scheme = seed_scheme;
+        class.deck_mode = deck_mode;
+        let teacher_key = class.teacher_key.clone();
+        classes.insert(code.clone(), class);
+        (code, teacher_key)
+    }
+
+    /// Run `f` on the class with this join code (codes are case-insensitive). Any number
+    /// of these can run at once, so dashboards and polling students don't queue up.
+    pub fn with_class<T>(
+        &self,
+        code: &str,
+        f: impl FnOnce(&Classroom) -> Result<T, ApiError>,
+    ) -> Result<T, ApiError> {
+        let classes = self.0.read().unwrap();
+        let class = classes.get(&code.to_ascii_uppercase())
+            .ok_or_else(|| ApiError::not_found("class", code))?;
+        f(class)
+    }
+
+    /// Run `f` on the class with this join code, to change it or play one of its games.
+    pub fn with_class_mut<T>(
+        &self,
+        code: &str,
+        f: impl FnOnce(&mut Classroom) -> Result<T, ApiError>,
+    ) -> Result<T, ApiError> {
+        let mut classes = self.0.write().unwrap();
+        let class = classes.get_mut(&code.to_ascii_uppercase())
+            .ok_or_else(|| ApiError::not_found("class", code))?;
+        f(class)
+    }
+
+    /// Drop every class whose students have all been idle for `limit` or longer as of
+    /// `now` (an empty class counts from its creation). Returns how many were dropped.
+    pub fn evict_idle(&self, now: Instant, limit: Duration) -> usize {
+        let mut classes = self.0.write().unwrap();
+        let before = classes.len();
+        classes.retain(|_, class| now.saturating_duration_since(class.last_active()) < limit);
+        before - classes.len()
+    }
+}
+
+fn random_code() -> String {
+    let mut rng = rand::thread_rng();
+    (0..CODE_LENGTH).map(|_| CODE_CHARS[rng.gen_range(0..CODE_CHARS.len())] as char).collect()
+}
+
+fn random_token(len: usize) -> String {
+    let mut rng = rand::thread_rng();
+    (0..len).map(|_| TOKEN_CHARS[rng.gen_range(0..TOKEN_CHARS.len())] as char).collect()
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateClass {
+    pub name: Option<String>,
+    pub seed: Option<String>,
+    /// Seed students' games the way classes did before seeds were hashed, so a seed
+    /// shared back then deals the same cards.
+    #[serde(default)]
+    pub legacy_seed: bool,
+    /// `shuffled` deals every student the same card sequence, however often they preview.
+    #[serde(default)]
+    pub deck_mode: DeckMode,
+}
+
+#[derive(Deserialize)]
+pub struct JoinClass {
+    pub name: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Lockstep {
+    pub enabled: bool,
+    pub allowed_turn: Option<u32>,
+}
+
+/// POST /api/class — Create a class with a shared seed (random if omitted). `legacySeed`
+/// keeps the seed on the pre-hashing scheme, and `deckMode` sets how students' decks are
+/// dealt. Returns the join code for students and the
+/// key the teacher uses to see the class.
+pub async fn create_class(
+    app_state: web::Data<AppState>,
+    body: web::Json<CreateClass>,
+) -> Result<HttpResponse, ApiError> {
+    let body = body.into_inner();
+    let seed = body.seed.filter(|s| !s.trim().is_empty()).unwrap_or_else(rng::generate_seed);
+    let seed_scheme = if body.legacy_seed { SeedScheme::Legacy } else { SeedScheme::Sha256 };
+    let (code, teacher_key) = app_state.classes.create(body.name.clone(), seed.clone(), seed_scheme, body.deck_mode, app_state.data.current());
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "code": code,
+        "name": body.name,
+        "seed": seed,
+        "legacySeed": body.legacy_seed,
+        "deckMode": body.deck_mode,
+        "teacherKey": teacher_key,
+    })))
+}
+
+/// GET /api/class/{code} — The class and its participants (teacher only).
+pub async fn get_class(
+    app_state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    app_state.classes.with_class(&path, |class| {
+        class.check_teacher(&req)?;
+        let students: Vec<serde_json::Value> = class.students.iter().map(|s| serde_json::json!({
+            "id": s.id,
+            "name": s.name,
+            "currentTurn": s.state.current_turn,
+            "currentStage": s.state.current_stage,
+            "isGameOver": turn_runner::is_game_over(&s.state, &class.game_data.stages),
+            "verifyHash": replay::sequence_hash(&s.state),
+        })).collect();
+        Ok(HttpResponse::Ok().json(serde_json::json!({
+            "code": class.code,
+            "name": class.name,
+            "seed": class.seed,
+            "legacySeed": class.seed_scheme == SeedScheme::Legacy,
+            "deckMode": class.deck_mode,
+            "allowedTurn": class.allowed_turn,
+            "students": students,
+        })))
+    })
+}
+
+/// GET /api/class/{code}/dashboard — Every student's progress and stats at a glance, with
+/// anyone who looks stuck flagged (teacher only).
+pub async fn class_dashboard(
+    app_state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    app_state.classes.with_class(&path, |class| {
+        class.check_teacher(&req)?;
+        let now = Instant::now();
+        let median_turn = class.median_turn();
+        let stages = &class.game_data.stages;
+        let students: Vec<serde_json::Value> = class.students.iter().map(|s| {
+            let game_over = turn_runner::is_game_over(&s.state, stages);
+            let waiting = class.is_waiting(s);
+            let stuck_reason = s.stuck_reason(now, median_turn, game_over || waiting);
+            serde_json::json!({
+                "id": s.id,
+                "name": s.name,
+                "currentTurn": s.state.current_turn,
+                "currentStage": s.state.current_stage,
+                "money": s.state.money,
+                "stress": s.state.stress,
+                "support": s.state.support,
+                "savings": s.state.savings,
+                "credentialCount": s.state.credentials.len(),
+                "isGameOver": game_over,
+                "waiting": waiting,
+                "idleSeconds": now.saturating_duration_since(s.last_active).as_secs(),
+                "stuck": stuck_reason.is_some(),
+                "stuckReason": stuck_reason,
+            })
+        }).collect();
+        Ok(HttpResponse::Ok().json(serde_json::json!({
+            "code": class.code,
+            "allowedTurn": class.allowed_turn,
+            "medianTurn": median_turn,
+            "finishedCount": class.students.iter().filter(|s| turn_runner::is_game_over(&s.state, stages)).count(),
+            "students": students,
+        })))
+    })
+}
+
+/// POST /api/class/{code}/lockstep — Turn lockstep mode on or off (teacher only). While
+/// it's on, students can't submit turns past the allowed turn.
+pub async fn set_lockstep(
+    app_state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    body: web::Json<Lockstep>,
+) -> Result<HttpResponse, ApiError> {
+    app_state.classes.with_class_mut(&path, |class| {
+        class.check_teacher(&req)?;
+        class.set_lockstep(body.enabled, body.allowed_turn);
+        Ok(HttpResponse::Ok().json(serde_json::json!({ "allowedTurn": class.allowed_turn })))
+    })
+}
+
+/// POST /api/class/{code}/advance — Open the next turn for a lockstep class (teacher only).
+pub async fn advance_turn(
+    app_state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    app_state.classes.with_class_mut(&path, |class| {
+        class.check_teacher(&req)?;
+        let allowed_turn = class.advance_turn()?;
+        Ok(HttpResponse::Ok().json(serde_json::json!({ "allowedTurn": allowed_turn })))
+    })
+}
+
+/// POST /api/class/{code}/join — Join a class and start a game on its seed.
+pub async fn join_class(
+    app_state: web::Data<AppState>,
+    path: web::Path<String>,
+    body: web::Json<JoinClass>,
+) -> Result<HttpResponse, ApiError> {
+    let name = body.into_inner().name.unwrap_or_default();
+    app_state.classes.with_class_mut(&path, |class| {
+        let code = class.code.clone();
+        let student = class.join(&name)?;
+        Ok(HttpResponse::Ok().json(serde_json::json!({
+            "classCode": code,
+            "studentId": student.id,
+            "state": student.state,
+        })))
+    })
+}
+
+/// GET /api/class/{code}/students/{student_id}/state — A student's game state, with milestones.
+pub async fn student_state(
+    app_state: web::Data<AppState>,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse, ApiError> {
+    let (code, student_id) = path.into_inner();
+    app_state.classes.with_class(&code, |class| {
+        let student = class.student(&student_id)?;
+        Ok(HttpResponse::Ok().json(routes::state_body(&student.state, &class.game_data)))
+    })
+}
+
+/// GET /api/class/{code}/students/{student_id}/verify — verify for a student's game.
+pub async fn student_verify(
+    app_state: web::Data<AppState>,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse, ApiError> {
+    let (code, student_id) = path.into_inner();
+    app_state.classes.with_class(&code, |class| {
+        let student = class.student(&student_id)?;
+        Ok(HttpResponse::Ok().json(routes::verify_body(&student.state)))
+    })
+}
+
+/// GET /api/class/{code}/students/{student_id}/phase_data — phase_data for a student's game.
+pub async fn student_phase_data(
+    app_state: web::Data<AppState>,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse, ApiError> {
+    let (code, student_id) = path.into_inner();
+    app_state.classes.with_class(&code, |class| {
+        let student = class.student(&student_id)?;
+        Ok(HttpResponse::Ok().json(routes::phase_data_body(&student.state, &class.game_data)))
+    })
+}
+
+/// GET /api/class/{code}/students/{student_id}/draw_event — draw_event for a student's game.
+pub async fn student_draw_event(
+    app_state: web::Data<AppState>,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse, ApiError> {
+    let (code, student_id) = path.into_inner();
+    app_state.classes.with_class_mut(&code, |class| {
+        let game_data = class.game_data.clone();
+        let student = class.student_mut(&student_id)?;
+        let body = routes::draw_event_body(&mut student.state, &mut student.rng, &mut student.pending_events, &game_data);
+        Ok(HttpResponse::Ok().json(body))
+    })
+}
+
+/// POST /api/class/{code}/students/{student_id}/submit_turn — submit_turn for a student's game.
+pub async fn student_submit_turn(
+    app_state: web::Data<AppState>,
+    version: web::Data<ApiVersion>,
+    path: web::Path<(String, String)>,
+    body: web::Json<serde_json::Value>,
+) -> Result<HttpResponse, ApiError> {
+    let (code, student_id) = path.into_inner();
+    let (response, finished) = app_state.classes.with_class_mut(&code, |class| {
+        let game_data = class.game_data.clone();
+        let allowed_turn = class.allowed_turn;
+        let class_code = class.code.clone();
+        let student = class.student_mut(&student_id)?;
+        if let Some(allowed) = allowed_turn.filter(|allowed| student.state.current_turn > *allowed) {
+            return Err(ApiError::turn_locked(allowed));
+        }
+        let (choices, result) = routes::play_turn(
+            &mut student.state, &mut student.rng, &mut student.pending_events, &game_data, **version, &body, false,
+        )?;
+        let player = gallery::student_player(&class_code, &student.id);
+        app_state.analytics.turn_played(&player, &student.state, &game_data, &choices, &result);
+        // The gallery writes the runs file, so record a finished game once the class is unlocked
+        let finished = turn_runner::is_game_over(&student.state, &game_data.stages)
+            .then(|| (player, class_code, student.state.clone(), game_data.clone()));
+        student.last_active = Instant::now();
+        Ok((HttpResponse::Ok().json(routes::turn_response(&student.state, result, &game_data)), finished))
+    })?;
+    if let Some((player, class_code, state, game_data)) = finished {
+        gallery::record_finished_game(&app_state, &player, Some(&class_code), &state, &game_data);
+    }
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data;
+
+    fn load_test_data() -> Arc<GameData> {
+        Arc::new(test_data())
+    }
+
+    #[test]
+    fn test_students_share_the_class_seed() {
+        let data = load_test_data();
+        let mut class = Classroom::new("ABC234".to_string(), None, "CLASS_SEED".to_string(), data.clone());
+        class.join("Ana").unwrap();
+        class.join("Ben").unwrap();
+
+        let draws: Vec<Vec<String>> = class.students.iter_mut()
+            .map(|s| turn_runner::draw_turn_events(&s.state, &data, &mut s.rng).into_iter().map(|e| e.id).collect())
+            .collect();
+        assert!(!draws[0].is_empty());
+        assert_eq!(draws[0], draws[1], "Same seed, same first card");
+        assert_ne!(class.students[0].id, class.students[1].id);
+        assert_eq!(class.students[1].state.seed, "CLASS_SEED");
+    }
+
+    #[test]
+    fn test_legacy_class_keeps_the_old_seed_scheme() {
+        let data = load_test_data();
+        let mut class = Classroom::new("ABC234".to_string(), None, "CLASS_SEED".to_string(), data.clone());
+        class.seed_scheme = SeedScheme::Legacy;
+        class.join("Ana").unwrap();
+
+        let student = &mut class.students[0];
+        assert_eq!(student.state.seed_scheme, SeedScheme::Legacy, "Saved with the game");
+        let drawn = turn_runner::draw_turn_events(&student.state, &data, &mut student.rng);
+        let expected = turn_runner::draw_turn_events(&student.state, &data, &mut GameRng::new(SeedScheme::Legacy, "CLASS_SEED"));
+        assert_eq!(drawn.iter().map(|e| &e.id).collect::<Vec<_>>(), expected.iter().map(|e| &e.id).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_shuffled_class_deals_every_student_the_same_cards() {
+        let data = load_test_data();
+        let mut class = Classroom::new("ABC234".to_string(), None, "CLASS_SEED".to_string(), data.clone());
+        class.deck_mode = DeckMode::Shuffled;
+        class.join("Ana").unwrap();
+        class.join("Ben").unwrap();
+
+        // Ana previews a few times; Ben doesn't
+        let ana = &mut class.students[0];
+        assert_eq!(ana.state.deck_mode, DeckMode::Shuffled, "Saved with the game");
+        for _ in 0..3 {
+            turn_runner::draw_turn_events(&ana.state, &data, &mut ana.rng);
+        }
+        let draws: Vec<Vec<String>> = class.students.iter_mut()
+            .map(|s| turn_runner::draw_turn_events(&s.state, &data, &mut s.rng).into_iter().map(|e| e.id).collect())
+            .collect();
+        assert_eq!(draws[0], draws[1]);
+    }
+
+    #[test]
+    fn test_join_rejects_blank_and_duplicate_names() {
+        let mut class = Classroom::new("ABC234".to_string(), None, "SEED".to_string(), load_test_data());
+        assert_eq!(class.join("  ").map(|_| ()).unwrap_err().code, "bad_request");
+        class.join("Ana").unwrap();
+        assert_eq!(class.join("ana").map(|_| ()).unwrap_err().code, "conflict");
+    }
+
+    #[test]
+    fn test_stuck_students_are_flagged() {
+        let mut class = Classroom::new("ABC234".to_string(), None, "SEED".to_string(), load_test_data());
+        for name in ["Ana", "Ben", "Cy"] {
+            class.join(name).unwrap();
+        }
+        class.students[1].state.current_turn += 4;
+        class.students[2].state.current_turn += 4;
+        let median = class.median_turn();
+        let now = class.students[0].last_active + Duration::from_secs(60);
+
+        let behind = class.students[0].stuck_reason(now, median, false).expect("Far behind the class");
+        assert!(behind.contains("4 turns behind"), "{}", behind);
+        assert_eq!(class.students[1].stuck_reason(now, median, false), None);
+        assert_eq!(class.students[0].stuck_reason(now, median, true), None, "Finished games aren't stuck");
+
+        let later = class.students[1].last_active + STUCK_IDLE;
+        assert!(class.students[1].stuck_reason(later, median, false).unwrap().contains("No turn submitted"));
+    }
+
+    #[test]
+    fn test_lockstep_holds_students_at_the_allowed_turn() {
+        let mut class = Classroom::new("ABC234".to_string(), None, "SEED".to_string(), load_test_data());
+        class.join("Ana").unwrap();
+        class.join("Ben").unwrap();
+        class.students[1].state.current_turn += 2;
+        let start = class.students[0].state.current_turn;
+
+        class.set_lockstep(true, None);
+        assert_eq!(class.allowed_turn, Some(start), "Regroups at the furthest-behind student");
+        assert!(!class.is_waiting(&class.students[0]));
+        assert!(class.is_waiting(&class.students[1]));
+
+        assert_eq!(class.advance_turn().unwrap(), start + 1);
+        class.set_lockstep(false, None);
+        assert!(!class.is_waiting(&class.students[1]));
+        assert_eq!(class.advance_turn().unwrap_err().code, "conflict");
+    }
+
+    #[test]
+    fn test_join_codes_are_case_insensitive() {
+        let classes = Classrooms::new();
+        let (code, _) = classes.create(None, "SEED".to_string(), SeedScheme::Sha256, DeckMode::Weighted, load_test_data());
+        assert_eq!(code.len(), CODE_LENGTH);
+        let found = classes.with_class(&code.to_ascii_lowercase(), |c| Ok(c.seed.clone())).unwrap();
+        assert_eq!(found, "SEED");
+        assert_eq!(classes.with_class("NOPE99", |_| Ok(())).unwrap_err().code, "not_found");
+    }
+
+    #[test]
+    fn test_idle_classes_are_evicted() {
+        let classes = Classrooms::new();
+        let (empty, _) = classes.create(None, "SEED".to_string(), SeedScheme::Sha256, DeckMode::Weighted, load_test_data());
+        let (playing, _) = classes.create(None, "SEED".to_string(), SeedScheme::Sha256, DeckMode::Weighted, load_test_data());
+        let limit = Duration::from_secs(60 * 60);
+        let later = classes.with_class(&empty, |c| Ok(c.created)).unwrap() + limit;
+        classes.with_class_mut(&playing, |c| {
+            c.join("Ana")?;
+            c.join("Ben")?;
+            c.students[1].last_active = later - Duration::from_secs(60);
+            Ok(())
+        }).unwrap();
+
+        assert_eq!(classes.evict_idle(later - Duration::from_secs(1), limit), 0);
+        assert_eq!(classes.evict_idle(later, limit), 1, "One student still playing keeps the class");
+        assert!(classes.with_class(&playing, |_| Ok(())).is_ok());
+        assert_eq!(classes.with_class(&empty, |_| Ok(())).unwrap_err().code, "not_found");
+    }
+}
@@ -0,0 +1,21 @@
+use actix_cors::Cors;
+use super::classroom::TEACHER_KEY_HEADER;
+use super::session::SESSION_HEADER;
+
+/// How long a browser may reuse a preflight answer, in seconds.
+const PREFLIGHT_MAX_AGE: usize = 3600;
+
+/// CORS for a frontend hosted somewhere else, such as a school LMS page embedding the game.
+/// Pages on `origins` may call the API with the headers it reads and see the ones it sends
+/// back; `*` lets any site call it.
+pub fn middleware(origins: &[String]) -> Cors {
+    let cors = Cors::default()
+        .allowed_methods(["GET", "POST"])
+        .allowed_headers(["Content-Type", SESSION_HEADER, TEACHER_KEY_HEADER])
+        .expose_headers(["Deprecation", "Link", "Content-Disposition"])
+        .max_age(PREFLIGHT_MAX_AGE);
+    origins.iter().fold(cors, |cors, origin| match origin.as_str() {
+        "*" => cors.allow_any_origin(),
+        origin => cors.allowed_origin(origin),
+    })
+}
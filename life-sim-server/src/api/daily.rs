@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+use actix_web::{web, HttpResponse};
+use schemars::JsonSchema;
+use serde::Serialize;
+use life_sim_engine::data_loader::GameData;
+use life_sim_engine::engine::endings;
+use life_sim_engine::engine::game_state::GameState;
+use super::error::ApiError;
+use super::routes::AppState;
+use super::session::SessionId;
+
+/// Today's date in UTC as YYYY-MM-DD, the same everywhere in the world.
+pub fn today() -> String {
+    let days = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs() / 86_400);
+    let (year, month, day) = civil_date(days as i64);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// The daily challenge seed for a date: every game started with it draws the same deck.
+pub fn seed_for(date: &str) -> String {
+    format!("DAILY-{}", date.replace('-', ""))
+}
+
+/// Days since 1970-01-01 to a (year, month, day) on the proleptic Gregorian calendar.
+fn civil_date(days: i64) -> (i64, u32, u32) {
+    // Shift to an era starting 0000-03-01 so leap days fall at the end of each year
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// How one finished daily run turned out.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyResult {
+    pub date: String,
+    pub ending_id: Option<String>,
+    pub ending_title: Option<String>,
+    pub money: i32,
+    pub stress: i32,
+    pub support: i32,
+    pub savings: i32,
+    pub credential_count: usize,
+}
+
+/// Finished daily runs by date, kept apart from ordinary games.
+#[derive(Default)]
+pub struct DailyResults(Mutex<HashMap<String, Vec<DailyResult>>>);
+
+impl DailyResults {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a finished daily run under the date it was played for.
+    pub fn record(&self, state: &GameState, data: &GameData) {
+        let Some(ref date) = state.daily_date else { return };
+        let ending = endings::resolve(state, &data.endings);
+        let result = DailyResult {
+            date: date.clone(),
+            ending_id: ending.map(|e| e.id.clone()),
+            ending_title: ending.map(|e| e.title.clone()),
+            money: state.money,
+            stress: state.stress,
+            support: state.support,
+            savings: state.savings,
+            credential_count: state.credentials.len(),
+        };
+        self.0.lock().unwrap().entry(date.clone()).or_default().push(result);
+    }
+
+    pub fn for_date(&self, date: &str) -> Vec<DailyResult> {
+        self.0.lock().unwrap().get(date).cloned().unwrap_or_default()
+    }
+}
+
+/// GET /api/daily — Today's challenge seed and the daily runs finished so far today.
+pub async fn get_daily(app_state: web::Data<AppState>) -> impl actix_web::Responder {
+    let date = today();
+    let results = app_state.daily.for_date(&date);
+    HttpResponse::Ok().json(serde_json::json!({
+        "seed": seed_for(&date),
+        "date": date,
+        "results": results,
+    }))
+}
+
+/// POST /api/daily — Start a game on today's challenge seed. Its result is recorded with
+/// the day's other daily runs when it ends.
+pub async fn start_daily(app_state: web::Data<AppState>, session_id: SessionId) -> Result<HttpResponse, ApiError> {
+    let date = today();
+    let seed = seed_for(&date);
+    let session = app_state.start_game(session_id, seed, |game, _| {
+        game.daily_date = Some(date.clone());
+        Ok(())
+    })?;
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "state": &session.state,
+        "message": format!("Daily challenge for {} started", date),
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data;
+
+    #[test]
+    fn test_civil_date_from_days() {
+        assert_eq!(civil_date(0), (1970, 1, 1));
+        assert_eq!(civil_date(59), (1970, 3, 1));
+        assert_eq!(civil_date(11_016), (2000, 2, 29));
+        assert_eq!(civil_date(20_743), (2026, 10, 17));
+        assert_eq!(civil_date(-1), (1969, 12, 31));
+    }
+
+    #[test]
+    fn test_daily_seed_is_the_same_for_a_date() {
+        assert_eq!(seed_for("2026-10-17"), "DAILY-20261017");
+        assert_ne!(seed_for("2026-10-17"), seed_for("2026-10-18"));
+        assert_eq!(today().len(), 10);
+    }
+
+    #[test]
+    fn test_only_daily_runs_are_recorded() {
+        let data = test_data();
+        let results = DailyResults::new();
+
+        let mut state = GameState::new(seed_for("2026-10-17"));
+        results.record(&state, &data);
+        assert!(results.for_date("2026-10-17").is_empty(), "Not tagged as a daily run");
+
+        state.daily_date = Some("2026-10-17".to_string());
+        results.record(&state, &data);
+        let recorded = results.for_date("2026-10-17");
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].money, state.money);
+        assert!(results.for_date("2026-10-18").is_empty());
+    }
+}
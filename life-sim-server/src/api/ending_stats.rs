@@ -0,0 +1,87 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use life_sim_engine::models::Ending;
+use crate::run_store::EndingCounts;
+use super::error::ApiError;
+use super::routes::AppState;
+use super::session::SessionId;
+
+/// Filters for GET /api/stats/endings.
+#[derive(Debug, Deserialize)]
+pub struct EndingStatsQuery {
+    /// A class join code; needs the teacher key.
+    pub class: Option<String>,
+}
+
+/// How often finished games reached one ending or badge.
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EndingStat {
+    pub id: String,
+    pub title: String,
+    pub badge: bool,
+    pub count: u32,
+    /// The share of finished games, from 0 to 1.
+    pub share: f64,
+}
+
+/// Every ending and badge in file order with its count. Endings no longer in the data
+/// (from an older data set) are left out.
+pub fn ending_stats(counts: &EndingCounts, endings: &[Ending]) -> Vec<EndingStat> {
+    endings.iter().map(|ending| {
+        let count = counts.reached.get(&ending.id).copied().unwrap_or(0);
+        EndingStat {
+            id: ending.id.clone(),
+            title: ending.title.clone(),
+            badge: ending.badge,
+            count,
+            share: if counts.games == 0 { 0.0 } else { f64::from(count) / f64::from(counts.games) },
+        }
+    }).collect()
+}
+
+/// GET /api/stats/endings — How often finished games reached each ending, across every game
+/// on the server or (with `class` and the teacher key) one class's games.
+pub async fn get_ending_stats(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+    req: HttpRequest,
+    query: web::Query<EndingStatsQuery>,
+) -> Result<HttpResponse, ApiError> {
+    let (class_code, game_data) = match query.class {
+        Some(ref code) => app_state.classes.with_class(code, |class| {
+            class.check_teacher(&req)?;
+            Ok((Some(class.code.clone()), class.game_data.clone()))
+        })?,
+        None => (None, app_state.game_data(&session_id)),
+    };
+    let counts = app_state.runs.ending_counts(class_code.as_deref());
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "classCode": class_code,
+        "games": counts.games,
+        "endings": ending_stats(&counts, &game_data.endings),
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data;
+
+    #[test]
+    fn test_stats_list_every_ending_with_its_share() {
+        let data = test_data();
+        let burnout = &data.endings[1];
+        let mut counts = EndingCounts { games: 4, ..EndingCounts::default() };
+        counts.reached.insert(burnout.id.clone(), 3);
+        counts.reached.insert("ending_retired".to_string(), 1);
+
+        let stats = ending_stats(&counts, &data.endings);
+        assert_eq!(stats.len(), data.endings.len(), "Retired endings are left out");
+        assert_eq!((stats[1].count, stats[1].share), (3, 0.75));
+        assert_eq!((stats[0].count, stats[0].share), (0, 0.0));
+        assert!(ending_stats(&EndingCounts::default(), &data.endings).iter().all(|s| s.share == 0.0));
+    }
+}
@@ -0,0 +1,112 @@
+use actix_web::http::StatusCode;
+use actix_web::{HttpResponse, ResponseError};
+use serde::Serialize;
+use life_sim_engine::engine::choices::InvalidChoice;
+use life_sim_engine::engine::turn_steps::StepError;
+
+/// An API failure, returned to clients as `{"error": {"code", "message", "details"}}`.
+/// `code` is stable and meant for the frontend to branch on; `message` is for players.
+#[derive(Debug, Serialize)]
+pub struct ApiError {
+    #[serde(skip)]
+    pub status: StatusCode,
+    pub code: &'static str,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<serde_json::Value>,
+}
+
+impl ApiError {
+    pub fn new(status: StatusCode, code: &'static str, message: impl Into<String>) -> Self {
+        Self { status, code, message: message.into(), details: None }
+    }
+
+    /// Attach structured context (offending fields, validation errors, ...).
+    pub fn with_details(mut self, details: serde_json::Value) -> Self {
+        self.details = Some(details);
+        self
+    }
+
+    /// 404: there is no game to act on.
+    pub fn no_game() -> Self {
+        Self::new(StatusCode::NOT_FOUND, "no_game", "No game in progress. Start a new game first.")
+    }
+
+    /// 403: the caller isn't allowed to see this (e.g. a wrong teacher key).
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::FORBIDDEN, "forbidden", message)
+    }
+
+    /// 404: an ID that doesn't name any content of this kind.
+    pub fn not_found(kind: &str, id: &str) -> Self {
+        Self::new(StatusCode::NOT_FOUND, "not_found", format!("Unknown {}: {}", kind, id))
+            .with_details(serde_json::json!({ "kind": kind, "id": id }))
+    }
+
+    /// 409: the request is well-formed, but the game's current state doesn't allow it
+    /// (can't afford it, already enrolled, game over, ...).
+    pub fn conflict(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::CONFLICT, "conflict", message)
+    }
+
+    /// 409: the game has ended; no more turns can be played.
+    pub fn game_over() -> Self {
+        Self::new(StatusCode::CONFLICT, "game_over", "Game is over!")
+    }
+
+    /// 409: a turn is being played a step at a time; `step` is the endpoint it's waiting on.
+    pub fn turn_in_progress(step: &str) -> Self {
+        Self::new(StatusCode::CONFLICT, "turn_in_progress", format!("Finish the turn in progress first (POST {} next)", step))
+            .with_details(serde_json::json!({ "nextStep": step }))
+    }
+
+    /// 409: lockstep mode — the class hasn't reached this turn yet.
+    pub fn turn_locked(allowed_turn: u32) -> Self {
+        Self::new(StatusCode::CONFLICT, "turn_locked", format!("Waiting for your teacher to open turn {}", allowed_turn + 1))
+            .with_details(serde_json::json!({ "allowedTurn": allowed_turn }))
+    }
+
+    /// 422: the submitted choices don't fit the current turn; `details.errors` lists
+    /// each offending field.
+    pub fn invalid_choice(errors: Vec<InvalidChoice>) -> Self {
+        let message = errors.iter().map(|e| e.message.as_str()).collect::<Vec<_>>().join("; ");
+        Self::new(StatusCode::UNPROCESSABLE_ENTITY, "invalid_choice", message)
+            .with_details(serde_json::json!({ "errors": errors }))
+    }
+
+    /// 400: the request itself is malformed or missing a required field.
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, "bad_request", message)
+    }
+
+    /// 500: something went wrong on the server's side.
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::INTERNAL_SERVER_ERROR, "internal", message)
+    }
+}
+
+/// A turn step taken out of order is a 409; one with choices that don't fit is a 422.
+impl From<StepError> for ApiError {
+    fn from(error: StepError) -> Self {
+        match error {
+            StepError::OutOfOrder(message) => ApiError::conflict(message),
+            StepError::Invalid(errors) => ApiError::invalid_choice(errors),
+        }
+    }
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({}): {}", self.status.as_u16(), self.code, self.message)
+    }
+}
+
+impl ResponseError for ApiError {
+    fn status_code(&self) -> StatusCode {
+        self.status
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status).json(serde_json::json!({ "error": self }))
+    }
+}
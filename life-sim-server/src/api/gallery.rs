@@ -0,0 +1,130 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+use actix_web::{web, HttpResponse};
+use schemars::JsonSchema;
+use serde::Serialize;
+use life_sim_engine::data_loader::GameData;
+use life_sim_engine::engine::endings;
+use life_sim_engine::engine::game_state::GameState;
+use crate::run_store::UnlockedEnding;
+use super::error::ApiError;
+use super::routes::AppState;
+use super::session::SessionId;
+
+/// One ending in a player's gallery. Locked endings keep their details hidden: only the
+/// silhouette of the title and a hint are sent.
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GalleryEntry {
+    pub unlocked: bool,
+    /// A badge rather than an ending.
+    pub badge: bool,
+    /// The ending's ID, once unlocked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The title, or while locked its silhouette ("██████ + ████████████").
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub narrative: Option<String>,
+    /// How to reach a locked ending.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+    /// Seconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_reached_at: Option<u64>,
+    pub times_reached: u32,
+}
+
+/// Every ending and badge in file order, unlocked or not.
+pub fn entries(unlocked: &[UnlockedEnding], data: &GameData) -> Vec<GalleryEntry> {
+    data.endings.iter().map(|ending| match unlocked.iter().find(|u| u.ending_id == ending.id) {
+        Some(u) => GalleryEntry {
+            unlocked: true,
+            badge: ending.badge,
+            id: Some(ending.id.clone()),
+            title: ending.title.clone(),
+            narrative: Some(ending.narrative.clone()),
+            hint: None,
+            first_reached_at: Some(u.first_reached_at),
+            times_reached: u.times_reached,
+        },
+        None => GalleryEntry {
+            unlocked: false,
+            badge: ending.badge,
+            id: None,
+            title: endings::silhouette(&ending.title),
+            narrative: None,
+            hint: Some(endings::hint(ending, &data.paths)),
+            first_reached_at: None,
+            times_reached: 0,
+        },
+    }).collect()
+}
+
+/// Record a just-finished game: unlock its ending and badges for the player and count them
+/// in the ending statistics. A failed save only costs the record surviving a restart, so
+/// it's reported rather than failing the turn.
+pub fn record_finished_game(app_state: &AppState, player: &str, class_code: Option<&str>, state: &GameState, data: &GameData) {
+    let resolution = endings::resolve_all(state, &data.endings);
+    let reached: Vec<&str> = resolution.primary.into_iter().chain(resolution.badges).map(|e| e.id.as_str()).collect();
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    if let Err(e) = app_state.runs.finish_game(player, class_code, &reached, now) {
+        tracing::error!("Gallery: {}", e);
+    }
+}
+
+fn gallery_body(unlocked: &[UnlockedEnding], data: &GameData) -> serde_json::Value {
+    let entries = entries(unlocked, data);
+    serde_json::json!({
+        "unlockedCount": entries.iter().filter(|e| e.unlocked).count(),
+        "total": entries.len(),
+        "endings": entries,
+    })
+}
+
+/// GET /api/gallery — Every ending, with the ones this session's player has ever reached
+/// unlocked and the rest shown as silhouettes with hints.
+pub async fn get_gallery(app_state: web::Data<AppState>, session_id: SessionId) -> HttpResponse {
+    let game_data = app_state.game_data(&session_id);
+    HttpResponse::Ok().json(gallery_body(&app_state.runs.unlocked(&session_id.0), &game_data))
+}
+
+/// GET /api/class/{code}/students/{student_id}/gallery — A student's endings gallery.
+pub async fn get_student_gallery(
+    app_state: web::Data<AppState>,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse, ApiError> {
+    let (code, student_id) = path.into_inner();
+    app_state.classes.with_class(&code, |class| {
+        let student = class.student(&student_id)?;
+        let unlocked = app_state.runs.unlocked(&student_player(&class.code, &student.id));
+        Ok(HttpResponse::Ok().json(gallery_body(&unlocked, &class.game_data)))
+    })
+}
+
+/// The gallery key for a student's games.
+pub fn student_player(code: &str, student_id: &str) -> String {
+    format!("{}/{}", code, student_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data;
+
+    #[test]
+    fn test_locked_endings_are_hidden() {
+        let data = test_data();
+        let first = &data.endings[0];
+        let unlocked = vec![UnlockedEnding { ending_id: first.id.clone(), first_reached_at: 5, times_reached: 2 }];
+
+        let gallery = entries(&unlocked, &data);
+        assert_eq!(gallery.len(), data.endings.len());
+        assert!(gallery[0].unlocked);
+        assert_eq!((gallery[0].title.as_str(), gallery[0].times_reached), (first.title.as_str(), 2));
+
+        let locked = &gallery[1];
+        assert!(!locked.unlocked && locked.id.is_none() && locked.narrative.is_none());
+        assert!(!locked.title.contains(|c: char| c.is_alphanumeric()), "Only the silhouette shows");
+        assert!(locked.hint.is_some());
+    }
+}
@@ -0,0 +1,55 @@
+use std::time::Instant;
+use actix_web::{web, HttpResponse};
+use serde::Deserialize;
+use life_sim_engine::engine::game_state::GameState;
+use life_sim_engine::engine::journal;
+use super::error::ApiError;
+use super::routes::AppState;
+use super::session::SessionId;
+
+/// Body for POST /api/journal.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewEntry {
+    pub text: Option<String>,
+    /// The turn reflected on; defaults to the latest turn played.
+    pub turn: Option<u32>,
+    /// The decision reflected on; defaults to the one answered that turn.
+    pub decision_id: Option<String>,
+}
+
+/// POST /api/journal — Write a reflection on a turn of the current game.
+pub async fn add_entry(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+    body: web::Json<NewEntry>,
+) -> Result<HttpResponse, ApiError> {
+    let mut session = app_state.session_mut(&session_id)?;
+    let state = &mut session.state;
+    write(state, body.into_inner())
+}
+
+/// POST /api/class/{code}/students/{student_id}/journal — journal for a student's game.
+pub async fn add_student_entry(
+    app_state: web::Data<AppState>,
+    path: web::Path<(String, String)>,
+    body: web::Json<NewEntry>,
+) -> Result<HttpResponse, ApiError> {
+    let (code, student_id) = path.into_inner();
+    app_state.classes.with_class_mut(&code, |class| {
+        let student = class.student_mut(&student_id)?;
+        let response = write(&mut student.state, body.into_inner())?;
+        student.last_active = Instant::now();
+        Ok(response)
+    })
+}
+
+fn write(state: &mut GameState, body: NewEntry) -> Result<HttpResponse, ApiError> {
+    let text = body.text.ok_or_else(|| ApiError::bad_request("Missing 'text' field"))?;
+    let entry = journal::add_entry(state, &text, body.turn, body.decision_id.as_deref())
+        .map_err(ApiError::bad_request)?;
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "entry": entry,
+        "journal": state.journal,
+    })))
+}
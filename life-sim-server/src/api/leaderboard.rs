@@ -0,0 +1,115 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+use actix_web::{web, HttpResponse};
+use serde::Deserialize;
+use life_sim_engine::data_loader::GameData;
+use life_sim_engine::engine::game_state::GameState;
+use life_sim_engine::engine::turn_runner;
+use crate::run_store::RunRecord;
+use super::error::ApiError;
+use super::routes::AppState;
+use super::session::{GameSession, SessionId};
+
+/// Entries returned when the request doesn't ask for a number.
+const DEFAULT_LIMIT: usize = 20;
+const MAX_NAME_LENGTH: usize = 32;
+
+/// Filters for GET /api/leaderboard.
+#[derive(Debug, Deserialize)]
+pub struct LeaderboardQuery {
+    pub seed: Option<String>,
+    /// A class join code.
+    pub class: Option<String>,
+    pub limit: Option<usize>,
+}
+
+#[derive(Deserialize)]
+pub struct SubmitRun {
+    pub name: Option<String>,
+}
+
+/// GET /api/leaderboard — Submitted runs ranked by score, optionally for one seed or class.
+pub async fn get_leaderboard(
+    app_state: web::Data<AppState>,
+    query: web::Query<LeaderboardQuery>,
+) -> HttpResponse {
+    let class = query.class.as_ref().map(|c| c.to_ascii_uppercase());
+    let runs = app_state.runs.ranked(|run| {
+        query.seed.as_ref().is_none_or(|seed| run.seed == *seed)
+            && class.as_ref().is_none_or(|code| run.class_code.as_ref() == Some(code))
+    });
+    let total = runs.len();
+    let entries: Vec<serde_json::Value> = runs.into_iter()
+        .take(query.limit.unwrap_or(DEFAULT_LIMIT))
+        .enumerate()
+        .map(|(i, run)| entry(i + 1, &run))
+        .collect();
+
+    HttpResponse::Ok().json(serde_json::json!({
+        "count": total,
+        "entries": entries,
+    }))
+}
+
+/// POST /api/leaderboard — Submit the finished current game under a name. Submitting the
+/// same run again changes nothing and returns the original entry.
+pub async fn submit_run(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+    body: web::Json<SubmitRun>,
+) -> Result<HttpResponse, ApiError> {
+    let name = body.into_inner().name.unwrap_or_default();
+    let session = app_state.session(&session_id)?;
+    let GameSession { state, game_data, .. } = &*session;
+    submit(&app_state, &name, None, state, game_data)
+}
+
+/// POST /api/class/{code}/students/{student_id}/leaderboard — Submit a student's finished
+/// game under their class name.
+pub async fn submit_student_run(
+    app_state: web::Data<AppState>,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse, ApiError> {
+    let (code, student_id) = path.into_inner();
+    // Saving the run writes the runs file, so copy the game out and let the class go first
+    let (name, class_code, state, game_data) = app_state.classes.with_class(&code, |class| {
+        let student = class.student(&student_id)?;
+        Ok((student.name.clone(), class.code.clone(), student.state.clone(), class.game_data.clone()))
+    })?;
+    submit(&app_state, &name, Some(&class_code), &state, &game_data)
+}
+
+fn submit(
+    app_state: &AppState,
+    name: &str,
+    class_code: Option<&str>,
+    state: &GameState,
+    game_data: &GameData,
+) -> Result<HttpResponse, ApiError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(ApiError::bad_request("Missing 'name' field"));
+    }
+    if name.chars().count() > MAX_NAME_LENGTH {
+        return Err(ApiError::bad_request(format!("Names are at most {} characters", MAX_NAME_LENGTH)));
+    }
+    if !turn_runner::is_game_over(state, &game_data.stages) {
+        return Err(ApiError::conflict("Finish the game before submitting it to the leaderboard"));
+    }
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    let (run, added) = app_state.runs.submit(RunRecord::new(name, class_code, state, game_data, now))
+        .map_err(ApiError::internal)?;
+    let rank = app_state.runs.ranked(|_| true).iter().position(|r| r.run_id == run.run_id).map_or(0, |i| i + 1);
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "entry": entry(rank, &run),
+        "duplicate": !added,
+    })))
+}
+
+/// A run with its place on the board.
+fn entry(rank: usize, run: &RunRecord) -> serde_json::Value {
+    let mut entry = serde_json::to_value(run).unwrap_or_default();
+    entry["rank"] = rank.into();
+    entry
+}
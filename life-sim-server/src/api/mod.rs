@@ -0,0 +1,15 @@
+pub mod routes;
+pub mod admin;
+pub mod classroom;
+pub mod daily;
+pub mod journal;
+pub mod quiz;
+pub mod leaderboard;
+pub mod gallery;
+pub mod ending_stats;
+pub mod cors;
+pub mod error;
+pub mod openapi;
+pub mod ws;
+pub mod session;
+pub mod turn_steps;
@@ -0,0 +1,655 @@
+use schemars::generate::{SchemaGenerator, SchemaSettings};
+use schemars::JsonSchema;
+use serde_json::{json, Map, Value};
+use super::admin::ADMIN_KEY_HEADER;
+use super::classroom::TEACHER_KEY_HEADER;
+use super::daily::DailyResult;
+use super::ending_stats::EndingStat;
+use super::gallery::GalleryEntry;
+use life_sim_engine::engine::actions::ActionStatus;
+use life_sim_engine::engine::choices::{InvalidChoice, OptionPreview};
+use life_sim_engine::engine::compat::ApiVersion;
+use life_sim_engine::engine::endings::{EndingStatus, NearMiss};
+use life_sim_engine::engine::epilogue::Epilogue;
+use life_sim_engine::engine::milestones::MilestoneStatus;
+use life_sim_engine::engine::stat_changes::StatChange;
+use life_sim_engine::engine::rng::RngDraw;
+use life_sim_engine::engine::event_deck::CardOdds;
+use life_sim_engine::engine::game_state::{DecisionEntry, GameState, JobApplication, JournalEntry, QuizAnswer, StatSnapshot};
+use life_sim_engine::engine::quiz::QuizSummary;
+use life_sim_engine::engine::report::ReportRow;
+use life_sim_engine::engine::score::Score;
+use life_sim_engine::engine::stage_report::StageReport;
+use life_sim_engine::engine::summary::RunSummary;
+use life_sim_engine::engine::replay::Outcome;
+use crate::run_store::RunRecord;
+use life_sim_engine::models::{CuratedSeed, DeckMode, Decision, Ending, EventCard, HousingTier, Rarity, Scenario, Stage, StageDefinition, StatDefinition, TransportOption};
+use life_sim_engine::models::job::JobTier;
+
+/// OpenAPI 3.1 description of every endpoint in one API version. Payload types (GameState,
+/// EventCard, ...) come from the model structs; the response envelopes around them are
+/// described here, so keep this in step with routes.rs when an endpoint changes.
+pub fn openapi(version: ApiVersion) -> Value {
+    let mut docs = Docs::new(version);
+    let state = docs.schema::<GameState>();
+    let stage = docs.schema::<Stage>();
+    let action_status = docs.schema::<ActionStatus>();
+    let decision = docs.schema::<Decision>();
+    let event = docs.schema::<EventCard>();
+    let ending = docs.schema::<Ending>();
+    let decision_entry = docs.schema::<DecisionEntry>();
+    let journal_entry = docs.schema::<JournalEntry>();
+    let quiz_answer = docs.schema::<QuizAnswer>();
+    let quiz_summary = docs.schema::<QuizSummary>();
+    let card_odds = docs.schema::<CardOdds>();
+    let deck_mode = docs.schema::<DeckMode>();
+    let stage_definition = docs.schema::<StageDefinition>();
+    let scenario = docs.schema::<Scenario>();
+    let curated_seed = docs.schema::<CuratedSeed>();
+    let rng_draw = docs.schema::<RngDraw>();
+    let stat_definition = docs.schema::<StatDefinition>();
+    let job_tier = docs.schema::<JobTier>();
+    let application = docs.schema::<JobApplication>();
+    let housing = docs.schema::<HousingTier>();
+    let transport = docs.schema::<TransportOption>();
+    let stat_snapshot = docs.schema::<StatSnapshot>();
+    let rarity = docs.schema::<Rarity>();
+    let ending_status = docs.schema::<EndingStatus>();
+    let near_miss = docs.schema::<NearMiss>();
+    let option_preview = docs.schema::<OptionPreview>();
+    let report_row = docs.schema::<ReportRow>();
+    let daily_result = docs.schema::<DailyResult>();
+    let run_record = docs.schema::<RunRecord>();
+    let gallery_entry = docs.schema::<GalleryEntry>();
+    let ending_stat = docs.schema::<EndingStat>();
+    let score = docs.schema::<Score>();
+    let epilogue = docs.schema::<Epilogue>();
+    let stage_report = docs.schema::<StageReport>();
+    let milestone_status = docs.schema::<MilestoneStatus>();
+    let stat_change = docs.schema::<StatChange>();
+    let state_message = object(json!({ "state": state, "message": string() }));
+    let state_feedback = object(json!({ "state": state, "feedback": array(string()) }));
+
+    docs.get("/health", "Health check", object(json!({
+        "status": string(), "game": string(), "version": string(),
+    })), &[]);
+    docs.get("/openapi.json", "This document", json!({ "type": "object" }), &[]);
+
+    // Game loop
+    docs.post("/new_game", "Start a new game", Some(object_with(json!({
+        "seed": { "type": "string", "description": "Seed for a reproducible run; random if omitted." },
+        "tutorial": { "type": "boolean", "default": false, "description": "Script the opening turns' cards and add guidance to their feedback, per tutorial.json." },
+        "scenario": { "type": "string", "description": "ID of a scenario to start from (see /scenarios)." },
+        "turnSeconds": { "type": "integer", "minimum": 1, "description": "Time each turn; once it runs out, the turn is played frozen under pressure (no actions, first open options)." },
+        "deckMode": deck_mode.clone(),
+    }), &[])), state_message.clone(), &[400, 404]);
+    docs.get("/daily", "Today's daily challenge seed and the daily runs finished today", object(json!({
+        "seed": string(),
+        "date": { "type": "string", "description": "Today in UTC, YYYY-MM-DD." },
+        "results": array(daily_result),
+    })), &[]);
+    docs.post("/daily", "Start a game on today's daily challenge seed", None, state_message.clone(), &[]);
+    let state_with_milestones = json!({ "allOf": [state, object(json!({ "milestones": array(milestone_status) }))] });
+    docs.get("/state", "Current game state, with progress toward each milestone", state_with_milestones.clone(), &[404]);
+    let phase_data = object(json!({
+        "actions": array(action_status),
+        "decision": nullable(decision),
+        "availableEventCount": integer(),
+        "isGameOver": boolean(),
+        "availableTimeSlots": integer(),
+        "bankedTimeSlots": { "type": "integer", "description": "Slots carried over from last turn, already counted in availableTimeSlots." },
+        "timeBankCap": { "type": "integer", "description": "Most slots a turn can bank; 0 when banking is off." },
+        "canReroll": { "type": "boolean", "description": "This stage's reroll is unused and economy.json prices it in money or support." },
+        "currentStage": stage,
+        "stageName": nullable(string()),
+        "currentTurn": integer(),
+    }));
+    let timed_phase_data = json!({ "allOf": [phase_data.clone(), object(json!({
+        "secondsLeft": { "type": ["integer", "null"], "description": "Seconds left on the turn in a timed game; null when untimed." },
+    }))] });
+    docs.get("/phase_data", "Actions, decision, and event count for the current turn", timed_phase_data, &[404]);
+    let drawn_event = object(json!({
+        "event": nullable(event.clone()),
+        "events": { "type": "array", "items": event.clone(), "description": "Every card dealt this turn, in order; later stages deal more than one. `event` is the first." },
+        "playerSupport": integer(),
+    }));
+    docs.get("/draw_event", "Draw this turn's event cards for preview; submit_turn resolves the same cards", drawn_event.clone(), &[404]);
+    docs.get("/draw_odds", "The chance of each card being this turn's first event draw", object(json!({
+        "cards": array(card_odds),
+        "rarityWeights": { "type": "object", "additionalProperties": number() },
+        "drawn": nullable(string()),
+    })), &[404]);
+    docs.post("/reroll_event", "Pay money or support to swap the first drawn card for another deck card, once per stage", Some(object(json!({
+        "pay": { "type": "string", "enum": ["money", "support"] },
+    }))), object(json!({
+        "replaced": event.clone(),
+        "event": event.clone(),
+        "events": array(event.clone()),
+        "message": string(),
+        "state": state.clone(),
+    })), &[400, 404, 409]);
+    let turn_choices = object_with(json!({
+        "actionIds": { "type": "array", "items": string(), "description": "Phase 1 actions, within the available time slots." },
+        "decisionId": { "type": "string", "description": "This turn's decision, if it has one." },
+        "decisionOptionIndex": { "type": "integer", "minimum": 0 },
+        "deferDecision": { "type": "boolean", "default": false, "description": "Put this turn's decision off instead of answering it; only if it has a deferConsequence." },
+        "eventOptionIndex": { "type": ["integer", "null"], "minimum": 0, "description": "Response to the drawn card, if there is one." },
+        "eventOptionIndices": {
+            "type": "array",
+            "items": { "type": ["integer", "null"], "minimum": 0 },
+            "description": "Responses to every card drawn this turn, in draw order, in place of eventOptionIndex.",
+        },
+        "eventId": { "type": ["string", "null"], "description": "The card being answered. If sent, it must be the card drawn this turn." },
+    }), &[]);
+    let turn_result = object(json!({
+        "state": state,
+        "turnResult": object(json!({
+            "feedback": array(string()),
+            "eventDrawn": nullable(event.clone()),
+            "eventsDrawn": array(event.clone()),
+            "stageTransitioned": boolean(),
+            "newStage": nullable(stage.clone()),
+            "oldStage": nullable(stage.clone()),
+            "stressWarning": nullable(string()),
+            "statChanges": array(stat_change),
+        })),
+        "isGameOver": boolean(),
+    }));
+    docs.post("/submit_turn", "Submit the turn's choices and run it", Some(turn_choices.clone()), turn_result.clone(), &[400, 404, 409, 422]);
+    docs.post("/simulate_turn", "Preview a turn's outcome without committing it", Some(turn_choices.clone()), turn_result.clone(), &[400, 404, 409, 422]);
+    let step_result = object(json!({
+        "feedback": { "type": "array", "items": string(), "description": "What this step's phase did." },
+        "events": { "type": "array", "items": event.clone(), "description": "The turn's cards, dealt by the plan step." },
+        "nextStep": { "type": "string", "enum": ["commit", "resolve_event"] },
+        "state": state,
+    }));
+    docs.post("/plan", "Start the turn a step at a time: lock in its actions (actionIds) and run Phase 1", Some(turn_choices.clone()), step_result.clone(), &[400, 404, 409, 422]);
+    docs.post("/commit", "Make the planned turn's decision (decisionId, decisionOptionIndex, deferDecision) and run Phase 2", Some(turn_choices.clone()), step_result, &[400, 404, 409, 422]);
+    docs.post("/resolve_event", "Answer the turn's cards (eventOptionIndex or eventOptionIndices) and play out the rest of it", Some(turn_choices.clone()), turn_result.clone(), &[400, 404, 409, 422]);
+    docs.get("/endings", "The ending the player has reached, the badges earned, the endings nearly reached, the run's score and grade, and its epilogue", object(json!({
+        "ending": nullable(ending.clone()),
+        "badges": array(ending.clone()),
+        "nearMisses": array(near_miss),
+        "score": score,
+        "epilogue": epilogue,
+        "state": state,
+    })), &[404]);
+    docs.get("/endings/all", "Every ending with its conditions, and during a game where the run stands", object(json!({
+        "endings": array(json!({ "allOf": [ending, {
+            "type": "object",
+            "properties": { "status": ending_status },
+        }] })),
+    })), &[]);
+    let timeline_entry = json!({ "allOf": [decision_entry, object(json!({
+        "reflections": { "type": "array", "items": string(), "description": "Journal entries on this decision's turn." },
+    }))] });
+    docs.get("/timeline", "The most impactful decisions, in turn order, with the player's reflections", object(json!({
+        "timeline": array(timeline_entry),
+        "journal": array(journal_entry.clone()),
+        "seed": string(),
+    })), &[404]);
+    let run_summary = docs.schema::<RunSummary>();
+    docs.get("/summary", "Totals across the run: money in and out, peak stress, turns in debt, event answers, credentials by stage", run_summary, &[404]);
+    let verified = object(json!({
+        "seed": string(),
+        "turn": integer(),
+        "hash": { "type": "string", "description": "SHA-256 of the seed, used event IDs, and decision log; equal for games that played the same sequence the same way." },
+    }));
+    docs.get("/verify", "A hash of the seed, the cards drawn, and the decision log, for checking two games played the same run", verified.clone(), &[404]);
+    let outcome = docs.schema::<Outcome>();
+    docs.post("/whatif", "Replay the game from its seed with different choices for one past turn, and compare where it ends up", Some(object_with(json!({
+        "turn": integer(),
+        "actionIds": array(string()),
+        "decisionOptionIndex": integer(),
+        "eventOptionIndex": integer(),
+    }), &["turn"])), object(json!({
+        "turn": integer(),
+        "endingChanged": boolean(),
+        "original": outcome.clone(),
+        "whatIf": outcome,
+        "adjustments": array(string()),
+        "isGameOver": boolean(),
+    })), &[400, 404, 422]);
+    docs.get("/stats_history", "The player's stats after every turn so far, oldest first", object(json!({
+        "history": array(stat_snapshot),
+    })), &[404]);
+    docs.get("/stage_reports", "A recap of each finished life stage: stat changes, credentials earned and the biggest event", object(json!({
+        "reports": array(stage_report),
+    })), &[404]);
+    let event_entry = json!({ "allOf": [event, {
+        "type": "object",
+        "properties": { "used": { "type": "boolean", "description": "Whether this game has drawn the card; present only during a game." } },
+    }] });
+    docs.get_with_query("/events", "Browse the event card pool", json!({
+        "stage": stage,
+        "rarity": rarity,
+        "used": { "type": "boolean", "description": "Needs a game in progress." },
+    }), object(json!({
+        "count": integer(),
+        "events": array(event_entry),
+    })), &[400, 404]);
+    docs.get("/decisions/{id}/preview", "The stats each option of a decision would leave the player with", object(json!({
+        "decisionId": string(),
+        "options": array(option_preview),
+    })), &[404]);
+    docs.get("/stages", "Stage definitions in play order", array(stage_definition), &[]);
+    docs.get("/stats", "Player stats: label, icon, range, and starting value", array(stat_definition), &[]);
+    docs.get("/scenarios", "Preconfigured starts a new game can pick", array(scenario), &[]);
+    docs.get("/seeds", "Playtested seeds with labels describing their decks, for new games and classes", array(curated_seed), &[]);
+    let ranked_run = json!({ "allOf": [run_record, object(json!({ "rank": integer() }))] });
+    docs.get_with_query("/leaderboard", "Submitted runs ranked by score", json!({
+        "seed": string(),
+        "class": { "type": "string", "description": "A class join code." },
+        "limit": { "type": "integer", "minimum": 0, "default": 20 },
+    }), object(json!({
+        "count": { "type": "integer", "description": "Runs matching the filters, before the limit." },
+        "entries": array(ranked_run.clone()),
+    })), &[400]);
+    let submitted_run = object(json!({
+        "entry": ranked_run,
+        "duplicate": { "type": "boolean", "description": "The run was already on the board; nothing changed." },
+    }));
+    docs.post("/leaderboard", "Submit the finished game to the leaderboard", Some(object(json!({
+        "name": { "type": "string", "maxLength": 32 },
+    }))), submitted_run.clone(), &[400, 404, 409, 500]);
+    let gallery = object(json!({
+        "unlockedCount": integer(),
+        "total": integer(),
+        "endings": array(gallery_entry),
+    }));
+    docs.get("/gallery", "Every ending, with the ones this player has ever reached unlocked and the rest as silhouettes with hints", gallery.clone(), &[]);
+    docs.get_with_query("/stats/endings", "How often finished games reached each ending, on the whole server or in one class", json!({
+        "class": { "type": "string", "description": "A class join code; counts only its students' games and needs X-Teacher-Key." },
+    }), object(json!({
+        "classCode": nullable(string()),
+        "games": integer(),
+        "endings": array(ending_stat),
+    })), &[403, 404]);
+    docs.get_with_query("/export/report", "Per-turn stats and decisions for the current game or a class; CSV unless format=json", json!({
+        "format": { "type": "string", "enum": ["csv", "json"], "default": "csv" },
+        "class": { "type": "string", "description": "A class join code; reports every student's game and needs X-Teacher-Key." },
+    }), object(json!({
+        "rows": array(report_row),
+    })), &[400, 403, 404]);
+    docs.also_returns("get", "/export/report", "text/csv", string());
+    let journal_body = object_with(json!({
+        "text": { "type": "string", "maxLength": 2000 },
+        "turn": { "type": "integer", "description": "A turn already played; defaults to the latest." },
+        "decisionId": { "type": "string", "description": "A decision answered that turn; defaults to the turn's decision." },
+    }), &["text"]);
+    let journal_written = object(json!({
+        "entry": journal_entry.clone(),
+        "journal": array(journal_entry),
+    }));
+    docs.post("/journal", "Write a reflection on a played turn and its decision", Some(journal_body.clone()), journal_written.clone(), &[400, 404]);
+    let quiz_body = object(json!({
+        "sourceId": { "type": "string", "description": "The event card or decision the question is on; it must have come up this game." },
+        "choice": { "type": "integer", "minimum": 0 },
+    }));
+    let quiz_answered = object(json!({
+        "answer": quiz_answer,
+        "correctChoice": integer(),
+        "explanation": nullable(string()),
+        "summary": quiz_summary.clone(),
+    }));
+    docs.post("/quiz", "Answer the quiz question on an event card or decision from this game; each is answered once", Some(quiz_body.clone()), quiz_answered.clone(), &[400, 404]);
+
+    // Careers
+    let tags = array(string());
+    docs.get("/jobs", "Jobs and side gigs for this stage, with eligibility", object(json!({
+        "jobs": array(object(json!({
+            "id": string(), "title": string(), "description": string(),
+            "hireChance": number(), "payPerTurn": integer(), "stressPerTurn": integer(),
+            "requiredTags": tags, "recommendedTags": tags,
+            "growthRate": integer(), "growthTag": nullable(string()),
+            "tiers": array(job_tier.clone()), "paths": tags,
+            "eligible": boolean(), "isCurrent": boolean(),
+            "missingRequired": tags, "missingRecommended": tags,
+        }))),
+        "currentJob": nullable(object(json!({
+            "jobTitle": string(), "jobTurns": integer(), "growthRate": integer(),
+            "growthTag": nullable(string()), "tier": integer(), "tenure": integer(),
+            "nextTier": nullable(job_tier),
+        }))),
+        "sideGigs": array(object(json!({
+            "id": string(), "title": string(), "description": string(),
+            "payPerTurn": integer(), "stressPerTurn": integer(), "timeCost": integer(),
+            "requiredTags": tags, "eligible": boolean(), "isActive": boolean(),
+            "missingRequired": tags,
+        }))),
+    })), &[404]);
+    docs.post("/jobs/change", "Switch to another job, or quit with a null jobId", Some(object(json!({
+        "jobId": nullable(string()),
+    }))), state_feedback.clone(), &[400, 404, 409]);
+    docs.post("/jobs/{id}/apply", "Apply for a job; a seeded roll decides on an interview next turn", None, object(json!({
+        "application": application,
+        "message": string(),
+        "state": state,
+    })), &[404, 409]);
+    docs.post("/side_gigs/{id}/start", "Take on a side gig", None, state_message.clone(), &[404, 409]);
+    docs.post("/side_gigs/{id}/drop", "Quit a side gig", None, state_message.clone(), &[404, 409]);
+
+    // Living costs
+    docs.get("/housing", "Housing tiers for this stage and the current home", object(json!({
+        "tiers": array(object(json!({
+            "id": string(), "title": string(), "description": string(),
+            "bills": integer(), "stressPerTurn": integer(), "movingCost": integer(), "isCurrent": boolean(),
+        }))),
+        "current": nullable(housing),
+    })), &[404]);
+    docs.post("/housing/move", "Move into another housing tier", Some(object(json!({
+        "housingId": string(),
+    }))), state_feedback.clone(), &[400, 404, 409]);
+    docs.get("/transport", "Transport options for this stage and the current one", object(json!({
+        "options": array(object(json!({
+            "id": string(), "title": string(), "description": string(),
+            "purchaseCost": integer(), "costPerTurn": integer(), "stressPerTurn": integer(),
+            "grantsTag": nullable(string()), "breakdownChance": number(), "isCurrent": boolean(),
+        }))),
+        "current": nullable(transport),
+    })), &[404]);
+    docs.post("/transport/{id}/acquire", "Buy into a transport option", None, state_feedback, &[404, 409]);
+    docs.get("/insurance", "Insurance plans offered this stage", object(json!({
+        "plans": array(object(json!({
+            "id": string(), "title": string(), "description": string(), "covers": tags,
+            "premiumPerTurn": integer(), "maxOutOfPocket": integer(), "isActive": boolean(),
+        }))),
+    })), &[404]);
+    docs.post("/insurance/{id}/buy", "Start paying for a plan", None, state_message.clone(), &[404, 409]);
+    docs.post("/insurance/{id}/cancel", "Stop paying for a plan", None, state_message.clone(), &[404, 409]);
+
+    // Classrooms
+    let student_path = "/class/{code}/students/{student_id}";
+    let teacher_key = "The teacherKey returned when the class was created.";
+    docs.post("/class", "Create a class: a shared seed, a join code for students, and a key for the teacher", Some(object_with(json!({
+        "name": string(),
+        "seed": { "type": "string", "description": "Seed every student plays; random if omitted." },
+        "legacySeed": { "type": "boolean", "default": false, "description": "Seed students' games the way classes did before seeds were hashed, so an old class seed deals the same cards." },
+        "deckMode": deck_mode.clone(),
+    }), &[])), object(json!({
+        "code": string(), "name": nullable(string()), "seed": string(), "legacySeed": boolean(), "deckMode": deck_mode.clone(), "teacherKey": string(),
+    })), &[400]);
+    let allowed_turn = json!({
+        "type": ["integer", "null"],
+        "description": "Lockstep mode: the last turn students may play. Null when students play at their own pace.",
+    });
+    docs.get("/class/{code}", "The class and its participants (teacher only)", object(json!({
+        "code": string(), "name": nullable(string()), "seed": string(), "legacySeed": boolean(), "deckMode": deck_mode, "allowedTurn": allowed_turn,
+        "students": array(object(json!({
+            "id": string(), "name": string(), "currentTurn": integer(), "currentStage": stage, "isGameOver": boolean(), "verifyHash": string(),
+        }))),
+    })), &[403, 404]);
+    docs.require_header("get", "/class/{code}", TEACHER_KEY_HEADER, teacher_key);
+    docs.get("/class/{code}/dashboard", "Every student's turn, stats, and credentials, with stuck students flagged (teacher only)", object(json!({
+        "code": string(),
+        "allowedTurn": allowed_turn,
+        "medianTurn": integer(),
+        "finishedCount": integer(),
+        "students": array(object(json!({
+            "id": string(), "name": string(), "currentTurn": integer(), "currentStage": stage,
+            "money": integer(), "stress": integer(), "support": integer(), "savings": integer(),
+            "credentialCount": integer(), "isGameOver": boolean(),
+            "waiting": { "type": "boolean", "description": "Played up to the lockstep turn and waiting for the teacher." },
+            "idleSeconds": { "type": "integer", "description": "Time since the student joined or last submitted a turn." },
+            "stuck": boolean(),
+            "stuckReason": nullable(string()),
+        }))),
+    })), &[403, 404]);
+    docs.require_header("get", "/class/{code}/dashboard", TEACHER_KEY_HEADER, teacher_key);
+    docs.get("/class/{code}/quiz", "Each student's quiz accuracy and missed questions (teacher only)", object(json!({
+        "code": string(),
+        "students": array(json!({ "allOf": [quiz_summary.clone(), object(json!({ "studentId": string(), "name": string() }))] })),
+    })), &[403, 404]);
+    docs.require_header("get", "/class/{code}/quiz", TEACHER_KEY_HEADER, teacher_key);
+    docs.post("/class/{code}/lockstep", "Turn lockstep mode on or off; without allowedTurn it regroups at the furthest-behind student (teacher only)", Some(object_with(json!({
+        "enabled": boolean(),
+        "allowedTurn": { "type": "integer", "minimum": 0 },
+    }), &["enabled"])), object(json!({ "allowedTurn": allowed_turn })), &[400, 403, 404]);
+    docs.require_header("post", "/class/{code}/lockstep", TEACHER_KEY_HEADER, teacher_key);
+    docs.post("/class/{code}/advance", "Open the next turn for a lockstep class (teacher only)", None, object(json!({
+        "allowedTurn": integer(),
+    })), &[403, 404, 409]);
+    docs.require_header("post", "/class/{code}/advance", TEACHER_KEY_HEADER, teacher_key);
+    docs.post("/class/{code}/join", "Join a class and start a game on its seed", Some(object(json!({
+        "name": string(),
+    }))), object(json!({
+        "classCode": string(), "studentId": string(), "state": state,
+    })), &[400, 404, 409]);
+    docs.get(&format!("{}/state", student_path), "A student's game state, with milestones", state_with_milestones, &[404]);
+    docs.get(&format!("{}/phase_data", student_path), "phase_data for a student's game", phase_data.clone(), &[404]);
+    docs.get(&format!("{}/draw_event", student_path), "draw_event for a student's game", drawn_event.clone(), &[404]);
+    docs.get(&format!("{}/verify", student_path), "verify for a student's game", verified, &[404]);
+    docs.post(&format!("{}/submit_turn", student_path), "submit_turn for a student's game", Some(turn_choices.clone()), turn_result.clone(), &[400, 404, 409, 422]);
+    docs.post(&format!("{}/leaderboard", student_path), "Submit a student's finished game to the leaderboard under their class name", None, submitted_run, &[404, 409, 500]);
+    docs.get(&format!("{}/gallery", student_path), "A student's endings gallery", gallery, &[404]);
+    docs.post(&format!("{}/journal", student_path), "journal for a student's game", Some(journal_body), journal_written, &[400, 404]);
+    docs.post(&format!("{}/quiz", student_path), "quiz for a student's game", Some(quiz_body), quiz_answered, &[400, 404]);
+
+    // Admin and debug
+    docs.post("/admin/reload_data", "Re-read and validate game data for newly started games", None, object(json!({
+        "message": string(),
+        "counts": { "type": "object", "additionalProperties": integer() },
+    })), &[403, 422, 500]);
+    docs.header("post", "/admin/reload_data", ADMIN_KEY_HEADER, false, "The server's admin key. Not needed from the server's own machine when no key is configured.");
+    docs.get("/debug/data_sources", "The pack each loaded entry came from, by data file and ID", json!({
+        "type": "object",
+        "additionalProperties": { "type": "object", "additionalProperties": string() },
+    }), &[]);
+    docs.post("/debug/skip_stage", "Jump to the start of the next stage", None, state_message.clone(), &[404]);
+    docs.post("/debug/set_stats", "Set any stat values", Some(object_with(json!({
+        "money": integer(), "stress": integer(), "support": integer(), "monthlyBills": integer(),
+        "emergencyFund": integer(), "savings": integer(), "turn": integer(),
+    }), &[])), state_message.clone(), &[400, 404]);
+    docs.post("/debug/grant_tag", "Grant a credential tag", Some(object(json!({
+        "tag": string(),
+    }))), state_message, &[400, 404]);
+    docs.post("/debug/fast_forward", "Let a bot play the next turns", Some(object_with(json!({
+        "turns": integer(),
+        "toEnd": boolean(),
+        "strategy": { "type": "string", "enum": life_sim_engine::engine::bot::STRATEGIES },
+    }), &[])), object(json!({
+        "state": state,
+        "turns": array(object(json!({ "turn": integer(), "feedback": array(string()) }))),
+        "isGameOver": boolean(),
+        "message": string(),
+    })), &[400, 404, 409]);
+    docs.get("/debug/rng_log", "Every random draw the game has taken (servers started with --rng-log)", object(json!({
+        "seed": string(),
+        "turn": integer(),
+        "draws": array(rng_draw),
+    })), &[403, 404]);
+
+    docs.finish()
+}
+
+/// Collects paths and the component schemas they reference.
+struct Docs {
+    version: ApiVersion,
+    generator: SchemaGenerator,
+    paths: Map<String, Value>,
+}
+
+impl Docs {
+    fn new(version: ApiVersion) -> Self {
+        let generator = SchemaSettings::draft2020_12()
+            .with(|s| s.definitions_path = "/components/schemas".into())
+            .for_serialize()
+            .into_generator();
+        Self { version, generator, paths: Map::new() }
+    }
+
+    /// A `$ref` to `T`, adding it (and whatever it uses) to the components.
+    fn schema<T: JsonSchema>(&mut self) -> Value {
+        self.generator.subschema_for::<T>().to_value()
+    }
+
+    fn get(&mut self, path: &str, summary: &str, response: Value, errors: &[u16]) {
+        self.operation("get", path, summary, None, response, errors);
+    }
+
+    /// A GET with optional query parameters, given as `{ name: schema }`.
+    fn get_with_query(&mut self, path: &str, summary: &str, query: Value, response: Value, errors: &[u16]) {
+        self.get(path, summary, response, errors);
+        let operation = &mut self.paths[&format!("/api/{}{}", self.version.segment(), path)]["get"];
+        let parameters: Vec<Value> = query.as_object().into_iter().flatten()
+            .map(|(name, schema)| json!({ "name": name, "in": "query", "required": false, "schema": schema }))
+            .collect();
+        operation["parameters"] = parameters.into();
+    }
+
+    /// Add another content type the operation's 200 response can come back as.
+    fn also_returns(&mut self, method: &str, path: &str, content_type: &str, schema: Value) {
+        let operation = &mut self.paths[&format!("/api/{}{}", self.version.segment(), path)][method];
+        operation["responses"]["200"]["content"][content_type] = json!({ "schema": schema });
+    }
+
+    /// Mark an operation as needing a request header.
+    fn require_header(&mut self, method: &str, path: &str, name: &str, description: &str) {
+        self.header(method, path, name, true, description);
+    }
+
+    fn header(&mut self, method: &str, path: &str, name: &str, required: bool, description: &str) {
+        let operation = &mut self.paths[&format!("/api/{}{}", self.version.segment(), path)][method];
+        let mut parameters = operation["parameters"].as_array().cloned().unwrap_or_default();
+        parameters.push(json!({ "name": name, "in": "header", "required": required, "description": description, "schema": string() }));
+        operation["parameters"] = parameters.into();
+    }
+
+    fn post(&mut self, path: &str, summary: &str, request: Option<Value>, response: Value, errors: &[u16]) {
+        self.operation("post", path, summary, request, response, errors);
+    }
+
+    fn operation(&mut self, method: &str, path: &str, summary: &str, request: Option<Value>, response: Value, errors: &[u16]) {
+        let mut operation = json!({
+            "summary": summary,
+            "responses": { "200": { "description": "OK", "content": { "application/json": { "schema": response } } } },
+        });
+        let parameters: Vec<Value> = path.split('/')
+            .filter_map(|segment| segment.strip_prefix('{')?.strip_suffix('}'))
+            .map(|name| json!({ "name": name, "in": "path", "required": true, "schema": string() }))
+            .collect();
+        if !parameters.is_empty() {
+            operation["parameters"] = parameters.into();
+        }
+        if let Some(schema) = request {
+            operation["requestBody"] = json!({ "required": true, "content": { "application/json": { "schema": schema } } });
+        }
+        for status in errors {
+            operation["responses"][status.to_string()] = json!({
+                "description": error_description(*status),
+                "content": { "application/json": { "schema": { "$ref": "#/components/schemas/ApiError" } } },
+            });
+        }
+        self.paths.entry(format!("/api/{}{}", self.version.segment(), path)).or_insert_with(|| json!({}))[method] = operation;
+    }
+
+    fn finish(mut self) -> Value {
+        let invalid_choice = self.schema::<InvalidChoice>();
+        let mut schemas = self.generator.take_definitions(true);
+        schemas.insert("ApiError".to_string(), object(json!({
+            "error": object_with(json!({
+                "code": { "type": "string", "description": "Stable error code to branch on, e.g. no_game or invalid_choice." },
+                "message": { "type": "string", "description": "Human-readable explanation for players." },
+                "details": {
+                    "description": "Structured context. For invalid_choice and invalid_data, `errors` lists each problem.",
+                    "type": "object",
+                    "properties": { "errors": { "type": "array", "items": { "anyOf": [invalid_choice, string()] } } },
+                },
+            }), &["code", "message"]),
+        })));
+        json!({
+            "openapi": "3.1.0",
+            "info": {
+                "title": format!("Life Roguelite API {}", self.version.segment()),
+                "version": env!("CARGO_PKG_VERSION"),
+                "description": "Game state lives on the server; every endpoint returns JSON. Failures return an ApiError body. \
+                    Send an X-Session-Id header to play a game of your own; requests without one share a single game. \
+                    The unversioned /api prefix is a deprecated alias for v1.",
+            },
+            "jsonSchemaDialect": "https://json-schema.org/draft/2020-12/schema",
+            "paths": self.paths,
+            "components": { "schemas": schemas },
+        })
+    }
+}
+
+fn error_description(status: u16) -> &'static str {
+    match status {
+        400 => "Malformed request (bad_request)",
+        403 => "Missing or wrong teacher key (forbidden)",
+        404 => "No game in progress (no_game) or unknown ID (not_found)",
+        409 => "Not allowed in the current game state (conflict, game_over, turn_locked)",
+        422 => "Choices or data failed validation (invalid_choice, invalid_data)",
+        _ => "Server error (internal)",
+    }
+}
+
+/// An object schema where every listed property is required.
+fn object(properties: Value) -> Value {
+    let required: Vec<String> = properties.as_object().map_or_else(Vec::new, |p| p.keys().cloned().collect());
+    json!({ "type": "object", "properties": properties, "required": required })
+}
+
+/// An object schema with only the named properties required.
+fn object_with(properties: Value, required: &[&str]) -> Value {
+    json!({ "type": "object", "properties": properties, "required": required })
+}
+
+fn array(items: Value) -> Value {
+    json!({ "type": "array", "items": items })
+}
+
+fn nullable(schema: Value) -> Value {
+    json!({ "anyOf": [schema, { "type": "null" }] })
+}
+
+fn string() -> Value {
+    json!({ "type": "string" })
+}
+
+fn integer() -> Value {
+    json!({ "type": "integer" })
+}
+
+fn number() -> Value {
+    json!({ "type": "number" })
+}
+
+fn boolean() -> Value {
+    json!({ "type": "boolean" })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_route_is_documented() {
+        let spec = openapi(ApiVersion::V1);
+        let routes = include_str!("routes.rs");
+        let mut count = 0;
+        for line in routes.lines().map(str::trim).filter(|l| l.starts_with(".route(\"")) {
+            let path = line.split('"').nth(1).unwrap();
+            let method = line.split("web::").nth(1).and_then(|m| m.split('(').next()).unwrap();
+            assert!(
+                spec["paths"][format!("/api/v1{}", path)][method].is_object(),
+                "{} /api/v1{} is missing from the OpenAPI document", method, path
+            );
+            count += 1;
+        }
+        assert_eq!(count, spec["paths"].as_object().unwrap().values().map(|p| p.as_object().unwrap().len()).sum::<usize>());
+    }
+
+    #[test]
+    fn test_component_refs_resolve() {
+        let spec = openapi(ApiVersion::V1);
+        let schemas = spec["components"]["schemas"].as_object().unwrap();
+        assert!(schemas.contains_key("GameState") && schemas.contains_key("ApiError"));
+        let text = spec.to_string();
+        for reference in text.split("\"$ref\":\"#/components/schemas/").skip(1) {
+            let name = reference.split('"').next().unwrap();
+            assert!(schemas.contains_key(name), "Unresolved reference to {}", name);
+        }
+        assert_eq!(spec["paths"]["/api/v1/jobs/{id}/apply"]["post"]["parameters"][0]["name"], "id");
+    }
+}
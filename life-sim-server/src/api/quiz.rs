@@ -0,0 +1,81 @@
+use std::time::Instant;
+use actix_web::{web, HttpRequest, HttpResponse};
+use serde::Deserialize;
+use life_sim_engine::data_loader::GameData;
+use life_sim_engine::engine::game_state::GameState;
+use life_sim_engine::engine::quiz;
+use super::error::ApiError;
+use super::routes::AppState;
+use super::session::{GameSession, SessionId};
+
+/// Body for POST /api/quiz.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuizResponse {
+    /// The event card or decision whose question is answered.
+    pub source_id: Option<String>,
+    /// Index of the chosen answer.
+    pub choice: Option<usize>,
+}
+
+/// POST /api/quiz — Answer the question on an event card or decision from the current game.
+pub async fn answer(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+    body: web::Json<QuizResponse>,
+) -> Result<HttpResponse, ApiError> {
+    let mut session = app_state.session_mut(&session_id)?;
+    let GameSession { state, game_data, .. } = &mut *session;
+    respond(state, game_data, body.into_inner())
+}
+
+/// POST /api/class/{code}/students/{student_id}/quiz — quiz for a student's game.
+pub async fn student_answer(
+    app_state: web::Data<AppState>,
+    path: web::Path<(String, String)>,
+    body: web::Json<QuizResponse>,
+) -> Result<HttpResponse, ApiError> {
+    let (code, student_id) = path.into_inner();
+    app_state.classes.with_class_mut(&code, |class| {
+        let game_data = class.game_data.clone();
+        let student = class.student_mut(&student_id)?;
+        let response = respond(&mut student.state, &game_data, body.into_inner())?;
+        student.last_active = Instant::now();
+        Ok(response)
+    })
+}
+
+/// GET /api/class/{code}/quiz — Each student's quiz accuracy, for the teacher.
+pub async fn class_summary(
+    app_state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    app_state.classes.with_class(&path.into_inner(), |class| {
+        class.check_teacher(&req)?;
+        let students: Vec<serde_json::Value> = class.students.iter().map(|s| {
+            let mut summary = serde_json::to_value(quiz::summary(&s.state)).unwrap_or_default();
+            summary["studentId"] = s.id.clone().into();
+            summary["name"] = s.name.clone().into();
+            summary
+        }).collect();
+        Ok(HttpResponse::Ok().json(serde_json::json!({
+            "code": class.code,
+            "students": students,
+        })))
+    })
+}
+
+fn respond(state: &mut GameState, game_data: &GameData, body: QuizResponse) -> Result<HttpResponse, ApiError> {
+    let source_id = body.source_id.ok_or_else(|| ApiError::bad_request("Missing 'sourceId' field"))?;
+    let choice = body.choice.ok_or_else(|| ApiError::bad_request("Missing 'choice' field"))?;
+    let answer = quiz::answer(state, game_data, &source_id, choice).map_err(ApiError::bad_request)?;
+    // Now that it's answered, the question can give away its answer
+    let question = quiz::question_for(state, game_data, &source_id);
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "answer": answer,
+        "correctChoice": question.map(|q| q.answer),
+        "explanation": question.and_then(|q| q.explanation.clone()),
+        "summary": quiz::summary(state),
+    })))
+}
@@ -0,0 +1,1520 @@
+use actix_web::{middleware, web, HttpRequest, HttpResponse, Responder};
+use actix_web::http::StatusCode;
+use super::admin;
+use super::error::ApiError;
+use super::classroom::{self, Classrooms};
+use super::daily::{self, DailyResults};
+use super::journal;
+use super::quiz;
+use super::leaderboard;
+use super::gallery;
+use super::ending_stats;
+use super::openapi;
+use super::turn_steps as steps;
+use super::ws::{self, TurnStream};
+use super::session::{self, GameSession, SessionId};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Instant;
+use dashmap::DashMap;
+use dashmap::mapref::one::{Ref, RefMut};
+use life_sim_engine::data_loader::{DataErrors, DataStore, GameData};
+use crate::run_store::RunStore;
+use crate::analytics::Analytics;
+use life_sim_engine::engine::game_state::{GameCommand, GameState};
+use life_sim_engine::engine::rng::{self, GameRng};
+use life_sim_engine::engine::turn_runner;
+use life_sim_engine::engine::actions;
+use life_sim_engine::engine::bot;
+use life_sim_engine::engine::choices;
+use life_sim_engine::engine::compat::ApiVersion;
+use life_sim_engine::engine::event_deck;
+use life_sim_engine::engine::calendar;
+use life_sim_engine::engine::hiring;
+use life_sim_engine::engine::career;
+use life_sim_engine::engine::insurance;
+use life_sim_engine::engine::housing;
+use life_sim_engine::engine::transport;
+use life_sim_engine::engine::paths;
+use life_sim_engine::engine::endings;
+use life_sim_engine::engine::epilogue;
+use life_sim_engine::engine::milestones;
+use life_sim_engine::engine::report;
+use life_sim_engine::engine::score;
+use life_sim_engine::engine::summary;
+use life_sim_engine::engine::replay;
+use life_sim_engine::engine::reroll::{self, RerollPayment};
+use life_sim_engine::engine::scenarios;
+use life_sim_engine::engine::turn_steps;
+use life_sim_engine::models::{DeckMode, EventCard, Rarity, Stage};
+use serde::Deserialize;
+
+/// Shared server state: every game in progress by session, plus any classes' games.
+pub struct AppState {
+    /// Games being played, by the session header their requests carry.
+    pub sessions: DashMap<SessionId, GameSession>,
+    /// The live data set, swapped by POST /api/admin/reload_data.
+    pub data: DataStore,
+    /// Turn updates pushed to clients connected to /ws.
+    pub turn_stream: TurnStream,
+    /// Classes, each with its own games apart from the sessions above.
+    pub classes: Classrooms,
+    /// Finished daily challenge runs, by date.
+    pub daily: DailyResults,
+    /// Runs submitted to the leaderboard.
+    pub runs: RunStore,
+    /// Opt-in gameplay log (disabled unless the server was started with --analytics).
+    pub analytics: Analytics,
+    /// Whether new games record every random draw for GET /api/debug/rng_log (off unless
+    /// the server was started with --rng-log).
+    pub rng_log: bool,
+    /// The key admin requests must send, from the server config (loopback only if unset).
+    pub admin_key: Option<String>,
+}
+
+impl AppState {
+    /// A session's game, to read. Counts as activity, like playing it.
+    pub fn session(&self, id: &SessionId) -> Result<Ref<'_, SessionId, GameSession>, ApiError> {
+        let session = self.sessions.get(id).ok_or_else(ApiError::no_game)?;
+        session.touch();
+        Ok(session)
+    }
+
+    /// A session's game, to play.
+    pub fn session_mut(&self, id: &SessionId) -> Result<RefMut<'_, SessionId, GameSession>, ApiError> {
+        let session = self.sessions.get_mut(id).ok_or_else(ApiError::no_game)?;
+        session.touch();
+        Ok(session)
+    }
+
+    /// Drop games nobody has touched for `SESSION_IDLE_LIMIT`, closing their turn streams.
+    /// Returns how many went.
+    pub fn evict_idle_sessions(&self) -> usize {
+        let evicted = session::evict_idle(&self.sessions, Instant::now(), session::SESSION_IDLE_LIMIT);
+        for id in &evicted {
+            self.turn_stream.close(id);
+        }
+        evicted.len()
+    }
+
+    /// Drop classes whose students have all been idle for `SESSION_IDLE_LIMIT`. Returns how
+    /// many went.
+    pub fn evict_idle_classes(&self) -> usize {
+        self.classes.evict_idle(Instant::now(), session::SESSION_IDLE_LIMIT)
+    }
+
+    /// Game data for a session's game, or the live data set if it has none running.
+    pub fn game_data(&self, id: &SessionId) -> Arc<GameData> {
+        self.sessions.get(id).map_or_else(|| self.data.current(), |s| s.game_data.clone())
+    }
+
+    /// Replace a session's game with a fresh one on this seed, using the live data set.
+    /// `setup` prepares the new game before it's stored (a daily run, a scenario, ...); if
+    /// it fails, the current game is left alone. Returns the stored session, still locked,
+    /// so the response serializes it in place.
+    pub fn start_game(
+        &self,
+        id: SessionId,
+        seed: String,
+        setup: impl FnOnce(&mut GameState, &GameData) -> Result<(), ApiError>,
+    ) -> Result<RefMut<'_, SessionId, GameSession>, ApiError> {
+        let game_data = self.data.current();
+        let mut state = turn_runner::start_game(seed.clone(), &game_data);
+        setup(&mut state, &game_data)?;
+
+        let mut rng = state.seed_rng();
+        if self.rng_log {
+            rng.audit();
+        }
+        let session = GameSession::new(state, rng, game_data);
+        Ok(self.sessions.entry(id).insert(session))
+    }
+}
+
+/// Health check endpoint.
+pub async fn health() -> impl Responder {
+    HttpResponse::Ok().json(serde_json::json!({
+        "status": "ok",
+        "game": "Life Roguelite",
+        "version": env!("CARGO_PKG_VERSION")
+    }))
+}
+
+/// GET /api/openapi.json — OpenAPI description of every endpoint in this API version.
+pub async fn openapi_json(version: web::Data<ApiVersion>) -> impl Responder {
+    HttpResponse::Ok().json(openapi::openapi(**version))
+}
+
+/// POST /api/new_game — Start a new game (optional seed param). `scenario` starts from a
+/// scenarios.json setup; `tutorial: true` scripts the opening turns from tutorial.json;
+/// `turnSeconds` times every turn, freezing the player under pressure once it runs out;
+/// `deckMode: "shuffled"` deals each stage's deck in an order fixed when the stage starts.
+pub async fn new_game(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+    body: web::Json<serde_json::Value>,
+) -> Result<HttpResponse, ApiError> {
+    let seed = body.get("seed")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+        .unwrap_or_else(rng::generate_seed);
+    let tutorial = body.get("tutorial").and_then(|v| v.as_bool()).unwrap_or(false);
+    let scenario_id = body.get("scenario").and_then(|v| v.as_str());
+    let turn_seconds = match body.get("turnSeconds") {
+        None | Some(serde_json::Value::Null) => None,
+        Some(v) => Some(v.as_u64().and_then(|s| u32::try_from(s).ok()).filter(|&s| s > 0).ok_or_else(|| {
+            ApiError::bad_request("turnSeconds must be a whole number of seconds above 0")
+        })?),
+    };
+    let deck_mode = match body.get("deckMode") {
+        None | Some(serde_json::Value::Null) => DeckMode::default(),
+        Some(v) => DeckMode::deserialize(v)
+            .map_err(|_| ApiError::bad_request("deckMode must be \"weighted\" or \"shuffled\""))?,
+    };
+
+    let session = app_state.start_game(session_id, seed.clone(), |game, data| {
+        if let Some(id) = scenario_id {
+            let scenario = data.scenario(id)
+                .ok_or_else(|| ApiError::not_found("scenario", id))?;
+            scenarios::apply(game, scenario, data);
+        }
+        game.tutorial = tutorial;
+        game.turn_seconds = turn_seconds;
+        game.deck_mode = deck_mode;
+        Ok(())
+    })?;
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "state": &session.state,
+        "message": format!("New game started with seed: {}", seed)
+    })))
+}
+
+/// GET /api/state — Get current game state, with progress toward each milestone.
+pub async fn get_state(app_state: web::Data<AppState>, session_id: SessionId) -> Result<HttpResponse, ApiError> {
+    let session = app_state.session(&session_id)?;
+    let GameSession { state, game_data, .. } = &*session;
+    Ok(HttpResponse::Ok().json(state_body(state, game_data)))
+}
+
+/// Response body for state: the game state plus its `milestones`, for any one game.
+pub(super) fn state_body(state: &GameState, game_data: &GameData) -> serde_json::Value {
+    let mut body = serde_json::to_value(state).unwrap_or_default();
+    if let Some(fields) = body.as_object_mut() {
+        fields.insert("milestones".to_string(), serde_json::json!(milestones::statuses(state, game_data)));
+    }
+    body
+}
+
+/// GET /api/phase_data — Get available actions, decisions, and events for the current turn.
+pub async fn phase_data(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+) -> Result<HttpResponse, ApiError> {
+    let session = app_state.session(&session_id)?;
+    let GameSession { state, game_data, .. } = &*session;
+    let mut body = phase_data_body(state, game_data);
+    if let Some(fields) = body.as_object_mut() {
+        fields.insert("secondsLeft".to_string(), serde_json::json!(session.seconds_left()));
+    }
+    Ok(HttpResponse::Ok().json(body))
+}
+
+/// Response body for phase_data, for any one game.
+pub(super) fn phase_data_body(state: &GameState, game_data: &GameData) -> serde_json::Value {
+    let stage = &state.current_stage;
+
+    // Actions for this stage, with any cooldown left on each
+    let actions = actions::statuses(state, game_data);
+
+    // Decision for this stage: one scheduled for this turn, then a pending
+    // promotion offer, otherwise the first for the stage
+    // (decisions for other life paths are skipped)
+    let decision = choices::current_decision(state, game_data);
+
+    // Available event count
+    let path = state.path.as_ref().map(|p| p.id.as_str());
+    let available_events = event_deck::available_events(
+        game_data.stage_deck(stage), stage, path, calendar::month(state), &state.used_event_ids
+    );
+
+    let is_game_over = turn_runner::is_game_over(state, &game_data.stages);
+
+    serde_json::json!({
+        "actions": actions,
+        "decision": decision,
+        "availableEventCount": available_events.len(),
+        "isGameOver": is_game_over,
+        "availableTimeSlots": state.available_time_slots(),
+        "bankedTimeSlots": state.banked_time_slots,
+        "timeBankCap": game_data.economy.time_bank_cap,
+        "canReroll": reroll::available(state, game_data),
+        "currentStage": state.current_stage,
+        "stageName": turn_runner::stage_def(&game_data.stages, stage).map(|s| &s.name),
+        "currentTurn": state.current_turn,
+    })
+}
+
+/// GET /api/draw_event — Draw this turn's event cards for preview (before player picks options).
+/// The drawn cards are cached so submit_turn uses the same ones.
+pub async fn draw_event(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+) -> Result<HttpResponse, ApiError> {
+    let mut session = app_state.session_mut(&session_id)?;
+    let GameSession { state, rng, pending_events, game_data, .. } = &mut *session;
+
+    Ok(HttpResponse::Ok().json(draw_event_body(state, rng, pending_events, game_data)))
+}
+
+/// Draw this turn's cards for one game (unless they're already pending) and describe them.
+/// `event` is the first card, for clients that only answer one.
+pub(super) fn draw_event_body(
+    state: &mut GameState,
+    rng: &mut GameRng,
+    pending: &mut Vec<EventCard>,
+    game_data: &GameData,
+) -> serde_json::Value {
+    // A turn played a step at a time already holds its cards
+    if let Some(partial) = &state.turn_in_progress {
+        return serde_json::json!({
+            "event": partial.events.first(),
+            "events": partial.events,
+            "playerSupport": state.support,
+        });
+    }
+
+    // Draw the events if we haven't already for this turn
+    // (a due interview or a breakdown takes the first slot)
+    if pending.is_empty() {
+        *pending = turn_runner::draw_turn_events(state, game_data, rng);
+        state.rng_position = rng.position();
+        state.commands.push(GameCommand::DrawEvent);
+    }
+
+    serde_json::json!({
+        "event": pending.first(),
+        "events": &*pending,
+        "playerSupport": state.support,
+    })
+}
+
+/// GET /api/draw_odds — The chance of each card being this turn's event draw, using the
+/// same stage, path, used-card, and rarity-weight rules as the draw itself, with the
+/// weights scaled to the player's luck. `drawn` names the first card if draw_event has
+/// already drawn it.
+pub async fn draw_odds(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+) -> Result<HttpResponse, ApiError> {
+    let session = app_state.session(&session_id)?;
+    let GameSession { state, pending_events, game_data, .. } = &*session;
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "cards": turn_runner::draw_odds(state, game_data),
+        "rarityWeights": turn_runner::rarity_tiers(state, game_data).iter()
+            .map(|t| (t.id.clone(), t.weight))
+            .collect::<BTreeMap<_, _>>(),
+        "drawn": state.turn_in_progress.as_ref().map_or(&**pending_events, |p| &p.events).first().map(|e| &e.id),
+    })))
+}
+
+/// POST /api/submit_turn — Submit choices and run one turn.
+/// If pending events were drawn via /api/draw_event, those events are used.
+pub async fn submit_turn(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+    version: web::Data<ApiVersion>,
+    body: web::Json<serde_json::Value>,
+) -> Result<HttpResponse, ApiError> {
+    let mut session = app_state.session_mut(&session_id)?;
+    let frozen = session.out_of_time();
+    let GameSession { state, rng, pending_events, game_data, .. } = &mut *session;
+
+    let turn = state.current_turn;
+    let (choices, result) = play_turn(state, rng, pending_events, game_data, **version, &body, frozen)?;
+    let response = turn_played(&app_state, &session_id, turn, state, game_data, &choices, result);
+    session.start_turn_clock();
+    Ok(response)
+}
+
+/// Everything that follows a session's turn being played: log it, stream it, record a
+/// finished game, and respond with the turn's result.
+pub(super) fn turn_played(
+    app_state: &AppState,
+    session_id: &SessionId,
+    turn: u32,
+    state: &GameState,
+    game_data: &GameData,
+    choices: &turn_runner::PlayerChoices,
+    result: turn_runner::TurnResult,
+) -> HttpResponse {
+    app_state.analytics.turn_played("", state, game_data, choices, &result);
+
+    let game_over = turn_runner::is_game_over(state, &game_data.stages);
+    app_state.turn_stream.publish(session_id, ws::turn_events(turn, &result, game_over));
+    if game_over {
+        app_state.daily.record(state, game_data);
+        gallery::record_finished_game(app_state, &session_id.0, None, state, game_data);
+    }
+
+    HttpResponse::Ok().json(turn_response(state, result, game_data))
+}
+
+/// Between-turn choices and whole turns wait while a turn is being played a step at a
+/// time, so the command log keeps everything in the order it happened.
+pub(super) fn between_turns(state: &GameState) -> Result<(), ApiError> {
+    match state.turn_in_progress {
+        Some(_) => Err(ApiError::turn_in_progress(turn_steps::next_step(state).endpoint())),
+        None => Ok(()),
+    }
+}
+
+/// POST /api/reroll_event — Pay (`pay`: "money" or "support") to swap the first drawn card
+/// for another from the stage's deck, once per stage. The cards must have been drawn with
+/// draw_event first.
+pub async fn reroll_event(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+    body: web::Json<serde_json::Value>,
+) -> Result<HttpResponse, ApiError> {
+    let mut session = app_state.session_mut(&session_id)?;
+    let GameSession { state, rng, pending_events, game_data, .. } = &mut *session;
+    if turn_runner::is_game_over(state, &game_data.stages) {
+        return Err(ApiError::game_over());
+    }
+    between_turns(state)?;
+
+    let pay = body.get("pay").ok_or_else(|| ApiError::bad_request("Missing 'pay' field"))?;
+    let pay: RerollPayment = serde_json::from_value(pay.clone())
+        .map_err(|_| ApiError::bad_request("pay must be \"money\" or \"support\""))?;
+    let replaced = reroll::reroll_event(state, pending_events, pay, game_data, rng).map_err(ApiError::conflict)?;
+    state.commands.push(GameCommand::RerollEvent { pay });
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "replaced": replaced,
+        "event": pending_events.first(),
+        "events": &*pending_events,
+        "message": format!("🎲 Rerolled {}: {} instead", replaced.title, pending_events[0].title),
+        "state": &*state,
+    })))
+}
+
+/// Feedback for a timed turn whose time ran out.
+pub(super) const FROZE: &str = "⏱️ Time ran out and you froze under pressure: no actions, and the first open option every time";
+
+/// Parse, validate, and run one submitted turn for one game. A `frozen` player's turn is
+/// played with `bot::frozen_choices` instead of the body. Returns the choices played along
+/// with the result.
+pub(super) fn play_turn(
+    state: &mut GameState,
+    rng: &mut GameRng,
+    pending: &mut Vec<EventCard>,
+    game_data: &GameData,
+    version: ApiVersion,
+    body: &serde_json::Value,
+    frozen: bool,
+) -> Result<(turn_runner::PlayerChoices, turn_runner::TurnResult), ApiError> {
+    let _turn = turn_span(state).entered();
+    if turn_runner::is_game_over(state, &game_data.stages) {
+        return Err(ApiError::game_over());
+    }
+    between_turns(state)?;
+
+    // Each API version has its own submission format; all of them become PlayerChoices
+    let choices = version.parse_choices(body).map_err(ApiError::invalid_choice)?;
+
+    // Check the choices against this turn's cards (drawing them now if the client didn't preview them)
+    if pending.is_empty() {
+        *pending = turn_runner::draw_turn_events(state, game_data, rng);
+    }
+    let choices = if frozen { bot::frozen_choices(state, game_data, pending) } else { choices };
+    choices::validate_choices(state, &choices, game_data, pending)
+        .map_err(ApiError::invalid_choice)?;
+
+    let mut result = turn_runner::run_turn_with_events(state, &choices, game_data, rng, std::mem::take(pending));
+    if frozen {
+        result.feedback.insert(0, FROZE.to_string());
+    }
+    tracing::info!(frozen, game_over = turn_runner::is_game_over(state, &game_data.stages), "Turn played");
+    Ok((choices, result))
+}
+
+/// The span a turn is played in, so every log line it writes carries the game's seed,
+/// turn and stage.
+pub(super) fn turn_span(state: &GameState) -> tracing::Span {
+    tracing::info_span!("turn", seed = %state.seed, turn = state.current_turn, stage = %state.current_stage)
+}
+
+/// POST /api/simulate_turn — Preview the outcome of a turn's choices without committing them.
+/// Takes the same body as submit_turn and returns the same shape, but runs on a copy of the
+/// game with a fork of its RNG; submitting the same choices afterwards gives the same result.
+pub async fn simulate_turn(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+    version: web::Data<ApiVersion>,
+    body: web::Json<serde_json::Value>,
+) -> Result<HttpResponse, ApiError> {
+    let session = app_state.session(&session_id)?;
+    let GameSession { state, rng, pending_events, game_data, .. } = &*session;
+
+    if turn_runner::is_game_over(state, &game_data.stages) {
+        return Err(ApiError::game_over());
+    }
+    between_turns(state)?;
+
+    let choices = version.parse_choices(&body).map_err(ApiError::invalid_choice)?;
+
+    // Draw this turn's cards on the fork if the client hasn't previewed them,
+    // exactly as submit_turn would on the real RNG
+    let mut forked = rng.clone();
+    let events = if pending_events.is_empty() {
+        turn_runner::draw_turn_events(state, game_data, &mut forked)
+    } else {
+        pending_events.clone()
+    };
+    choices::validate_choices(state, &choices, game_data, &events)
+        .map_err(ApiError::invalid_choice)?;
+
+    let (projected, result) = turn_runner::simulate_turn(state, &choices, game_data, &forked, events);
+
+    Ok(HttpResponse::Ok().json(turn_response(&projected, result, game_data)))
+}
+
+/// Response body for a turn that was run (or simulated).
+pub(super) fn turn_response(state: &GameState, result: turn_runner::TurnResult, game_data: &GameData) -> serde_json::Value {
+    serde_json::json!({
+        "state": state,
+        "turnResult": {
+            "feedback": result.feedback,
+            "eventDrawn": result.events_drawn.first(),
+            "eventsDrawn": result.events_drawn,
+            "stageTransitioned": result.stage_transitioned,
+            "newStage": result.new_stage,
+            "oldStage": result.old_stage,
+            "stressWarning": result.stress_warning,
+            "statChanges": result.stat_changes,
+        },
+        "isGameOver": turn_runner::is_game_over(state, &game_data.stages),
+    })
+}
+
+/// GET /api/endings — Get the resolved ending, the badges earned, the endings only just
+/// missed, the run's score, and its epilogue.
+pub async fn get_ending(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+) -> Result<HttpResponse, ApiError> {
+    let session = app_state.session(&session_id)?;
+    let GameSession { state, game_data, .. } = &*session;
+    let resolution = endings::resolve_all(state, &game_data.endings);
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "ending": resolution.primary,
+        "badges": resolution.badges,
+        "nearMisses": endings::near_misses(state, &game_data.endings),
+        "score": score::score(state, &game_data.endings),
+        "epilogue": epilogue::write(state, game_data),
+        "state": state,
+    })))
+}
+
+/// GET /api/endings/all — Every ending with its conditions. During a game each one also
+/// carries a status: whether the player satisfies it now, reaches it, or has missed it for good.
+pub async fn get_all_endings(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+) -> Result<HttpResponse, ApiError> {
+    let game_data = app_state.game_data(&session_id);
+    let session = app_state.sessions.get(&session_id);
+    let statuses = session.as_ref().map(|s| {
+        endings::statuses(&s.state, &game_data.endings, turn_runner::is_game_over(&s.state, &game_data.stages))
+    });
+
+    let all: Vec<serde_json::Value> = game_data.endings.iter().enumerate()
+        .map(|(i, ending)| {
+            let mut entry = serde_json::to_value(ending).unwrap_or_default();
+            if let Some(ref statuses) = statuses {
+                entry["status"] = serde_json::to_value(&statuses[i]).unwrap_or_default();
+            }
+            entry
+        })
+        .collect();
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({ "endings": all })))
+}
+
+/// GET /api/decisions/{id}/preview — The stats each option of a decision would leave the
+/// player with, from the current state.
+pub async fn preview_decision(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+    path: web::Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    let session = app_state.session(&session_id)?;
+    let GameSession { state, game_data, .. } = &*session;
+
+    // Promotion offers are engine-generated, so look there too
+    let decision_id = path.into_inner();
+    let decision = game_data.decision(&decision_id).map(Cow::Borrowed)
+        .or_else(|| career::promotion_offer(state).filter(|d| d.id == decision_id).map(Cow::Owned))
+        .ok_or_else(|| ApiError::not_found("decision", &decision_id))?;
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "decisionId": &decision.id,
+        "options": choices::preview_decision(state, &decision, game_data),
+    })))
+}
+
+/// GET /api/scenarios — Scenarios a new game can start from.
+pub async fn get_scenarios(app_state: web::Data<AppState>) -> impl Responder {
+    HttpResponse::Ok().json(&app_state.data.current().scenarios)
+}
+
+/// GET /api/seeds — Playtested seeds from seeds.json, each labeled with what its deck is
+/// like, for picking a known deck order.
+pub async fn get_seeds(app_state: web::Data<AppState>) -> impl Responder {
+    HttpResponse::Ok().json(&app_state.data.current().seeds)
+}
+
+/// GET /api/stages — Stage definitions in play order.
+pub async fn get_stages(app_state: web::Data<AppState>, session_id: SessionId) -> impl Responder {
+    HttpResponse::Ok().json(&app_state.game_data(&session_id).stages)
+}
+
+/// GET /api/stats — How each player stat is shown and the range it's kept in.
+pub async fn get_stats(app_state: web::Data<AppState>, session_id: SessionId) -> impl Responder {
+    HttpResponse::Ok().json(&app_state.game_data(&session_id).stats)
+}
+
+/// GET /api/jobs — List available jobs for the current stage with eligibility.
+pub async fn get_jobs(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+) -> Result<HttpResponse, ApiError> {
+    let session = app_state.session(&session_id)?;
+    let GameSession { state, game_data, .. } = &*session;
+    let current_job_id = state.current_job.as_ref().map(|j| j.id.clone());
+    let jobs: Vec<serde_json::Value> = game_data.jobs.iter()
+        .filter(|j| j.stages.contains(&state.current_stage))
+        .map(|j| {
+            let missing_required = j.missing_requirements(&state.credentials);
+            let missing_recommended: Vec<&String> = j.recommended_tags.iter()
+                .filter(|t| !state.credentials.contains(t))
+                .collect();
+            let is_current = current_job_id.as_ref() == Some(&j.id);
+            let path_ok = paths::on_path(state, &j.paths);
+
+            serde_json::json!({
+                "id": j.id,
+                "hireChance": hiring::hire_chance(j, state),
+                "title": j.title,
+                "description": j.description,
+                "payPerTurn": j.pay_per_turn,
+                "stressPerTurn": j.stress_per_turn,
+                "requiredTags": j.required_tags,
+                "requiredLevels": j.required_levels,
+                "recommendedTags": j.recommended_tags,
+                "growthRate": j.growth_rate,
+                "growthTag": j.growth_tag,
+                "tiers": j.tiers,
+                "paths": j.paths,
+                "eligible": missing_required.is_empty() && path_ok,
+                "isCurrent": is_current,
+                "missingRequired": missing_required,
+                "missingRecommended": missing_recommended,
+            })
+        })
+        .collect();
+
+    let growth_info = state.current_job.as_ref().map(|j| {
+        serde_json::json!({
+            "jobTitle": j.title,
+            "jobTurns": state.job_turns,
+            "growthRate": j.growth_rate,
+            "growthTag": j.growth_tag,
+            "tier": state.job_tier,
+            "tenure": state.job_tenure,
+            "nextTier": career::next_tier(j, state.job_tier),
+        })
+    });
+
+    let side_gigs: Vec<serde_json::Value> = game_data.side_gigs.iter()
+        .filter(|g| g.stages.contains(&state.current_stage))
+        .map(|g| {
+            let missing_required: Vec<&String> = g.required_tags.iter()
+                .filter(|t| !state.credentials.contains(t))
+                .collect();
+            serde_json::json!({
+                "id": g.id,
+                "title": g.title,
+                "description": g.description,
+                "payPerTurn": g.pay_per_turn,
+                "stressPerTurn": g.stress_per_turn,
+                "timeCost": g.time_cost,
+                "requiredTags": g.required_tags,
+                "eligible": missing_required.is_empty(),
+                "isActive": state.side_gigs.iter().any(|s| s.id == g.id),
+                "missingRequired": missing_required,
+            })
+        })
+        .collect();
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "jobs": jobs,
+        "currentJob": growth_info,
+        "sideGigs": side_gigs,
+    })))
+}
+
+/// POST /api/jobs/{id}/apply — Apply for a job; a seeded roll decides whether
+/// the player lands an interview next turn.
+pub async fn apply_job(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+    path: web::Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    let mut session = app_state.session_mut(&session_id)?;
+    let GameSession { state, rng, game_data, .. } = &mut *session;
+    between_turns(state)?;
+
+    let job_id = path.into_inner();
+    let job = game_data.job(&job_id)
+        .ok_or_else(|| ApiError::not_found("job", &job_id))?;
+
+    match hiring::apply_for_job(state, job, rng) {
+        Ok(application) => {
+            state.commands.push(GameCommand::ApplyJob { job_id });
+            let message = if application.got_interview {
+                format!("📞 {} wants to interview you next turn!", job.title)
+            } else {
+                format!("📭 {} passed on your application this time.", job.title)
+            };
+            Ok(HttpResponse::Ok().json(serde_json::json!({
+                "application": application,
+                "message": message,
+                "state": &*state,
+            })))
+        }
+        Err(message) => Err(ApiError::conflict(message)),
+    }
+}
+
+/// POST /api/jobs/change — Switch to another job (`jobId`) or quit (`jobId: null`).
+pub async fn change_job(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+    body: web::Json<serde_json::Value>,
+) -> Result<HttpResponse, ApiError> {
+    let mut session = app_state.session_mut(&session_id)?;
+    let GameSession { state, game_data, .. } = &mut *session;
+    between_turns(state)?;
+
+    let new_job = match body.get("jobId").and_then(|v| v.as_str()) {
+        Some(job_id) => match game_data.job(job_id) {
+            Some(job) => Some(job),
+            None => return Err(ApiError::not_found("job", job_id)),
+        },
+        None => None,
+    };
+
+    match career::change_job(state, new_job, game_data) {
+        Ok(feedback) => {
+            state.commands.push(GameCommand::ChangeJob { job_id: new_job.map(|j| j.id.clone()) });
+            Ok(HttpResponse::Ok().json(serde_json::json!({
+                "feedback": feedback,
+                "state": &*state,
+            })))
+        }
+        Err(message) => Err(ApiError::conflict(message)),
+    }
+}
+
+/// GET /api/housing — Housing tiers for this stage and where the player lives now.
+pub async fn get_housing(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+) -> Result<HttpResponse, ApiError> {
+    let session = app_state.session(&session_id)?;
+    let GameSession { state, game_data, .. } = &*session;
+
+    let current_id = state.housing.as_ref().map(|h| h.id.as_str());
+    let tiers: Vec<serde_json::Value> = game_data.housing.iter()
+        .filter(|h| h.stages.contains(&state.current_stage))
+        .map(|h| serde_json::json!({
+            "id": h.id,
+            "title": h.title,
+            "description": h.description,
+            "bills": h.bills,
+            "stressPerTurn": h.stress_per_turn,
+            // Moving cost only applies when leaving an existing home
+            "movingCost": if state.housing.is_some() { h.moving_cost } else { 0 },
+            "isCurrent": current_id == Some(h.id.as_str()),
+        }))
+        .collect();
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "tiers": tiers,
+        "current": state.housing,
+    })))
+}
+
+/// POST /api/housing/move — Move into another housing tier (`housingId`), paying the moving cost.
+pub async fn move_housing(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+    body: web::Json<serde_json::Value>,
+) -> Result<HttpResponse, ApiError> {
+    let mut session = app_state.session_mut(&session_id)?;
+    let GameSession { state, game_data, .. } = &mut *session;
+    between_turns(state)?;
+
+    let housing_id = body.get("housingId").and_then(|v| v.as_str())
+        .ok_or_else(|| ApiError::bad_request("Missing housingId."))?;
+    let tier = game_data.housing_tier(housing_id)
+        .ok_or_else(|| ApiError::not_found("housing", housing_id))?;
+
+    match housing::move_into(state, tier) {
+        Ok(feedback) => {
+            state.commands.push(GameCommand::MoveHousing { housing_id: tier.id.clone() });
+            Ok(HttpResponse::Ok().json(serde_json::json!({
+                "feedback": feedback,
+                "state": &*state,
+            })))
+        }
+        Err(message) => Err(ApiError::conflict(message)),
+    }
+}
+
+/// GET /api/transport — Transport options for this stage and what the player uses now.
+pub async fn get_transport(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+) -> Result<HttpResponse, ApiError> {
+    let session = app_state.session(&session_id)?;
+    let GameSession { state, game_data, .. } = &*session;
+
+    let current_id = state.transport.as_ref().map(|t| t.id.as_str());
+    let options: Vec<serde_json::Value> = game_data.transport.iter()
+        .filter(|t| t.stages.contains(&state.current_stage))
+        .map(|t| serde_json::json!({
+            "id": t.id,
+            "title": t.title,
+            "description": t.description,
+            "purchaseCost": t.purchase_cost,
+            "costPerTurn": t.cost_per_turn,
+            "stressPerTurn": t.stress_per_turn,
+            "grantsTag": t.grants_tag,
+            "breakdownChance": t.breakdown_chance,
+            "isCurrent": current_id == Some(t.id.as_str()),
+        }))
+        .collect();
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "options": options,
+        "current": state.transport,
+    })))
+}
+
+/// POST /api/transport/{id}/acquire — Buy into a transport option, replacing the current one.
+pub async fn acquire_transport(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+    path: web::Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    let mut session = app_state.session_mut(&session_id)?;
+    let GameSession { state, game_data, .. } = &mut *session;
+    between_turns(state)?;
+
+    let transport_id = path.into_inner();
+    let option = game_data.transport_option(&transport_id)
+        .ok_or_else(|| ApiError::not_found("transport", &transport_id))?;
+
+    match transport::acquire(state, option) {
+        Ok(feedback) => {
+            state.commands.push(GameCommand::AcquireTransport { transport_id });
+            Ok(HttpResponse::Ok().json(serde_json::json!({
+                "feedback": feedback,
+                "state": &*state,
+            })))
+        }
+        Err(message) => Err(ApiError::conflict(message)),
+    }
+}
+
+/// GET /api/insurance — Plans offered this stage, and which ones the player holds.
+pub async fn get_insurance(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+) -> Result<HttpResponse, ApiError> {
+    let session = app_state.session(&session_id)?;
+    let GameSession { state, game_data, .. } = &*session;
+
+    let plans: Vec<serde_json::Value> = game_data.insurance.iter()
+        .filter(|p| p.stages.contains(&state.current_stage))
+        .map(|p| serde_json::json!({
+            "id": p.id,
+            "title": p.title,
+            "description": p.description,
+            "covers": p.covers,
+            "premiumPerTurn": p.premium_per_turn,
+            "maxOutOfPocket": p.max_out_of_pocket,
+            "isActive": state.coverage.iter().any(|c| c.id == p.id),
+        }))
+        .collect();
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({ "plans": plans })))
+}
+
+/// POST /api/insurance/{id}/buy — Start paying for an insurance plan.
+pub async fn buy_insurance(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+    path: web::Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    let mut session = app_state.session_mut(&session_id)?;
+    let GameSession { state, game_data, .. } = &mut *session;
+    between_turns(state)?;
+
+    let plan_id = path.into_inner();
+    let plan = game_data.insurance_plan(&plan_id)
+        .ok_or_else(|| ApiError::not_found("insurance plan", &plan_id))?;
+
+    match insurance::buy_plan(state, plan) {
+        Ok(message) => {
+            state.commands.push(GameCommand::BuyInsurance { plan_id });
+            Ok(HttpResponse::Ok().json(serde_json::json!({
+                "message": message,
+                "state": &*state,
+            })))
+        }
+        Err(message) => Err(ApiError::conflict(message)),
+    }
+}
+
+/// POST /api/insurance/{id}/cancel — Stop paying for (and lose) a plan.
+pub async fn cancel_insurance(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+    path: web::Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    let mut session = app_state.session_mut(&session_id)?;
+    let state = &mut session.state;
+    between_turns(state)?;
+
+    let plan_id = path.into_inner();
+    match insurance::cancel_plan(state, &plan_id) {
+        Ok(message) => {
+            state.commands.push(GameCommand::CancelInsurance { plan_id });
+            Ok(HttpResponse::Ok().json(serde_json::json!({
+                "message": message,
+                "state": &*state,
+            })))
+        }
+        Err(message) => Err(ApiError::conflict(message)),
+    }
+}
+
+/// POST /api/side_gigs/{id}/start — Take on a side gig alongside the main job.
+pub async fn start_side_gig(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+    path: web::Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    let mut session = app_state.session_mut(&session_id)?;
+    let GameSession { state, game_data, .. } = &mut *session;
+    between_turns(state)?;
+
+    let gig_id = path.into_inner();
+    let gig = game_data.side_gig(&gig_id)
+        .ok_or_else(|| ApiError::not_found("side gig", &gig_id))?;
+
+    match career::start_side_gig(state, gig) {
+        Ok(message) => {
+            state.commands.push(GameCommand::StartSideGig { gig_id });
+            Ok(HttpResponse::Ok().json(serde_json::json!({
+                "message": message,
+                "state": &*state,
+            })))
+        }
+        Err(message) => Err(ApiError::conflict(message)),
+    }
+}
+
+/// POST /api/side_gigs/{id}/drop — Quit a side gig, freeing its time slots.
+pub async fn drop_side_gig(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+    path: web::Path<String>,
+) -> Result<HttpResponse, ApiError> {
+    let mut session = app_state.session_mut(&session_id)?;
+    let state = &mut session.state;
+    between_turns(state)?;
+
+    let gig_id = path.into_inner();
+    match career::drop_side_gig(state, &gig_id) {
+        Ok(message) => {
+            state.commands.push(GameCommand::DropSideGig { gig_id });
+            Ok(HttpResponse::Ok().json(serde_json::json!({
+                "message": message,
+                "state": &*state,
+            })))
+        }
+        Err(message) => Err(ApiError::conflict(message)),
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Admin Endpoints
+// ═══════════════════════════════════════════════════════════════
+
+/// POST /api/admin/reload_data — Re-read and validate the data directory. Needs the
+/// admin key, or a request from this machine if the server has none.
+/// The new data applies to games started afterwards; a game in progress keeps its data.
+pub async fn reload_data(req: HttpRequest, app_state: web::Data<AppState>) -> Result<HttpResponse, ApiError> {
+    admin::check_admin(app_state.admin_key.as_deref(), &req)?;
+    // Reading files (or syncing a remote data URL) blocks, so keep it off the async workers
+    let reloaded = web::block(move || {
+        app_state.data.reload().map_err(|e| match e.downcast_ref::<DataErrors>() {
+            Some(DataErrors(errors)) => errors.clone(),
+            None => vec![e.to_string()],
+        })
+    }).await.map_err(|e| ApiError::internal(e.to_string()))?;
+    let data = reloaded.map_err(|errors| {
+        ApiError::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "invalid_data",
+            "Game data failed to load; the previous data is still active.",
+        ).with_details(serde_json::json!({ "errors": errors }))
+    })?;
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "message": "Game data reloaded. Changes apply to newly started games.",
+        "counts": {
+            "stages": data.stages.len(),
+            "events": data.events.len(),
+            "actions": data.actions.len(),
+            "decisions": data.decisions.len(),
+            "jobs": data.jobs.len(),
+            "endings": data.endings.len(),
+            "education": data.education.len(),
+            "sideGigs": data.side_gigs.len(),
+            "insurance": data.insurance.len(),
+            "housing": data.housing.len(),
+            "transport": data.transport.len(),
+            "paths": data.paths.len(),
+            "tutorial": data.tutorial.len(),
+            "scenarios": data.scenarios.len(),
+        }
+    })))
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Debug / Dev Endpoints
+// ═══════════════════════════════════════════════════════════════
+
+/// GET /api/debug/data_sources — Which pack each loaded entry came from, by file and ID.
+pub async fn debug_data_sources(app_state: web::Data<AppState>, session_id: SessionId) -> impl Responder {
+    HttpResponse::Ok().json(&app_state.game_data(&session_id).sources)
+}
+
+/// POST /api/debug/skip_stage — Jump to the start of the next stage.
+pub async fn debug_skip_stage(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+) -> Result<HttpResponse, ApiError> {
+    let mut session = app_state.session_mut(&session_id)?;
+    let GameSession { state, game_data, .. } = &mut *session;
+    between_turns(state)?;
+    let old_stage = state.current_stage.clone();
+    let end = turn_runner::stage_end_turn(&game_data.stages, &state.current_stage);
+    state.current_turn = end + 1; // Move past the boundary
+
+    // Trigger the transition
+    if let Some(ns) = turn_runner::next_stage(&game_data.stages, &state.current_stage) {
+        state.enter_stage(ns);
+    }
+    calendar::update(state, &game_data.stages);
+
+    let body = serde_json::json!({
+        "state": &*state,
+        "message": format!("Skipped from {} to {}", old_stage, state.current_stage),
+    });
+    session.start_turn_clock();
+    Ok(HttpResponse::Ok().json(body))
+}
+
+/// POST /api/debug/set_stats — Freely set any stat values.
+pub async fn debug_set_stats(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+    body: web::Json<serde_json::Value>,
+) -> Result<HttpResponse, ApiError> {
+    let mut session = app_state.session_mut(&session_id)?;
+    let state = &mut session.state;
+    if let Some(v) = body.get("money").and_then(|v| v.as_i64()) {
+        state.money = v as i32;
+    }
+    if let Some(v) = body.get("stress").and_then(|v| v.as_i64()) {
+        state.stress = v as i32;
+    }
+    if let Some(v) = body.get("support").and_then(|v| v.as_i64()) {
+        state.support = v as i32;
+    }
+    if let Some(v) = body.get("monthlyBills").and_then(|v| v.as_i64()) {
+        state.monthly_bills = v as i32;
+    }
+    if let Some(v) = body.get("emergencyFund").and_then(|v| v.as_i64()) {
+        state.emergency_fund = v as i32;
+    }
+    if let Some(v) = body.get("savings").and_then(|v| v.as_i64()) {
+        state.savings = v as i32;
+    }
+    if let Some(v) = body.get("turn").and_then(|v| v.as_u64()) {
+        state.current_turn = v as u32;
+    }
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "state": &*state,
+        "message": "Stats updated",
+    })))
+}
+
+/// POST /api/debug/grant_tag — Grant a credential tag to the player.
+pub async fn debug_grant_tag(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+    body: web::Json<serde_json::Value>,
+) -> Result<HttpResponse, ApiError> {
+    let mut session = app_state.session_mut(&session_id)?;
+    let state = &mut session.state;
+    let tag = body.get("tag").and_then(|v| v.as_str())
+        .ok_or_else(|| ApiError::bad_request("Missing 'tag' field."))?;
+    let held = state.credentials.grant(tag).name();
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "state": &*state,
+        "message": format!("Granted credential: {}", held),
+    })))
+}
+
+/// POST /api/debug/fast_forward — Let a bot play the next turns of the current game:
+/// `turns` of them (default 1, or the rest of the game with `toEnd: true`) using
+/// `strategy` (default "random"). A card already drawn for this turn is played.
+pub async fn debug_fast_forward(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+    body: web::Json<serde_json::Value>,
+) -> Result<HttpResponse, ApiError> {
+    let name = body.get("strategy").and_then(|v| v.as_str()).unwrap_or("random");
+    let strategy = bot::by_name(name).ok_or_else(|| ApiError::bad_request(format!(
+        "Unknown strategy '{}' (one of: {})", name, bot::STRATEGIES.join(", ")
+    )))?;
+    let turns = if body.get("toEnd").and_then(|v| v.as_bool()).unwrap_or(false) {
+        usize::MAX
+    } else {
+        body.get("turns").and_then(|v| v.as_u64()).unwrap_or(1) as usize
+    };
+
+    let mut session = app_state.session_mut(&session_id)?;
+    let GameSession { state, rng, pending_events, game_data, .. } = &mut *session;
+    between_turns(state)?;
+    if turn_runner::is_game_over(state, &game_data.stages) {
+        return Err(ApiError::game_over());
+    }
+
+    let mut bot_rng = rng::create_rng(&format!("{}/bot/{}", state.seed, state.current_turn));
+    let mut played = Vec::new();
+    while played.len() < turns && !turn_runner::is_game_over(state, &game_data.stages) {
+        let turn = state.current_turn;
+        let _turn = turn_span(state).entered();
+        let result = bot::play_turn(strategy.as_ref(), state, game_data, rng, &mut bot_rng, std::mem::take(pending_events));
+        let game_over = turn_runner::is_game_over(state, &game_data.stages);
+        app_state.turn_stream.publish(&session_id, ws::turn_events(turn, &result, game_over));
+        played.push(serde_json::json!({ "turn": turn, "feedback": result.feedback }));
+    }
+
+    let body = serde_json::json!({
+        "state": &*state,
+        "turns": played,
+        "isGameOver": turn_runner::is_game_over(state, &game_data.stages),
+        "message": format!("The {} bot played {} turn(s)", strategy.name(), played.len()),
+    });
+    session.start_turn_clock();
+    Ok(HttpResponse::Ok().json(body))
+}
+
+/// GET /api/debug/rng_log — Every random draw the current game has taken: its turn,
+/// stream, what it decided and the number it rolled, in order. Only games started while
+/// the server runs with --rng-log keep one.
+pub async fn debug_rng_log(app_state: web::Data<AppState>, session_id: SessionId) -> Result<HttpResponse, ApiError> {
+    let session = app_state.session(&session_id)?;
+    let log = session.rng.audit_log().ok_or_else(|| ApiError::forbidden(
+        "This game isn't keeping an RNG log; restart the server with --rng-log and start a new game",
+    ))?;
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "seed": session.state.seed,
+        "turn": session.state.current_turn,
+        "draws": log,
+    })))
+}
+
+/// Filters for GET /api/events.
+#[derive(Debug, Deserialize)]
+pub struct EventFilter {
+    pub stage: Option<Stage>,
+    pub rarity: Option<Rarity>,
+    /// Only cards already drawn (`true`) or not yet drawn (`false`) this game.
+    pub used: Option<bool>,
+}
+
+/// GET /api/events — Browse the full event card pool, optionally filtered by stage, rarity,
+/// and (during a game) whether each card has been drawn yet.
+pub async fn get_events(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+    filter: web::Query<EventFilter>,
+) -> Result<HttpResponse, ApiError> {
+    let game_data = app_state.game_data(&session_id);
+    let session = app_state.sessions.get(&session_id);
+    let used_ids = session.as_ref().map(|s| &s.state.used_event_ids);
+    if filter.used.is_some() && used_ids.is_none() {
+        return Err(ApiError::no_game());
+    }
+    if let Some(ref stage) = filter.stage {
+        if turn_runner::stage_def(&game_data.stages, stage).is_none() {
+            return Err(ApiError::not_found("stage", stage.id()));
+        }
+    }
+
+    let events: Vec<serde_json::Value> = game_data.events.iter()
+        .filter(|e| filter.stage.as_ref().is_none_or(|s| e.stages.contains(s)))
+        .filter(|e| filter.rarity.as_ref().is_none_or(|r| e.rarity == *r))
+        .filter_map(|e| {
+            let used = used_ids.map(|ids| ids.contains(&e.id));
+            if filter.used.is_some_and(|want| used != Some(want)) {
+                return None;
+            }
+            let mut card = serde_json::to_value(e).ok()?;
+            // Drawn/undrawn only means something while a game is running
+            if let Some(used) = used {
+                card["used"] = used.into();
+            }
+            Some(card)
+        })
+        .collect();
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "count": events.len(),
+        "events": events,
+    })))
+}
+
+/// Output formats for GET /api/export/report.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportFormat {
+    #[default]
+    Csv,
+    Json,
+}
+
+/// Query for GET /api/export/report.
+#[derive(Debug, Deserialize)]
+pub struct ReportQuery {
+    #[serde(default)]
+    pub format: ReportFormat,
+    /// Report every game in this class instead of the current game (needs the teacher key).
+    pub class: Option<String>,
+}
+
+/// GET /api/export/report — Per-turn stats and decisions for the current game, or with
+/// `class` every student's game, as CSV for a spreadsheet (or JSON).
+pub async fn export_report(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+    req: actix_web::HttpRequest,
+    query: web::Query<ReportQuery>,
+) -> Result<HttpResponse, ApiError> {
+    let (name, rows) = match query.class {
+        Some(ref code) => app_state.classes.with_class(code, |class| {
+            class.check_teacher(&req)?;
+            let rows: Vec<report::ReportRow> = class.students.iter()
+                .flat_map(|s| report::report_rows(&s.name, &s.state))
+                .collect();
+            Ok((format!("class-{}", class.code), rows))
+        })?,
+        None => {
+            let session = app_state.session(&session_id)?;
+            let state = &session.state;
+            (format!("game-{}", state.seed), report::report_rows("player", state))
+        }
+    };
+
+    match query.format {
+        ReportFormat::Json => Ok(HttpResponse::Ok().json(serde_json::json!({ "rows": rows }))),
+        ReportFormat::Csv => {
+            // Seeds are free text, so keep the file name to safe characters
+            let file_name: String = name.chars().filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_').collect();
+            Ok(HttpResponse::Ok()
+                .content_type("text/csv; charset=utf-8")
+                .insert_header(("Content-Disposition", format!("attachment; filename=\"life-sim-{}.csv\"", file_name)))
+                .body(report::to_csv(&rows)))
+        }
+    }
+}
+
+/// GET /api/stats_history — The player's stats after every turn so far, for charts.
+pub async fn get_stats_history(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+) -> Result<HttpResponse, ApiError> {
+    let session = app_state.session(&session_id)?;
+    let state = &session.state;
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "history": state.stats_history,
+    })))
+}
+
+/// GET /api/stage_reports — A recap of each life stage the player has finished, for the
+/// screen between stages.
+pub async fn get_stage_reports(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+) -> Result<HttpResponse, ApiError> {
+    let session = app_state.session(&session_id)?;
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "reports": session.state.stage_reports,
+    })))
+}
+
+/// GET /api/timeline — Get the top 8 most impactful decisions, each with the player's
+/// reflections on its turn, plus the whole journal.
+pub async fn get_timeline(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+) -> Result<HttpResponse, ApiError> {
+    let session = app_state.session(&session_id)?;
+    let state = &session.state;
+    let mut entries: Vec<_> = state.decision_log.iter().collect();
+    // Sort by total absolute impact magnitude (descending)
+    entries.sort_by(|a, b| {
+        let mag_a: i32 = a.impact.split(", ")
+            .filter_map(|s| s.split_whitespace().last())
+            .filter_map(|v| v.parse::<i32>().ok())
+            .map(|v| v.abs())
+            .sum();
+        let mag_b: i32 = b.impact.split(", ")
+            .filter_map(|s| s.split_whitespace().last())
+            .filter_map(|v| v.parse::<i32>().ok())
+            .map(|v| v.abs())
+            .sum();
+        mag_b.cmp(&mag_a)
+    });
+    entries.truncate(8);
+    // Re-sort by turn order for display
+    entries.sort_by_key(|e| e.turn);
+    let timeline: Vec<serde_json::Value> = entries.iter().map(|entry| {
+        let mut value = serde_json::to_value(entry).unwrap_or_default();
+        value["reflections"] = life_sim_engine::engine::journal::entries_for_turn(state, entry.turn)
+            .filter(|r| r.decision_id.is_none() || r.decision_id == entry.decision_id)
+            .map(|r| r.text.clone())
+            .collect();
+        value
+    }).collect();
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "timeline": timeline,
+        "journal": state.journal,
+        "seed": &state.seed,
+    })))
+}
+
+/// GET /api/summary — Totals across the run so far: money in and out, peak stress, turns
+/// in debt, how event cards were answered, and credentials by stage.
+pub async fn get_summary(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+) -> Result<HttpResponse, ApiError> {
+    let session = app_state.session(&session_id)?;
+    let GameSession { state, game_data, .. } = &*session;
+    Ok(HttpResponse::Ok().json(summary::summarize(state, &game_data.stages)))
+}
+
+/// GET /api/verify — A hash of the game's seed, the cards it drew, and its decision log.
+/// Two games with the same hash played the same seeded sequence the same way.
+pub async fn verify(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+) -> Result<HttpResponse, ApiError> {
+    let session = app_state.session(&session_id)?;
+    Ok(HttpResponse::Ok().json(verify_body(&session.state)))
+}
+
+/// Response body for verify, for any one game.
+pub(super) fn verify_body(state: &GameState) -> serde_json::Value {
+    serde_json::json!({
+        "seed": state.seed,
+        "turn": state.current_turn,
+        "hash": replay::sequence_hash(state),
+    })
+}
+
+/// POST /api/whatif — Replay the game from its seed with different choices for one past
+/// `turn` (`actionIds`, `decisionOptionIndex`, and/or `eventOptionIndex`; anything left out
+/// keeps what was played). Later turns reuse the recorded choices where they still apply.
+/// The current game isn't changed. Debug edits aren't part of the replay.
+pub async fn what_if(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+    body: web::Json<serde_json::Value>,
+) -> Result<HttpResponse, ApiError> {
+    let session = app_state.session(&session_id)?;
+    let GameSession { state, game_data, .. } = &*session;
+
+    let turn = body.get("turn").and_then(|v| v.as_u64())
+        .ok_or_else(|| ApiError::bad_request("Missing turn."))? as u32;
+    let played = state.commands.iter().any(|c| matches!(c, GameCommand::Turn { turn: t, .. } if *t == turn));
+    if !played {
+        return Err(ApiError::bad_request(format!("Turn {} hasn't been played.", turn)));
+    }
+    let index = |key: &str| body.get(key).and_then(|v| v.as_u64()).map(|i| i as usize);
+    let action_ids = match body.get("actionIds") {
+        None | Some(serde_json::Value::Null) => None,
+        Some(ids) => Some(serde_json::from_value::<Vec<String>>(ids.clone())
+            .map_err(|_| ApiError::bad_request("actionIds must be a list of action IDs."))?),
+    };
+    let alternative = replay::Alternative {
+        turn,
+        action_ids,
+        decision_option_index: index("decisionOptionIndex"),
+        event_option_index: index("eventOptionIndex"),
+    };
+    if alternative.action_ids.is_none() && alternative.decision_option_index.is_none() && alternative.event_option_index.is_none() {
+        return Err(ApiError::bad_request("Give actionIds, decisionOptionIndex, or eventOptionIndex to change."));
+    }
+
+    let replayed = replay::replay(state, game_data, Some(&alternative)).map_err(ApiError::invalid_choice)?;
+    let original = replay::Outcome::of(state, game_data);
+    let what_if = replay::Outcome::of(&replayed.state, game_data);
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "turn": turn,
+        "endingChanged": original.ending_id != what_if.ending_id,
+        "original": original,
+        "whatIf": what_if,
+        "adjustments": replayed.adjustments,
+        "isGameOver": turn_runner::is_game_over(state, &game_data.stages),
+    })))
+}
+
+/// Fallback for any /api path that doesn't match a route.
+async fn unknown_endpoint(req: actix_web::HttpRequest) -> Result<HttpResponse, ApiError> {
+    Err(ApiError::new(
+        StatusCode::NOT_FOUND,
+        "unknown_endpoint",
+        format!("No API endpoint at {} {}", req.method(), req.path()),
+    ))
+}
+
+/// Configure all API routes: each version under /api/<version>, plus the deprecated
+/// unversioned /api prefix as an alias for the legacy version.
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    // The turn stream is shared by every API version, so it lives outside the versioned scopes
+    cfg.route("/ws", web::get().to(ws::turn_stream));
+    for version in ApiVersion::ALL {
+        cfg.service(api_scope(&format!("/api/{}", version.segment()), *version));
+    }
+    let successor = ApiVersion::ALL.last().copied().unwrap_or(ApiVersion::LEGACY);
+    cfg.service(
+        api_scope("/api", ApiVersion::LEGACY).wrap(
+            middleware::DefaultHeaders::new()
+                .add(("Deprecation", "true"))
+                .add(("Link", format!("</api/{}>; rel=\"successor-version\"", successor.segment()))),
+        ),
+    );
+}
+
+/// Every API route, served with the given version's request formats.
+fn api_scope(prefix: &str, version: ApiVersion) -> actix_web::Scope {
+    web::scope(prefix)
+        .app_data(web::Data::new(version))
+        .app_data(web::JsonConfig::default().error_handler(|err, _req| {
+            ApiError::bad_request(format!("Invalid JSON body: {}", err)).into()
+        }))
+        .app_data(web::QueryConfig::default().error_handler(|err, _req| {
+            ApiError::bad_request(format!("Invalid query: {}", err)).into()
+        }))
+        .route("/health", web::get().to(health))
+        .route("/openapi.json", web::get().to(openapi_json))
+        .route("/new_game", web::post().to(new_game))
+        .route("/daily", web::get().to(daily::get_daily))
+        .route("/daily", web::post().to(daily::start_daily))
+        .route("/state", web::get().to(get_state))
+        .route("/phase_data", web::get().to(phase_data))
+        .route("/draw_event", web::get().to(draw_event))
+        .route("/draw_odds", web::get().to(draw_odds))
+        .route("/reroll_event", web::post().to(reroll_event))
+        .route("/submit_turn", web::post().to(submit_turn))
+        .route("/plan", web::post().to(steps::plan))
+        .route("/commit", web::post().to(steps::commit))
+        .route("/resolve_event", web::post().to(steps::resolve_event))
+        .route("/simulate_turn", web::post().to(simulate_turn))
+        .route("/endings", web::get().to(get_ending))
+        .route("/endings/all", web::get().to(get_all_endings))
+        .route("/timeline", web::get().to(get_timeline))
+        .route("/summary", web::get().to(get_summary))
+        .route("/verify", web::get().to(verify))
+        .route("/whatif", web::post().to(what_if))
+        .route("/stats_history", web::get().to(get_stats_history))
+        .route("/stage_reports", web::get().to(get_stage_reports))
+        .route("/events", web::get().to(get_events))
+        .route("/stages", web::get().to(get_stages))
+        .route("/stats", web::get().to(get_stats))
+        .route("/scenarios", web::get().to(get_scenarios))
+        .route("/seeds", web::get().to(get_seeds))
+        .route("/leaderboard", web::get().to(leaderboard::get_leaderboard))
+        .route("/leaderboard", web::post().to(leaderboard::submit_run))
+        .route("/gallery", web::get().to(gallery::get_gallery))
+        .route("/stats/endings", web::get().to(ending_stats::get_ending_stats))
+        .route("/export/report", web::get().to(export_report))
+        .route("/journal", web::post().to(journal::add_entry))
+        .route("/quiz", web::post().to(quiz::answer))
+        .route("/decisions/{id}/preview", web::get().to(preview_decision))
+        .route("/jobs", web::get().to(get_jobs))
+        .route("/jobs/change", web::post().to(change_job))
+        .route("/jobs/{id}/apply", web::post().to(apply_job))
+        .route("/side_gigs/{id}/start", web::post().to(start_side_gig))
+        .route("/side_gigs/{id}/drop", web::post().to(drop_side_gig))
+        .route("/housing", web::get().to(get_housing))
+        .route("/housing/move", web::post().to(move_housing))
+        .route("/transport", web::get().to(get_transport))
+        .route("/transport/{id}/acquire", web::post().to(acquire_transport))
+        .route("/insurance", web::get().to(get_insurance))
+        .route("/insurance/{id}/buy", web::post().to(buy_insurance))
+        .route("/insurance/{id}/cancel", web::post().to(cancel_insurance))
+        // Classroom endpoints
+        .route("/class", web::post().to(classroom::create_class))
+        .route("/class/{code}", web::get().to(classroom::get_class))
+        .route("/class/{code}/dashboard", web::get().to(classroom::class_dashboard))
+        .route("/class/{code}/quiz", web::get().to(quiz::class_summary))
+        .route("/class/{code}/lockstep", web::post().to(classroom::set_lockstep))
+        .route("/class/{code}/advance", web::post().to(classroom::advance_turn))
+        .route("/class/{code}/join", web::post().to(classroom::join_class))
+        .route("/class/{code}/students/{student_id}/state", web::get().to(classroom::student_state))
+        .route("/class/{code}/students/{student_id}/phase_data", web::get().to(classroom::student_phase_data))
+        .route("/class/{code}/students/{student_id}/verify", web::get().to(classroom::student_verify))
+        .route("/class/{code}/students/{student_id}/draw_event", web::get().to(classroom::student_draw_event))
+        .route("/class/{code}/students/{student_id}/submit_turn", web::post().to(classroom::student_submit_turn))
+        .route("/class/{code}/students/{student_id}/leaderboard", web::post().to(leaderboard::submit_student_run))
+        .route("/class/{code}/students/{student_id}/gallery", web::get().to(gallery::get_student_gallery))
+        .route("/class/{code}/students/{student_id}/journal", web::post().to(journal::add_student_entry))
+        .route("/class/{code}/students/{student_id}/quiz", web::post().to(quiz::student_answer))
+        // Admin endpoints
+        .route("/admin/reload_data", web::post().to(reload_data))
+        // Debug endpoints
+        .route("/debug/data_sources", web::get().to(debug_data_sources))
+        .route("/debug/skip_stage", web::post().to(debug_skip_stage))
+        .route("/debug/set_stats", web::post().to(debug_set_stats))
+        .route("/debug/grant_tag", web::post().to(debug_grant_tag))
+        .route("/debug/fast_forward", web::post().to(debug_fast_forward))
+        .route("/debug/rng_log", web::get().to(debug_rng_log))
+        .default_service(web::to(unknown_endpoint))
+}
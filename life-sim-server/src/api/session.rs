@@ -0,0 +1,201 @@
+use std::future::{ready, Ready};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use actix_web::{dev::Payload, FromRequest, HttpRequest};
+use dashmap::DashMap;
+use life_sim_engine::engine::rng::GameRng;
+use super::error::ApiError;
+use life_sim_engine::data_loader::GameData;
+use life_sim_engine::engine::game_state::GameState;
+use life_sim_engine::models::EventCard;
+
+/// Header a client names its game in, so one server can run several players' games.
+/// Requests without it all share one game, as a single-player frontend expects.
+pub const SESSION_HEADER: &str = "X-Session-Id";
+
+/// The game requests without a session header play.
+pub const DEFAULT_SESSION: &str = "default";
+
+const MAX_SESSION_ID_LEN: usize = 64;
+
+/// A game nobody has touched for this long is dropped, so abandoned tabs don't pile up.
+pub const SESSION_IDLE_LIMIT: Duration = Duration::from_secs(4 * 60 * 60);
+
+/// How often the server looks for idle games to drop.
+pub const SESSION_SWEEP_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// Which game a request is for, taken from its `X-Session-Id` header.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SessionId(pub String);
+
+impl SessionId {
+    /// The session a header value names. IDs are short and printable so they can't be
+    /// used to fill the sessions map with junk keys.
+    pub fn parse(value: Option<&str>) -> Result<Self, ApiError> {
+        match value {
+            None => Ok(Self(DEFAULT_SESSION.to_string())),
+            Some(id) if !id.is_empty() && id.len() <= MAX_SESSION_ID_LEN && id.bytes().all(|b| b.is_ascii_graphic()) => {
+                Ok(Self(id.to_string()))
+            }
+            Some(_) => Err(ApiError::bad_request(format!(
+                "{} must be 1–{} printable characters", SESSION_HEADER, MAX_SESSION_ID_LEN
+            ))),
+        }
+    }
+}
+
+impl FromRequest for SessionId {
+    type Error = ApiError;
+    type Future = Ready<Result<Self, ApiError>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let header = req.headers().get(SESSION_HEADER).map(|v| v.to_str().unwrap_or(""));
+        ready(Self::parse(header))
+    }
+}
+
+/// One game in progress: everything a turn reads or advances, kept together so a handler
+/// takes a single lock for all of it.
+pub struct GameSession {
+    pub state: GameState,
+    pub rng: GameRng,
+    /// The event cards drawn for the current turn (preview before player picks an option).
+    pub pending_events: Vec<EventCard>,
+    /// The data set the game was started with (unaffected by reloads).
+    pub game_data: Arc<GameData>,
+    /// Timed games: when the current turn's time runs out.
+    pub turn_clock: Option<TurnClock>,
+    /// When the session was created; `last_active_ms` counts from here.
+    started: Instant,
+    /// Milliseconds after `started` that a request last read or played the game. Atomic so
+    /// reads can mark the game active under DashMap's shared lock.
+    last_active_ms: AtomicU64,
+}
+
+/// The deadline for one turn of a timed game.
+#[derive(Debug, Clone, Copy)]
+pub struct TurnClock {
+    pub turn: u32,
+    pub deadline: Instant,
+}
+
+impl GameSession {
+    /// A session for a freshly started game, its first turn's clock running if it's timed.
+    pub fn new(state: GameState, rng: GameRng, game_data: Arc<GameData>) -> Self {
+        let mut session = Self {
+            state,
+            rng,
+            pending_events: Vec::new(),
+            game_data,
+            turn_clock: None,
+            started: Instant::now(),
+            last_active_ms: AtomicU64::new(0),
+        };
+        session.start_turn_clock();
+        session
+    }
+
+    /// Start the current turn's clock, if the game is timed. Call whenever a new turn begins.
+    pub fn start_turn_clock(&mut self) {
+        self.turn_clock = self.state.turn_seconds.map(|seconds| TurnClock {
+            turn: self.state.current_turn,
+            deadline: Instant::now() + Duration::from_secs(seconds.into()),
+        });
+    }
+
+    /// Mark the game as just read or played.
+    pub fn touch(&self) {
+        self.mark_active(Instant::now());
+    }
+
+    fn mark_active(&self, at: Instant) {
+        let ms = at.saturating_duration_since(self.started).as_millis();
+        self.last_active_ms.store(ms.try_into().unwrap_or(u64::MAX), Ordering::Relaxed);
+    }
+
+    /// When a request last read or played the game.
+    pub fn last_active(&self) -> Instant {
+        self.started + Duration::from_millis(self.last_active_ms.load(Ordering::Relaxed))
+    }
+
+    /// Whether the current turn's time is up. A clock left over from an earlier turn
+    /// never runs out.
+    pub fn out_of_time(&self) -> bool {
+        self.turn_clock.is_some_and(|clock| clock.turn == self.state.current_turn && Instant::now() >= clock.deadline)
+    }
+
+    /// Whole seconds left on the current turn's clock, for timed games.
+    pub fn seconds_left(&self) -> Option<u64> {
+        self.turn_clock
+            .filter(|clock| clock.turn == self.state.current_turn)
+            .map(|clock| clock.deadline.saturating_duration_since(Instant::now()).as_secs())
+    }
+}
+
+/// Drop every game idle for `limit` or longer as of `now`. Returns the sessions dropped.
+pub fn evict_idle(sessions: &DashMap<SessionId, GameSession>, now: Instant, limit: Duration) -> Vec<SessionId> {
+    let mut evicted = Vec::new();
+    sessions.retain(|id, session| {
+        let keep = now.saturating_duration_since(session.last_active()) < limit;
+        if !keep {
+            evicted.push(id.clone());
+        }
+        keep
+    });
+    evicted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data;
+
+    #[test]
+    fn test_turn_clock() {
+        let data = Arc::new(test_data());
+        let state = life_sim_engine::engine::turn_runner::start_game("CLOCK".to_string(), &data);
+        let rng = life_sim_engine::engine::rng::game_rng("CLOCK");
+        let untimed = GameSession::new(state.clone(), rng.clone(), data.clone());
+        assert!(untimed.turn_clock.is_none() && !untimed.out_of_time());
+
+        let mut timed = GameSession::new(GameState { turn_seconds: Some(30), ..state }, rng, data);
+        assert!(!timed.out_of_time());
+        assert!(timed.seconds_left().is_some_and(|s| s <= 30));
+
+        timed.turn_clock = Some(TurnClock { turn: timed.state.current_turn, deadline: Instant::now() });
+        assert!(timed.out_of_time());
+        timed.state.current_turn += 1;
+        assert!(!timed.out_of_time(), "An old turn's clock doesn't count");
+        assert_eq!(timed.seconds_left(), None);
+        timed.start_turn_clock();
+        assert!(!timed.out_of_time());
+    }
+
+    #[test]
+    fn test_idle_sessions_are_evicted() {
+        let data = Arc::new(test_data());
+        let state = life_sim_engine::engine::turn_runner::start_game("IDLE".to_string(), &data);
+        let rng = life_sim_engine::engine::rng::game_rng("IDLE");
+        let sessions = DashMap::new();
+        for id in ["left-open", "playing"] {
+            sessions.insert(SessionId(id.to_string()), GameSession::new(state.clone(), rng.clone(), data.clone()));
+        }
+        let later = Instant::now() + SESSION_IDLE_LIMIT;
+        sessions.get(&SessionId("playing".to_string())).unwrap().mark_active(later - Duration::from_secs(60));
+
+        assert!(evict_idle(&sessions, later - Duration::from_secs(1), SESSION_IDLE_LIMIT).is_empty());
+        assert_eq!(evict_idle(&sessions, later, SESSION_IDLE_LIMIT), vec![SessionId("left-open".to_string())]);
+        assert!(sessions.contains_key(&SessionId("playing".to_string())));
+        assert!(!sessions.contains_key(&SessionId("left-open".to_string())));
+    }
+
+    #[test]
+    fn test_session_ids() {
+        assert_eq!(SessionId::parse(None).unwrap(), SessionId(DEFAULT_SESSION.to_string()));
+        assert_eq!(SessionId::parse(Some("tab-2")).unwrap(), SessionId("tab-2".to_string()));
+        assert!(SessionId::parse(Some("")).is_err());
+        assert!(SessionId::parse(Some("has space")).is_err());
+        assert!(SessionId::parse(Some(&"x".repeat(65))).is_err());
+    }
+}
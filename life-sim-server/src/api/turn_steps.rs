@@ -0,0 +1,105 @@
+use actix_web::{web, HttpResponse};
+use life_sim_engine::engine::bot;
+use life_sim_engine::engine::compat::ApiVersion;
+use life_sim_engine::engine::game_state::GameState;
+use life_sim_engine::engine::turn_runner;
+use life_sim_engine::engine::turn_steps;
+use super::error::ApiError;
+use super::routes::{self, AppState};
+use super::session::{GameSession, SessionId};
+
+/// Response body for a step that leaves the turn open.
+fn step_body(state: &GameState, feedback: Vec<String>) -> serde_json::Value {
+    serde_json::json!({
+        "feedback": feedback,
+        "events": state.turn_in_progress.as_ref().map(|p| &p.events),
+        "nextStep": turn_steps::next_step(state).endpoint(),
+        "state": state,
+    })
+}
+
+/// Lead a step's feedback with the note that the player froze, if they did.
+fn froze(frozen: bool, feedback: Vec<String>) -> Vec<String> {
+    if frozen {
+        std::iter::once(routes::FROZE.to_string()).chain(feedback).collect()
+    } else {
+        feedback
+    }
+}
+
+/// POST /api/plan — Start the turn a step at a time: lock in the Phase 1 actions
+/// (`actionIds`, as in submit_turn) and run them. The turn's cards are dealt now, or the
+/// ones draw_event previewed are kept, and come back as `events`.
+pub async fn plan(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+    version: web::Data<ApiVersion>,
+    body: web::Json<serde_json::Value>,
+) -> Result<HttpResponse, ApiError> {
+    let mut session = app_state.session_mut(&session_id)?;
+    let frozen = session.out_of_time();
+    let GameSession { state, rng, pending_events, game_data, .. } = &mut *session;
+    let _turn = routes::turn_span(state).entered();
+    if turn_runner::is_game_over(state, &game_data.stages) {
+        return Err(ApiError::game_over());
+    }
+
+    let mut choices = version.parse_choices(&body).map_err(ApiError::invalid_choice)?;
+    if frozen {
+        choices.action_ids.clear();
+    }
+    let feedback = turn_steps::plan(state, choices.action_ids, pending_events, game_data, rng)?;
+    Ok(HttpResponse::Ok().json(step_body(state, froze(frozen, feedback))))
+}
+
+/// POST /api/commit — Make the planned turn's decision (`decisionId`,
+/// `decisionOptionIndex`, `deferDecision`, as in submit_turn) and run Phase 2.
+pub async fn commit(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+    version: web::Data<ApiVersion>,
+    body: web::Json<serde_json::Value>,
+) -> Result<HttpResponse, ApiError> {
+    let mut session = app_state.session_mut(&session_id)?;
+    let frozen = session.out_of_time();
+    let GameSession { state, rng, game_data, .. } = &mut *session;
+    let _turn = routes::turn_span(state).entered();
+
+    let choices = if frozen {
+        bot::frozen_choices(state, game_data, &[])
+    } else {
+        version.parse_choices(&body).map_err(ApiError::invalid_choice)?
+    };
+    let feedback = turn_steps::commit(state, &choices, game_data, rng)?;
+    Ok(HttpResponse::Ok().json(step_body(state, froze(frozen, feedback))))
+}
+
+/// POST /api/resolve_event — Answer the turn's cards (`eventOptionIndex` or
+/// `eventOptionIndices`, and `eventId`, as in submit_turn) and play out the rest of the
+/// turn. Responds like submit_turn, with every phase's feedback.
+pub async fn resolve_event(
+    app_state: web::Data<AppState>,
+    session_id: SessionId,
+    version: web::Data<ApiVersion>,
+    body: web::Json<serde_json::Value>,
+) -> Result<HttpResponse, ApiError> {
+    let mut session = app_state.session_mut(&session_id)?;
+    let frozen = session.out_of_time();
+    let GameSession { state, rng, game_data, .. } = &mut *session;
+    let turn_span = routes::turn_span(state).entered();
+
+    let turn = state.current_turn;
+    let events = state.turn_in_progress.as_ref().map_or(&[][..], |p| &p.events);
+    let choices = if frozen {
+        bot::frozen_choices(state, game_data, events)
+    } else {
+        version.parse_choices(&body).map_err(ApiError::invalid_choice)?
+    };
+    let (played, mut result) = turn_steps::resolve_event(state, &choices, game_data, rng)?;
+    result.feedback = froze(frozen, result.feedback);
+    tracing::info!(frozen, game_over = turn_runner::is_game_over(state, &game_data.stages), "Turn played");
+    turn_span.exit();
+    let response = routes::turn_played(&app_state, &session_id, turn, state, game_data, &played, result);
+    session.start_turn_clock();
+    Ok(response)
+}
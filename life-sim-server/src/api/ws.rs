@@ -0,0 +1,191 @@
+use actix_web::{rt, web, HttpRequest, HttpResponse};
+use actix_ws::Message;
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+use life_sim_engine::engine::turn_runner::TurnResult;
+use life_sim_engine::models::{EventCard, Stage};
+use super::routes::AppState;
+use super::session::SessionId;
+
+/// How many updates a slow client can fall behind before it starts missing them.
+const STREAM_CAPACITY: usize = 256;
+
+/// One update pushed to /ws clients as a turn resolves, in the order it happened.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum TurnEvent {
+    TurnStarted { turn: u32 },
+    EventDrawn { event: Box<EventCard> },
+    Feedback { message: String },
+    StressWarning { message: String },
+    StageTransition { old_stage: Stage, new_stage: Stage },
+    TurnEnded { turn: u32, is_game_over: bool },
+}
+
+/// Break a finished turn into the updates a client animates one by one.
+pub fn turn_events(turn: u32, result: &TurnResult, is_game_over: bool) -> Vec<TurnEvent> {
+    let mut events = vec![TurnEvent::TurnStarted { turn }];
+    events.extend(result.events_drawn.iter().map(|event| TurnEvent::EventDrawn { event: Box::new(event.clone()) }));
+    // The stress warning and stage banner are also in the feedback; send them as their own updates
+    events.extend(result.feedback.iter()
+        .filter(|m| result.stress_warning.as_ref() != Some(*m))
+        .map(|m| TurnEvent::Feedback { message: m.clone() }));
+    if let Some(ref warning) = result.stress_warning {
+        events.push(TurnEvent::StressWarning { message: warning.clone() });
+    }
+    if let (Some(old_stage), Some(new_stage)) = (&result.old_stage, &result.new_stage) {
+        events.push(TurnEvent::StageTransition { old_stage: old_stage.clone(), new_stage: new_stage.clone() });
+    }
+    events.push(TurnEvent::TurnEnded { turn, is_game_over });
+    events
+}
+
+/// Fan-out of turn updates to the /ws clients watching each session. Every session gets
+/// its own channel, so a busy game can't push a quiet one's clients into lagging.
+pub struct TurnStream(DashMap<SessionId, broadcast::Sender<TurnEvent>>);
+
+impl TurnStream {
+    pub fn new() -> Self {
+        TurnStream(DashMap::new())
+    }
+
+    /// Send a session's updates to its clients (dropped if nobody is listening).
+    pub fn publish(&self, session: &SessionId, events: Vec<TurnEvent>) {
+        let Some(channel) = self.0.get(session) else { return };
+        for event in events {
+            let _ = channel.send(event);
+        }
+    }
+
+    /// Listen to a session's updates, opening its channel on first use.
+    fn subscribe(&self, session: &SessionId) -> broadcast::Receiver<TurnEvent> {
+        self.0.entry(session.clone())
+            .or_insert_with(|| broadcast::channel(STREAM_CAPACITY).0)
+            .subscribe()
+    }
+
+    /// Drop a session's channel once its last client has gone.
+    fn unsubscribed(&self, session: &SessionId) {
+        self.0.remove_if(session, |_, channel| channel.receiver_count() == 0);
+    }
+
+    /// Drop a session's channel, ending the streams of any clients still on it.
+    pub fn close(&self, session: &SessionId) {
+        self.0.remove(session);
+    }
+}
+
+impl Default for TurnStream {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Deserialize)]
+pub struct StreamQuery {
+    /// The session to watch. Browsers can't set headers on a WebSocket, so this stands in
+    /// for `X-Session-Id`.
+    session: Option<String>,
+}
+
+/// GET /ws — WebSocket stream of the session's turn updates (JSON text frames, one
+/// `TurnEvent` each). The session comes from `?session=`, else the usual header.
+pub async fn turn_stream(
+    req: HttpRequest,
+    body: web::Payload,
+    app_state: web::Data<AppState>,
+    query: web::Query<StreamQuery>,
+    header_session: SessionId,
+) -> Result<HttpResponse, actix_web::Error> {
+    let session_id = match query.session.as_deref() {
+        Some(id) => SessionId::parse(Some(id))?,
+        None => header_session,
+    };
+    let (response, mut session, mut messages) = actix_ws::handle(&req, body)?;
+    let mut updates = app_state.turn_stream.subscribe(&session_id);
+
+    rt::spawn(async move {
+        loop {
+            tokio::select! {
+                update = updates.recv() => match update {
+                    Ok(update) => {
+                        let Ok(text) = serde_json::to_string(&update) else { continue };
+                        if session.text(text).await.is_err() {
+                            break;
+                        }
+                    }
+                    // A client that fell behind skips what it missed and carries on
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                },
+                message = messages.recv() => match message {
+                    Some(Ok(Message::Ping(bytes))) => {
+                        if session.pong(&bytes).await.is_err() {
+                            break;
+                        }
+                    }
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                    Some(Ok(_)) => {}
+                },
+            }
+        }
+        drop(updates);
+        app_state.turn_stream.unsubscribed(&session_id);
+        let _ = session.close(None).await;
+    });
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turn_events_in_order() {
+        let result = TurnResult {
+            events_drawn: Vec::new(),
+            feedback: vec![
+                "💰 Money +10".to_string(),
+                "⚠️ Stress is high".to_string(),
+                "🎓 Advancing to High School!".to_string(),
+            ],
+            stage_transitioned: true,
+            new_stage: Some(Stage::HIGH_SCHOOL),
+            old_stage: Some(Stage::MIDDLE_SCHOOL),
+            stress_warning: Some("⚠️ Stress is high".to_string()),
+            stat_changes: vec![],
+        };
+        let events = serde_json::to_value(turn_events(4, &result, false)).unwrap();
+        let types: Vec<&str> = events.as_array().unwrap().iter().map(|e| e["type"].as_str().unwrap()).collect();
+        assert_eq!(types, vec!["turnStarted", "feedback", "feedback", "stressWarning", "stageTransition", "turnEnded"]);
+        assert_eq!(events[4]["newStage"], "high-school");
+        assert_eq!(events[5]["isGameOver"], false);
+    }
+
+    #[test]
+    fn test_sessions_stream_separately() {
+        let stream = TurnStream::new();
+        let (busy, quiet) = (SessionId("busy".to_string()), SessionId("quiet".to_string()));
+        let mut busy_updates = stream.subscribe(&busy);
+        let mut quiet_updates = stream.subscribe(&quiet);
+
+        for turn in 0..STREAM_CAPACITY as u32 * 2 {
+            stream.publish(&busy, vec![TurnEvent::TurnStarted { turn }]);
+        }
+        assert!(matches!(busy_updates.try_recv(), Err(broadcast::error::TryRecvError::Lagged(_))));
+        assert!(matches!(quiet_updates.try_recv(), Err(broadcast::error::TryRecvError::Empty)));
+
+        stream.publish(&quiet, vec![TurnEvent::TurnEnded { turn: 1, is_game_over: false }]);
+        assert!(matches!(quiet_updates.try_recv(), Ok(TurnEvent::TurnEnded { turn: 1, .. })));
+
+        // Closing a session ends its clients' streams; the last client leaving drops the channel
+        while busy_updates.try_recv().is_ok() {}
+        stream.close(&busy);
+        assert!(matches!(busy_updates.try_recv(), Err(broadcast::error::TryRecvError::Closed)));
+        drop(quiet_updates);
+        stream.unsubscribed(&quiet);
+        assert!(stream.0.is_empty());
+    }
+}
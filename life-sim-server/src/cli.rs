@@ -0,0 +1,271 @@
+use std::io::{self, BufRead, Write};
+use life_sim_engine::data_loader::GameData;
+use life_sim_engine::engine::actions;
+use life_sim_engine::engine::choices;
+use life_sim_engine::engine::endings;
+use life_sim_engine::engine::game_state::GameState;
+use life_sim_engine::engine::turn_runner::{self, PlayerChoices};
+use life_sim_engine::models::{Action, Decision, EventCard};
+
+/// Play a whole game in the terminal: print each phase, read numbered choices from
+/// `input`, and run the turns through the same engine the server uses.
+/// Entering `q` (or closing the input) stops early; the game as it stands is returned.
+pub fn play<R: BufRead, W: Write>(data: &GameData, seed: &str, input: &mut R, out: &mut W) -> io::Result<GameState> {
+    let mut state = turn_runner::start_game(seed.to_string(), data);
+    let mut game_rng = state.seed_rng();
+    writeln!(out, "🎮 Life Roguelite — seed {}", seed)?;
+
+    while !turn_runner::is_game_over(&state, &data.stages) {
+        let stage_name = turn_runner::stage_def(&data.stages, &state.current_stage)
+            .map_or_else(|| state.current_stage.to_string(), |s| s.name.clone());
+        writeln!(out, "\n━━ Turn {} · {} ━━", state.current_turn, stage_name)?;
+        writeln!(out, "{}", stat_line(&state))?;
+
+        // The cards are drawn before the player commits, like GET /api/draw_event
+        let events = turn_runner::draw_turn_events(&state, data, &mut game_rng);
+        let turn_choices = loop {
+            let Some(turn_choices) = read_turn(&state, data, &events, input, out)? else {
+                writeln!(out, "\nStopped at turn {}.", state.current_turn)?;
+                return Ok(state);
+            };
+            match choices::validate_choices(&state, &turn_choices, data, &events) {
+                Ok(()) => break turn_choices,
+                Err(errors) => for error in errors {
+                    writeln!(out, "⚠️ {}", error.message)?;
+                },
+            }
+        };
+
+        let result = turn_runner::run_turn_with_events(&mut state, &turn_choices, data, &mut game_rng, events);
+        writeln!(out)?;
+        for line in &result.feedback {
+            writeln!(out, "  {}", line)?;
+        }
+    }
+
+    writeln!(out, "\n━━ Game over ━━")?;
+    writeln!(out, "{}", stat_line(&state))?;
+    let resolution = endings::resolve_all(&state, &data.endings);
+    match resolution.primary {
+        Some(ending) => {
+            writeln!(out, "\n🏁 {}\n{}", ending.title, ending.narrative)?;
+            for reflection in &ending.reflections {
+                writeln!(out, "  • {}", reflection)?;
+            }
+        }
+        None => writeln!(out, "\n🏁 No ending matched this run.")?,
+    }
+    if !resolution.badges.is_empty() {
+        let titles: Vec<&str> = resolution.badges.iter().map(|b| b.title.as_str()).collect();
+        writeln!(out, "🎖️ Badges: {}", titles.join(", "))?;
+    }
+    for near_miss in endings::near_misses(&state, &data.endings) {
+        writeln!(out, "🎯 {}", near_miss.message)?;
+    }
+    Ok(state)
+}
+
+/// Walk the player through Phases 1–3. None if they quit.
+fn read_turn<R: BufRead, W: Write>(
+    state: &GameState,
+    data: &GameData,
+    events: &[EventCard],
+    input: &mut R,
+    out: &mut W,
+) -> io::Result<Option<PlayerChoices>> {
+    // Phase 1: Plan
+    let actions: Vec<&Action> = data.stage_actions(&state.current_stage)
+        .filter(|a| actions::unavailable_reason(state, data, a).is_none())
+        .collect();
+    let time_slots = state.available_time_slots();
+    writeln!(out, "\n📋 Plan — {} time slot(s) to spend", time_slots)?;
+    for (i, action) in actions.iter().enumerate() {
+        writeln!(out, "  {}. {} ({} slot{}) — {}", i + 1, action.label, action.time_cost,
+            if action.time_cost == 1 { "" } else { "s" }, action.description)?;
+    }
+    let action_ids = loop {
+        let Some(picked) = prompt_numbers(input, out, "Actions (numbers separated by spaces, blank for none)", actions.len())? else {
+            return Ok(None);
+        };
+        let time_used: u32 = picked.iter().map(|&i| actions[i].time_cost).sum();
+        if time_used > time_slots {
+            writeln!(out, "That needs {} time slots; you have {}.", time_used, time_slots)?;
+            continue;
+        }
+        break picked.iter().map(|&i| actions[i].id.clone()).collect();
+    };
+
+    // Phase 2: Commit
+    let decision = choices::current_decision(state, data);
+    let (decision_option_index, defer_decision) = match decision {
+        Some(ref decision) => {
+            writeln!(out, "\n🤔 {}", decision.prompt)?;
+            for (i, option) in decision.options.iter().enumerate() {
+                writeln!(out, "  {}. {}{} — {}", i + 1, option.label, locked_note(state, decision, i), option.description)?;
+            }
+            // Deferrable decisions get one more numbered choice after the options
+            let count = decision.options.len();
+            if let Some(ref consequence) = decision.defer_consequence {
+                writeln!(out, "  {}. Put it off — {}", count + 1, consequence)?;
+            }
+            let choices = count + usize::from(decision.defer_consequence.is_some());
+            loop {
+                let Some(index) = prompt_one(input, out, "Decision", choices)? else {
+                    return Ok(None);
+                };
+                if index == count {
+                    break (0, true);
+                }
+                match decision.options[index].requires_tag {
+                    Some(ref tag) if !state.credentials.contains(tag) => {
+                        writeln!(out, "That option requires {}.", tag)?;
+                    }
+                    _ => break (index, false),
+                }
+            }
+        }
+        None => (0, false),
+    };
+
+    // Phase 3: Event, one response per card
+    if events.is_empty() {
+        writeln!(out, "\n🃏 No event this turn.")?;
+    }
+    let mut answers = Vec::new();
+    for event in events {
+        writeln!(out, "\n🃏 {}\n   {}", event.title, event.flavor_text)?;
+        for (i, option) in event.options.iter().enumerate() {
+            let gate = option.requires_support
+                .map_or(String::new(), |min| format!(" (needs Support ≥ {})", min));
+            writeln!(out, "  {}. {}{} — {}", i + 1, option.label, gate, option.description)?;
+        }
+        if event.options.is_empty() {
+            answers.push(None);
+        } else {
+            match prompt_one(input, out, "Response", event.options.len())? {
+                Some(index) => answers.push(Some(index)),
+                None => return Ok(None),
+            }
+        }
+    }
+    let mut answers = answers.into_iter();
+
+    Ok(Some(PlayerChoices {
+        action_ids,
+        decision_id: decision.map(|d| d.id.clone()).unwrap_or_default(),
+        decision_option_index,
+        defer_decision,
+        event_option_index: answers.next().flatten(),
+        event_id: None,
+        extra_event_options: answers.collect(),
+    }))
+}
+
+fn stat_line(state: &GameState) -> String {
+    format!(
+        "💰 ${}   😰 Stress {}   🤝 Support {}   🎓 {} credential(s)",
+        state.money, state.stress, state.support, state.credentials.len()
+    )
+}
+
+fn locked_note(state: &GameState, decision: &Decision, index: usize) -> String {
+    match decision.options[index].requires_tag {
+        Some(ref tag) if !state.credentials.contains(tag) => format!(" 🔒 requires {}", tag),
+        _ => String::new(),
+    }
+}
+
+/// Read one line, or None at end of input or on `q`.
+fn read_line<R: BufRead, W: Write>(input: &mut R, out: &mut W, label: &str) -> io::Result<Option<String>> {
+    write!(out, "{} > ", label)?;
+    out.flush()?;
+    let mut line = String::new();
+    if input.read_line(&mut line)? == 0 {
+        return Ok(None);
+    }
+    let line = line.trim();
+    if line.eq_ignore_ascii_case("q") {
+        return Ok(None);
+    }
+    Ok(Some(line.to_string()))
+}
+
+/// Ask for distinct choice numbers from 1 to `count`; returns zero-based indexes.
+fn prompt_numbers<R: BufRead, W: Write>(input: &mut R, out: &mut W, label: &str, count: usize) -> io::Result<Option<Vec<usize>>> {
+    loop {
+        let Some(line) = read_line(input, out, label)? else { return Ok(None) };
+        match parse_numbers(&line, count) {
+            Ok(picked) => return Ok(Some(picked)),
+            Err(e) => writeln!(out, "{}", e)?,
+        }
+    }
+}
+
+/// Ask for a single choice number from 1 to `count`; returns a zero-based index.
+fn prompt_one<R: BufRead, W: Write>(input: &mut R, out: &mut W, label: &str, count: usize) -> io::Result<Option<usize>> {
+    loop {
+        let Some(line) = read_line(input, out, label)? else { return Ok(None) };
+        match parse_numbers(&line, count) {
+            Ok(picked) if picked.len() == 1 => return Ok(Some(picked[0])),
+            Ok(_) => writeln!(out, "Pick one number from 1 to {}.", count)?,
+            Err(e) => writeln!(out, "{}", e)?,
+        }
+    }
+}
+
+/// Parse "1 3" or "1,3" into zero-based indexes, rejecting repeats and numbers out of range.
+fn parse_numbers(line: &str, count: usize) -> Result<Vec<usize>, String> {
+    let mut picked = Vec::new();
+    for word in line.split(|c: char| c == ',' || c.is_whitespace()).filter(|w| !w.is_empty()) {
+        let index = match word.parse::<usize>() {
+            Ok(n) if (1..=count).contains(&n) => n - 1,
+            _ => return Err(format!("\"{}\" isn't a choice from 1 to {}.", word, count)),
+        };
+        if picked.contains(&index) {
+            return Err(format!("{} is picked more than once.", word));
+        }
+        picked.push(index);
+    }
+    Ok(picked)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data;
+
+    #[test]
+    fn test_scripted_game_plays_to_an_ending() {
+        let data = test_data();
+        // No actions, then the first option of every decision and card; stray
+        // answers are re-asked, so the script never falls out of step for long
+        let script = "\n1\n1\n".repeat(200);
+        let mut out = Vec::new();
+        let state = play(&data, "CLI_TEST", &mut script.as_bytes(), &mut out).unwrap();
+
+        assert!(turn_runner::is_game_over(&state, &data.stages));
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.contains("━━ Turn 1 · Middle School ━━"));
+        assert!(text.contains("━━ Game over ━━"));
+        assert!(text.contains("🏁"));
+    }
+
+    #[test]
+    fn test_quitting_stops_the_game() {
+        let data = test_data();
+        let mut out = Vec::new();
+        let state = play(&data, "CLI_TEST", &mut "\nq\n".as_bytes(), &mut out).unwrap();
+        assert_eq!(state.current_turn, 1);
+        assert!(String::from_utf8(out).unwrap().contains("Stopped at turn 1."));
+    }
+
+    #[test]
+    fn test_parse_numbers() {
+        assert_eq!(parse_numbers("1 3", 3), Ok(vec![0, 2]));
+        assert_eq!(parse_numbers("2,1", 3), Ok(vec![1, 0]));
+        assert_eq!(parse_numbers("", 3), Ok(vec![]));
+        assert!(parse_numbers("4", 3).is_err());
+        assert!(parse_numbers("1 1", 3).is_err());
+        assert!(parse_numbers("one", 3).is_err());
+    }
+}
@@ -0,0 +1,281 @@
+use std::path::{Path, PathBuf};
+use serde::Deserialize;
+use crate::logging::{self, LogFormat};
+
+/// The config file read when neither `--config` nor `LIFE_SIM_CONFIG` names one, if it exists.
+pub const DEFAULT_CONFIG_FILE: &str = "life-sim.json";
+
+/// Environment variable naming the config file.
+pub const CONFIG_ENV: &str = "LIFE_SIM_CONFIG";
+
+/// Where the server listens, which directories it reads from, which other sites may call
+/// it, who may use its admin endpoints, and how it logs. Each setting comes from the config file, then its environment
+/// variable, then its command-line flag, later ones winning; anything left unset keeps its
+/// default.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct ServerConfig {
+    /// The address to bind, e.g. `0.0.0.0` to accept players on the school network.
+    pub bind: String,
+    pub port: u16,
+    /// The base game data, used unless `--data-url` is given.
+    pub data_dir: PathBuf,
+    /// The web frontend's files.
+    pub static_dir: PathBuf,
+    /// Origins allowed to call the API from another site, like `https://lms.example.edu`,
+    /// or `*` for any. None by default: only pages the server itself serves can call it.
+    pub cors_origins: Vec<String>,
+    /// PEM certificate chain and private key: set both to serve HTTPS instead of HTTP.
+    pub tls_cert: Option<PathBuf>,
+    pub tls_key: Option<PathBuf>,
+    /// The key admin requests (like reloading the data) must send in `X-Admin-Key`. Unset,
+    /// only requests from this machine may use them.
+    pub admin_key: Option<String>,
+    /// The log level, or per-module directives like `warn,life_sim_server=debug`.
+    pub log_level: String,
+    pub log_format: LogFormat,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            bind: "127.0.0.1".to_string(),
+            port: 8080,
+            data_dir: PathBuf::from("data"),
+            static_dir: PathBuf::from("static"),
+            cors_origins: Vec::new(),
+            tls_cert: None,
+            tls_key: None,
+            admin_key: None,
+            log_level: "info".to_string(),
+            log_format: LogFormat::Text,
+        }
+    }
+}
+
+/// One of the settings that can be overridden by environment variable or flag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Setting {
+    Bind,
+    Port,
+    DataDir,
+    StaticDir,
+    CorsOrigins,
+    TlsCert,
+    TlsKey,
+    AdminKey,
+    LogLevel,
+    LogFormat,
+}
+
+impl Setting {
+    pub const ALL: [Setting; 10] = [
+        Setting::Bind, Setting::Port, Setting::DataDir, Setting::StaticDir, Setting::CorsOrigins, Setting::TlsCert, Setting::TlsKey,
+        Setting::AdminKey, Setting::LogLevel, Setting::LogFormat,
+    ];
+
+    /// The command-line flag that sets it.
+    pub fn flag(self) -> &'static str {
+        match self {
+            Setting::Bind => "--bind",
+            Setting::Port => "--port",
+            Setting::DataDir => "--data-dir",
+            Setting::StaticDir => "--static-dir",
+            Setting::CorsOrigins => "--cors-origins",
+            Setting::TlsCert => "--tls-cert",
+            Setting::TlsKey => "--tls-key",
+            Setting::AdminKey => "--admin-key",
+            Setting::LogLevel => "--log-level",
+            Setting::LogFormat => "--log-format",
+        }
+    }
+
+    /// The environment variable that sets it.
+    pub fn env_var(self) -> &'static str {
+        match self {
+            Setting::Bind => "LIFE_SIM_BIND",
+            Setting::Port => "LIFE_SIM_PORT",
+            Setting::DataDir => "LIFE_SIM_DATA_DIR",
+            Setting::StaticDir => "LIFE_SIM_STATIC_DIR",
+            Setting::CorsOrigins => "LIFE_SIM_CORS_ORIGINS",
+            Setting::TlsCert => "LIFE_SIM_TLS_CERT",
+            Setting::TlsKey => "LIFE_SIM_TLS_KEY",
+            Setting::AdminKey => "LIFE_SIM_ADMIN_KEY",
+            Setting::LogLevel => "LIFE_SIM_LOG_LEVEL",
+            Setting::LogFormat => "LIFE_SIM_LOG_FORMAT",
+        }
+    }
+
+    pub fn from_flag(flag: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|s| s.flag() == flag)
+    }
+}
+
+impl ServerConfig {
+    /// Read a config file. Every key is optional; unknown keys are an error, so a typo
+    /// doesn't silently leave the default in place.
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read config file {}: {}", path.display(), e))?;
+        serde_json::from_str(&text).map_err(|e| format!("Invalid config file {}: {}", path.display(), e))
+    }
+
+    /// Build the config: `file` if given (otherwise `LIFE_SIM_CONFIG`, otherwise
+    /// `life-sim.json` if there is one), then the environment `env` reads, then `flags`
+    /// in the order they were passed.
+    pub fn resolve(
+        file: Option<&Path>,
+        env: impl Fn(&str) -> Option<String>,
+        flags: &[(Setting, String)],
+    ) -> Result<Self, String> {
+        let file = file.map(Path::to_path_buf).or_else(|| env(CONFIG_ENV).map(PathBuf::from));
+        let mut config = match file {
+            Some(path) => Self::from_file(&path)?,
+            None if Path::new(DEFAULT_CONFIG_FILE).is_file() => Self::from_file(Path::new(DEFAULT_CONFIG_FILE))?,
+            None => Self::default(),
+        };
+        for setting in Setting::ALL {
+            if let Some(value) = env(setting.env_var()) {
+                config.set(setting, &value).map_err(|e| format!("{}: {}", setting.env_var(), e))?;
+            }
+        }
+        for (setting, value) in flags {
+            config.set(*setting, value).map_err(|e| format!("{}: {}", setting.flag(), e))?;
+        }
+        for origin in &config.cors_origins {
+            check_origin(origin)?;
+        }
+        if config.tls_cert.is_some() != config.tls_key.is_some() {
+            return Err("HTTPS needs both a TLS certificate (tlsCert) and its private key (tlsKey)".to_string());
+        }
+        logging::filter(&config.log_level)?;
+        Ok(config)
+    }
+
+    fn set(&mut self, setting: Setting, value: &str) -> Result<(), String> {
+        match setting {
+            Setting::Bind => self.bind = value.to_string(),
+            Setting::Port => self.port = value.parse().map_err(|_| format!("'{}' is not a port number", value))?,
+            Setting::DataDir => self.data_dir = PathBuf::from(value),
+            Setting::StaticDir => self.static_dir = PathBuf::from(value),
+            Setting::CorsOrigins => {
+                self.cors_origins = value.split(',').map(str::trim).filter(|o| !o.is_empty()).map(str::to_string).collect();
+            }
+            Setting::TlsCert => self.tls_cert = Some(PathBuf::from(value)),
+            Setting::TlsKey => self.tls_key = Some(PathBuf::from(value)),
+            Setting::AdminKey => self.admin_key = Some(value.to_string()).filter(|key| !key.is_empty()),
+            Setting::LogLevel => self.log_level = value.to_string(),
+            Setting::LogFormat => self.log_format = value.parse()?,
+        }
+        Ok(())
+    }
+
+    /// The address and port to bind.
+    pub fn address(&self) -> (&str, u16) {
+        (&self.bind, self.port)
+    }
+
+    /// The certificate and key files, if the server is to speak HTTPS.
+    pub fn tls(&self) -> Option<(&Path, &Path)> {
+        self.tls_cert.as_deref().zip(self.tls_key.as_deref())
+    }
+}
+
+/// A CORS origin is `*` or a scheme and host with no path, as a browser sends it:
+/// `https://lms.example.edu`, `http://localhost:3000`.
+fn check_origin(origin: &str) -> Result<(), String> {
+    let host = origin.strip_prefix("https://").or_else(|| origin.strip_prefix("http://"));
+    match host {
+        _ if origin == "*" => Ok(()),
+        Some(host) if !host.is_empty() && !host.contains('/') => Ok(()),
+        _ => Err(format!("CORS origin '{}' must be * or a scheme and host, like https://lms.example.edu", origin)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    fn write_config(name: &str, json: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("life-sim-config-{}-{}.json", name, std::process::id()));
+        std::fs::write(&path, json).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_flags_override_env_which_overrides_the_file() {
+        let path = write_config("layers", r#"{ "bind": "0.0.0.0", "port": 9000, "staticDir": "www" }"#);
+        let config = ServerConfig::resolve(
+            Some(&path),
+            env(&[("LIFE_SIM_PORT", "9100"), ("LIFE_SIM_DATA_DIR", "/srv/data"), ("LIFE_SIM_ADMIN_KEY", "district-admin")]),
+            &[(Setting::Port, "9200".to_string())],
+        ).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(config, ServerConfig {
+            bind: "0.0.0.0".to_string(),
+            port: 9200,
+            data_dir: PathBuf::from("/srv/data"),
+            static_dir: PathBuf::from("www"),
+            cors_origins: Vec::new(),
+            tls_cert: None,
+            tls_key: None,
+            admin_key: Some("district-admin".to_string()),
+            log_level: "info".to_string(),
+            log_format: LogFormat::Text,
+        });
+    }
+
+    #[test]
+    fn test_the_config_file_can_come_from_the_environment() {
+        let path = write_config("env", r#"{ "port": 3000 }"#);
+        let config = ServerConfig::resolve(None, env(&[(CONFIG_ENV, path.to_str().unwrap())]), &[]).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(config.address(), ("127.0.0.1", 3000), "Unset keys keep their defaults");
+    }
+
+    #[test]
+    fn test_cors_origins_are_a_checked_list() {
+        let config = ServerConfig::resolve(None, env(&[("LIFE_SIM_CORS_ORIGINS", "https://lms.example.edu, http://localhost:3000")]), &[]).unwrap();
+        assert_eq!(config.cors_origins, ["https://lms.example.edu", "http://localhost:3000"]);
+
+        let config = ServerConfig::resolve(None, env(&[]), &[(Setting::CorsOrigins, "*".to_string())]).unwrap();
+        assert_eq!(config.cors_origins, ["*"]);
+
+        for bad in ["lms.example.edu", "https://lms.example.edu/", "https://"] {
+            let err = ServerConfig::resolve(None, env(&[]), &[(Setting::CorsOrigins, bad.to_string())]).unwrap_err();
+            assert!(err.contains(bad), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_bad_settings_are_reported() {
+        let err = ServerConfig::resolve(None, env(&[("LIFE_SIM_PORT", "eighty")]), &[]).unwrap_err();
+        assert!(err.contains("LIFE_SIM_PORT") && err.contains("eighty"), "{}", err);
+
+        let path = write_config("typo", r#"{ "prot": 3000 }"#);
+        let err = ServerConfig::resolve(Some(&path), env(&[]), &[]).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        assert!(err.contains("prot"), "{}", err);
+
+        assert!(ServerConfig::resolve(Some(Path::new("no-such-config.json")), env(&[]), &[]).is_err());
+
+        let err = ServerConfig::resolve(None, env(&[("LIFE_SIM_TLS_CERT", "cert.pem")]), &[]).unwrap_err();
+        assert!(err.contains("tlsKey"), "A certificate without its key: {}", err);
+        let config = ServerConfig::resolve(None, env(&[]), &[
+            (Setting::TlsCert, "cert.pem".to_string()), (Setting::TlsKey, "key.pem".to_string()),
+        ]).unwrap();
+        assert_eq!(config.tls(), Some((Path::new("cert.pem"), Path::new("key.pem"))));
+
+        let err = ServerConfig::resolve(None, env(&[("LIFE_SIM_LOG_FORMAT", "xml")]), &[]).unwrap_err();
+        assert!(err.starts_with("LIFE_SIM_LOG_FORMAT"), "{}", err);
+        assert!(ServerConfig::resolve(None, env(&[]), &[(Setting::LogLevel, "life_sim_server=chatty".to_string())]).is_err());
+    }
+}
@@ -0,0 +1,154 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+use life_sim_engine::data_loader::GameData;
+use life_sim_engine::engine::game_state::{GameCommand, GameState};
+use life_sim_engine::engine::replay;
+use life_sim_engine::engine::rng::SeedScheme;
+use life_sim_engine::models::DeckMode;
+use life_sim_engine::remote_data::sha256_hex;
+
+/// Where golden runs are kept, relative to the project root.
+pub const DEFAULT_DIR: &str = "replays";
+
+/// A recorded run: how the game was started, every command played, and a hash of where it
+/// ended up. Replaying it on an engine that draws and rolls in the same order lands on
+/// the same hash.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GoldenRun {
+    pub seed: String,
+    /// Runs recorded before seeds were hashed have none, and replay on the legacy scheme.
+    #[serde(default)]
+    pub seed_scheme: SeedScheme,
+    #[serde(default)]
+    pub deck_mode: DeckMode,
+    #[serde(default)]
+    pub scenario: Option<String>,
+    #[serde(default)]
+    pub tutorial: bool,
+    pub commands: Vec<GameCommand>,
+    /// `fingerprint` of the final state.
+    pub final_state_hash: String,
+}
+
+impl GoldenRun {
+    /// Record a game as it stands.
+    pub fn record(state: &GameState) -> Self {
+        Self {
+            seed: state.seed.clone(),
+            seed_scheme: state.seed_scheme,
+            deck_mode: state.deck_mode,
+            scenario: state.scenario.clone(),
+            tutorial: state.tutorial,
+            commands: state.commands.clone(),
+            final_state_hash: fingerprint(state),
+        }
+    }
+
+    /// Replay the run on the current engine. Err describes how it went differently.
+    pub fn verify(&self, data: &GameData) -> Result<(), String> {
+        let mut original = GameState::new(self.seed.clone());
+        original.seed_scheme = self.seed_scheme;
+        original.deck_mode = self.deck_mode;
+        original.scenario = self.scenario.clone();
+        original.tutorial = self.tutorial;
+        original.commands = self.commands.clone();
+
+        let replayed = replay::replay(&original, data, None)
+            .map_err(|_| "the replay was rejected".to_string())?;
+        if let Some(adjustment) = replayed.adjustments.first() {
+            return Err(format!("a recorded command no longer applies ({})", adjustment));
+        }
+        let hash = fingerprint(&replayed.state);
+        if hash != self.final_state_hash {
+            return Err(format!("final state hash {} doesn't match the recorded {}", short(&hash), short(&self.final_state_hash)));
+        }
+        Ok(())
+    }
+}
+
+fn short(hash: &str) -> &str {
+    hash.get(..12).unwrap_or(hash)
+}
+
+/// Hash of what a game played out to: each turn's stats and card, and the final
+/// credentials, decisions, job, and path. Only played-out values go in, so adding a field
+/// to GameState doesn't invalidate recordings, but any change in what the RNG picked does.
+pub fn fingerprint(state: &GameState) -> String {
+    let turns: Vec<_> = state.stats_history.iter()
+        .map(|s| (s.turn, s.money, s.stress, s.support, s.event_id.as_deref()))
+        .collect();
+    let decisions: Vec<&str> = state.decision_log.iter().map(|d| d.description.as_str()).collect();
+    let played = serde_json::json!({
+        "turns": turns,
+        "money": state.money,
+        "stress": state.stress,
+        "support": state.support,
+        "savings": state.savings,
+        "credentials": state.credentials.names(),
+        "usedEventIds": state.used_event_ids,
+        "decisions": decisions,
+        "job": state.current_job.as_ref().map(|j| &j.id),
+        "path": state.path.as_ref().map(|p| &p.id),
+    });
+    sha256_hex(played.to_string().as_bytes())
+}
+
+/// Write a run to `dir` as `<name>.json`.
+pub fn save(dir: &Path, name: &str, run: &GoldenRun) -> Result<PathBuf, String> {
+    fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    let path = dir.join(format!("{}.json", name));
+    let json = serde_json::to_string_pretty(run).map_err(|e| e.to_string())?;
+    fs::write(&path, json + "\n").map_err(|e| format!("{}: {}", path.display(), e))?;
+    Ok(path)
+}
+
+/// Every run in `dir` (its `.json` files), sorted by file name.
+pub fn load_all(dir: &Path) -> Result<Vec<(String, GoldenRun)>, String> {
+    let entries = fs::read_dir(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort();
+    paths.into_iter().map(|path| {
+        let text = fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let run = serde_json::from_str(&text).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let name = path.file_stem().map_or_else(String::new, |s| s.to_string_lossy().into_owned());
+        Ok((name, run))
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data;
+    use life_sim_engine::engine::bot;
+
+    #[test]
+    fn test_checked_in_replays_still_match() {
+        let data = test_data();
+        let runs = load_all(&PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..").join(DEFAULT_DIR)).unwrap();
+        assert!(!runs.is_empty());
+        for (name, run) in runs {
+            assert_eq!(run.verify(&data), Ok(()), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_recorded_run_verifies_until_tampered_with() {
+        let data = test_data();
+        let state = bot::play_game(&bot::RandomStrategy, &data, "GOLDEN");
+        let run = GoldenRun::record(&state);
+        assert_eq!(run.verify(&data), Ok(()));
+
+        let mut changed = run.clone();
+        if let Some(GameCommand::Turn { decision_option_index, .. }) = changed.commands.iter_mut()
+            .find(|c| matches!(c, GameCommand::Turn { decision_id, .. } if decision_id == "dec_club_choice_a"))
+        {
+            *decision_option_index = (*decision_option_index + 1) % 2;
+        }
+        assert!(changed.verify(&data).unwrap_err().contains("doesn't match"));
+    }
+}
@@ -0,0 +1,236 @@
+use std::collections::{BTreeMap, BTreeSet};
+use life_sim_engine::data_loader::GameData;
+use life_sim_engine::models::event::{StatEffect, StatType};
+
+/// Semantic checks on content that loads and validates but is probably a mistake.
+/// Returns one human-readable warning per finding (empty if nothing looks off).
+pub fn lint(data: &GameData) -> Vec<String> {
+    let mut warnings = Vec::new();
+    let grants = tag_grants(data);
+
+    // Tags that something asks for but nothing hands out
+    let mut orphans: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut require = |tag: &str, by: String| {
+        if !grants.contains_key(tag) {
+            orphans.entry(tag.to_string()).or_default().push(by);
+        }
+    };
+    for job in &data.jobs {
+        for tag in job.required_tags.iter().chain(&job.recommended_tags) {
+            require(tag, format!("jobs.json [{}]", job.id));
+        }
+        for tier in &job.tiers {
+            for tag in &tier.required_tags {
+                require(tag, format!("jobs.json [{}] \"{}\"", job.id, tier.title));
+            }
+        }
+    }
+    for gig in &data.side_gigs {
+        for tag in &gig.required_tags {
+            require(tag, format!("side_gigs.json [{}]", gig.id));
+        }
+    }
+    for decision in &data.decisions {
+        for option in &decision.options {
+            if let Some(ref tag) = option.requires_tag {
+                require(tag, format!("decisions.json [{}] \"{}\"", decision.id, option.label));
+            }
+        }
+    }
+    for action in &data.actions {
+        if let Some(tag) = action.requires.as_ref().and_then(|r| r.tag.as_ref()) {
+            require(tag, format!("actions.json [{}]", action.id));
+        }
+    }
+    for ending in &data.endings {
+        for tag in &ending.conditions.required_tags {
+            require(tag, format!("endings.json [{}]", ending.id));
+        }
+    }
+    for (tag, users) in orphans {
+        warnings.push(format!("tag \"{}\" is never granted but is required by {}", tag, users.join(", ")));
+    }
+
+    // Jobs whose entry requirements can't all be earned (a job's own growth tag doesn't count)
+    for job in &data.jobs {
+        let unreachable: Vec<&str> = job.required_tags.iter()
+            .filter(|tag| grants.get(*tag)
+                .is_none_or(|sources| sources.iter().all(|s| *s == format!("jobs.json [{}]", job.id))))
+            .map(String::as_str)
+            .collect();
+        if !unreachable.is_empty() {
+            warnings.push(format!(
+                "jobs.json [{}]: unreachable, no way to earn {}",
+                job.id,
+                unreachable.join(", ")
+            ));
+        }
+    }
+
+    // Events the player can't respond to
+    for event in &data.events {
+        if event.options.is_empty() {
+            warnings.push(format!("events.json [{}]: has no options", event.id));
+        }
+    }
+
+    // Decisions where the choice makes no difference
+    for decision in &data.decisions {
+        let outcomes: Vec<serde_json::Value> = decision.options.iter().map(|option| {
+            let mut outcome = serde_json::to_value(option).unwrap_or_default();
+            if let Some(fields) = outcome.as_object_mut() {
+                fields.remove("label");
+                fields.remove("description");
+            }
+            outcome
+        }).collect();
+        if outcomes.len() > 1 && outcomes.iter().all(|o| *o == outcomes[0]) {
+            warnings.push(format!("decisions.json [{}]: every option has identical effects", decision.id));
+        }
+    }
+
+    warnings
+}
+
+/// Every tag the content can grant, mapped to where it comes from.
+fn tag_grants(data: &GameData) -> BTreeMap<String, BTreeSet<String>> {
+    let mut grants: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    let mut grant = |tag: &str, from: String| {
+        grants.entry(tag.to_string()).or_default().insert(from);
+    };
+    for action in &data.actions {
+        credential_tags(&action.effects).for_each(|t| grant(t, format!("actions.json [{}]", action.id)));
+    }
+    for decision in &data.decisions {
+        for option in &decision.options {
+            let from = format!("decisions.json [{}]", decision.id);
+            credential_tags(&option.effects).for_each(|t| grant(t, from.clone()));
+            if let Some(ref tag) = option.grants_tag {
+                grant(tag, from);
+            }
+        }
+    }
+    for event in &data.events {
+        for option in &event.options {
+            let from = format!("events.json [{}]", event.id);
+            credential_tags(&option.effects).for_each(|t| grant(t, from.clone()));
+            for delayed in option.delayed_effects.iter().flatten() {
+                credential_tags(&delayed.effects).for_each(|t| grant(t, from.clone()));
+            }
+        }
+    }
+    for program in &data.education {
+        grant(&program.grants_tag, format!("education.json [{}]", program.id));
+    }
+    for option in &data.transport {
+        if let Some(ref tag) = option.grants_tag {
+            grant(tag, format!("transport.json [{}]", option.id));
+        }
+    }
+    for job in &data.jobs {
+        if let Some(ref tag) = job.growth_tag {
+            grant(tag, format!("jobs.json [{}]", job.id));
+        }
+    }
+    for scenario in &data.scenarios {
+        for tag in &scenario.tags {
+            grant(tag, format!("scenarios.json [{}]", scenario.id));
+        }
+    }
+    for effect in &data.special_effects {
+        script_tags(&effect.script).for_each(|t| grant(t, format!("special_effects.json [{}]", effect.id)));
+    }
+    grants
+}
+
+/// Tags a script grants with `grant_tag("...")`. Tags it builds at run time can't be
+/// seen, so those go unlisted.
+fn script_tags(script: &str) -> impl Iterator<Item = &str> {
+    script.split("grant_tag(").skip(1).filter_map(|call| {
+        let literal = call.trim_start().strip_prefix('"')?;
+        literal.split_once('"').map(|(tag, _)| tag)
+    })
+}
+
+/// Tags granted by credential effects.
+fn credential_tags(effects: &[StatEffect]) -> impl Iterator<Item = &str> {
+    effects.iter()
+        .filter(|e| e.stat == StatType::Credentials)
+        .filter_map(|e| e.tag.as_deref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data;
+    use life_sim_engine::models::action::ActionRequirements;
+    use life_sim_engine::models::special_effect::SpecialEffect;
+
+    #[test]
+    fn test_shipped_data_is_lint_clean() {
+        let data = test_data();
+        assert!(lint(&data).is_empty(), "{:#?}", lint(&data));
+    }
+
+    #[test]
+    fn test_lint_findings() {
+        let mut data = test_data();
+        data.jobs[0].required_tags.push("Astronaut License".to_string());
+        data.events[0].options.clear();
+        let same = data.decisions[0].options[0].clone();
+        for option in &mut data.decisions[0].options {
+            *option = life_sim_engine::models::decision::DecisionOption { label: option.label.clone(), ..same.clone() };
+        }
+
+        let warnings = lint(&data);
+        assert!(warnings.iter().any(|w| w.starts_with("tag \"Astronaut License\" is never granted")), "{:?}", warnings);
+        assert!(warnings.iter().any(|w| w.contains(&format!("[{}]: unreachable", data.jobs[0].id))), "{:?}", warnings);
+        assert!(warnings.iter().any(|w| w.contains("has no options")), "{:?}", warnings);
+        assert!(warnings.iter().any(|w| w.contains("identical effects")), "{:?}", warnings);
+    }
+
+    #[test]
+    fn test_own_growth_tag_does_not_unlock_job() {
+        let mut data = test_data();
+        let job = &mut data.jobs[0];
+        job.growth_tag = Some("Bootstrapped".to_string());
+        job.required_tags.push("Bootstrapped".to_string());
+        let id = job.id.clone();
+        let warnings = lint(&data);
+        assert!(warnings.iter().any(|w| w.contains(&format!("[{}]: unreachable, no way to earn Bootstrapped", id))), "{:?}", warnings);
+    }
+
+    #[test]
+    fn test_action_and_ending_tags_must_be_granted() {
+        let mut data = test_data();
+        data.actions[0].requires = Some(ActionRequirements { tag: Some("Pilot License".to_string()), ..Default::default() });
+        data.endings[0].conditions.required_tags.push("Pilot License".to_string());
+        let (action, ending) = (data.actions[0].id.clone(), data.endings[0].id.clone());
+
+        let warnings = lint(&data);
+        let orphan = warnings.iter().find(|w| w.starts_with("tag \"Pilot License\" is never granted")).expect("Orphaned tag");
+        assert!(orphan.contains(&format!("actions.json [{}]", action)), "{}", orphan);
+        assert!(orphan.contains(&format!("endings.json [{}]", ending)), "{}", orphan);
+    }
+
+    #[test]
+    fn test_scenario_and_script_tags_count_as_granted() {
+        let mut data = test_data();
+        data.jobs[0].required_tags.extend(["Head Start".to_string(), "Zen".to_string()]);
+        data.scenarios[0].tags.push("Head Start".to_string());
+        data.special_effects.push(SpecialEffect {
+            id: "calm".to_string(),
+            description: String::new(),
+            script: "state.stress -= 5; state.grant_tag( \"Zen\");".to_string(),
+        });
+
+        let warnings = lint(&data);
+        assert!(!warnings.iter().any(|w| w.contains("Head Start") || w.contains("Zen")), "{:?}", warnings);
+    }
+
+    #[test]
+    fn test_script_tags() {
+        let tags: Vec<&str> = script_tags("grant_tag(\"A\"); if x { state.grant_tag(\"B\") } grant_tag(name);").collect();
+        assert_eq!(tags, vec!["A", "B"]);
+    }
+}
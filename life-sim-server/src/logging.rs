@@ -0,0 +1,72 @@
+use std::str::FromStr;
+use serde::Deserialize;
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::EnvFilter;
+
+/// How the server writes its log lines.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LogFormat {
+    /// One readable line per event, for a terminal.
+    #[default]
+    Text,
+    /// One JSON object per event, with the fields of the spans it happened in, for hosted
+    /// deployments that collect logs.
+    Json,
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "text" => Ok(LogFormat::Text),
+            "json" => Ok(LogFormat::Json),
+            _ => Err(format!("'{}' is not a log format (text or json)", s)),
+        }
+    }
+}
+
+/// The filter for a log level: a level such as `info`, or per-module directives such as
+/// `warn,life_sim_server=debug`.
+pub fn filter(level: &str) -> Result<EnvFilter, String> {
+    EnvFilter::try_new(level).map_err(|e| format!("'{}' is not a log level: {}", level, e))
+}
+
+/// Send the server's logs to stdout. Each request is a span, closed with its status and
+/// timing, and each turn played is a span inside it carrying the game's seed, turn and stage.
+pub fn init(level: &str, format: LogFormat) -> Result<(), String> {
+    let builder = tracing_subscriber::fmt()
+        .with_env_filter(filter(level)?)
+        .with_span_events(FmtSpan::CLOSE);
+    let installed = match format {
+        LogFormat::Text => builder.try_init(),
+        LogFormat::Json => builder.json().flatten_event(true).try_init(),
+    };
+    installed.map_err(|e| format!("Failed to start logging: {}", e))
+}
+
+/// Logs for the command-line tools: warnings and errors only, on stderr, so they stay out
+/// of a command's own output.
+pub fn init_cli() {
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::new("warn"))
+        .with_writer(std::io::stderr)
+        .without_time()
+        .try_init();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levels_and_formats_parse() {
+        assert!(filter("info").is_ok());
+        assert!(filter("warn,life_sim_server=debug").is_ok());
+        assert!(filter("info,life_sim_server=loud").is_err());
+
+        assert_eq!("json".parse(), Ok(LogFormat::Json));
+        assert!("yaml".parse::<LogFormat>().unwrap_err().contains("text or json"));
+    }
+}
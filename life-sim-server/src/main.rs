@@ -0,0 +1,583 @@
+mod schema;
+mod lint;
+mod run_store;
+mod analytics;
+mod api;
+mod cli;
+mod simulate;
+mod soak;
+mod golden;
+mod config;
+mod tls;
+mod logging;
+
+use actix_web::{middleware, App, HttpServer, web};
+use actix_files as fs;
+use tracing::{error, info};
+use tracing_actix_web::TracingLogger;
+use std::path::PathBuf;
+use dashmap::DashMap;
+use life_sim_engine::{data_loader, engine};
+
+#[actix_web::main]
+async fn main() -> std::io::Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let command: Option<fn(&[String]) -> i32> = match args.first().map(String::as_str) {
+        Some("schema") => Some(schema_command),
+        Some("lint") => Some(lint_command),
+        Some("play" | "--cli") => Some(play_command),
+        Some("simulate") => Some(simulate_command),
+        Some("soak") => Some(soak_command),
+        Some("record-replays") => Some(record_replays_command),
+        Some("verify-replays") => Some(verify_replays_command),
+        _ => None,
+    };
+    if let Some(command) = command {
+        logging::init_cli();
+        std::process::exit(command(&args[1..]));
+    }
+
+    let options = match Options::parse(args.into_iter()) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    };
+    if let Err(e) = logging::init(&options.server.log_level, options.server.log_format) {
+        eprintln!("{}", e);
+        std::process::exit(2);
+    }
+
+    let (base, game_data) = match load_data(&options) {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            error!("{}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let runs = match run_store::RunStore::open(options.runs_file.clone()) {
+        Ok(runs) => runs,
+        Err(e) => {
+            error!("Failed to load leaderboard runs from {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let analytics = match options.analytics_file {
+        Some(ref path) => match analytics::Analytics::open(path) {
+            Ok(analytics) => {
+                info!(path = %path.display(), "Recording gameplay analytics");
+                analytics
+            }
+            Err(e) => {
+                error!("Failed to open the analytics log {}", e);
+                std::process::exit(1);
+            }
+        },
+        None => analytics::Analytics::disabled(),
+    };
+    if options.rng_log {
+        info!("Keeping an RNG audit log for each new game (GET /api/debug/rng_log)");
+    }
+
+    // Shared mutable game state (one game per session)
+    let app_state = web::Data::new(api::routes::AppState {
+        sessions: DashMap::new(),
+        data: data_loader::DataStore::new(base, options.data_packs, game_data),
+        turn_stream: api::ws::TurnStream::new(),
+        classes: api::classroom::Classrooms::new(),
+        daily: api::daily::DailyResults::new(),
+        runs,
+        analytics,
+        rng_log: options.rng_log,
+        admin_key: options.server.admin_key.clone(),
+    });
+
+    let sweeper = app_state.clone();
+    actix_web::rt::spawn(async move {
+        let mut sweep = actix_web::rt::time::interval(api::session::SESSION_SWEEP_INTERVAL);
+        loop {
+            sweep.tick().await;
+            let evicted = sweeper.evict_idle_sessions();
+            if evicted > 0 {
+                info!(evicted, "Dropped idle games");
+            }
+            let evicted = sweeper.evict_idle_classes();
+            if evicted > 0 {
+                info!(evicted, "Dropped idle classes");
+            }
+        }
+    });
+
+    let server = options.server;
+    let tls = match server.tls() {
+        Some((cert, key)) => match tls::server_config(cert, key) {
+            Ok(tls) => {
+                info!(cert = %cert.display(), "Serving HTTPS");
+                Some(tls)
+            }
+            Err(e) => {
+                error!("{}", e);
+                std::process::exit(1);
+            }
+        },
+        None => None,
+    };
+    if !server.cors_origins.is_empty() {
+        info!(origins = %server.cors_origins.join(", "), "Allowing cross-origin API calls");
+    }
+
+    let scheme = if tls.is_some() { "https" } else { "http" };
+    let url = format!("{}://{}:{}", scheme, display_host(&server.bind), server.port);
+    info!(%url, "🎮 Life Roguelite server starting; open {} in your browser", url);
+
+    let static_dir = server.static_dir.clone();
+    let cors_origins = server.cors_origins.clone();
+    let http_server = HttpServer::new(move || {
+        App::new()
+            .app_data(app_state.clone())
+            .wrap(middleware::Condition::new(!cors_origins.is_empty(), api::cors::middleware(&cors_origins)))
+            .wrap(TracingLogger::default())
+            // API routes
+            .configure(api::routes::configure)
+            // Static files (index.html, css, js)
+            .service(fs::Files::new("/", &static_dir).index_file("index.html"))
+    });
+    match tls {
+        Some(tls) => http_server.bind_rustls_0_23(server.address(), tls)?.run().await,
+        None => http_server.bind(server.address())?.run().await,
+    }
+}
+
+/// The host to show in the server's address: a wildcard bind is reachable on this machine
+/// as localhost.
+fn display_host(bind: &str) -> &str {
+    match bind {
+        "0.0.0.0" | "::" | "127.0.0.1" => "localhost",
+        host => host,
+    }
+}
+
+/// Command-line options.
+struct Options {
+    /// Content packs from `--data-pack <dir>`, in the order given (later packs win).
+    data_packs: Vec<PathBuf>,
+    /// `--require-data-dir`: refuse to fall back to the built-in data set.
+    require_data_dir: bool,
+    /// `--data-url <url>`: pull the base data set from this HTTP(S) URL instead of data/.
+    data_url: Option<String>,
+    /// `--data-cache <dir>`: where remote data is cached between runs.
+    data_cache: PathBuf,
+    /// `--runs-file <path>`: where leaderboard runs are saved.
+    runs_file: PathBuf,
+    /// `--analytics <path>`: append gameplay events to this JSONL file (off by default).
+    analytics_file: Option<PathBuf>,
+    /// `--rng-log`: keep an audit log of every random draw, for GET /api/debug/rng_log.
+    rng_log: bool,
+    /// Bind address, port, directories, CORS origins, TLS files, admin key, and logging,
+    /// from `--config <file>` (or the environment and `life-sim.json`) overridden by
+    /// `--bind`, `--port`, `--data-dir`, `--static-dir`, `--cors-origins`, `--tls-cert`,
+    /// `--tls-key`, `--admin-key`, `--log-level`, `--log-format`.
+    server: config::ServerConfig,
+}
+
+impl Options {
+    fn parse(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut options = Options {
+            data_packs: Vec::new(),
+            require_data_dir: false,
+            data_url: None,
+            data_cache: PathBuf::from("data-cache"),
+            runs_file: PathBuf::from("runs.json"),
+            analytics_file: None,
+            rng_log: false,
+            server: config::ServerConfig::default(),
+        };
+        let mut config_file = None;
+        let mut config_flags = Vec::new();
+        let mut args = args;
+        while let Some(arg) = args.next() {
+            if let Some(setting) = config::Setting::from_flag(&arg) {
+                match args.next() {
+                    Some(value) => config_flags.push((setting, value)),
+                    None => return Err(format!("{} needs a value", arg)),
+                }
+                continue;
+            }
+            match arg.as_str() {
+                "--data-pack" => match args.next() {
+                    Some(dir) => options.data_packs.push(PathBuf::from(dir)),
+                    None => return Err("--data-pack needs a directory".to_string()),
+                },
+                "--require-data-dir" => options.require_data_dir = true,
+                "--rng-log" => options.rng_log = true,
+                "--config" => match args.next() {
+                    Some(path) => config_file = Some(PathBuf::from(path)),
+                    None => return Err("--config needs a path".to_string()),
+                },
+                "--data-url" => match args.next() {
+                    Some(url) => options.data_url = Some(url),
+                    None => return Err("--data-url needs a URL".to_string()),
+                },
+                "--data-cache" => match args.next() {
+                    Some(dir) => options.data_cache = PathBuf::from(dir),
+                    None => return Err("--data-cache needs a directory".to_string()),
+                },
+                "--runs-file" => match args.next() {
+                    Some(path) => options.runs_file = PathBuf::from(path),
+                    None => return Err("--runs-file needs a path".to_string()),
+                },
+                "--analytics" => match args.next() {
+                    Some(path) => options.analytics_file = Some(PathBuf::from(path)),
+                    None => return Err("--analytics needs a path".to_string()),
+                },
+                other => match other.strip_prefix("--data-pack=") {
+                    Some(dir) => options.data_packs.push(PathBuf::from(dir)),
+                    None => return Err(format!("Unknown argument: {}", other)),
+                },
+            }
+        }
+        options.server = config::ServerConfig::resolve(config_file.as_deref(), |name| std::env::var(name).ok(), &config_flags)?;
+        for pack in &options.data_packs {
+            if !pack.is_dir() {
+                return Err(format!("Data pack {} is not a directory", pack.display()));
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// Load game data from --data-url, the data directory (`data/` unless configured), or the
+/// built-in copy (in that order), layering any `--data-pack <dir>` packs on top.
+fn load_data(options: &Options) -> Result<(data_loader::DataSource, data_loader::GameData), String> {
+    let data_dir = options.server.data_dir.clone();
+    let base = if let Some(ref url) = options.data_url {
+        data_loader::DataSource::Remote { url: url.clone(), cache: options.data_cache.clone() }
+    } else if data_dir.is_dir() {
+        data_loader::DataSource::Dir(data_dir)
+    } else if options.require_data_dir {
+        return Err(format!("No {} directory found (--require-data-dir is set).", data_dir.display()));
+    } else {
+        info!("No {} directory found; using the built-in data set.", data_dir.display());
+        data_loader::DataSource::Embedded
+    };
+    match data_loader::GameData::load(&base, &options.data_packs) {
+        Ok(data) => Ok((base, data)),
+        Err(e) => Err(format!("Failed to load game data from {}:\n{}", base, e)),
+    }
+}
+
+/// The shipped data set in `data/`, for tests.
+#[cfg(test)]
+pub(crate) fn test_data() -> data_loader::GameData {
+    let data_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../data");
+    data_loader::GameData::load_from_dir(&data_dir).expect("Should load test data")
+}
+
+/// `schema [OUT_DIR]`: write JSON Schema for every data file (default `schemas/`).
+fn schema_command(args: &[String]) -> i32 {
+    let out_dir = PathBuf::from(args.first().map_or("schemas", String::as_str));
+    match schema::write_schemas(&out_dir) {
+        Ok(written) => {
+            for path in written {
+                println!("Wrote {}", path.display());
+            }
+            0
+        }
+        Err(e) => {
+            eprintln!("Failed to write schemas to {}: {}", out_dir.display(), e);
+            1
+        }
+    }
+}
+
+/// `lint [DATA_DIR] [--data-pack DIR]...`: load and validate the data, then run the
+/// semantic content checks. Exits nonzero if anything is reported.
+fn lint_command(args: &[String]) -> i32 {
+    let (data_dir, rest) = match args.first() {
+        Some(dir) if !dir.starts_with("--") => (PathBuf::from(dir), &args[1..]),
+        _ => (PathBuf::from("data"), args),
+    };
+    let options = match Options::parse(rest.iter().cloned()) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}", e);
+            return 2;
+        }
+    };
+    let data = match data_loader::GameData::load(&data_loader::DataSource::Dir(data_dir.clone()), &options.data_packs) {
+        Ok(data) => data,
+        Err(e) => {
+            eprintln!("{}", e);
+            return 1;
+        }
+    };
+    let warnings = lint::lint(&data);
+    if warnings.is_empty() {
+        println!("✅ {}: no problems found", data_dir.display());
+        return 0;
+    }
+    println!("{} warning(s) in {}:", warnings.len(), data_dir.display());
+    for warning in &warnings {
+        println!("  - {}", warning);
+    }
+    1
+}
+
+/// `play [--seed SEED] [data options]` (or `--cli`): play a game in the terminal, no server.
+fn play_command(args: &[String]) -> i32 {
+    let mut seed = None;
+    let mut rest = Vec::new();
+    let mut args = args.iter().cloned();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => match args.next() {
+                Some(s) => seed = Some(s),
+                None => {
+                    eprintln!("--seed needs a value");
+                    return 2;
+                }
+            },
+            _ => rest.push(arg),
+        }
+    }
+    let options = match Options::parse(rest.into_iter()) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}", e);
+            return 2;
+        }
+    };
+    let (_, data) = match load_data(&options) {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            eprintln!("{}", e);
+            return 1;
+        }
+    };
+    let seed = seed.unwrap_or_else(engine::rng::generate_seed);
+    let stdin = std::io::stdin();
+    match cli::play(&data, &seed, &mut stdin.lock(), &mut std::io::stdout()) {
+        Ok(_) => 0,
+        Err(e) => {
+            eprintln!("{}", e);
+            1
+        }
+    }
+}
+
+/// `simulate [--games N] [--strategy NAME] [--seed-prefix PREFIX] [--split DECISION_ID] [data options]`:
+/// play many seeded games with a bot and print how they ended.
+fn simulate_command(args: &[String]) -> i32 {
+    let mut games = 1000;
+    let mut strategy = engine::bot::by_name("random").unwrap();
+    let mut seed_prefix = "SIM".to_string();
+    let mut split = None;
+    let mut rest = Vec::new();
+    let mut args = args.iter().cloned();
+    while let Some(arg) = args.next() {
+        let parsed = match arg.as_str() {
+            "--games" => args.next().and_then(|n| n.parse().ok()).map(|n| games = n)
+                .ok_or("--games needs a number".to_string()),
+            "--strategy" => args.next().and_then(|name| engine::bot::by_name(&name)).map(|s| strategy = s)
+                .ok_or_else(|| format!("--strategy needs one of: {}", engine::bot::STRATEGIES.join(", "))),
+            "--seed-prefix" => args.next().map(|p| seed_prefix = p).ok_or("--seed-prefix needs a value".to_string()),
+            "--split" => args.next().map(|id| split = Some(id)).ok_or("--split needs a decision ID".to_string()),
+            _ => {
+                rest.push(arg);
+                Ok(())
+            }
+        };
+        if let Err(e) = parsed {
+            eprintln!("{}", e);
+            return 2;
+        }
+    }
+    let options = match Options::parse(rest.into_iter()) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}", e);
+            return 2;
+        }
+    };
+    let (_, data) = match load_data(&options) {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            eprintln!("{}", e);
+            return 1;
+        }
+    };
+    if let Some(ref id) = split {
+        if data.decision(id).is_none() {
+            eprintln!("Unknown decision: {}", id);
+            return 2;
+        }
+    }
+
+    let reports = simulate::simulate(&data, strategy.as_ref(), games, &seed_prefix, split.as_deref());
+    println!(
+        "\nSimulated {} game(s) with the {} strategy (seeds {}-0 to {}-{})",
+        games, strategy.name(), seed_prefix, seed_prefix, games.saturating_sub(1)
+    );
+    print!("{}", simulate::format_reports(&reports));
+    0
+}
+
+/// `soak [--games N] [--strategy NAME] [--seed-prefix PREFIX] [data options]`:
+/// play many seeded games checking engine invariants after every turn. Exits 1 if any broke.
+fn soak_command(args: &[String]) -> i32 {
+    let mut games = 1000;
+    let mut strategy = engine::bot::by_name("random").unwrap();
+    let mut seed_prefix = "SOAK".to_string();
+    let mut rest = Vec::new();
+    let mut args = args.iter().cloned();
+    while let Some(arg) = args.next() {
+        let parsed = match arg.as_str() {
+            "--games" => args.next().and_then(|n| n.parse().ok()).map(|n| games = n)
+                .ok_or("--games needs a number".to_string()),
+            "--strategy" => args.next().and_then(|name| engine::bot::by_name(&name)).map(|s| strategy = s)
+                .ok_or_else(|| format!("--strategy needs one of: {}", engine::bot::STRATEGIES.join(", "))),
+            "--seed-prefix" => args.next().map(|p| seed_prefix = p).ok_or("--seed-prefix needs a value".to_string()),
+            _ => {
+                rest.push(arg);
+                Ok(())
+            }
+        };
+        if let Err(e) = parsed {
+            eprintln!("{}", e);
+            return 2;
+        }
+    }
+    let options = match Options::parse(rest.into_iter()) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}", e);
+            return 2;
+        }
+    };
+    let (_, data) = match load_data(&options) {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            eprintln!("{}", e);
+            return 1;
+        }
+    };
+
+    let report = soak::soak(&data, strategy.as_ref(), games, &seed_prefix);
+    print!("{}", soak::format_report(&report));
+    if report.violations.is_empty() { 0 } else { 1 }
+}
+
+/// `record-replays [--games N] [--strategy NAME] [--seed-prefix PREFIX] [--dir DIR] [data options]`:
+/// play seeded bot games and save each as a golden run for verify-replays.
+fn record_replays_command(args: &[String]) -> i32 {
+    let mut games = 3;
+    let mut strategy = engine::bot::by_name("random").unwrap();
+    let mut seed_prefix = "GOLDEN".to_string();
+    let mut dir = PathBuf::from(golden::DEFAULT_DIR);
+    let mut rest = Vec::new();
+    let mut args = args.iter().cloned();
+    while let Some(arg) = args.next() {
+        let parsed = match arg.as_str() {
+            "--games" => args.next().and_then(|n| n.parse().ok()).map(|n| games = n)
+                .ok_or("--games needs a number".to_string()),
+            "--strategy" => args.next().and_then(|name| engine::bot::by_name(&name)).map(|s| strategy = s)
+                .ok_or_else(|| format!("--strategy needs one of: {}", engine::bot::STRATEGIES.join(", "))),
+            "--seed-prefix" => args.next().map(|p| seed_prefix = p).ok_or("--seed-prefix needs a value".to_string()),
+            "--dir" => args.next().map(|d| dir = PathBuf::from(d)).ok_or("--dir needs a directory".to_string()),
+            _ => {
+                rest.push(arg);
+                Ok(())
+            }
+        };
+        if let Err(e) = parsed {
+            eprintln!("{}", e);
+            return 2;
+        }
+    }
+    let options = match Options::parse(rest.into_iter()) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}", e);
+            return 2;
+        }
+    };
+    let (_, data) = match load_data(&options) {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            eprintln!("{}", e);
+            return 1;
+        }
+    };
+
+    for i in 0..games {
+        let seed = format!("{}-{}", seed_prefix, i);
+        let state = engine::bot::play_game(strategy.as_ref(), &data, &seed);
+        let name = format!("{}-{}", strategy.name(), seed);
+        match golden::save(&dir, &name, &golden::GoldenRun::record(&state)) {
+            Ok(path) => println!("Wrote {}", path.display()),
+            Err(e) => {
+                eprintln!("{}", e);
+                return 1;
+            }
+        }
+    }
+    0
+}
+
+/// `verify-replays [--dir DIR] [data options]`: replay every golden run on this build and
+/// check it ends in the recorded state. Exits 1 if any doesn't.
+fn verify_replays_command(args: &[String]) -> i32 {
+    let mut dir = PathBuf::from(golden::DEFAULT_DIR);
+    let mut rest = Vec::new();
+    let mut args = args.iter().cloned();
+    while let Some(arg) = args.next() {
+        if arg == "--dir" {
+            match args.next() {
+                Some(d) => dir = PathBuf::from(d),
+                None => {
+                    eprintln!("--dir needs a directory");
+                    return 2;
+                }
+            }
+        } else {
+            rest.push(arg);
+        }
+    }
+    let options = match Options::parse(rest.into_iter()) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}", e);
+            return 2;
+        }
+    };
+    let (_, data) = match load_data(&options) {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            eprintln!("{}", e);
+            return 1;
+        }
+    };
+    let runs = match golden::load_all(&dir) {
+        Ok(runs) => runs,
+        Err(e) => {
+            eprintln!("{}", e);
+            return 1;
+        }
+    };
+
+    let mut failed = 0;
+    for (name, run) in &runs {
+        match run.verify(&data) {
+            Ok(()) => println!("✓ {}", name),
+            Err(e) => {
+                println!("✗ {}: {}", name, e);
+                failed += 1;
+            }
+        }
+    }
+    println!("\n{} of {} replay(s) match", runs.len() - failed, runs.len());
+    if failed == 0 { 0 } else { 1 }
+}
@@ -0,0 +1,358 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use life_sim_engine::data_loader::GameData;
+use life_sim_engine::engine::endings;
+use life_sim_engine::engine::game_state::GameState;
+use life_sim_engine::engine::score::{self, Grade, Score};
+use life_sim_engine::remote_data::sha256_hex;
+
+/// Version of the run file layout, bumped if it changes incompatibly.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// A finished run submitted to the leaderboard.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RunRecord {
+    /// Fingerprint of the player and every turn of the run; the same run submitted twice
+    /// has the same ID.
+    pub run_id: String,
+    pub name: String,
+    pub seed: String,
+    /// The class the run was played in, if any.
+    pub class_code: Option<String>,
+    /// Set for daily challenge runs.
+    pub daily_date: Option<String>,
+    pub ending_id: Option<String>,
+    pub ending_title: Option<String>,
+    pub money: i32,
+    pub stress: i32,
+    pub support: i32,
+    pub credential_count: usize,
+    pub score: Score,
+    /// Seconds since the Unix epoch.
+    pub submitted_at: u64,
+}
+
+impl RunRecord {
+    pub fn new(name: &str, class_code: Option<&str>, state: &GameState, data: &GameData, submitted_at: u64) -> Self {
+        let ending = endings::resolve(state, &data.endings);
+        Self {
+            run_id: run_id(name, class_code, state),
+            name: name.to_string(),
+            seed: state.seed.clone(),
+            class_code: class_code.map(str::to_string),
+            daily_date: state.daily_date.clone(),
+            ending_id: ending.map(|e| e.id.clone()),
+            ending_title: ending.map(|e| e.title.clone()),
+            money: state.money,
+            stress: state.stress,
+            support: state.support,
+            credential_count: state.credentials.len(),
+            score: score::score(state, &data.endings),
+            submitted_at,
+        }
+    }
+}
+
+/// Fingerprint a run: who played it, where, and what happened every turn.
+fn run_id(name: &str, class_code: Option<&str>, state: &GameState) -> String {
+    let run = serde_json::json!({
+        "name": name.trim().to_lowercase(),
+        "classCode": class_code,
+        "seed": state.seed,
+        "dailyDate": state.daily_date,
+        "decisions": state.decision_log,
+        "history": state.stats_history,
+    });
+    sha256_hex(run.to_string().as_bytes())[..16].to_string()
+}
+
+/// An ending a player has reached in at least one finished game.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UnlockedEnding {
+    pub ending_id: String,
+    /// Seconds since the Unix epoch.
+    pub first_reached_at: u64,
+    /// Finished games that ended this way.
+    pub times_reached: u32,
+}
+
+/// How many finished games reached each ending and badge.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EndingCounts {
+    /// Finished games counted.
+    pub games: u32,
+    /// Ending or badge ID → games that reached it.
+    pub reached: BTreeMap<String, u32>,
+}
+
+impl EndingCounts {
+    fn count(&mut self, reached: &[&str]) {
+        self.games += 1;
+        for id in reached {
+            *self.reached.entry(id.to_string()).or_insert(0) += 1;
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RunFile {
+    format_version: u32,
+    runs: Vec<RunRecord>,
+    /// Each player's unlocked endings, by player: a session ID, or "{code}/{studentId}"
+    /// for a student.
+    #[serde(default)]
+    unlocked: BTreeMap<String, Vec<UnlockedEnding>>,
+    /// Every finished game's ending and badges.
+    #[serde(default)]
+    ending_counts: EndingCounts,
+    /// The same, for each class by join code.
+    #[serde(default)]
+    class_ending_counts: BTreeMap<String, EndingCounts>,
+}
+
+impl RunFile {
+    fn empty() -> Self {
+        Self {
+            format_version: FORMAT_VERSION,
+            runs: Vec::new(),
+            unlocked: BTreeMap::new(),
+            ending_counts: EndingCounts::default(),
+            class_ending_counts: BTreeMap::new(),
+        }
+    }
+}
+
+/// Finished runs and the endings players have unlocked, kept in a JSON file so the
+/// leaderboard and the endings gallery survive restarts.
+pub struct RunStore {
+    /// None keeps runs in memory only.
+    path: Option<PathBuf>,
+    file: Mutex<RunFile>,
+}
+
+impl RunStore {
+    /// Open the run file, starting empty if it doesn't exist yet.
+    pub fn open(path: PathBuf) -> Result<Self, String> {
+        let file = match std::fs::read_to_string(&path) {
+            Ok(text) => {
+                let mut file: RunFile = serde_json::from_str(&text)
+                    .map_err(|e| format!("{}: {}", path.display(), e))?;
+                if file.format_version > FORMAT_VERSION {
+                    return Err(format!(
+                        "{}: formatVersion {} is newer than this server supports ({})",
+                        path.display(), file.format_version, FORMAT_VERSION
+                    ));
+                }
+                // Runs saved before scores had grades are graded on the total they were saved with
+                for run in &mut file.runs {
+                    run.score.grade = Grade::for_total(run.score.total);
+                }
+                file
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => RunFile::empty(),
+            Err(e) => return Err(format!("{}: {}", path.display(), e)),
+        };
+        Ok(Self { path: Some(path), file: Mutex::new(file) })
+    }
+
+    /// Add a run unless the same run is already stored. Returns the stored record and
+    /// whether it was new.
+    pub fn submit(&self, record: RunRecord) -> Result<(RunRecord, bool), String> {
+        let mut file = self.file.lock().unwrap();
+        if let Some(existing) = file.runs.iter().find(|r| r.run_id == record.run_id) {
+            return Ok((existing.clone(), false));
+        }
+        file.runs.push(record.clone());
+        if let Err(e) = self.save(&file) {
+            file.runs.pop();
+            return Err(e);
+        }
+        Ok((record, true))
+    }
+
+    /// Runs that pass the filter, best first (ties go to the earlier submission).
+    pub fn ranked(&self, filter: impl Fn(&RunRecord) -> bool) -> Vec<RunRecord> {
+        let mut runs: Vec<RunRecord> = self.file.lock().unwrap().runs.iter().filter(|r| filter(r)).cloned().collect();
+        runs.sort_by(|a, b| b.score.total.cmp(&a.score.total).then(a.submitted_at.cmp(&b.submitted_at)));
+        runs
+    }
+
+    /// Record a finished game: unlock the ending and badges it reached for the player, and
+    /// count them in the ending statistics, overall and for the game's class. Returns the
+    /// IDs newly unlocked. If saving fails the game is still counted until the server stops.
+    pub fn finish_game(&self, player: &str, class_code: Option<&str>, reached: &[&str], at: u64) -> Result<Vec<String>, String> {
+        let mut file = self.file.lock().unwrap();
+        let unlocked = file.unlocked.entry(player.to_string()).or_default();
+        let mut new = Vec::new();
+        for &id in reached {
+            match unlocked.iter_mut().find(|u| u.ending_id == id) {
+                Some(existing) => existing.times_reached += 1,
+                None => {
+                    unlocked.push(UnlockedEnding { ending_id: id.to_string(), first_reached_at: at, times_reached: 1 });
+                    new.push(id.to_string());
+                }
+            }
+        }
+        file.ending_counts.count(reached);
+        if let Some(code) = class_code {
+            file.class_ending_counts.entry(code.to_string()).or_default().count(reached);
+        }
+        self.save(&file)?;
+        Ok(new)
+    }
+
+    /// The endings a player has unlocked, in the order they first reached them.
+    pub fn unlocked(&self, player: &str) -> Vec<UnlockedEnding> {
+        self.file.lock().unwrap().unlocked.get(player).cloned().unwrap_or_default()
+    }
+
+    /// How often finished games reached each ending: overall, or for one class.
+    pub fn ending_counts(&self, class_code: Option<&str>) -> EndingCounts {
+        let file = self.file.lock().unwrap();
+        match class_code {
+            Some(code) => file.class_ending_counts.get(code).cloned().unwrap_or_default(),
+            None => file.ending_counts.clone(),
+        }
+    }
+
+    /// Write every run, unlock, and count out, via a temporary file so a crash can't leave half a file.
+    fn save(&self, file: &RunFile) -> Result<(), String> {
+        let Some(ref path) = self.path else { return Ok(()) };
+        let text = serde_json::to_string_pretty(file).map_err(|e| e.to_string())?;
+        let tmp = path.with_extension("json.tmp");
+        std::fs::write(&tmp, text)
+            .and_then(|_| std::fs::rename(&tmp, path))
+            .map_err(|e| format!("Failed to save runs to {}: {}", path.display(), e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data;
+    use life_sim_engine::engine::game_state::StatSnapshot;
+    use life_sim_engine::models::Stage;
+
+    fn in_memory() -> RunStore {
+        RunStore { path: None, file: Mutex::new(RunFile::empty()) }
+    }
+
+    fn finished(seed: &str, money: i32) -> GameState {
+        let mut state = GameState::new(seed.to_string());
+        state.money = money;
+        state.stats_history.push(StatSnapshot {
+            turn: 1, stage: Stage::MIDDLE_SCHOOL, money, stress: 20, support: 5, time_slots: 3,
+            money_earned: 0, money_spent: 0, credentials_earned: Vec::new(), event_id: None, event_option_index: None, extra_events: Vec::new(), job_id: None,
+        });
+        state
+    }
+
+    #[test]
+    fn test_duplicate_runs_are_stored_once() {
+        let data = test_data();
+        let store = in_memory();
+        let state = finished("LEADER", 150);
+
+        let (_, added) = store.submit(RunRecord::new("Ana", None, &state, &data, 1)).unwrap();
+        assert!(added);
+        let (existing, added) = store.submit(RunRecord::new("ana ", None, &state, &data, 2)).unwrap();
+        assert!(!added, "Same player, same run");
+        assert_eq!(existing.submitted_at, 1);
+
+        store.submit(RunRecord::new("Ben", None, &state, &data, 3)).unwrap();
+        assert_eq!(store.ranked(|_| true).len(), 2, "Another player's identical run counts");
+    }
+
+    #[test]
+    fn test_runs_rank_by_score_and_filter() {
+        let data = test_data();
+        let store = in_memory();
+        store.submit(RunRecord::new("Ana", Some("ABC234"), &finished("ONE", 100), &data, 1)).unwrap();
+        store.submit(RunRecord::new("Ben", None, &finished("ONE", 400), &data, 2)).unwrap();
+        store.submit(RunRecord::new("Cy", None, &finished("TWO", 900), &data, 3)).unwrap();
+
+        let names = |runs: Vec<RunRecord>| runs.into_iter().map(|r| r.name).collect::<Vec<_>>();
+        assert_eq!(names(store.ranked(|_| true)), vec!["Cy", "Ben", "Ana"]);
+        assert_eq!(names(store.ranked(|r| r.seed == "ONE")), vec!["Ben", "Ana"]);
+        assert_eq!(names(store.ranked(|r| r.class_code.as_deref() == Some("ABC234"))), vec!["Ana"]);
+    }
+
+    #[test]
+    fn test_runs_survive_reopening() {
+        let data = test_data();
+        let path = std::env::temp_dir().join(format!("life-sim-runs-{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let store = RunStore::open(path.clone()).unwrap();
+        store.submit(RunRecord::new("Ana", None, &finished("SAVED", 300), &data, 1)).unwrap();
+        drop(store);
+
+        let reopened = RunStore::open(path.clone()).unwrap();
+        let runs = reopened.ranked(|_| true);
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].seed, "SAVED");
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_runs_saved_without_grades_are_graded() {
+        let path = std::env::temp_dir().join(format!("life-sim-ungraded-{}.json", std::process::id()));
+        let run = serde_json::json!({
+            "runId": "abc", "name": "Ana", "seed": "OLD", "classCode": null, "dailyDate": null,
+            "endingId": null, "endingTitle": null, "money": 300, "stress": 20, "support": 5, "credentialCount": 0,
+            "score": { "money": 60, "lowStress": 80, "credentials": 0, "ending": 250, "total": 390 },
+            "submittedAt": 1,
+        });
+        std::fs::write(&path, serde_json::json!({ "formatVersion": 1, "runs": [run] }).to_string()).unwrap();
+
+        let runs = RunStore::open(path.clone()).unwrap().ranked(|_| true);
+        assert_eq!((runs[0].score.total, runs[0].score.grade), (390, Grade::B));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_unlocked_endings_are_kept_per_player() {
+        let path = std::env::temp_dir().join(format!("life-sim-gallery-{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let store = RunStore::open(path.clone()).unwrap();
+        assert_eq!(store.finish_game("tab-1", None, &["ending_stable", "badge_debt_free"], 10).unwrap(), vec!["ending_stable", "badge_debt_free"]);
+        assert!(store.finish_game("tab-1", None, &["ending_stable"], 20).unwrap().is_empty(), "Already unlocked");
+        assert_eq!(store.finish_game("tab-1", None, &["ending_broke"], 30).unwrap(), vec!["ending_broke"]);
+        assert_eq!(store.finish_game("ABC234/s1", Some("ABC234"), &["ending_stable"], 40).unwrap().len(), 1, "Each player unlocks their own");
+        drop(store);
+
+        let reopened = RunStore::open(path.clone()).unwrap();
+        let unlock = |id: &str, first_reached_at, times_reached| UnlockedEnding { ending_id: id.to_string(), first_reached_at, times_reached };
+        assert_eq!(reopened.unlocked("tab-1"), vec![
+            unlock("ending_stable", 10, 2),
+            unlock("badge_debt_free", 10, 1),
+            unlock("ending_broke", 30, 1),
+        ]);
+        assert_eq!(reopened.unlocked("ABC234/s1").len(), 1);
+        assert!(reopened.unlocked("tab-2").is_empty());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_finished_games_are_counted_overall_and_by_class() {
+        let store = in_memory();
+        store.finish_game("tab-1", None, &["ending_burnout"], 1).unwrap();
+        store.finish_game("ABC234/s1", Some("ABC234"), &["ending_burnout", "badge_debt_free"], 2).unwrap();
+        store.finish_game("ABC234/s2", Some("ABC234"), &["ending_stable"], 3).unwrap();
+
+        let overall = store.ending_counts(None);
+        assert_eq!(overall.games, 3);
+        assert_eq!(overall.reached["ending_burnout"], 2);
+        let class = store.ending_counts(Some("ABC234"));
+        assert_eq!((class.games, class.reached["ending_burnout"], class.reached["badge_debt_free"]), (2, 1, 1));
+        assert_eq!(store.ending_counts(Some("XYZ789")), EndingCounts::default());
+    }
+}
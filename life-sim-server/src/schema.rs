@@ -0,0 +1,100 @@
+use std::path::{Path, PathBuf};
+use schemars::{schema_for, Schema};
+use serde_json::{json, Value};
+use life_sim_engine::migrations::{CURRENT_FORMAT_VERSION, FORMAT_VERSION_KEY};
+use life_sim_engine::models::{EventCard, Action, Decision, Job, Ending, EconomyConfig, EducationProgram, SideGig, InsurancePlan, HousingTier, TransportOption, StageDefinition, LifePath, TutorialStep, Scenario, StatDefinition, SpecialEffect, RarityTier, Milestone, CuratedSeed};
+
+/// JSON Schema for every data file, generated from the model types.
+/// Keyed by data file name (e.g. "events"). List files hold their entries under the
+/// file's name; every file carries the current format version.
+pub fn data_schemas() -> Vec<(&'static str, Schema)> {
+    let schemas = vec![
+        ("stages", schema_for!(Vec<StageDefinition>)),
+        ("events", schema_for!(Vec<EventCard>)),
+        ("actions", schema_for!(Vec<Action>)),
+        ("decisions", schema_for!(Vec<Decision>)),
+        ("jobs", schema_for!(Vec<Job>)),
+        ("endings", schema_for!(Vec<Ending>)),
+        ("economy", schema_for!(EconomyConfig)),
+        ("education", schema_for!(Vec<EducationProgram>)),
+        ("side_gigs", schema_for!(Vec<SideGig>)),
+        ("insurance", schema_for!(Vec<InsurancePlan>)),
+        ("housing", schema_for!(Vec<HousingTier>)),
+        ("transport", schema_for!(Vec<TransportOption>)),
+        ("paths", schema_for!(Vec<LifePath>)),
+        ("tutorial", schema_for!(Vec<TutorialStep>)),
+        ("scenarios", schema_for!(Vec<Scenario>)),
+        ("stats", schema_for!(Vec<StatDefinition>)),
+        ("special_effects", schema_for!(Vec<SpecialEffect>)),
+        ("rarities", schema_for!(Vec<RarityTier>)),
+        ("milestones", schema_for!(Vec<Milestone>)),
+        ("seeds", schema_for!(Vec<CuratedSeed>)),
+    ];
+    schemas.into_iter().map(|(name, schema)| (name, versioned(name, schema))).collect()
+}
+
+/// Wrap a generated schema in the versioned file layout.
+fn versioned(name: &str, schema: Schema) -> Schema {
+    let mut schema = schema.to_value();
+    let root = schema.as_object_mut().expect("Generated schemas are objects");
+    root.remove("title");
+    let meta: Vec<(String, Value)> = ["$schema", "$defs"].iter()
+        .filter_map(|key| root.remove(*key).map(|v| (key.to_string(), v)))
+        .collect();
+    let version = json!({
+        "description": "Data format version this file was written for.",
+        "const": CURRENT_FORMAT_VERSION,
+    });
+
+    let mut file = if root.get("type") == Some(&json!("array")) {
+        json!({
+            "type": "object",
+            "properties": { FORMAT_VERSION_KEY: version, name: schema },
+            "required": [FORMAT_VERSION_KEY, name],
+        })
+    } else {
+        root.entry("properties").or_insert_with(|| json!({}))[FORMAT_VERSION_KEY] = version;
+        schema
+    };
+    let fields = file.as_object_mut().expect("Versioned schemas are objects");
+    fields.insert("title".to_string(), format!("{}.json", name).into());
+    fields.extend(meta);
+    Schema::try_from(file).expect("Versioned schemas are objects")
+}
+
+/// Write `<name>.schema.json` for every data file into `out_dir`, returning the paths written.
+pub fn write_schemas(out_dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    std::fs::create_dir_all(out_dir)?;
+    let mut written = Vec::new();
+    for (name, schema) in data_schemas() {
+        let path = out_dir.join(format!("{}.schema.json", name));
+        let json = serde_json::to_string_pretty(&schema).map_err(std::io::Error::other)?;
+        std::fs::write(&path, json + "\n")?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_for_every_data_file() {
+        let schemas = data_schemas();
+        let data_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../data");
+        for (name, _) in &schemas {
+            assert!(data_dir.join(format!("{}.json", name)).exists(), "No data file for schema {}", name);
+        }
+
+        let events = schemas.iter().find(|(name, _)| *name == "events").unwrap().1.as_value();
+        assert_eq!(events["properties"]["events"]["type"], "array");
+        assert_eq!(events["properties"]["formatVersion"]["const"], CURRENT_FORMAT_VERSION);
+        let economy = schemas.iter().find(|(name, _)| *name == "economy").unwrap().1.as_value();
+        assert!(economy["properties"]["formatVersion"].is_object());
+        let schema_text = events.to_string();
+        // camelCase field names and doc comments come through from the models
+        assert!(schema_text.contains("\"delayedEffects\""), "{}", schema_text);
+        assert!(schema_text.contains("\"description\""), "{}", schema_text);
+    }
+}
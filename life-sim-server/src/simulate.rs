@@ -0,0 +1,144 @@
+use std::collections::BTreeMap;
+use life_sim_engine::data_loader::GameData;
+use life_sim_engine::engine::bot::{self, Strategy};
+use life_sim_engine::engine::endings;
+use life_sim_engine::engine::game_state::GameState;
+
+/// Spread of one final stat across a set of games.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Distribution {
+    pub min: i32,
+    pub p10: i32,
+    pub median: i32,
+    pub p90: i32,
+    pub max: i32,
+    pub mean: f64,
+}
+
+impl Distribution {
+    fn of(mut values: Vec<i32>) -> Self {
+        values.sort_unstable();
+        let at = |q: f64| values[((values.len() - 1) as f64 * q).round() as usize];
+        Self {
+            min: values[0],
+            p10: at(0.1),
+            median: at(0.5),
+            p90: at(0.9),
+            max: values[values.len() - 1],
+            mean: values.iter().map(|&v| v as f64).sum::<f64>() / values.len() as f64,
+        }
+    }
+}
+
+/// Results for one group of games (all of them, or those that made one choice).
+#[derive(Debug, Clone)]
+pub struct GroupReport {
+    pub label: String,
+    pub games: usize,
+    pub money: Distribution,
+    pub stress: Distribution,
+    pub support: Distribution,
+    /// Ending ID ("none" if no ending matched) → number of games.
+    pub endings: BTreeMap<String, usize>,
+}
+
+impl GroupReport {
+    fn new(label: String, states: &[GameState], data: &GameData) -> Self {
+        let mut endings = BTreeMap::new();
+        for state in states {
+            let ending = endings::resolve(state, &data.endings).map_or("none", |e| e.id.as_str());
+            *endings.entry(ending.to_string()).or_insert(0) += 1;
+        }
+        Self {
+            label,
+            games: states.len(),
+            money: Distribution::of(states.iter().map(|s| s.money).collect()),
+            stress: Distribution::of(states.iter().map(|s| s.stress).collect()),
+            support: Distribution::of(states.iter().map(|s| s.support).collect()),
+            endings,
+        }
+    }
+}
+
+/// Play `games` seeded games (`{seed_prefix}-0`, `{seed_prefix}-1`, ...) and summarize them.
+/// With `split_by`, games are also grouped by the option they chose for that decision.
+pub fn simulate(data: &GameData, strategy: &dyn Strategy, games: usize, seed_prefix: &str, split_by: Option<&str>) -> Vec<GroupReport> {
+    let states: Vec<GameState> = (0..games)
+        .map(|i| bot::play_game(strategy, data, &format!("{}-{}", seed_prefix, i)))
+        .collect();
+    if states.is_empty() {
+        return Vec::new();
+    }
+
+    let mut reports = vec![GroupReport::new("All games".to_string(), &states, data)];
+    if let Some(decision_id) = split_by {
+        let mut groups: BTreeMap<String, Vec<GameState>> = BTreeMap::new();
+        for state in states {
+            let choice = state.decision_log.iter()
+                .find(|d| d.decision_id.as_deref() == Some(decision_id))
+                .map_or_else(|| "(not offered)".to_string(), |d| chosen_label(data, decision_id, &d.description));
+            groups.entry(choice).or_default().push(state);
+        }
+        for (label, group) in groups {
+            reports.push(GroupReport::new(label, &group, data));
+        }
+    }
+    reports
+}
+
+/// The option label from a decision log entry ("<prompt>: <label>").
+fn chosen_label(data: &GameData, decision_id: &str, description: &str) -> String {
+    data.decision(decision_id)
+        .and_then(|d| description.strip_prefix(&format!("{}: ", d.prompt)))
+        .unwrap_or(description)
+        .to_string()
+}
+
+/// Render reports as a plain-text table for the terminal.
+pub fn format_reports(reports: &[GroupReport]) -> String {
+    let mut text = String::new();
+    for report in reports {
+        text.push_str(&format!("\n── {} ({} games) ──\n", report.label, report.games));
+        text.push_str(&format!("  {:<8}{:>8}{:>8}{:>8}{:>8}{:>8}{:>10}\n", "", "min", "p10", "median", "p90", "max", "mean"));
+        for (name, d) in [("Money", &report.money), ("Stress", &report.stress), ("Support", &report.support)] {
+            text.push_str(&format!(
+                "  {:<8}{:>8}{:>8}{:>8}{:>8}{:>8}{:>10.1}\n",
+                name, d.min, d.p10, d.median, d.p90, d.max, d.mean
+            ));
+        }
+        text.push_str("  Endings:\n");
+        let mut endings: Vec<_> = report.endings.iter().collect();
+        endings.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+        for (ending, count) in endings {
+            text.push_str(&format!(
+                "    {:<24}{:>7}  {:>5.1}%\n",
+                ending, count, *count as f64 * 100.0 / report.games as f64
+            ));
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data;
+
+    #[test]
+    fn test_split_groups_games_by_choice() {
+        let data = test_data();
+        let reports = simulate(&data, &bot::RandomStrategy, 40, "SPLIT", Some("dec_club_choice_a"));
+        assert_eq!(reports[0].games, 40);
+        assert!(reports.iter().any(|r| r.label == "Tech Club"));
+        assert_eq!(reports[1..].iter().map(|r| r.games).sum::<usize>(), 40);
+        assert_eq!(reports[0].endings.values().sum::<usize>(), 40);
+        assert!(format_reports(&reports).contains("── Tech Club ("));
+    }
+
+    #[test]
+    fn test_distribution_percentiles() {
+        let d = Distribution::of((1..=11).rev().collect());
+        assert_eq!((d.min, d.p10, d.median, d.p90, d.max), (1, 2, 6, 10, 11));
+        assert_eq!(d.mean, 6.0);
+    }
+}
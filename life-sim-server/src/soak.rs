@@ -0,0 +1,181 @@
+use std::collections::HashSet;
+use std::panic::{self, AssertUnwindSafe};
+use life_sim_engine::data_loader::GameData;
+use life_sim_engine::engine::bot::{self, Strategy};
+use life_sim_engine::engine::endings;
+use life_sim_engine::engine::game_state::{EventAnswer, GameCommand, GameState};
+use life_sim_engine::engine::hiring;
+use life_sim_engine::engine::rng;
+use life_sim_engine::engine::turn_runner::{self, PlayerChoices};
+
+/// A broken engine invariant, with what's needed to reproduce it.
+#[derive(Debug, Clone)]
+pub struct Violation {
+    pub seed: String,
+    /// The turn being played when it broke.
+    pub turn: u32,
+    pub message: String,
+    /// Every command played up to and including the turn that broke.
+    pub trace: Vec<GameCommand>,
+}
+
+/// Results of a soak run.
+#[derive(Debug, Clone)]
+pub struct SoakReport {
+    pub games: usize,
+    pub turns: usize,
+    pub violations: Vec<Violation>,
+}
+
+/// Play `games` seeded games (`{seed_prefix}-0`, `{seed_prefix}-1`, ...) with a strategy,
+/// checking the engine's invariants after every turn. A game stops at its first violation.
+pub fn soak(data: &GameData, strategy: &dyn Strategy, games: usize, seed_prefix: &str) -> SoakReport {
+    let mut report = SoakReport { games, turns: 0, violations: Vec::new() };
+    for i in 0..games {
+        let seed = format!("{}-{}", seed_prefix, i);
+        let (turns, violation) = soak_game(data, strategy, &seed);
+        report.turns += turns;
+        report.violations.extend(violation);
+    }
+    report
+}
+
+/// Play one game, returning the turns played and the first violation, if any.
+fn soak_game(data: &GameData, strategy: &dyn Strategy, seed: &str) -> (usize, Option<Violation>) {
+    let mut state = turn_runner::start_game(seed.to_string(), data);
+    let mut game_rng = state.seed_rng();
+    let mut bot_rng = rng::create_rng(&format!("{}/bot", seed));
+    let last_turn = data.stages.last().map_or(0, |s| s.end_turn);
+    let mut turns = 0;
+
+    while !turn_runner::is_game_over(&state, &data.stages) {
+        let turn = state.current_turn;
+        let violation = |state: &GameState, message: String, attempted: Option<&(PlayerChoices, Vec<String>)>| {
+            let mut trace = state.commands.clone();
+            // A turn that panicked never got as far as recording itself
+            if let Some((choices, event_ids)) = attempted {
+                trace.push(GameCommand::Turn {
+                    turn,
+                    action_ids: choices.action_ids.clone(),
+                    decision_id: choices.decision_id.clone(),
+                    decision_option_index: choices.decision_option_index,
+                    defer_decision: choices.defer_decision,
+                    event_id: event_ids.first().cloned(),
+                    event_option_index: choices.event_option_index,
+                    extra_events: event_ids.iter().enumerate().skip(1)
+                        .map(|(i, id)| EventAnswer { event_id: id.clone(), option_index: choices.event_answer(i) })
+                        .collect(),
+                });
+            }
+            Some(Violation { seed: seed.to_string(), turn, message, trace })
+        };
+        if turns > last_turn as usize {
+            return (turns, violation(&state, format!("Still not over after {} turns", turns), None));
+        }
+
+        let before = state.clone();
+        let mut attempted = None;
+        let played = panic::catch_unwind(AssertUnwindSafe(|| {
+            let events = turn_runner::draw_turn_events(&state, data, &mut game_rng);
+            let choices = bot::choose_turn(strategy, &state, data, &events, &mut bot_rng);
+            attempted = Some((choices.clone(), events.iter().map(|e| e.id.clone()).collect()));
+            turn_runner::run_turn_with_events(&mut state, &choices, data, &mut game_rng, events)
+        }));
+        turns += 1;
+        if let Err(payload) = played {
+            let message = payload.downcast_ref::<&str>().map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            return (turns, violation(&before, format!("Panicked: {}", message), attempted.as_ref()));
+        }
+        if let Err(message) = check_turn(&before, &state, data) {
+            return (turns, violation(&state, message, None));
+        }
+    }
+
+    if endings::resolve(&state, &data.endings).is_none() {
+        let turn = state.current_turn;
+        return (turns, Some(Violation { seed: seed.to_string(), turn, message: "No ending matched".to_string(), trace: state.commands }));
+    }
+    (turns, None)
+}
+
+/// The invariants that must hold after every turn, given the state before it.
+fn check_turn(before: &GameState, after: &GameState, data: &GameData) -> Result<(), String> {
+    for stat in &data.stats {
+        let value = after.stat(&stat.id);
+        if stat.clamp(value) != value {
+            let bound = |b: Option<i32>| b.map_or(String::new(), |b| b.to_string());
+            return Err(format!("{} {} is outside {}–{}", stat.label, value, bound(stat.min), bound(stat.max)));
+        }
+    }
+    if after.current_turn != before.current_turn + 1 {
+        return Err(format!("Turn went from {} to {}", before.current_turn, after.current_turn));
+    }
+    if after.stats_history.len() != before.stats_history.len() + 1 {
+        return Err("The turn wasn't recorded in the stats history".to_string());
+    }
+    let mut seen = HashSet::new();
+    if let Some(id) = after.used_event_ids.iter().find(|id| !seen.insert(*id)) {
+        return Err(format!("{} is listed as used twice", id));
+    }
+
+    // Interviews and breakdowns can come up again; deck cards can't
+    let drawn = after.stats_history.last().into_iter()
+        .flat_map(|s| s.event_id.iter().chain(s.extra_events.iter().map(|e| &e.event_id)));
+    for id in drawn.filter(|id| before.used_event_ids.contains(id)) {
+        let interview = hiring::due_interview(before, &data.jobs).is_some_and(|card| card.id == *id);
+        let breakdown = before.transport.as_ref().is_some_and(|t| t.breakdown_event.as_ref() == Some(id));
+        if !interview && !breakdown {
+            return Err(format!("{} was drawn again", id));
+        }
+    }
+    Ok(())
+}
+
+/// Render a report for the terminal: a summary line, then each violation with its
+/// trace as one JSON line per command.
+pub fn format_report(report: &SoakReport) -> String {
+    let mut text = format!(
+        "\nPlayed {} game(s), {} turn(s): {} violation(s)\n",
+        report.games, report.turns, report.violations.len()
+    );
+    for violation in &report.violations {
+        text.push_str(&format!("\n✗ seed {} · turn {}: {}\n", violation.seed, violation.turn, violation.message));
+        for command in &violation.trace {
+            text.push_str(&format!("  {}\n", serde_json::to_string(command).unwrap_or_default()));
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data;
+
+    #[test]
+    fn test_random_games_keep_every_invariant() {
+        let data = test_data();
+        let report = soak(&data, &bot::RandomStrategy, 50, "SOAK");
+        assert_eq!(report.games, 50);
+        assert_eq!(report.turns, 50 * data.stages.last().unwrap().end_turn as usize);
+        assert!(report.violations.is_empty(), "{}", format_report(&report));
+    }
+
+    #[test]
+    fn test_broken_invariants_are_reported() {
+        let data = test_data();
+        let before = turn_runner::start_game("SOAK".to_string(), &data);
+        let mut after = before.clone();
+        after.current_turn += 1;
+        after.stats_history.push(bot::play_game(&bot::FirstOption, &data, "SOAK").stats_history[0].clone());
+        assert_eq!(check_turn(&before, &after, &data), Ok(()));
+
+        after.stress = 101;
+        assert_eq!(check_turn(&before, &after, &data), Err("Stress 101 is outside 0–100".to_string()));
+        after.stress = 50;
+        after.used_event_ids = vec!["evt_a".to_string(), "evt_a".to_string()];
+        assert_eq!(check_turn(&before, &after, &data), Err("evt_a is listed as used twice".to_string()));
+    }
+}
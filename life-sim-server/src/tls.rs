@@ -0,0 +1,44 @@
+use std::path::Path;
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+
+/// The TLS setup for serving HTTPS from a PEM certificate chain and private key, such as
+/// the `fullchain.pem` and `privkey.pem` a certificate authority issues.
+pub fn server_config(cert_file: &Path, key_file: &Path) -> Result<rustls::ServerConfig, String> {
+    let certs = CertificateDer::pem_file_iter(cert_file)
+        .and_then(|certs| certs.collect::<Result<Vec<_>, _>>())
+        .map_err(|e| format!("Failed to read TLS certificate {}: {}", cert_file.display(), e))?;
+    if certs.is_empty() {
+        return Err(format!("No certificates found in {}", cert_file.display()));
+    }
+    let key = PrivateKeyDer::from_pem_file(key_file)
+        .map_err(|e| format!("Failed to read TLS private key {}: {}", key_file.display(), e))?;
+    rustls::ServerConfig::builder()
+        .with_no_client_auth()
+        .with_single_cert(certs, key)
+        .map_err(|e| format!("The TLS certificate and key don't work together: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn write_pem(name: &str, text: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("life-sim-tls-{}-{}.pem", name, std::process::id()));
+        std::fs::write(&path, text).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_unusable_files_are_reported() {
+        let missing = Path::new("no-such-cert.pem");
+        let err = server_config(missing, missing).unwrap_err();
+        assert!(err.contains("no-such-cert.pem"), "{}", err);
+
+        let empty = write_pem("empty", "not a certificate\n");
+        let err = server_config(&empty, &empty).unwrap_err();
+        std::fs::remove_file(&empty).unwrap();
+        assert!(err.starts_with("No certificates found"), "{}", err);
+    }
+}
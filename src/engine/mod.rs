@@ -1,5 +0,0 @@
-pub mod game_state;
-pub mod rng;
-pub mod stat_calculator;
-pub mod event_deck;
-pub mod turn_runner;
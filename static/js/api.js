@@ -1,32 +1,93 @@
 // api.js — Fetch wrappers for REST API calls
 
+const API_BASE = '/api/v1';
+
 const API = {
-    async newGame(seed = null) {
-        const res = await fetch('/api/new_game', {
+    async newGame(seed = null, tutorial = false, scenario = null) {
+        const body = seed ? { seed } : {};
+        if (tutorial) body.tutorial = true;
+        if (scenario) body.scenario = scenario;
+        const res = await fetch(`${API_BASE}/new_game`, {
             method: 'POST',
             headers: { 'Content-Type': 'application/json' },
-            body: JSON.stringify(seed ? { seed } : {}),
+            body: JSON.stringify(body),
         });
         return res.json();
     },
 
+    // ─── Turn Stream ────────────────────────────────────
+    turnStream: null,
+
+    /** Listen on /ws for turn updates as they happen; reconnects if the socket drops. */
+    openTurnStream(onUpdate) {
+        const scheme = location.protocol === 'https:' ? 'wss' : 'ws';
+        const socket = new WebSocket(`${scheme}://${location.host}/ws`);
+        socket.onmessage = (msg) => onUpdate(JSON.parse(msg.data));
+        socket.onclose = () => setTimeout(() => API.openTurnStream(onUpdate), 3000);
+        this.turnStream = socket;
+    },
+
+    turnStreamOpen() {
+        return this.turnStream?.readyState === WebSocket.OPEN;
+    },
+
+    async getDaily() {
+        const res = await fetch(`${API_BASE}/daily`);
+        return res.json();
+    },
+
+    async startDaily() {
+        const res = await fetch(`${API_BASE}/daily`, { method: 'POST' });
+        return res.json();
+    },
+
+    async submitRun(name) {
+        const res = await fetch(`${API_BASE}/leaderboard`, {
+            method: 'POST',
+            headers: { 'Content-Type': 'application/json' },
+            body: JSON.stringify({ name }),
+        });
+        return res.json();
+    },
+
+    async getLeaderboard(filters = {}) {
+        const query = new URLSearchParams(filters).toString();
+        const res = await fetch(`${API_BASE}/leaderboard${query ? `?${query}` : ''}`);
+        return res.json();
+    },
+
+    async getGallery() {
+        const res = await fetch(`${API_BASE}/gallery`);
+        return res.json();
+    },
+
     async getState() {
-        const res = await fetch('/api/state');
+        const res = await fetch(`${API_BASE}/state`);
         return res.json();
     },
 
     async getPhaseData() {
-        const res = await fetch('/api/phase_data');
+        const res = await fetch(`${API_BASE}/phase_data`);
         return res.json();
     },
 
     async drawEvent() {
-        const res = await fetch('/api/draw_event');
+        const res = await fetch(`${API_BASE}/draw_event`);
         return res.json();
     },
 
     async submitTurn(choices) {
-        const res = await fetch('/api/submit_turn', {
+        const res = await fetch(`${API_BASE}/submit_turn`, {
+            method: 'POST',
+            headers: { 'Content-Type': 'application/json' },
+            body: JSON.stringify(choices),
+        });
+        return res.json();
+    },
+
+    // Preview a turn's outcome without committing it
+    async simulateTurn(choices) {
+        const res = await fetch(`${API_BASE}/simulate_turn`, {
             method: 'POST',
             headers: { 'Content-Type': 'application/json' },
             body: JSON.stringify(choices),
@@ -34,34 +95,163 @@ const API = {
         return res.json();
     },
 
+    // filters: { stage, rarity, used } — all optional; `used` needs a game in progress
+    async getEvents(filters = {}) {
+        const params = new URLSearchParams(
+            Object.entries(filters).filter(([, v]) => v !== undefined && v !== null)
+        );
+        const res = await fetch(`${API_BASE}/events?${params}`);
+        return res.json();
+    },
+
+    async getAllEndings() {
+        const res = await fetch(`${API_BASE}/endings/all`);
+        return res.json();
+    },
+
+    async previewDecision(decisionId) {
+        const res = await fetch(`${API_BASE}/decisions/${encodeURIComponent(decisionId)}/preview`);
+        return res.json();
+    },
+
+    async addJournalEntry(text) {
+        const res = await fetch(`${API_BASE}/journal`, {
+            method: 'POST',
+            headers: { 'Content-Type': 'application/json' },
+            body: JSON.stringify({ text }),
+        });
+        return res.json();
+    },
+
+    async answerQuiz(sourceId, choice) {
+        const res = await fetch(`${API_BASE}/quiz`, {
+            method: 'POST',
+            headers: { 'Content-Type': 'application/json' },
+            body: JSON.stringify({ sourceId, choice }),
+        });
+        return res.json();
+    },
+
+    async getScenarios() {
+        const res = await fetch(`${API_BASE}/scenarios`);
+        return res.json();
+    },
+
+    reportUrl(format = 'csv') {
+        return `${API_BASE}/export/report?format=${format}`;
+    },
+
+    async getStatsHistory() {
+        const res = await fetch(`${API_BASE}/stats_history`);
+        return res.json();
+    },
+
+    async getStageReports() {
+        const res = await fetch(`${API_BASE}/stage_reports`);
+        return res.json();
+    },
+
     async getEnding() {
-        const res = await fetch('/api/endings');
+        const res = await fetch(`${API_BASE}/endings`);
         return res.json();
     },
 
     async getTimeline() {
-        const res = await fetch('/api/timeline');
+        const res = await fetch(`${API_BASE}/timeline`);
+        return res.json();
+    },
+
+    async getSummary() {
+        const res = await fetch(`${API_BASE}/summary`);
         return res.json();
     },
 
     async health() {
-        const res = await fetch('/api/health');
+        const res = await fetch(`${API_BASE}/health`);
         return res.json();
     },
 
     // ─── Debug Endpoints ────────────────────────────────
+    async getStages() {
+        const res = await fetch(`${API_BASE}/stages`);
+        return res.json();
+    },
+
     async getJobs() {
-        const res = await fetch('/api/jobs');
+        const res = await fetch(`${API_BASE}/jobs`);
+        return res.json();
+    },
+
+    async applyJob(jobId) {
+        const res = await fetch(`${API_BASE}/jobs/${encodeURIComponent(jobId)}/apply`, { method: 'POST' });
+        return res.json();
+    },
+
+    async getHousing() {
+        const res = await fetch(`${API_BASE}/housing`);
+        return res.json();
+    },
+
+    async moveHousing(housingId) {
+        const res = await fetch(`${API_BASE}/housing/move`, {
+            method: 'POST',
+            headers: { 'Content-Type': 'application/json' },
+            body: JSON.stringify({ housingId }),
+        });
+        return res.json();
+    },
+
+    async getTransport() {
+        const res = await fetch(`${API_BASE}/transport`);
+        return res.json();
+    },
+
+    async acquireTransport(transportId) {
+        const res = await fetch(`${API_BASE}/transport/${encodeURIComponent(transportId)}/acquire`, { method: 'POST' });
+        return res.json();
+    },
+
+    async getInsurance() {
+        const res = await fetch(`${API_BASE}/insurance`);
+        return res.json();
+    },
+
+    async buyInsurance(planId) {
+        const res = await fetch(`${API_BASE}/insurance/${encodeURIComponent(planId)}/buy`, { method: 'POST' });
+        return res.json();
+    },
+
+    async cancelInsurance(planId) {
+        const res = await fetch(`${API_BASE}/insurance/${encodeURIComponent(planId)}/cancel`, { method: 'POST' });
+        return res.json();
+    },
+
+    async startSideGig(gigId) {
+        const res = await fetch(`${API_BASE}/side_gigs/${encodeURIComponent(gigId)}/start`, { method: 'POST' });
+        return res.json();
+    },
+
+    async dropSideGig(gigId) {
+        const res = await fetch(`${API_BASE}/side_gigs/${encodeURIComponent(gigId)}/drop`, { method: 'POST' });
+        return res.json();
+    },
+
+    async changeJob(jobId) {
+        const res = await fetch(`${API_BASE}/jobs/change`, {
+            method: 'POST',
+            headers: { 'Content-Type': 'application/json' },
+            body: JSON.stringify({ jobId }),
+        });
         return res.json();
     },
 
     async debugSkipStage() {
-        const res = await fetch('/api/debug/skip_stage', { method: 'POST' });
+        const res = await fetch(`${API_BASE}/debug/skip_stage`, { method: 'POST' });
         return res.json();
     },
 
     async debugSetStats(stats) {
-        const res = await fetch('/api/debug/set_stats', {
+        const res = await fetch(`${API_BASE}/debug/set_stats`, {
             method: 'POST',
             headers: { 'Content-Type': 'application/json' },
             body: JSON.stringify(stats),
@@ -70,7 +260,7 @@ const API = {
     },
 
     async debugGrantTag(tag) {
-        const res = await fetch('/api/debug/grant_tag', {
+        const res = await fetch(`${API_BASE}/debug/grant_tag`, {
             method: 'POST',
             headers: { 'Content-Type': 'application/json' },
             body: JSON.stringify({ tag }),
@@ -5,40 +5,70 @@ const Game = {
     phaseData: null,
     selectedActions: [],
     selectedDecisionIndex: null,
+    deferDecision: false,
     selectedEventOption: null,
+    drawnEvents: [], // this turn's cards, in draw order
+    eventAnswers: [], // the option picked on each card answered so far
     turnLog: [],
     prevState: null,
     currentState: null,
     pendingTransition: null, // { oldStage, newStage }
+    liveUpdates: Promise.resolve(), // streamed turn updates still being revealed
+    liveTurnDone: null, // resolves when the stream reports the turn ended
+    endLiveTurn: null,
 
     // ─── Boot ───────────────────────────────────────────
-    init() {
+    async init() {
         document.getElementById('btn-new-game').addEventListener('click', () => Game.startNewGame());
+        document.getElementById('btn-daily').addEventListener('click', () => Game.startDaily());
+        document.getElementById('btn-tutorial').addEventListener('click', () => Game.startTutorial());
+        const stages = await API.getStages();
+        stages.forEach(s => { Components.stageNames[s.id] = s.name; });
+        const scenarios = await API.getScenarios();
+        const scenarioSelect = document.getElementById('scenario-select');
+        scenarios.forEach(s => scenarioSelect.add(new Option(s.title, s.id)));
+        API.openTurnStream(update => Game.onTurnUpdate(update));
     },
 
     async startNewGame(seedOverride = null) {
         const seedInput = document.getElementById('seed-input');
         const seed = seedOverride || (seedInput ? seedInput.value.trim() : null) || null;
+        const scenario = document.getElementById('scenario-select')?.value || null;
 
-        const result = await API.newGame(seed);
-        if (result.state) {
-            this.currentState = result.state;
-            this.prevState = null;
-            this.turnLog = [];
-            this.pendingTransition = null;
-            Components.updateStats(result.state, null);
-            this.renderTurnLog();
-            this.phase = 'plan';
-            this.resetSelections();
-            await this.loadPhase();
-            Components.showToast(`Game started! Seed: ${result.state.seed}`, 'info');
-        }
+        const result = await API.newGame(seed, false, scenario);
+        await this.beginGame(result, `Game started! Seed: ${result.state?.seed}`);
+    },
+
+    async startTutorial() {
+        const result = await API.newGame(null, true);
+        await this.beginGame(result, '🧭 Tutorial started! Tips appear after each of the first turns.');
+    },
+
+    async startDaily() {
+        const result = await API.startDaily();
+        await this.beginGame(result, `📅 ${result.message}`);
+    },
+
+    async beginGame(result, message) {
+        if (!result.state) return;
+        this.currentState = result.state;
+        this.prevState = null;
+        this.turnLog = [];
+        this.pendingTransition = null;
+        Components.updateStats(result.state, null);
+        this.renderTurnLog();
+        this.phase = 'plan';
+        this.resetSelections();
+        await this.loadPhase();
+        Components.showToast(message, 'info');
     },
 
     resetSelections() {
         this.selectedActions = [];
         this.selectedDecisionIndex = null;
         this.selectedEventOption = null;
+        this.drawnEvents = [];
+        this.eventAnswers = [];
     },
 
     // ─── Phase Flow ─────────────────────────────────────
@@ -52,6 +82,7 @@ const Game = {
         }
 
         Components.updateStageInfo(this.phaseData.currentStage, this.phaseData.currentTurn);
+        this.renderMilestones();
 
         switch (this.phase) {
             case 'plan': this.renderPlanPhase(); break;
@@ -66,7 +97,7 @@ const Game = {
         const actions = this.phaseData.actions || [];
         this.selectedActions = [];
 
-        const timeSlots = this.currentState?.timeSlots || 3;
+        const timeSlots = this.phaseData.availableTimeSlots ?? this.currentState?.timeSlots ?? 3;
 
         let html = `
             <div class="phase-card">
@@ -101,7 +132,7 @@ const Game = {
     },
 
     toggleAction(el, id) {
-        const timeSlots = this.currentState?.timeSlots || 3;
+        const timeSlots = this.phaseData.availableTimeSlots ?? this.currentState?.timeSlots ?? 3;
         const action = this.phaseData.actions.find(a => a.id === id);
         const cost = action?.timeCost || 1;
 
@@ -142,6 +173,7 @@ const Game = {
         const content = document.getElementById('phase-content');
         const decision = this.phaseData.decision;
         this.selectedDecisionIndex = null;
+        this.deferDecision = false;
 
         if (!decision) {
             this.phase = 'event';
@@ -149,7 +181,12 @@ const Game = {
             return;
         }
 
-        const playerCreds = this.currentState?.credentials || [];
+        const playerCreds = (this.currentState?.credentials || []).map(c => c.tag);
+        const deferral = decision.deferConsequence;
+        const deferHtml = deferral ? Components.deferNote(deferral) : '';
+        const deferButton = deferral
+            ? '<button class="btn btn-secondary" onclick="Game.putOffDecision()">⏸️ Put It Off</button>'
+            : '';
 
         let html = `
             <div class="phase-card">
@@ -159,8 +196,10 @@ const Game = {
                 <div class="decision-grid" id="decision-grid">
                     ${decision.options.map((opt, i) => Components.decisionCard(opt, i, false, playerCreds)).join('')}
                 </div>
+                ${deferHtml}
                 <div class="phase-nav">
                     <button class="btn btn-secondary" onclick="Game.backToPlan()">← Back to Plan</button>
+                    ${deferButton}
                     <button class="btn btn-primary" id="btn-commit-next" onclick="Game.finishCommit()" disabled>
                         Draw Life Event →
                     </button>
@@ -168,6 +207,18 @@ const Game = {
             </div>
         `;
         content.innerHTML = html;
+        this.showDecisionPreviews(decision.id);
+    },
+
+    async showDecisionPreviews(decisionId) {
+        const result = await API.previewDecision(decisionId);
+        if (!result.options) return;
+        result.options.forEach(preview => {
+            const card = document.querySelector(`.decision-card[data-index="${preview.index}"]`);
+            if (card && !preview.locked) {
+                card.insertAdjacentHTML('beforeend', Components.decisionPreview(preview));
+            }
+        });
     },
 
     selectDecision(el, index) {
@@ -177,6 +228,12 @@ const Game = {
         document.getElementById('btn-commit-next').disabled = false;
     },
 
+    putOffDecision() {
+        this.deferDecision = true;
+        this.selectedDecisionIndex = null;
+        this.finishCommit();
+    },
+
     backToPlan() {
         this.phase = 'plan';
         this.renderPlanPhase();
@@ -214,25 +271,36 @@ const Game = {
     async revealEvent() {
         // Draw event from the server (preview, no state change yet)
         const drawResult = await API.drawEvent();
-        const event = drawResult.event;
+        this.drawnEvents = drawResult.events || (drawResult.event ? [drawResult.event] : []);
+        this.eventAnswers = [];
         this.playerSupport = drawResult.playerSupport ?? (this.currentState?.support || 0);
+        await this.nextEventCard();
+    },
 
-        if (event && event.options && event.options.length > 0) {
-            this.drawnEvent = event;
-            this.renderEventOptions(event);
-        } else {
-            // No event available — submit turn immediately
-            await this.submitFinalTurn(0);
+    /** Show the next card that needs an answer, or submit the turn once every card has one. */
+    async nextEventCard() {
+        while (this.eventAnswers.length < this.drawnEvents.length) {
+            const event = this.drawnEvents[this.eventAnswers.length];
+            if (event.options && event.options.length > 0) {
+                this.renderEventOptions(event);
+                return;
+            }
+            this.eventAnswers.push(null);
         }
+        // No event left to answer (or none available) — submit the turn
+        await this.submitFinalTurn(this.eventAnswers);
     },
 
     renderEventOptions(event) {
         const content = document.getElementById('phase-content');
         this.selectedEventOption = null;
+        const cardNumber = this.eventAnswers.length + 1;
+        const cardCount = this.drawnEvents.length;
+        const counter = cardCount > 1 ? ` (card ${cardNumber} of ${cardCount})` : '';
 
         let html = `
             <div class="phase-card event-reveal-card">
-                <div class="phase-label">Phase 3 of 4 — Life Event</div>
+                <div class="phase-label">Phase 3 of 4 — Life Event${counter}</div>
                 <div class="event-card-display">
                     <div class="event-rarity rarity-${event.rarity || 'common'}">${(event.rarity || 'common').toUpperCase()}</div>
                     <h2>🃏 ${event.title}</h2>
@@ -244,7 +312,7 @@ const Game = {
                 </div>
                 <div class="phase-nav">
                     <button class="btn btn-primary" id="btn-event-submit" onclick="Game.submitEventChoice()" disabled>
-                        See Results →
+                        ${cardNumber < cardCount ? 'Next Card →' : 'See Results →'}
                     </button>
                 </div>
             </div>
@@ -260,23 +328,49 @@ const Game = {
     },
 
     async submitEventChoice() {
-        await this.submitFinalTurn(this.selectedEventOption ?? 0);
+        this.eventAnswers.push(this.selectedEventOption ?? 0);
+        await this.nextEventCard();
     },
 
-    async submitFinalTurn(eventOptionIdx) {
+    async submitFinalTurn(eventAnswers) {
         const choices = {
             actionIds: this.selectedActions,
             decisionId: this.phaseData.decision ? this.phaseData.decision.id : '',
             decisionOptionIndex: this.selectedDecisionIndex ?? 0,
-            eventOptionIndex: eventOptionIdx,
+            deferDecision: this.deferDecision,
+            eventOptionIndices: eventAnswers,
+            eventId: eventAnswers[0] == null ? null : this.drawnEvents[0]?.id,
         };
 
+        // With the turn stream open, results are revealed one by one as the server resolves them
+        const streaming = API.turnStreamOpen();
+        if (streaming) {
+            document.getElementById('phase-content').innerHTML = Components.liveTurnCard();
+            this.liveTurnDone = new Promise(resolve => { this.endLiveTurn = resolve; });
+        }
+
         const result = await API.submitTurn(choices);
 
+        if (streaming && !result.error) {
+            // Don't wait forever if the socket drops mid-turn
+            await Promise.race([this.liveTurnDone, new Promise(r => setTimeout(r, 3000))]);
+            await this.liveUpdates;
+        }
+
+        if (result.error) {
+            if (result.error.code === 'game_over') {
+                this.phase = 'gameover';
+                this.renderGameOver();
+            } else {
+                Components.showToast(result.error.message, 'warning');
+            }
+            return;
+        }
+
         if (result.state) {
             this.prevState = this.currentState;
             this.currentState = result.state;
-            Components.updateStats(result.state, this.prevState);
+            Components.updateStats(result.state, this.prevState, result.turnResult?.statChanges);
         }
 
         if (result.isGameOver) {
@@ -297,12 +391,31 @@ const Game = {
         this.renderFeedback(result);
     },
 
+    /** Reveal a streamed turn update on the live results card, one every 250ms. */
+    onTurnUpdate(update) {
+        if (update.type === 'turnEnded') {
+            this.liveUpdates = this.liveUpdates.then(() => this.endLiveTurn?.());
+            return;
+        }
+        this.liveUpdates = this.liveUpdates.then(() => {
+            const list = document.getElementById('live-feedback');
+            if (!list) return;
+            if (update.type === 'turnStarted') {
+                document.getElementById('live-turn-title').textContent = `⏳ Turn ${update.turn}`;
+                return;
+            }
+            list.insertAdjacentHTML('beforeend',
+                `<div class="feedback-item-wrapper">${Components.liveTurnUpdate(update)}</div>`);
+            return new Promise(r => setTimeout(r, 250));
+        });
+    },
+
     // ─── Phase 4: Feedback ──────────────────────────────
     renderFeedback(result) {
         const content = document.getElementById('phase-content');
         const turnResult = result.turnResult || {};
         const feedback = turnResult.feedback || [];
-        const event = turnResult.eventDrawn;
+        const events = turnResult.eventsDrawn || [];
         const state = result.state;
 
         // Log this turn
@@ -318,17 +431,18 @@ const Game = {
         html += `<div class="phase-label">Phase 4 of 4 — Results</div>`;
         html += `<h2>📊 Turn ${turnNum} Complete</h2>`;
 
-        // Show event card summary if any
-        if (event) {
-            const chosenIdx = this.selectedEventOption ?? 0;
-            const chosenLabel = event.options[chosenIdx]?.label || 'N/A';
+        // Show a summary of each event card, if any
+        events.forEach((event, i) => {
+            const chosenLabel = event.options[this.eventAnswers[i] ?? 0]?.label || 'N/A';
             html += `
                 <div class="event-summary">
                     <span class="event-summary-title">🃏 ${event.title}</span>
                     <span class="event-summary-choice">→ ${chosenLabel}</span>
                 </div>
             `;
-        }
+        });
+
+        html += Components.statChanges(turnResult.statChanges);
 
         // Feedback list with staggered animation
         if (feedback.length > 0) {
@@ -343,11 +457,7 @@ const Game = {
 
         // Stage transition banner (if transitioning)
         if (turnResult.stageTransitioned) {
-            const stageNames = {
-                'middle-school': 'Middle School', 'high-school': 'High School',
-                'post-high': 'Post-High', 'early-adult': 'Early Adult',
-            };
-            const newStage = stageNames[state?.currentStage] || state?.currentStage;
+            const newStage = Components.stageName(state?.currentStage);
             html += `
                 <div class="stage-transition-banner">
                     🎓 Stage Complete! Advancing to <strong>${newStage}</strong>
@@ -360,6 +470,18 @@ const Game = {
             html += `<div class="turn-warning">⚠️ ${turnResult.stressWarning}</div>`;
         }
 
+        // Quiz questions on this turn's decision and card
+        const quizzes = [this.phaseData?.decision, ...events].filter(source => source?.quiz);
+        quizzes.forEach(source => { html += Components.quizCard(source.id, source.quiz); });
+
+        // Reflection on this turn's choices, kept in the journal for class discussion
+        html += `
+            <div class="seed-input-group">
+                <input type="text" id="journal-text" placeholder="Why did you choose that? (optional)" maxlength="2000">
+                <button class="btn btn-secondary" onclick="Game.saveReflection()">📝 Save Reflection</button>
+            </div>
+        `;
+
         html += `
             <div class="phase-nav">
                 <button class="btn btn-primary" onclick="Game.nextTurn()">
@@ -386,9 +508,11 @@ const Game = {
     },
 
     // ─── Stage Transition Screen ────────────────────────
-    renderStageTransition(oldStage, newStage) {
+    async renderStageTransition(oldStage, newStage) {
         const content = document.getElementById('phase-content');
-        content.innerHTML = Components.stageTransitionScreen(oldStage, newStage, this.currentState);
+        const { reports = [] } = await API.getStageReports();
+        const report = reports.find(r => r.stage === oldStage);
+        content.innerHTML = Components.stageTransitionScreen(oldStage, newStage, this.currentState, report);
     },
 
     continueAfterTransition() {
@@ -400,9 +524,13 @@ const Game = {
     // ─── Game Over ──────────────────────────────────────
     async renderGameOver() {
         const content = document.getElementById('phase-content');
-        const [endingData, timelineData] = await Promise.all([
+        const [endingData, timelineData, historyData, allEndingsData, summary, gallery] = await Promise.all([
             API.getEnding(),
             API.getTimeline(),
+            API.getStatsHistory(),
+            API.getAllEndings(),
+            API.getSummary(),
+            API.getGallery(),
         ]);
         const ending = endingData.ending;
         const state = endingData.state;
@@ -421,7 +549,10 @@ const Game = {
 
             html += `
                 <div class="ending-badge">${ending.title}</div>
-                <p class="ending-narrative">${ending.narrative}</p>
+                ${Components.badgeList(endingData.badges)}
+                ${endingData.epilogue
+                    ? Components.epilogue(endingData.epilogue)
+                    : `<p class="ending-narrative">${ending.narrative}</p>`}
                 <div class="ending-reflection">
                     <div class="reflection-header">💭 Reflection</div>
                     <ul class="reflection-prompts">${reflectionHtml}</ul>
@@ -429,6 +560,15 @@ const Game = {
             `;
         } else {
             html += `<p class="ending-narrative">Your journey has ended. Every choice led you here.</p>`;
+            if (endingData.epilogue) {
+                html += Components.epilogue(endingData.epilogue);
+            }
+        }
+
+        html += Components.nearMisses(endingData.nearMisses);
+
+        if (endingData.score) {
+            html += Components.scoreCard(endingData.score);
         }
 
         html += `
@@ -453,10 +593,18 @@ const Game = {
                         </div>
                     </div>
                     ${state.credentials.length > 0 ?
-                `<div class="final-creds">${state.credentials.map(c => `<span class="cred-tag">${c}</span>`).join(' ')}</div>`
+                `<div class="final-creds">${state.credentials.map(c => `<span class="cred-tag">${Components.credentialName(c)}</span>`).join(' ')}</div>`
                 : ''}
                 </div>
 
+                ${Components.statsChart(historyData.history)}
+
+                ${Components.runSummary(summary)}
+
+                ${Components.endingsList(allEndingsData.endings)}
+
+                ${Components.endingsGallery(gallery)}
+
                 ${Components.timelineView(timeline)}
 
                 <div class="gameover-actions">
@@ -469,6 +617,13 @@ const Game = {
                     <button class="btn btn-outline" onclick="Game.shareSeed('${seed}')">
                         🔗 Share Seed
                     </button>
+                    <a class="btn btn-outline" href="${API.reportUrl()}" download>
+                        📄 Download Report (CSV)
+                    </a>
+                </div>
+                <div class="seed-input-group">
+                    <input type="text" id="leaderboard-name" placeholder="Your name" maxlength="32">
+                    <button class="btn btn-secondary" onclick="Game.submitToLeaderboard()">🏆 Submit Score</button>
                 </div>
                 <div class="seed-display">Seed: <code>${seed}</code></div>
             </div>
@@ -494,13 +649,22 @@ const Game = {
         logEl.scrollTop = logEl.scrollHeight;
     },
 
+    async renderMilestones() {
+        const listEl = document.getElementById('milestones');
+        if (!listEl) return;
+        const { milestones = [] } = await API.getState();
+        listEl.innerHTML = milestones.map(m => Components.milestone(m)).join('');
+    },
+
     // ─── Job Board ──────────────────────────────────────
     async showJobBoard() {
         const data = await API.getJobs();
         if (data.error) {
-            Components.showToast(data.error, 'warning');
+            Components.showToast(data.error.message, 'warning');
             return;
         }
+        const insurance = await API.getInsurance();
+        data.insurancePlans = insurance.plans || [];
         const modal = Components.jobBoardModal(data);
         const container = document.createElement('div');
         container.id = 'job-board-container';
@@ -508,11 +672,154 @@ const Game = {
         document.body.appendChild(container);
     },
 
+    async showHousing() {
+        const data = await API.getHousing();
+        if (data.error) {
+            Components.showToast(data.error.message, 'warning');
+            return;
+        }
+        const container = document.createElement('div');
+        container.id = 'job-board-container';
+        container.innerHTML = Components.housingModal(data);
+        document.body.appendChild(container);
+    },
+
+    async showTransport() {
+        const data = await API.getTransport();
+        if (data.error) {
+            Components.showToast(data.error.message, 'warning');
+            return;
+        }
+        const container = document.createElement('div');
+        container.id = 'job-board-container';
+        container.innerHTML = Components.transportModal(data);
+        document.body.appendChild(container);
+    },
+
+    async acquireTransport(transportId) {
+        const result = await API.acquireTransport(transportId);
+        if (result.error) {
+            Components.showToast(result.error.message, 'warning');
+            return;
+        }
+        this.prevState = this.currentState;
+        this.currentState = result.state;
+        Components.updateStats(result.state, this.prevState);
+        Components.showToast(result.feedback.join(' · '), 'info');
+        this.closeJobBoard();
+    },
+
+    async moveHousing(housingId) {
+        const result = await API.moveHousing(housingId);
+        if (result.error) {
+            Components.showToast(result.error.message, 'warning');
+            return;
+        }
+        this.prevState = this.currentState;
+        this.currentState = result.state;
+        Components.updateStats(result.state, this.prevState);
+        Components.showToast(result.feedback.join(' · '), 'info');
+        this.closeJobBoard();
+    },
+
+    async applyForJob(jobId) {
+        const result = await API.applyJob(jobId);
+        if (result.error) {
+            Components.showToast(result.error.message, 'warning');
+            return;
+        }
+        Components.showToast(result.message, result.application.gotInterview ? 'success' : 'info');
+        this.closeJobBoard();
+    },
+
+    async quitJob() {
+        const result = await API.changeJob(null);
+        if (result.error) {
+            Components.showToast(result.error.message, 'warning');
+            return;
+        }
+        this.prevState = this.currentState;
+        this.currentState = result.state;
+        Components.updateStats(result.state, this.prevState);
+        Components.showToast(result.feedback.join(' · '), 'info');
+        this.closeJobBoard();
+    },
+
+    async startSideGig(gigId) {
+        await this.updateFromBoard(API.startSideGig(gigId));
+    },
+
+    async dropSideGig(gigId) {
+        await this.updateFromBoard(API.dropSideGig(gigId));
+    },
+
+    async buyInsurance(planId) {
+        await this.updateFromBoard(API.buyInsurance(planId));
+    },
+
+    async cancelInsurance(planId) {
+        await this.updateFromBoard(API.cancelInsurance(planId));
+    },
+
+    async updateFromBoard(request) {
+        const result = await request;
+        if (result.error) {
+            Components.showToast(result.error.message, 'warning');
+            return;
+        }
+        this.prevState = this.currentState;
+        this.currentState = result.state;
+        Components.updateStats(result.state, this.prevState);
+        Components.showToast(result.message, 'info');
+        this.closeJobBoard();
+    },
+
     closeJobBoard() {
-        const modal = document.getElementById('job-board-modal');
+        const modal = document.getElementById('job-board-container');
         if (modal) modal.remove();
     },
 
+    // ─── Leaderboard ────────────────────────────────────
+    async answerQuiz(sourceId, choice) {
+        const result = await API.answerQuiz(sourceId, choice);
+        if (result.error) {
+            Components.showToast(result.error.message, 'warning');
+            return;
+        }
+        const card = document.getElementById(`quiz-${sourceId}`);
+        card.querySelectorAll('.quiz-choice').forEach((button, i) => {
+            button.disabled = true;
+            if (i === result.correctChoice) button.classList.add('correct');
+            else if (i === choice) button.classList.add('wrong');
+        });
+        const verdict = result.answer.correct ? '✅ Correct!' : '❌ Not quite.';
+        card.querySelector('.quiz-explanation').textContent = `${verdict} ${result.explanation || ''}`;
+    },
+
+    async saveReflection() {
+        const input = document.getElementById('journal-text');
+        const result = await API.addJournalEntry(input.value.trim());
+        if (result.error) {
+            Components.showToast(result.error.message, 'warning');
+            return;
+        }
+        input.value = '';
+        Components.showToast(`📝 Reflection saved for turn ${result.entry.turn}`, 'info');
+    },
+
+    async submitToLeaderboard() {
+        const name = document.getElementById('leaderboard-name').value.trim();
+        const result = await API.submitRun(name);
+        if (result.error) {
+            Components.showToast(result.error.message, 'warning');
+            return;
+        }
+        const { rank, score } = result.entry;
+        Components.showToast(result.duplicate
+            ? `Already on the board at #${rank}`
+            : `🏆 Ranked #${rank} with ${score.total} points (grade ${score.grade})`, 'info');
+    },
+
     // ─── Share Seed ─────────────────────────────────────
     async shareSeed(seed) {
         try {
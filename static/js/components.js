@@ -6,28 +6,46 @@ const Components = {
         const icons = {
             money: '💰', stress: '😰', support: '🤝',
             timeSlots: '⏰', credentials: '📚',
-            bills: '🏠', emergencyFund: '🏦',
+            bills: '🏠', emergencyFund: '🏦', savings: '🐖',
         };
         return icons[stat] || '•';
     },
 
+    // ─── Credential Names ───────────────────────────────
+    // "Spanish" at level 1, then "Spanish II", "Spanish III", … (matches Credential::name)
+    credentialName(credential) {
+        if (credential.level <= 1) return credential.tag;
+        const numerals = [[100, 'C'], [90, 'XC'], [50, 'L'], [40, 'XL'], [10, 'X'], [9, 'IX'], [5, 'V'], [4, 'IV'], [1, 'I']];
+        let n = credential.level;
+        let roman = '';
+        for (const [value, numeral] of numerals) {
+            while (n >= value) { roman += numeral; n -= value; }
+        }
+        return `${credential.tag} ${roman}`;
+    },
+
     // ─── Render Effects Tags ────────────────────────────
     effectTags(effects) {
         return effects.map(e => {
-            const sign = e.delta >= 0 ? '+' : '';
+            const amount = e.percent ?? e.delta;
+            const sign = amount >= 0 ? '+' : '';
+            const unit = e.percent != null ? '%' : '';
             const icon = this.statIcon(e.stat);
-            const cls = e.delta >= 0 ? 'effect-positive' : 'effect-negative';
-            return `<span class="effect-tag ${cls}">${icon} ${sign}${e.delta}</span>`;
+            const cls = amount >= 0 ? 'effect-positive' : 'effect-negative';
+            const onlyIf = e.when ? ' title="Only in some situations"' : '';
+            const mark = e.when ? '*' : '';
+            return `<span class="effect-tag ${cls}"${onlyIf}>${icon} ${sign}${amount}${unit}${mark}</span>`;
         }).join('');
     },
 
     // ─── Stats Bar ──────────────────────────────────────
-    updateStats(state, prevState) {
+    /** Refresh the stats bar. `changes` is the turn's statChanges, when there is one. */
+    updateStats(state, prevState, changes = []) {
         const stats = [
-            { id: 'stat-money', val: `$${state.money}`, prev: prevState?.money },
-            { id: 'stat-stress', val: `${state.stress}/100`, prev: prevState?.stress },
-            { id: 'stat-support', val: `${state.support}/10`, prev: prevState?.support },
-            { id: 'stat-time', val: `${state.timeSlots} slots`, prev: prevState?.timeSlots },
+            { id: 'stat-money', stat: 'money', val: `$${state.money}` },
+            { id: 'stat-stress', stat: 'stress', val: `${state.stress}/100` },
+            { id: 'stat-support', stat: 'support', val: `${state.support}/10` },
+            { id: 'stat-time', stat: 'timeSlots', val: `${state.timeSlots} slots` },
         ];
 
         for (const s of stats) {
@@ -36,31 +54,35 @@ const Components = {
             const oldText = el.textContent;
             el.textContent = s.val;
 
-            // Animate on change
+            // Animate on change, in the direction the turn moved the stat
             if (oldText !== s.val && prevState) {
                 const parent = el.parentElement;
                 parent.classList.remove('stat-flash-up', 'stat-flash-down');
                 void parent.offsetWidth; // reflow
-                const curr = parseFloat(s.val.replace(/[^0-9.-]/g, ''));
-                const prev = s.prev ?? curr;
-                parent.classList.add(curr > prev ? 'stat-flash-up' : 'stat-flash-down');
+                const change = changes.find(c => c.stat === s.stat);
+                const delta = change ? change.delta : state[s.stat] - (prevState[s.stat] ?? state[s.stat]);
+                parent.classList.add(delta > 0 ? 'stat-flash-up' : 'stat-flash-down');
             }
         }
 
         // Credentials
         const creds = state.credentials.length > 0
-            ? state.credentials.map(c => `<span class="cred-tag">${c}</span>`).join(' ')
+            ? state.credentials.map(c => `<span class="cred-tag">${this.credentialName(c)}</span>`).join(' ')
             : 'No credentials yet';
         document.getElementById('stat-credentials').innerHTML = creds;
 
         // Bills & Emergency Fund (visible in EarlyAdult, or when set)
         const billsRow = document.getElementById('stat-bills-row');
         const efundRow = document.getElementById('stat-efund-row');
+        const savingsRow = document.getElementById('stat-savings-row');
+        const loanRow = document.getElementById('stat-loan-row');
 
         if (billsRow) {
             if (state.monthlyBills > 0 || state.currentStage === 'early-adult') {
                 billsRow.style.display = '';
-                document.getElementById('stat-bills').textContent = `$${state.monthlyBills}/turn`;
+                const home = state.housing ? state.housing.title : 'Housing';
+                document.getElementById('stat-bills').innerHTML =
+                    `<span class="job-link" onclick="Game.showHousing()">${home}</span> · $${state.monthlyBills}/turn`;
             } else {
                 billsRow.style.display = 'none';
             }
@@ -73,6 +95,43 @@ const Components = {
                 efundRow.style.display = 'none';
             }
         }
+        if (savingsRow) {
+            if (state.savings > 0) {
+                savingsRow.style.display = '';
+                document.getElementById('stat-savings').textContent = `$${state.savings}`;
+            } else {
+                savingsRow.style.display = 'none';
+            }
+        }
+        if (loanRow) {
+            if (state.studentLoan > 0 || state.enrollment) {
+                loanRow.style.display = '';
+                const school = state.enrollment
+                    ? ` · ${state.enrollment.program.title} ${state.enrollment.turnsCompleted}/${state.enrollment.program.durationTurns}`
+                    : '';
+                document.getElementById('stat-loan').textContent = `$${state.studentLoan} owed${school}`;
+            } else {
+                loanRow.style.display = 'none';
+            }
+        }
+
+        const pathRow = document.getElementById('stat-path-row');
+        if (pathRow) {
+            pathRow.style.display = state.path ? '' : 'none';
+            if (state.path) document.getElementById('stat-path').textContent = state.path.title;
+        }
+
+        const transportRow = document.getElementById('stat-transport-row');
+        if (transportRow) {
+            if (state.transport || state.currentStage !== 'middle-school') {
+                transportRow.style.display = '';
+                const ride = state.transport ? state.transport.title : 'Get around…';
+                document.getElementById('stat-transport').innerHTML =
+                    `<span class="job-link" onclick="Game.showTransport()">${ride}</span>`;
+            } else {
+                transportRow.style.display = 'none';
+            }
+        }
 
         // Job display with growth progress
         const jobRow = document.getElementById('stat-job-row');
@@ -100,14 +159,15 @@ const Components = {
     },
 
     // ─── Stage Info ─────────────────────────────────────
+    // Display names from /api/stages, filled in at boot
+    stageNames: {},
+
+    stageName(stage) {
+        return this.stageNames[stage] || stage;
+    },
+
     updateStageInfo(stage, turn) {
-        const names = {
-            'middle-school': 'Middle School',
-            'high-school': 'High School',
-            'post-high': 'Post-High',
-            'early-adult': 'Early Adult',
-        };
-        const display = names[stage] || stage;
+        const display = this.stageName(stage);
         document.getElementById('stage-label').textContent = `Stage: ${display}`;
         document.getElementById('turn-label').textContent = `Turn: ${turn}`;
     },
@@ -119,10 +179,23 @@ const Components = {
         const specialLabel = action.specialEffect
             ? `<div class="special-tag">✨ ${action.specialEffect === 'emergency_fund_deposit' ? 'Adds to Emergency Fund' : 'Reduces Bills'}</div>`
             : '';
+        const cooldown = action.cooldownRemaining || 0;
+        const usedUp = action.usesLeft === 0;
+        const lockReasons = action.lockReasons || [];
+        const locked = lockReasons.length > 0 || usedUp || cooldown > 0;
+        const requiresHtml = lockReasons
+            .map(r => `<div class="requires-tag locked">🔑 ${r}</div>`)
+            .join('');
+        const usesHtml = action.usesLeft !== undefined
+            ? `<div class="uses-left">🔁 ${action.usesLeft} use${action.usesLeft === 1 ? '' : 's'} left</div>`
+            : '';
+        const lockText = lockReasons.length > 0 ? '🔒 Locked'
+            : usedUp ? '🚫 Used up'
+            : `⏳ Ready in ${cooldown} turn${cooldown === 1 ? '' : 's'}`;
         return `
-            <div class="action-card ${isSelected ? 'selected' : ''}" 
+            <div class="action-card ${isSelected ? 'selected' : ''} ${locked ? 'locked' : ''}" 
                  data-id="${action.id}" 
-                 onclick="Game.toggleAction(this, '${action.id}')">
+                 onclick="${locked ? '' : `Game.toggleAction(this, '${action.id}')`}">
                 <div class="card-header">
                     <span class="action-name">${action.label}</span>
                     <span class="time-cost">⏰ ${timeCost}</span>
@@ -130,6 +203,51 @@ const Components = {
                 <div class="action-desc">${action.description}</div>
                 <div class="action-effects">${effectsHtml}</div>
                 ${specialLabel}
+                ${usesHtml}
+                ${requiresHtml}
+                ${locked ? `<div class="lock-overlay">${lockText}</div>` : ''}
+            </div>
+        `;
+    },
+
+    // ─── Epilogue ───────────────────────────────────────
+    epilogue(epilogue) {
+        return `
+            <div class="epilogue">
+                ${epilogue.paragraphs.map(p => `<p class="ending-narrative">${p}</p>`).join('')}
+            </div>
+        `;
+    },
+
+    // ─── Final Score ────────────────────────────────────
+    scoreCard(score) {
+        const parts = [
+            ['Money', score.money],
+            ['Low stress', score.lowStress],
+            ['Support', score.support],
+            ['Credentials', score.credentials],
+            ['Debt-free', score.debtFree],
+            ['Ending', score.ending],
+        ];
+        return `
+            <div class="final-score">
+                <div class="score-grade grade-${score.grade.toLowerCase()}">${score.grade}</div>
+                <div class="score-total">${score.total} points</div>
+                <div class="score-parts">
+                    ${parts.map(([label, points]) => `<span>${label} +${points}</span>`).join('')}
+                </div>
+            </div>
+        `;
+    },
+
+    // ─── Putting a Decision Off ─────────────────────────
+    deferNote(consequence) {
+        const turns = consequence.returnsAfter;
+        const returns = turns == null ? "It won't come back."
+            : `It comes back in ${turns} turn${turns === 1 ? '' : 's'}.`;
+        return `
+            <div class="defer-note">
+                ⏸️ You can put this off. ${this.effectTags(consequence.effects || [])} ${returns}
             </div>
         `;
     },
@@ -147,8 +265,8 @@ const Components = {
             ? `<div class="requires-tag ${locked ? 'locked' : 'unlocked'}">🔑 Requires: ${option.requiresTag}</div>`
             : '';
 
-        const jobHtml = option.setsJob
-            ? `<div class="sets-job">💼 Assigns job</div>`
+        const jobHtml = option.appliesFor
+            ? `<div class="sets-job">💼 Starts a job application</div>`
             : '';
         const billsHtml = option.setBills !== undefined && option.setBills !== null
             ? `<div class="sets-bills">🏠 Bills: $${option.setBills}/turn</div>`
@@ -167,6 +285,16 @@ const Components = {
         `;
     },
 
+    // Projected stats after picking a decision option
+    decisionPreview(preview) {
+        return `
+            <div class="decision-preview">
+                If you pick this: 💰 $${preview.money} · 😰 ${preview.stress} · 🤝 ${preview.support}
+                ${preview.monthlyBills ? ` · 🏠 $${preview.monthlyBills}/turn` : ''}
+            </div>
+        `;
+    },
+
     // ─── Event Option Card ──────────────────────────────
     eventOptionCard(option, index, isSelected, playerSupport = 10) {
         const effectsHtml = this.effectTags(option.effects);
@@ -188,13 +316,29 @@ const Components = {
     },
 
     // ─── Stage Transition Screen ────────────────────────
-    stageTransitionScreen(oldStage, newStage, state) {
-        const names = {
-            'middle-school': 'Middle School',
-            'high-school': 'High School',
-            'post-high': 'Post-High',
-            'early-adult': 'Early Adult',
-        };
+    /** The recap of a finished stage: how the stats moved, what was earned, what happened. */
+    stageReport(report) {
+        if (!report) return '';
+        const signed = (n, prefix = '') => `${n >= 0 ? '+' : '-'}${prefix}${Math.abs(n)}`;
+        const change = report.change;
+        const credentials = report.credentialsEarned.map(c => this.credentialName(c)).join(', ');
+        const event = report.biggestEvent;
+        return `
+            <div class="stage-report">
+                <div class="stage-report-changes">
+                    <span>💰 ${signed(change.money, '$')}</span>
+                    <span>😰 ${signed(change.stress)}</span>
+                    <span>🤝 ${signed(change.support)}</span>
+                    <span>🏦 ${signed(change.savings, '$')}</span>
+                </div>
+                <p>${report.decisionsMade} decision${report.decisionsMade === 1 ? '' : 's'} made
+                    ${credentials ? `· Earned ${credentials}` : ''}</p>
+                ${event ? `<p>Biggest moment: <strong>${event.title}</strong> (turn ${event.turn}) — you chose ${event.choice}.</p>` : ''}
+            </div>
+        `;
+    },
+
+    stageTransitionScreen(oldStage, newStage, state, report) {
         const themes = {
             'middle-school': { emoji: '📚', color: '#4fc3f7' },
             'high-school': { emoji: '🎓', color: '#ab47bc' },
@@ -202,8 +346,8 @@ const Components = {
             'early-adult': { emoji: '💼', color: '#ffa726' },
         };
 
-        const oldName = names[oldStage] || oldStage;
-        const newName = names[newStage] || newStage;
+        const oldName = this.stageName(oldStage);
+        const newName = this.stageName(newStage);
         const newTheme = themes[newStage] || { emoji: '🌟', color: '#fff' };
 
         const stageHints = {
@@ -219,6 +363,7 @@ const Components = {
                         <div class="transition-check">✅</div>
                         <h3>${oldName} Complete</h3>
                     </div>
+                    ${this.stageReport(report)}
                     <div class="transition-divider"></div>
                     <div class="transition-upcoming">
                         <div class="transition-emoji">${newTheme.emoji}</div>
@@ -240,6 +385,21 @@ const Components = {
     },
 
     // ─── Feedback Item ──────────────────────────────────
+    /** The turn's stat changes, each with the phases that caused it. */
+    statChanges(changes) {
+        if (!changes || changes.length === 0) return '';
+        const labels = { money: '💰 Money', stress: '😰 Stress', support: '🤝 Support', timeSlots: '⏰ Time', savings: '🐖 Savings', emergencyFund: '🏦 Emergency fund' };
+        const signed = n => `${n > 0 ? '+' : ''}${n}`;
+        const rows = changes.map(c => `
+            <tr>
+                <td>${labels[c.stat] || c.stat}</td>
+                <td>${c.before} → ${c.after} (${signed(c.delta)})</td>
+                <td class="stat-change-phases">${c.byPhase.map(p => `${p.phase} ${signed(p.delta)}`).join(' · ')}</td>
+            </tr>
+        `).join('');
+        return `<table class="stat-changes">${rows}</table>`;
+    },
+
     feedbackItem(msg) {
         let cls = 'neutral';
         if (msg.includes('⚠️')) cls = 'warning';
@@ -248,7 +408,59 @@ const Components = {
         return `<div class="feedback-item ${cls}">${msg}</div>`;
     },
 
+    // ─── Quiz Question ──────────────────────────────────
+    quizCard(sourceId, quiz) {
+        return `
+            <div class="quiz-card" id="quiz-${sourceId}">
+                <div class="quiz-prompt">❓ ${quiz.prompt}</div>
+                ${quiz.choices.map((choice, i) => `
+                    <button class="btn btn-outline quiz-choice" onclick="Game.answerQuiz('${sourceId}', ${i})">${choice}</button>
+                `).join('')}
+                <div class="quiz-explanation"></div>
+            </div>
+        `;
+    },
+
+    // ─── Live Turn (streamed over /ws) ───────────────────
+    liveTurnCard() {
+        return `
+            <div class="phase-card feedback-card">
+                <div class="phase-label">Phase 4 of 4 — Results</div>
+                <h2 id="live-turn-title">⏳ Resolving turn…</h2>
+                <div class="feedback-list" id="live-feedback"></div>
+            </div>
+        `;
+    },
+
+    liveTurnUpdate(update) {
+        switch (update.type) {
+            case 'eventDrawn':
+                return `<div class="event-summary"><span class="event-summary-title">🃏 ${update.event.title}</span></div>`;
+            case 'feedback':
+                return this.feedbackItem(update.message);
+            case 'stressWarning':
+                return `<div class="turn-warning">⚠️ ${update.message}</div>`;
+            case 'stageTransition':
+                return `<div class="stage-transition-banner">🎓 Advancing to <strong>${this.stageName(update.newStage)}</strong></div>`;
+            default:
+                return '';
+        }
+    },
+
     // ─── Turn Log Entry ─────────────────────────────────
+    /** One milestone in the side panel: reached, missed, or a progress bar toward it. */
+    milestone(m) {
+        const status = m.reachedTurn ? `✅ Turn ${m.reachedTurn}` : m.missed ? '❌ Missed' : `${m.progress}%`;
+        const state = m.reachedTurn ? 'reached' : m.missed ? 'missed' : 'open';
+        return `
+            <div class="milestone milestone-${state}" title="${m.unmet.join('\n') || m.description}">
+                <div class="milestone-title">${m.title}</div>
+                <div class="milestone-bar"><div style="width: ${m.progress}%"></div></div>
+                <div class="milestone-status">${status}</div>
+            </div>
+        `;
+    },
+
     turnLogEntry(turnNum, stage, feedback) {
         const stageNames = {
             'middle-school': 'MS',
@@ -268,19 +480,13 @@ const Components = {
 
     // ─── Timeline Entry (Ending Screen) ─────────────────
     timelineEntry(entry, index) {
-        const stageLabels = {
-            'middle-school': 'Middle School',
-            'high-school': 'High School',
-            'post-high': 'Post-High',
-            'early-adult': 'Early Adult',
-        };
         const stageAbbrev = {
             'middle-school': 'MS',
             'high-school': 'HS',
             'post-high': 'PH',
             'early-adult': 'EA',
         };
-        const stageLabel = stageLabels[entry.stage] || entry.stage;
+        const stageLabel = this.stageName(entry.stage);
         const stageShort = stageAbbrev[entry.stage] || entry.stage;
 
         // Parse impact string into colored tags
@@ -322,6 +528,136 @@ const Components = {
         `;
     },
 
+    // ─── Stats Chart (Ending Screen) ────────────────────
+    statsChart(history) {
+        if (!history || history.length < 2) return '';
+        const series = [
+            { key: 'money', label: '💰 Money', color: 'var(--success)' },
+            { key: 'stress', label: '😰 Stress', color: 'var(--danger)' },
+            { key: 'support', label: '🤝 Support', color: 'var(--accent)' },
+            { key: 'timeSlots', label: '⏰ Time', color: 'var(--purple)' },
+        ];
+        const width = 300, height = 48;
+        const rows = series.map(({ key, label, color }) => {
+            const values = history.map(h => h[key]);
+            const min = Math.min(...values), max = Math.max(...values);
+            const span = max - min || 1;
+            const points = values.map((v, i) => {
+                const x = (i / (values.length - 1)) * width;
+                const y = height - 4 - ((v - min) / span) * (height - 8);
+                return `${x.toFixed(1)},${y.toFixed(1)}`;
+            }).join(' ');
+            return `
+                <div class="stats-chart-row">
+                    <div class="stats-chart-label">${label}<span>${min} – ${max}</span></div>
+                    <svg viewBox="0 0 ${width} ${height}" preserveAspectRatio="none">
+                        <polyline points="${points}" fill="none" stroke="${color}" stroke-width="2" vector-effect="non-scaling-stroke" />
+                    </svg>
+                </div>
+            `;
+        }).join('');
+        return `
+            <div class="stats-chart">
+                <h3>📈 Your Run, Turn by Turn</h3>
+                ${rows}
+            </div>
+        `;
+    },
+
+    // ─── Run Summary (Ending Screen) ────────────────────
+    runSummary(summary) {
+        if (!summary || !summary.turnsPlayed) return '';
+        const answers = Object.entries(summary.eventOptions || {})
+            .map(([letter, count]) => `${letter}: ${count}`).join(' · ') || 'none';
+        const creds = (summary.credentialsByStage || []).map(s => `
+            <div class="summary-row">
+                <span>🎓 ${s.stage.replace(/-/g, ' ')}</span>
+                <span>${s.credentials.length > 0 ? s.credentials.join(', ') : '—'}</span>
+            </div>
+        `).join('');
+        return `
+            <div class="run-summary">
+                <h3>📊 Your Run in Numbers</h3>
+                <div class="summary-row"><span>💵 Money earned</span><span>$${summary.moneyEarned}</span></div>
+                <div class="summary-row"><span>💸 Money spent</span><span>$${summary.moneySpent}</span></div>
+                <div class="summary-row"><span>📉 Lowest balance</span><span>$${summary.lowestMoney}</span></div>
+                <div class="summary-row"><span>🧾 Turns in debt</span><span>${summary.turnsInDebt} of ${summary.turnsPlayed}</span></div>
+                <div class="summary-row"><span>😰 Peak stress</span><span>${summary.peakStress}${summary.peakStressTurn ? ` (turn ${summary.peakStressTurn})` : ''}</span></div>
+                <div class="summary-row"><span>🃏 Event answers</span><span>${answers}</span></div>
+                ${creds}
+            </div>
+        `;
+    },
+
+    // ─── Badges (Ending Screen) ─────────────────────────
+    badgeList(badges) {
+        if (!badges || badges.length === 0) return '';
+        return `
+            <div class="badge-list">
+                ${badges.map(b => `<span class="earned-badge" title="${b.narrative}">🎖️ ${b.title}</span>`).join('')}
+            </div>
+        `;
+    },
+
+    // ─── Other Endings (Ending Screen) ──────────────────
+    /** "You were $40 away from ..." for each ending the run only just missed. */
+    nearMisses(nearMisses) {
+        if (!nearMisses || nearMisses.length === 0) return '';
+        return `
+            <div class="near-misses">
+                ${nearMisses.map(n => `<p class="near-miss">🎯 ${n.message}</p>`).join('')}
+            </div>
+        `;
+    },
+
+    endingsList(endings) {
+        endings = (endings || []).filter(e => !e.badge);
+        if (endings.length < 2) return '';
+        const rows = endings.map(e => {
+            const status = e.status || {};
+            const icon = status.reached ? '🏆' : status.missed ? '🔒' : '❔';
+            const unmet = (status.unmet || []).length > 0
+                ? `<div class="ending-unmet">Needed: ${status.unmet.join(', ')}</div>`
+                : '';
+            return `
+                <div class="ending-row ${status.reached ? 'reached' : ''}">
+                    <span class="ending-row-title">${icon} ${e.title}</span>
+                    ${status.reached ? '' : unmet}
+                </div>
+            `;
+        }).join('');
+        return `
+            <div class="endings-list">
+                <h3>🗺️ Other Ways It Could Have Gone</h3>
+                ${rows}
+            </div>
+        `;
+    },
+
+    // ─── Endings Gallery (Ending Screen) ────────────────
+    endingsGallery(gallery) {
+        if (!gallery || !gallery.endings) return '';
+        const tiles = gallery.endings.map(e => e.unlocked
+            ? `
+                <div class="gallery-tile unlocked">
+                    <div class="gallery-title">${e.badge ? '🎖️' : '🏆'} ${e.title}</div>
+                    <div class="gallery-meta">Reached ${e.timesReached}×</div>
+                </div>
+            `
+            : `
+                <div class="gallery-tile locked">
+                    <div class="gallery-title">🔒 ${e.title}</div>
+                    <div class="gallery-hint">${e.hint}</div>
+                </div>
+            `).join('');
+        return `
+            <div class="endings-gallery">
+                <h3>🖼️ Endings Gallery (${gallery.unlockedCount}/${gallery.total})</h3>
+                <div class="gallery-grid">${tiles}</div>
+            </div>
+        `;
+    },
+
     // ─── Toast Notification ─────────────────────────────
     showToast(message, type = 'info') {
         const container = document.getElementById('toast-container');
@@ -337,13 +673,79 @@ const Components = {
         }, 3000);
     },
 
+    // ─── Housing Modal ──────────────────────────────────
+    housingModal(housingData) {
+        const cards = housingData.tiers.map(h => `
+            <div class="jb-card${h.isCurrent ? ' jb-current-card' : ''}">
+                <div class="jb-card-header">
+                    <h4>${h.title}${h.isCurrent ? ' ⭐' : ''}</h4>
+                </div>
+                <p class="jb-desc">${h.description}</p>
+                <div class="jb-stats">
+                    <span class="jb-pay">🏠 $${h.bills}/turn</span>
+                    <span class="jb-stress">😰 ${h.stressPerTurn >= 0 ? '+' : ''}${h.stressPerTurn}</span>
+                    ${h.movingCost > 0 ? `<span>🚚 $${h.movingCost} to move</span>` : ''}
+                </div>
+                ${h.isCurrent
+                ? ''
+                : `<button class="btn btn-secondary jb-apply" onclick="Game.moveHousing('${h.id}')">Move Here</button>`}
+            </div>
+        `).join('');
+
+        return `
+            <div class="jb-overlay" onclick="Game.closeJobBoard()">
+                <div class="jb-modal" onclick="event.stopPropagation()">
+                    <div class="jb-modal-header">
+                        <h3>🏠 Housing</h3>
+                        <button class="jb-close" onclick="Game.closeJobBoard()">✕</button>
+                    </div>
+                    <div class="jb-grid">${cards}</div>
+                </div>
+            </div>
+        `;
+    },
+
+    // ─── Transport Modal ────────────────────────────────
+    transportModal(transportData) {
+        const cards = transportData.options.map(t => `
+            <div class="jb-card${t.isCurrent ? ' jb-current-card' : ''}">
+                <div class="jb-card-header">
+                    <h4>${t.title}${t.isCurrent ? ' ⭐' : ''}</h4>
+                </div>
+                <p class="jb-desc">${t.description}</p>
+                <div class="jb-stats">
+                    <span class="jb-pay">💵 $${t.purchaseCost} up front · $${t.costPerTurn}/turn</span>
+                    <span class="jb-stress">😰 +${t.stressPerTurn}</span>
+                    ${t.breakdownChance > 0 ? `<span>🔧 ${Math.round(t.breakdownChance * 100)}% breakdown</span>` : ''}
+                </div>
+                ${t.grantsTag ? `<div class="jb-tags"><span class="jb-tag jb-tag-ok">🔑 ${t.grantsTag}</span></div>` : ''}
+                ${t.isCurrent
+                ? ''
+                : `<button class="btn btn-secondary jb-apply" onclick="Game.acquireTransport('${t.id}')">Switch</button>`}
+            </div>
+        `).join('');
+
+        return `
+            <div class="jb-overlay" onclick="Game.closeJobBoard()">
+                <div class="jb-modal" onclick="event.stopPropagation()">
+                    <div class="jb-modal-header">
+                        <h3>🚗 Transportation</h3>
+                        <button class="jb-close" onclick="Game.closeJobBoard()">✕</button>
+                    </div>
+                    <div class="jb-grid">${cards}</div>
+                </div>
+            </div>
+        `;
+    },
+
     // ─── Job Board Modal ────────────────────────────────
     jobBoardModal(jobsData) {
-        const { jobs, currentJob } = jobsData;
+        const { jobs, currentJob, sideGigs = [], insurancePlans = [] } = jobsData;
 
         const currentInfo = currentJob
             ? `<div class="jb-current">
                 <span>Current: <strong>${currentJob.jobTitle}</strong></span>
+                <button class="btn btn-outline jb-quit" onclick="Game.quitJob()">Quit</button>
                 ${currentJob.growthRate > 0
                 ? `<span class="jb-growth">🌱 ${currentJob.jobTurns}/${currentJob.growthRate} → ${currentJob.growthTag || '?'}</span>`
                 : ''}
@@ -385,10 +787,64 @@ const Components = {
                     ${reqTags ? `<div class="jb-tags"><small>Required:</small> ${reqTags}</div>` : ''}
                     ${recTags ? `<div class="jb-tags"><small>Recommended:</small> ${recTags}</div>` : ''}
                     ${growthInfo}
+                    ${j.eligible && !j.isCurrent
+                ? `<button class="btn btn-secondary jb-apply" onclick="Game.applyForJob('${j.id}')">
+                        Apply (${Math.round(j.hireChance * 100)}% interview chance)
+                       </button>`
+                : ''}
+                </div>
+            `;
+        }).join('');
+
+        const gigCards = sideGigs.map(g => {
+            const classes = ['jb-card'];
+            if (g.isActive) classes.push('jb-current-card');
+            if (!g.eligible) classes.push('jb-locked');
+
+            const reqTags = (g.requiredTags || []).map(t => {
+                const has = !g.missingRequired.includes(t);
+                return `<span class="jb-tag ${has ? 'jb-tag-ok' : 'jb-tag-missing'}">${has ? '✅' : '🔒'} ${t}</span>`;
+            }).join('');
+
+            const button = g.isActive
+                ? `<button class="btn btn-outline jb-apply" onclick="Game.dropSideGig('${g.id}')">Drop</button>`
+                : g.eligible
+                    ? `<button class="btn btn-secondary jb-apply" onclick="Game.startSideGig('${g.id}')">Start</button>`
+                    : '';
+
+            return `
+                <div class="${classes.join(' ')}">
+                    <div class="jb-card-header">
+                        <h4>${g.title}${g.isActive ? ' ⭐' : ''}</h4>
+                    </div>
+                    <p class="jb-desc">${g.description}</p>
+                    <div class="jb-stats">
+                        <span class="jb-pay">💰 $${g.payPerTurn}/turn</span>
+                        <span class="jb-stress">😰 +${g.stressPerTurn}</span>
+                        <span>⏰ -${g.timeCost}</span>
+                    </div>
+                    ${reqTags ? `<div class="jb-tags"><small>Required:</small> ${reqTags}</div>` : ''}
+                    ${button}
                 </div>
             `;
         }).join('');
 
+        const planCards = insurancePlans.map(p => `
+                <div class="jb-card${p.isActive ? ' jb-current-card' : ''}">
+                    <div class="jb-card-header">
+                        <h4>${p.title}${p.isActive ? ' ⭐' : ''}</h4>
+                    </div>
+                    <p class="jb-desc">${p.description}</p>
+                    <div class="jb-stats">
+                        <span class="jb-pay">💸 $${p.premiumPerTurn}/turn</span>
+                        <span>🛡️ Max $${p.maxOutOfPocket} per ${p.covers.join('/')} emergency</span>
+                    </div>
+                    ${p.isActive
+                ? `<button class="btn btn-outline jb-apply" onclick="Game.cancelInsurance('${p.id}')">Cancel</button>`
+                : `<button class="btn btn-secondary jb-apply" onclick="Game.buyInsurance('${p.id}')">Sign Up</button>`}
+                </div>
+            `).join('');
+
         return `
             <div class="jb-overlay" onclick="Game.closeJobBoard()">
                 <div class="jb-modal" onclick="event.stopPropagation()">
@@ -398,6 +854,8 @@ const Components = {
                     </div>
                     ${currentInfo}
                     <div class="jb-grid">${cards}</div>
+                    ${gigCards ? `<h3>🛵 Side Gigs</h3><div class="jb-grid">${gigCards}</div>` : ''}
+                    ${planCards ? `<h3>🛡️ Insurance</h3><div class="jb-grid">${planCards}</div>` : ''}
                 </div>
             </div>
         `;
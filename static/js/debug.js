@@ -17,7 +17,7 @@ const DebugPanel = {
             await Game.loadPhase();
             Components.showToast(result.message || 'Skipped to next stage', 'info');
         } else {
-            Components.showToast(result.error || 'Failed', 'warning');
+            Components.showToast(result.error?.message || 'Failed', 'warning');
         }
     },
 
@@ -29,17 +29,34 @@ const DebugPanel = {
             return;
         }
 
-        const actions = phaseData.actions || [];
+        const actions = (phaseData.actions || []).filter(a => !a.cooldownRemaining && a.usesLeft !== 0 && !(a.lockReasons || []).length && a.timeCost <= phaseData.availableTimeSlots);
         const actionIds = actions.length > 0 ? [actions[0].id] : [];
 
+        // First option the player is allowed to pick
+        const decision = phaseData.decision;
+        const credentials = (Game.currentState?.credentials || []).map(c => c.tag);
+        const decisionOptionIndex = decision
+            ? Math.max(0, decision.options.findIndex(o => !o.requiresTag || credentials.includes(o.requiresTag)))
+            : 0;
+        const drawResult = await API.drawEvent();
+        const events = drawResult.events || [];
+        const eventOptionIndices = events.map(event => event.options.length > 0
+            ? Math.max(0, event.options.findIndex(o => !o.requiresSupport || drawResult.playerSupport >= o.requiresSupport))
+            : null);
+
         const choices = {
             actionIds: actionIds,
-            decisionId: phaseData.decision ? phaseData.decision.id : '',
-            decisionOptionIndex: 0,
-            eventOptionIndex: 0,
+            decisionId: decision ? decision.id : '',
+            decisionOptionIndex: decisionOptionIndex,
+            eventOptionIndices: eventOptionIndices,
+            eventId: events.length > 0 ? events[0].id : null,
         };
 
         const result = await API.submitTurn(choices);
+        if (result.error) {
+            Components.showToast(result.error.message, 'warning');
+            return;
+        }
         if (result.state) {
             Game.prevState = Game.currentState;
             Game.currentState = result.state;
@@ -83,6 +100,7 @@ const DebugPanel = {
             { id: 'dbg-support', key: 'support' },
             { id: 'dbg-bills', key: 'monthlyBills' },
             { id: 'dbg-efund', key: 'emergencyFund' },
+            { id: 'dbg-savings', key: 'savings' },
             { id: 'dbg-turn', key: 'turn' },
         ];
 